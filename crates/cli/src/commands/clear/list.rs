@@ -0,0 +1,236 @@
+use crate::{
+    execute::Execute,
+    subgraph::{CliPaginationArgs, CliSubgraphArgs},
+};
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Table;
+use rain_orderbook_common::{
+    csv::TryIntoCsv,
+    subgraph::SubgraphArgs,
+    types::{ClearFlattened, FlattenError, NO_SYMBOL},
+};
+use rain_orderbook_subgraph_client::types::common::{SgBytes, SgClearsListFilterArgs};
+use tracing::info;
+
+#[derive(Args, Clone)]
+pub struct CliClearsListArgs {
+    #[arg(
+        short = 'i',
+        long,
+        help = "Only clears that traded the Order with this ID"
+    )]
+    order_id: Option<String>,
+
+    #[arg(long, help = "Only clears submitted by this clearer address")]
+    clearer: Option<String>,
+
+    #[arg(long, help = "Only clears included in this transaction hash")]
+    tx: Option<String>,
+
+    #[clap(flatten)]
+    pagination_args: CliPaginationArgs,
+
+    #[clap(flatten)]
+    subgraph_args: CliSubgraphArgs,
+}
+
+impl From<CliClearsListArgs> for SgClearsListFilterArgs {
+    fn from(val: CliClearsListArgs) -> Self {
+        Self {
+            order_id: val.order_id.map(SgBytes),
+            clearer: val.clearer.map(SgBytes),
+            transaction_id: val.tx.map(SgBytes),
+        }
+    }
+}
+
+impl Execute for CliClearsListArgs {
+    async fn execute(&self) -> Result<()> {
+        let subgraph_args: SubgraphArgs = self.subgraph_args.clone().into();
+        let filter_args: SgClearsListFilterArgs = self.clone().into();
+
+        if self.pagination_args.csv {
+            let csv_text = subgraph_args
+                .to_subgraph_client()?
+                .clears_list_all(filter_args)
+                .await?
+                .into_iter()
+                .map(|o| o.try_into())
+                .collect::<Result<Vec<ClearFlattened>, FlattenError>>()?
+                .try_into_csv()?;
+
+            println!("{}", csv_text);
+        } else {
+            let table = build_table(
+                subgraph_args
+                    .to_subgraph_client()?
+                    .clears_list(filter_args, self.pagination_args.clone().into())
+                    .await?
+                    .into_iter()
+                    .map(|o| o.try_into())
+                    .collect::<Result<Vec<ClearFlattened>, FlattenError>>()?,
+            )?;
+
+            info!("\n{}", table);
+        }
+
+        Ok(())
+    }
+}
+
+fn build_table(clears: Vec<ClearFlattened>) -> Result<Table> {
+    let mut table = comfy_table::Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_FULL)
+        .set_content_arrangement(comfy_table::ContentArrangement::Dynamic)
+        .set_header(vec![
+            "ID",
+            "Cleared At",
+            "Clearer",
+            "Alice In / Out",
+            "Bob In / Out",
+            "Alice Bounty",
+            "Bob Bounty",
+        ]);
+
+    for clear in clears.into_iter() {
+        table.add_row(vec![
+            clear.id,
+            clear.timestamp_display,
+            clear.clearer.0,
+            format!(
+                "{} / {}",
+                clear.alice_input_display, clear.alice_output_display
+            ),
+            format!("{} / {}", clear.bob_input_display, clear.bob_output_display),
+            format!(
+                "{} {}",
+                clear.alice_bounty_display,
+                clear.alice_bounty_token_symbol.unwrap_or(NO_SYMBOL.into())
+            ),
+            format!(
+                "{} {}",
+                clear.bob_bounty_display,
+                clear.bob_bounty_token_symbol.unwrap_or(NO_SYMBOL.into())
+            ),
+        ]);
+    }
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{
+        hex::encode_prefixed,
+        primitives::{Address, B256},
+    };
+    use httpmock::MockServer;
+    use rain_orderbook_subgraph_client::utils::float::*;
+    use serde_json::{json, Value};
+
+    fn cli_args(subgraph_url: String, csv: bool) -> CliClearsListArgs {
+        CliClearsListArgs {
+            order_id: Some(encode_prefixed(B256::random())),
+            clearer: None,
+            tx: None,
+            subgraph_args: CliSubgraphArgs { subgraph_url },
+            pagination_args: CliPaginationArgs {
+                csv,
+                page_size: 25,
+                page: 1,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_csv_execute_happy() {
+        // mock subgraph with pagination
+        let sg_server = MockServer::start();
+        sg_server.mock(|when, then| {
+            when.body_contains("\"skip\":0")
+                .body_contains("\"trades_\"");
+            then.json_body_obj(&get_sg_response());
+        });
+        sg_server.mock(|_when, then| {
+            then.json_body_obj(&json!({"data": {"clears": []}}));
+        });
+
+        // should succeed
+        assert!(cli_args(sg_server.url("/sg"), true).execute().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_no_csv_execute_happy() {
+        // mock subgraph
+        let sg_server = MockServer::start();
+        sg_server.mock(|_when, then| {
+            then.json_body_obj(&get_sg_response());
+        });
+
+        // should succeed
+        cli_args(sg_server.url("/sg"), false)
+            .execute()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_execute_unhappy() {
+        // should error
+        cli_args("https://bad-url".to_string(), false)
+            .execute()
+            .await
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_filter_args_from_cli() {
+        let args = CliClearsListArgs {
+            order_id: None,
+            clearer: Some("0xclearer".to_string()),
+            tx: Some("0xtx".to_string()),
+            ..cli_args("http://localhost".to_string(), false)
+        };
+        let filter_args: SgClearsListFilterArgs = args.into();
+        assert!(filter_args.order_id.is_none());
+        assert_eq!(filter_args.clearer, Some(SgBytes("0xclearer".to_string())));
+        assert_eq!(
+            filter_args.transaction_id,
+            Some(SgBytes("0xtx".to_string()))
+        );
+    }
+
+    // helper function that returns mocked sg response in json
+    fn get_sg_response() -> Value {
+        let transaction = json!({
+            "id": encode_prefixed(B256::random()),
+            "blockNumber": "0",
+            "timestamp": "0",
+            "from": encode_prefixed(Address::random())
+        });
+        json!({
+            "data": {
+                "clears": [{
+                    "id": encode_prefixed(B256::random()),
+                    "aliceInputAmount": F1,
+                    "aliceOutputAmount": F2,
+                    "bobInputAmount": F2,
+                    "bobOutputAmount": F1,
+                    "aliceBountyAmount": F0,
+                    "bobBountyAmount": F0,
+                    "aliceBountyVaultBalanceChange": null,
+                    "bobBountyVaultBalanceChange": null,
+                    "orderbook": {
+                        "id": encode_prefixed(Address::random()),
+                    },
+                    "trades": [],
+                    "transaction": transaction,
+                    "sender": encode_prefixed(Address::random()),
+                }]
+            }
+        })
+    }
+}
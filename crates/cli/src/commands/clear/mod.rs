@@ -0,0 +1,31 @@
+mod list;
+
+use crate::execute::Execute;
+use anyhow::Result;
+use clap::Parser;
+use list::CliClearsListArgs;
+
+#[derive(Parser)]
+pub enum Clear {
+    #[command(about = "List clears and their bounties", alias = "ls")]
+    List(CliClearsListArgs),
+}
+
+impl Execute for Clear {
+    async fn execute(&self) -> Result<()> {
+        match self {
+            Clear::List(list) => list.execute().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_command() {
+        Clear::command().debug_assert();
+    }
+}
@@ -0,0 +1,743 @@
+use crate::execute::Execute;
+use crate::status::display_write_transaction_status;
+use alloy::primitives::{Address, Bytes};
+use alloy::sol_types::SolCall;
+use alloy_ethers_typecast::WriteTransaction;
+use anyhow::{anyhow, Result};
+use clap::{ArgAction, Args};
+use rain_orderbook_app_settings::order::VaultType;
+use rain_orderbook_bindings::{OrderBook::multicallCall, IERC20::approveCall};
+use rain_orderbook_common::gui::{
+    gas_estimation::DeploymentCostEstimate, order_operations::ExtendedApprovalCalldata,
+    DotrainOrderGui, GuiError,
+};
+use rain_orderbook_common::transaction::TransactionArgs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use tracing::info;
+
+#[derive(Args, Clone)]
+pub struct Deploy {
+    #[arg(
+        short = 'f',
+        long,
+        help = "Path to the .rain file with the gui configuration"
+    )]
+    dotrain_file: PathBuf,
+
+    #[arg(
+        short = 'e',
+        long,
+        help = "GUI deployment key, prompted for when omitted"
+    )]
+    deployment: Option<String>,
+
+    #[arg(
+        short = 'a',
+        long,
+        help = "Path to a yaml or json file with answers for the deployment"
+    )]
+    answers_file: Option<PathBuf>,
+
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Fail on missing answers instead of prompting for them"
+    )]
+    no_prompt: bool,
+
+    #[arg(
+        long,
+        help = "Address that will deploy the order, used to check token allowances"
+    )]
+    owner: Option<Address>,
+
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Sign and broadcast the transactions with a Ledger wallet instead of printing the calldata"
+    )]
+    broadcast: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "Derivation index of the Ledger wallet address to use",
+        default_value = "0"
+    )]
+    derivation_index: Option<usize>,
+
+    #[arg(short = 'p', long, help = "Max priority fee per gas (in wei)")]
+    max_priority_fee_per_gas: Option<u128>,
+
+    #[arg(short, long, help = "Max fee per gas (in wei)")]
+    max_fee_per_gas: Option<u128>,
+
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Estimate gas and cost of each deployment transaction"
+    )]
+    estimate_gas: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "Write the calldata to this file instead of stdout"
+    )]
+    output: Option<PathBuf>,
+}
+
+/// Pre-filled answers for a gui deployment, any value not given here is
+/// prompted for unless prompting is disabled.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct DeployAnswers {
+    pub deployment: Option<String>,
+    pub select_tokens: BTreeMap<String, String>,
+    pub fields: BTreeMap<String, String>,
+    pub deposits: BTreeMap<String, String>,
+    pub vault_ids: VaultIdAnswers,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct VaultIdAnswers {
+    pub input: BTreeMap<String, String>,
+    pub output: BTreeMap<String, String>,
+}
+
+impl DeployAnswers {
+    /// Parses answers from yaml, json documents are accepted as well.
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentCalldata {
+    pub chain_id: u32,
+    pub orderbook_address: Address,
+    pub approvals: Vec<ExtendedApprovalCalldata>,
+    pub deposits: Vec<Bytes>,
+    pub add_order: Bytes,
+    pub multicall: Bytes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_estimate: Option<DeploymentCostEstimate>,
+}
+
+/// Line based prompts, questions go to `output` so stdout is left for the
+/// generated calldata.
+pub struct Prompter<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output }
+    }
+
+    fn ask(&mut self, question: &str) -> Result<String> {
+        write!(self.output, "{question}: ")?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(anyhow!("Input ended before '{question}' was answered"));
+        }
+        Ok(line.trim().to_string())
+    }
+
+    fn say(&mut self, line: &str) -> Result<()> {
+        writeln!(self.output, "{line}")?;
+        Ok(())
+    }
+}
+
+fn gui_error(err: GuiError) -> anyhow::Error {
+    anyhow!(err.to_readable_msg())
+}
+
+fn display_cost_estimate(estimate: &DeploymentCostEstimate) {
+    let currency = estimate.currency.as_deref().unwrap_or("native");
+    for step in &estimate.steps {
+        let name = match &step.symbol {
+            Some(symbol) => format!("{:?} {symbol}", step.kind),
+            None => format!("{:?}", step.kind),
+        };
+        let fallback = if step.fallback {
+            " (deposits at fallback gas until the approvals are mined)"
+        } else {
+            ""
+        };
+        match (&step.gas, &step.cost, &step.error) {
+            (_, _, Some(error)) => info!("{name}: would revert: {error}"),
+            (Some(gas), Some(cost), _) => {
+                info!("{name}: {gas} gas, up to {cost} {currency}{fallback}")
+            }
+            _ => info!("{name}: not estimated"),
+        }
+    }
+    info!(
+        "{}: {} gas, up to {} {currency} at max fee per gas {} wei",
+        if estimate.partial {
+            "Partial total"
+        } else {
+            "Total"
+        },
+        estimate.total_gas,
+        estimate.total_cost,
+        estimate.fees.max_fee_per_gas
+    );
+}
+
+fn check_answer_keys<'a>(
+    kind: &str,
+    answers: impl IntoIterator<Item = &'a String>,
+    known: &[String],
+) -> Result<()> {
+    match answers.into_iter().find(|key| !known.contains(key)) {
+        Some(key) => Err(anyhow!(
+            "Unknown {kind} '{key}' in answers, expected one of: {}",
+            known.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+async fn select_deployment<R: BufRead, W: Write>(
+    dotrain: &str,
+    deployment: Option<String>,
+    prompter: &mut Option<Prompter<R, W>>,
+) -> Result<String> {
+    if let Some(deployment) = deployment {
+        return Ok(deployment);
+    }
+
+    let keys = DotrainOrderGui::get_deployment_keys(dotrain.to_string())
+        .await
+        .map_err(gui_error)?;
+    match (keys.as_slice(), prompter) {
+        ([key], _) => Ok(key.clone()),
+        (_, Some(prompter)) => {
+            prompter.say(&format!("Available deployments: {}", keys.join(", ")))?;
+            loop {
+                let key = prompter.ask("Deployment")?;
+                if keys.contains(&key) {
+                    return Ok(key);
+                }
+                prompter.say(&format!("Unknown deployment '{key}'"))?;
+            }
+        }
+        _ => Err(anyhow!(
+            "A deployment key is required, available deployments: {}",
+            keys.join(", ")
+        )),
+    }
+}
+
+/// Applies the answers to the gui and prompts for anything still missing:
+/// token selections first as they decide the rest of the deployment, then
+/// field values, deposits and finally vault ids.
+pub async fn configure_gui<R: BufRead, W: Write>(
+    gui: &mut DotrainOrderGui,
+    answers: &DeployAnswers,
+    prompter: &mut Option<Prompter<R, W>>,
+) -> Result<()> {
+    let select_tokens = gui.get_select_tokens().map_err(gui_error)?;
+    check_answer_keys(
+        "select token",
+        answers.select_tokens.keys(),
+        &select_tokens
+            .iter()
+            .map(|token| token.key.clone())
+            .collect::<Vec<_>>(),
+    )?;
+    for select_token in select_tokens {
+        let address = match answers.select_tokens.get(&select_token.key) {
+            Some(address) => address.clone(),
+            None => match prompter {
+                Some(prompter) => prompter.ask(&format!(
+                    "Token address for {}",
+                    select_token.name.as_ref().unwrap_or(&select_token.key)
+                ))?,
+                None => continue,
+            },
+        };
+        gui.set_select_token(select_token.key, address)
+            .await
+            .map_err(gui_error)?;
+    }
+
+    // Fields are asked in definition order so the answers that switch optional
+    // fields on come before the fields they reveal
+    let fields = gui.get_current_deployment().map_err(gui_error)?.fields;
+    check_answer_keys(
+        "field",
+        answers.fields.keys(),
+        &fields
+            .iter()
+            .map(|field| field.binding.clone())
+            .collect::<Vec<_>>(),
+    )?;
+    for field in fields {
+        let value = match answers.fields.get(&field.binding) {
+            Some(value) => value.clone(),
+            None if !gui
+                .is_field_visible(field.binding.clone())
+                .map_err(gui_error)? =>
+            {
+                continue
+            }
+            None => match prompter {
+                Some(prompter) => {
+                    if let Some(description) = &field.description {
+                        prompter.say(description)?;
+                    }
+                    for preset in field.presets.iter().flatten() {
+                        match &preset.name {
+                            Some(name) => prompter.say(&format!("  {name}: {}", preset.value))?,
+                            None => prompter.say(&format!("  {}", preset.value))?,
+                        }
+                    }
+                    let question = match &field.default {
+                        Some(default) => format!("{} [{default}]", field.name),
+                        None => field.name.clone(),
+                    };
+                    let value = prompter.ask(&question)?;
+                    if value.is_empty() {
+                        continue;
+                    }
+                    value
+                }
+                None => continue,
+            },
+        };
+        gui.set_field_value(field.binding, value)
+            .map_err(gui_error)?;
+    }
+
+    let deposit_tokens = gui
+        .get_current_deployment()
+        .map_err(gui_error)?
+        .deposits
+        .iter()
+        .filter_map(|deposit| deposit.token.as_ref().map(|token| token.key.clone()))
+        .collect::<Vec<_>>();
+    check_answer_keys("deposit", answers.deposits.keys(), &deposit_tokens)?;
+    for token in deposit_tokens {
+        let amount = match answers.deposits.get(&token) {
+            Some(amount) => amount.clone(),
+            None => match prompter {
+                Some(prompter) => {
+                    let amount = prompter
+                        .ask(&format!("Deposit amount for {token}, leave empty to skip"))?;
+                    if amount.is_empty() {
+                        continue;
+                    }
+                    amount
+                }
+                None => continue,
+            },
+        };
+        gui.set_deposit(token, amount).await.map_err(gui_error)?;
+    }
+
+    for (vault_type, vault_ids) in [
+        (VaultType::Input, &answers.vault_ids.input),
+        (VaultType::Output, &answers.vault_ids.output),
+    ] {
+        for (token, vault_id) in vault_ids {
+            gui.set_vault_id(vault_type.clone(), token.clone(), Some(vault_id.clone()))
+                .map_err(gui_error)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates the approvals for `owner` and the deposit and `addOrder3`
+/// calldata, both on their own and combined into a single multicall. With
+/// `estimate_gas` the generated transactions are estimated as well, a new
+/// generation would pick another order nonce.
+pub async fn generate_deployment_calldata(
+    gui: &mut DotrainOrderGui,
+    owner: Address,
+    estimate_gas: bool,
+) -> Result<DeploymentCalldata> {
+    let args = gui
+        .get_deployment_transaction_args(owner.to_string())
+        .await
+        .map_err(gui_error)?;
+    let gas_estimate = if estimate_gas {
+        Some(
+            gui.estimate_deployment_transactions(owner, &args)
+                .await
+                .map_err(gui_error)?,
+        )
+    } else {
+        None
+    };
+
+    // the multicall always starts with addOrder3 followed by the deposits
+    let mut calls = multicallCall::abi_decode(&args.deployment_calldata)?
+        .data
+        .into_iter();
+    let add_order = calls.next().ok_or(anyhow!(
+        "Deployment multicall is missing the addOrder3 call"
+    ))?;
+
+    Ok(DeploymentCalldata {
+        chain_id: args.chain_id,
+        orderbook_address: args.orderbook_address,
+        approvals: args.approvals,
+        deposits: calls.collect(),
+        add_order,
+        multicall: args.deployment_calldata,
+        gas_estimate,
+    })
+}
+
+impl Deploy {
+    async fn broadcast(&self, gui: &mut DotrainOrderGui) -> Result<()> {
+        let network = gui
+            .get_current_deployment()
+            .map_err(gui_error)?
+            .deployment
+            .order
+            .network
+            .clone();
+        let tx_args = TransactionArgs {
+            derivation_index: self.derivation_index,
+            chain_id: Some(network.chain_id as u64),
+            rpcs: network.rpcs.iter().map(|rpc| rpc.to_string()).collect(),
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            max_fee_per_gas: self.max_fee_per_gas,
+            ..Default::default()
+        };
+        let (ledger_client, owner) = tx_args.clone().try_into_ledger_client().await?;
+        let calldata = generate_deployment_calldata(gui, owner, self.estimate_gas).await?;
+        if let Some(estimate) = &calldata.gas_estimate {
+            display_cost_estimate(estimate);
+        }
+
+        for approval in &calldata.approvals {
+            info!("----- Approve {} -----", approval.symbol);
+            let params = tx_args.try_into_write_contract_parameters(
+                approveCall::abi_decode(&approval.calldata)?,
+                approval.token,
+            )?;
+            WriteTransaction::new(ledger_client.clone(), params, 4, |status| {
+                display_write_transaction_status(status)
+            })
+            .execute()
+            .await?;
+        }
+
+        info!("----- Deposit and Add Order -----");
+        let params = tx_args.try_into_write_contract_parameters(
+            multicallCall::abi_decode(&calldata.multicall)?,
+            calldata.orderbook_address,
+        )?;
+        WriteTransaction::new(ledger_client, params, 4, |status| {
+            display_write_transaction_status(status)
+        })
+        .execute()
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl Execute for Deploy {
+    async fn execute(&self) -> Result<()> {
+        let dotrain = read_to_string(&self.dotrain_file).map_err(|e| anyhow!(e))?;
+        let answers = match &self.answers_file {
+            Some(path) => DeployAnswers::parse(&read_to_string(path).map_err(|e| anyhow!(e))?)?,
+            None => DeployAnswers::default(),
+        };
+        let mut prompter =
+            (!self.no_prompt).then(|| Prompter::new(std::io::stdin().lock(), std::io::stderr()));
+
+        let deployment = select_deployment(
+            &dotrain,
+            self.deployment.clone().or(answers.deployment.clone()),
+            &mut prompter,
+        )
+        .await?;
+        let mut gui = DotrainOrderGui::new_with_deployment(dotrain, deployment, None)
+            .await
+            .map_err(gui_error)?;
+        configure_gui(&mut gui, &answers, &mut prompter).await?;
+
+        if self.broadcast {
+            return self.broadcast(&mut gui).await;
+        }
+
+        let owner = self
+            .owner
+            .ok_or(anyhow!("--owner is required unless --broadcast is set"))?;
+        let calldata = generate_deployment_calldata(&mut gui, owner, self.estimate_gas).await?;
+        let json = serde_json::to_string_pretty(&calldata)?;
+        match &self.output {
+            Some(path) => std::fs::write(path, json)?,
+            None => println!("{json}"),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+    use std::io::Cursor;
+
+    #[derive(clap::Parser)]
+    struct Cli {
+        #[command(flatten)]
+        deploy: Deploy,
+    }
+
+    fn get_dotrain() -> String {
+        format!(
+            r#"
+version: {}
+gui:
+    name: Test gui
+    description: Test gui description
+    short-description: Test gui
+    deployments:
+        some-deployment:
+            name: Some deployment
+            description: Some deployment description
+            deposits:
+                - token: token1
+            fields:
+                - binding: price
+                  name: Price
+                  description: Price to sell at
+                  presets:
+                    - name: Low
+                      value: "1"
+                - binding: amount
+                  name: Amount
+                  default: 10
+networks:
+    some-network:
+        rpcs:
+            - https://some-rpc.com
+        chain-id: 123
+        network-id: 123
+        currency: ETH
+subgraphs:
+    some-sg: https://www.some-sg.com
+deployers:
+    some-deployer:
+        network: some-network
+        address: 0xF14E09601A47552De6aBd3A0B165607FaFd2B5Ba
+orderbooks:
+    some-orderbook:
+        address: 0xc95A5f8eFe14d7a20BD2E5BAFEC4E71f8Ce0B9A6
+        network: some-network
+        subgraph: some-sg
+        deployment-block: 12345
+tokens:
+    token1:
+        network: some-network
+        address: 0xc2132d05d31c914a87c6611c10748aeb04b58e8f
+        decimals: 6
+        label: T1
+        symbol: T1
+    token2:
+        network: some-network
+        address: 0x8f3cf7ad23cd3cadbd9735aff958023239c6a063
+        decimals: 18
+        label: T2
+        symbol: T2
+scenarios:
+    some-scenario:
+        deployer: some-deployer
+orders:
+    some-order:
+        inputs:
+            - token: token2
+        outputs:
+            - token: token1
+        deployer: some-deployer
+        orderbook: some-orderbook
+deployments:
+    some-deployment:
+        scenario: some-scenario
+        order: some-order
+---
+#price !
+#amount !
+#calculate-io
+_ _: 0 0;
+#handle-io
+:;
+#handle-add-order
+:;"#,
+            SpecVersion::current()
+        )
+    }
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let yaml = r#"
+deployment: some-deployment
+select-tokens:
+    token1: "0x0000000000000000000000000000000000000001"
+fields:
+    price: "1"
+deposits:
+    token1: "100"
+vault-ids:
+    output:
+        token1: "2"
+"#;
+        let answers = DeployAnswers::parse(yaml).unwrap();
+        assert_eq!(answers.deployment, Some("some-deployment".to_string()));
+        assert_eq!(
+            answers.select_tokens.get("token1"),
+            Some(&"0x0000000000000000000000000000000000000001".to_string())
+        );
+        assert_eq!(answers.fields.get("price"), Some(&"1".to_string()));
+        assert_eq!(answers.deposits.get("token1"), Some(&"100".to_string()));
+        assert!(answers.vault_ids.input.is_empty());
+        assert_eq!(
+            answers.vault_ids.output.get("token1"),
+            Some(&"2".to_string())
+        );
+
+        let json = r#"{"fields": {"price": "1"}, "vault-ids": {"input": {"token2": "3"}}}"#;
+        let answers = DeployAnswers::parse(json).unwrap();
+        assert_eq!(answers.deployment, None);
+        assert!(answers.deposits.is_empty());
+        assert_eq!(answers.fields.get("price"), Some(&"1".to_string()));
+        assert_eq!(
+            answers.vault_ids.input.get("token2"),
+            Some(&"3".to_string())
+        );
+
+        assert!(DeployAnswers::parse("fields: [1, 2]").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_select_deployment() {
+        let dotrain = get_dotrain();
+        let mut prompter: Option<Prompter<Cursor<&[u8]>, Vec<u8>>> = None;
+
+        let deployment = select_deployment(&dotrain, Some("other".to_string()), &mut prompter)
+            .await
+            .unwrap();
+        assert_eq!(deployment, "other");
+
+        // a single deployment is selected without asking
+        let deployment = select_deployment(&dotrain, None, &mut prompter)
+            .await
+            .unwrap();
+        assert_eq!(deployment, "some-deployment");
+    }
+
+    #[tokio::test]
+    async fn test_configure_gui_from_answers_and_prompts() {
+        let mut gui = DotrainOrderGui::new_with_deployment(
+            get_dotrain(),
+            "some-deployment".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let answers = DeployAnswers {
+            fields: BTreeMap::from([("price".to_string(), "1".to_string())]),
+            vault_ids: VaultIdAnswers {
+                input: BTreeMap::from([("token2".to_string(), "7".to_string())]),
+                output: BTreeMap::new(),
+            },
+            ..Default::default()
+        };
+        // the amount field keeps its default and the deposit is prompted for
+        let mut prompter = Some(Prompter::new(Cursor::new(&b"\n50\n"[..]), Vec::new()));
+        configure_gui(&mut gui, &answers, &mut prompter)
+            .await
+            .unwrap();
+
+        let field_values = gui.get_all_field_values().unwrap();
+        assert_eq!(field_values.len(), 1);
+        assert_eq!(field_values[0].field, "price");
+        assert_eq!(field_values[0].value, "1");
+        assert!(field_values[0].is_preset);
+
+        let deposits = gui.get_deposits().unwrap();
+        assert_eq!(deposits.len(), 1);
+        assert_eq!(deposits[0].token, "token1");
+        assert_eq!(deposits[0].amount, "50");
+
+        let vault_ids = gui.get_vault_ids().unwrap();
+        assert_eq!(
+            vault_ids.0["input"]["token2"],
+            Some(alloy::primitives::U256::from(7))
+        );
+
+        let prompts = String::from_utf8(prompter.unwrap().output).unwrap();
+        assert!(prompts.contains("Amount [10]: "));
+        assert!(prompts.contains("Deposit amount for token1, leave empty to skip: "));
+        assert!(!prompts.contains("Price"));
+    }
+
+    #[tokio::test]
+    async fn test_configure_gui_without_prompts() {
+        let mut gui = DotrainOrderGui::new_with_deployment(
+            get_dotrain(),
+            "some-deployment".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let mut prompter: Option<Prompter<Cursor<&[u8]>, Vec<u8>>> = None;
+        configure_gui(&mut gui, &DeployAnswers::default(), &mut prompter)
+            .await
+            .unwrap();
+        assert!(gui.get_all_field_values().unwrap().is_empty());
+        assert!(gui.get_deposits().unwrap().is_empty());
+
+        let answers = DeployAnswers {
+            fields: BTreeMap::from([("missing".to_string(), "1".to_string())]),
+            ..Default::default()
+        };
+        let err = configure_gui(&mut gui, &answers, &mut prompter)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown field 'missing' in answers, expected one of: price, amount"
+        );
+
+        let answers = DeployAnswers {
+            deposits: BTreeMap::from([("token2".to_string(), "1".to_string())]),
+            ..Default::default()
+        };
+        let err = configure_gui(&mut gui, &answers, &mut prompter)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown deposit 'token2' in answers, expected one of: token1"
+        );
+    }
+}
@@ -1,11 +1,16 @@
 mod chart;
+mod clear;
+mod deploy;
 mod order;
 mod quote;
+mod registry;
+mod settings;
 mod subgraph;
 mod trade;
 mod vault;
 mod words;
 
 pub use self::{
-    chart::Chart, order::Order, subgraph::Subgraph, trade::Trade, vault::Vault, words::Words,
+    chart::Chart, clear::Clear, deploy::Deploy, order::Order, registry::Registry,
+    settings::Settings, subgraph::Subgraph, trade::Trade, vault::Vault, words::Words,
 };
@@ -116,6 +116,7 @@ mod tests {
             }],
             deployer: Address::from_str("0xF14E09601A47552De6aBd3A0B165607FaFd2B5Ba").unwrap(),
             bindings: HashMap::new(),
+            gui: None,
         };
         assert_eq!(result, expected);
     }
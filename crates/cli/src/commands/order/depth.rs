@@ -0,0 +1,177 @@
+use crate::execute::Execute;
+use alloy::primitives::Address;
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use comfy_table::Table;
+use rain_orderbook_common::raindex_client::{
+    pair_depth::{RaindexPairDepth, RaindexPairDepthLevel},
+    RaindexClient,
+};
+use std::fs::read_to_string;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+#[derive(Parser, Clone)]
+pub struct CliPairDepthArgs {
+    #[arg(
+        short = 'c',
+        long,
+        help = "Path to a settings yaml file with the networks and orderbooks to query, can be repeated",
+        required = true
+    )]
+    settings_file: Vec<PathBuf>,
+
+    #[arg(
+        short = 'i',
+        long,
+        help = "Token the asks receive and the bids send, prices are quoted in this token"
+    )]
+    input_token: Address,
+
+    #[arg(short = 'o', long, help = "Token the asks send and the bids receive")]
+    output_token: Address,
+
+    #[arg(long, help = "Only query this chain id, can be repeated")]
+    chain_id: Vec<u32>,
+}
+
+impl Execute for CliPairDepthArgs {
+    async fn execute(&self) -> Result<()> {
+        let settings = self
+            .settings_file
+            .iter()
+            .map(read_to_string)
+            .collect::<Result<Vec<String>, _>>()?;
+        let raindex_client =
+            RaindexClient::new(settings, None).map_err(|e| anyhow!(e.to_readable_msg()))?;
+        let depth = raindex_client
+            .get_pair_depth(
+                self.input_token,
+                self.output_token,
+                (!self.chain_id.is_empty()).then(|| self.chain_id.clone()),
+            )
+            .await
+            .map_err(|e| anyhow!(e.to_readable_msg()))?;
+
+        report_failures(&depth);
+        info!("Asks\n{}", build_table(&depth.asks));
+        info!("Bids\n{}", build_table(&depth.bids));
+
+        Ok(())
+    }
+}
+
+fn report_failures(depth: &RaindexPairDepth) {
+    for status in depth.subgraph_statuses.iter() {
+        if let Some(error) = &status.error {
+            warn!("Subgraph {} failed: {}", status.subgraph_name, error);
+        }
+    }
+    for failed_quote in depth.failed_quotes.iter() {
+        warn!(
+            "Quote of order {} on chain {} failed: {}",
+            failed_quote.order_hash, failed_quote.chain_id, failed_quote.error
+        );
+    }
+}
+
+fn build_table(levels: &[RaindexPairDepthLevel]) -> Table {
+    let mut table = comfy_table::Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_FULL)
+        .set_content_arrangement(comfy_table::ContentArrangement::Dynamic)
+        .set_header(vec![
+            "Price",
+            "Max Output",
+            "Cumulative Depth",
+            "Order Hash",
+            "Chain ID",
+        ]);
+
+    for level in levels {
+        table.add_row(vec![
+            level.formatted_price.clone(),
+            level.formatted_max_output.clone(),
+            level.formatted_cumulative_depth.clone(),
+            level.order_hash.to_string(),
+            level.chain_id.to_string(),
+        ]);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use std::str::FromStr;
+
+    #[test]
+    fn verify_cli() {
+        CliPairDepthArgs::command().debug_assert();
+    }
+
+    #[test]
+    fn test_cli_args() {
+        let input_token = "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d";
+        let output_token = "0x12e605bc104e93b45e1ad99f9e555f659051c2bb";
+        let cmd = CliPairDepthArgs::command();
+        let result = cmd.get_matches_from(vec![
+            "cmd",
+            "-c",
+            "./a.yaml",
+            "-i",
+            input_token,
+            "-o",
+            output_token,
+            "--chain-id",
+            "1",
+            "--chain-id",
+            "137",
+        ]);
+        assert_eq!(
+            result.get_many::<PathBuf>("settings_file").unwrap().count(),
+            1
+        );
+        assert_eq!(
+            result.get_one::<Address>("input_token"),
+            Some(&Address::from_str(input_token).unwrap())
+        );
+        assert_eq!(
+            result.get_one::<Address>("output_token"),
+            Some(&Address::from_str(output_token).unwrap())
+        );
+        assert_eq!(
+            result
+                .get_many::<u32>("chain_id")
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 137]
+        );
+    }
+
+    #[test]
+    fn test_settings_file_required() {
+        let result = CliPairDepthArgs::command().try_get_matches_from(vec![
+            "cmd",
+            "-i",
+            "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d",
+            "-o",
+            "0x12e605bc104e93b45e1ad99f9e555f659051c2bb",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_missing_settings_file() {
+        let args = CliPairDepthArgs {
+            settings_file: vec![PathBuf::from("./missing-settings.yaml")],
+            input_token: Address::random(),
+            output_token: Address::random(),
+            chain_id: vec![],
+        };
+        assert!(args.execute().await.is_err());
+    }
+}
@@ -129,6 +129,7 @@ mod tests {
                 hide_zero_balance: None,
                 order_hash: None,
                 tokens: vec![],
+                ..Default::default()
             },
         };
 
@@ -159,6 +160,7 @@ mod tests {
                 hide_zero_balance: None,
                 order_hash: None,
                 tokens: vec![],
+                ..Default::default()
             },
         };
 
@@ -189,6 +191,7 @@ mod tests {
                 hide_zero_balance: None,
                 order_hash: None,
                 tokens: vec![],
+                ..Default::default()
             },
         };
 
@@ -1,6 +1,7 @@
 mod add;
 mod calldata;
 mod compose;
+mod depth;
 mod detail;
 mod list;
 mod listorderfrontmatterkeys;
@@ -16,6 +17,7 @@ use clap::Parser;
 use compose::Compose;
 use listorderfrontmatterkeys::ListOrderFrontmatterKeys;
 
+use depth::CliPairDepthArgs;
 use detail::CliOrderDetailArgs;
 use list::CliOrderListArgs;
 use remove::CliOrderRemoveArgs;
@@ -51,6 +53,9 @@ pub enum Order {
 
     #[command(about = "Get frontmatter keys from a dotrain file", alias = "keys")]
     ListOrderFrontmatterKeys(ListOrderFrontmatterKeys),
+
+    #[command(about = "View the quoted order book depth of a token pair")]
+    Depth(CliPairDepthArgs),
 }
 
 impl Execute for Order {
@@ -64,6 +69,7 @@ impl Execute for Order {
             Order::Calldata(calldata) => calldata.execute().await,
             Order::OrderbookAddress(orderbook_address) => orderbook_address.execute().await,
             Order::ListOrderFrontmatterKeys(keys) => keys.execute().await,
+            Order::Depth(depth) => depth.execute().await,
         }
     }
 }
@@ -0,0 +1,83 @@
+use super::RegistryArgs;
+use crate::execute::Execute;
+use anyhow::{anyhow, Result};
+use clap::Args;
+use comfy_table::Table;
+use rain_orderbook_common::registry::DotrainRegistry;
+use tracing::info;
+
+#[derive(Args, Clone)]
+pub struct Deployments {
+    #[clap(flatten)]
+    registry_args: RegistryArgs,
+
+    #[arg(short = 'k', long, help = "Key of the order in the registry")]
+    order_key: String,
+}
+
+impl Execute for Deployments {
+    async fn execute(&self) -> Result<()> {
+        let registry = self.registry_args.load().await?;
+        let table = build_table(&registry, &self.order_key)?;
+        info!("\n{}", table);
+        Ok(())
+    }
+}
+
+fn build_table(registry: &DotrainRegistry, order_key: &str) -> Result<Table> {
+    let mut table = comfy_table::Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_FULL)
+        .set_content_arrangement(comfy_table::ContentArrangement::Dynamic)
+        .set_header(vec!["Deployment", "Name", "Description"]);
+
+    let deployment_details = registry
+        .get_deployment_details(order_key.to_string())
+        .map_err(|err| anyhow!(err.to_readable_msg()))?;
+    for (key, details) in deployment_details {
+        table.add_row(vec![
+            key,
+            details.name,
+            details.short_description.unwrap_or(details.description),
+        ]);
+    }
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::registry::tests::mock_registry;
+    use httpmock::MockServer;
+
+    #[tokio::test]
+    async fn test_build_table() {
+        let server = MockServer::start_async().await;
+        let registry = mock_registry(&server, false).load().await.unwrap();
+
+        let table = build_table(&registry, "fixed-limit").unwrap();
+        let rows = table
+            .row_iter()
+            .map(|row| {
+                row.cell_iter()
+                    .map(|cell| cell.content())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![vec![
+                "base".to_string(),
+                "Base deployment".to_string(),
+                "Sell USDC on Base".to_string()
+            ]]
+        );
+
+        let err = build_table(&registry, "missing").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The order key 'missing' was not found in the registry. Please check the available order keys."
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use super::RegistryArgs;
+use crate::execute::Execute;
+use anyhow::{anyhow, Result};
+use clap::Args;
+use std::path::PathBuf;
+use tracing::info;
+
+#[derive(Args, Clone)]
+pub struct Fetch {
+    #[clap(flatten)]
+    registry_args: RegistryArgs,
+
+    #[arg(short = 'k', long, help = "Key of the order in the registry")]
+    order_key: String,
+
+    #[arg(
+        short = 'o',
+        long,
+        help = "Path to write the dotrain to, defaults to <order-key>.rain"
+    )]
+    output: Option<PathBuf>,
+}
+
+/// Registry keys come from a remote file, so a key is only used as a file
+/// name when it can't point outside the working directory
+fn default_output(order_key: &str) -> Result<PathBuf> {
+    let is_file_name = !order_key.is_empty()
+        && !order_key.starts_with('.')
+        && order_key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !is_file_name {
+        return Err(anyhow!(
+            "Order key '{}' can't be used as a file name, pass --output",
+            order_key
+        ));
+    }
+    Ok(PathBuf::from(format!("{}.rain", order_key)))
+}
+
+impl Execute for Fetch {
+    async fn execute(&self) -> Result<()> {
+        let output = match &self.output {
+            Some(output) => output.clone(),
+            None => default_output(&self.order_key)?,
+        };
+
+        let registry = self.registry_args.load().await?;
+        let dotrain = registry
+            .get_merged_dotrain(&self.order_key)
+            .map_err(|err| anyhow!(err.to_readable_msg()))?;
+        std::fs::write(&output, dotrain)?;
+        info!("Wrote {} to {}", self.order_key, output.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::registry::tests::{mock_registry, ORDER, SETTINGS};
+    use httpmock::MockServer;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_default_output() {
+        assert_eq!(
+            default_output("fixed-limit").unwrap(),
+            PathBuf::from("fixed-limit.rain")
+        );
+        assert_eq!(
+            default_output("dca_v2.1").unwrap(),
+            PathBuf::from("dca_v2.1.rain")
+        );
+        for key in [
+            "",
+            "../order",
+            "/tmp/order",
+            "dir/order",
+            "..",
+            ".hidden",
+            "a\\b",
+        ] {
+            let err = default_output(key).unwrap_err();
+            assert!(err.to_string().contains("can't be used as a file name"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute() {
+        let server = MockServer::start_async().await;
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("order.rain");
+
+        Fetch {
+            registry_args: mock_registry(&server, true),
+            order_key: "fixed-limit".to_string(),
+            output: Some(output.clone()),
+        }
+        .execute()
+        .await
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&output).unwrap(),
+            format!("{SETTINGS}\n\n{ORDER}")
+        );
+
+        let err = Fetch {
+            registry_args: mock_registry(&server, false),
+            order_key: "missing".to_string(),
+            output: Some(output),
+        }
+        .execute()
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("'missing' was not found"));
+    }
+}
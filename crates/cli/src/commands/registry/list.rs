@@ -0,0 +1,78 @@
+use super::RegistryArgs;
+use crate::execute::Execute;
+use anyhow::{anyhow, Result};
+use clap::Args;
+use comfy_table::Table;
+use rain_orderbook_common::registry::DotrainRegistry;
+use tracing::info;
+
+#[derive(Args, Clone)]
+pub struct List {
+    #[clap(flatten)]
+    registry_args: RegistryArgs,
+}
+
+impl Execute for List {
+    async fn execute(&self) -> Result<()> {
+        let registry = self.registry_args.load().await?;
+        let table = build_table(&registry)?;
+        info!("\n{}", table);
+        Ok(())
+    }
+}
+
+fn build_table(registry: &DotrainRegistry) -> Result<Table> {
+    let mut table = comfy_table::Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_FULL)
+        .set_content_arrangement(comfy_table::ContentArrangement::Dynamic)
+        .set_header(vec!["Key", "Name", "Description", "Url", "Sha256"]);
+
+    let order_details = registry
+        .get_all_order_details()
+        .map_err(|err| anyhow!(err.to_readable_msg()))?;
+    for (key, details) in order_details {
+        table.add_row(vec![
+            key.clone(),
+            details.name,
+            details.short_description.unwrap_or(details.description),
+            registry
+                .get_order_url(&key)
+                .map(|url| url.to_string())
+                .unwrap_or_default(),
+            registry.get_order_hash(&key).unwrap_or("").to_string(),
+        ]);
+    }
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::registry::tests::mock_registry;
+    use httpmock::MockServer;
+
+    #[tokio::test]
+    async fn test_build_table() {
+        let server = MockServer::start_async().await;
+        let registry_args = mock_registry(&server, true);
+        let registry = registry_args.load().await.unwrap();
+
+        let table = build_table(&registry).unwrap();
+        let rows = table
+            .row_iter()
+            .map(|row| {
+                row.cell_iter()
+                    .map(|cell| cell.content())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0][0], "fixed-limit");
+        assert_eq!(rows[0][1], "Fixed limit");
+        assert_eq!(rows[0][2], "Buy WETH with USDC");
+        assert_eq!(rows[0][3], server.url("/fixed-limit.rain"));
+        assert_eq!(rows[0][4].len(), 64);
+    }
+}
@@ -0,0 +1,190 @@
+mod deployments;
+mod fetch;
+mod list;
+
+use crate::execute::Execute;
+use anyhow::{anyhow, Result};
+use clap::{ArgAction, Args, Parser};
+use deployments::Deployments;
+use fetch::Fetch;
+use list::List;
+use rain_orderbook_app_settings::remote::source::RemoteFetchOptions;
+use rain_orderbook_common::registry::DotrainRegistry;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub enum Registry {
+    #[command(about = "List the orders in a registry")]
+    List(List),
+    #[command(about = "Show the gui deployments of a registry order")]
+    Deployments(Deployments),
+    #[command(about = "Write the merged settings and order dotrain of a registry order to a file")]
+    Fetch(Fetch),
+}
+
+impl Execute for Registry {
+    async fn execute(&self) -> Result<()> {
+        match self {
+            Registry::List(list) => list.execute().await,
+            Registry::Deployments(deployments) => deployments.execute().await,
+            Registry::Fetch(fetch) => fetch.execute().await,
+        }
+    }
+}
+
+#[derive(Args, Clone)]
+pub struct RegistryArgs {
+    #[arg(short = 'r', long, help = "URL of the registry file")]
+    registry_url: String,
+
+    #[arg(
+        long,
+        help = "Directory to cache registry responses in, defaults to $RAIN_ORDERBOOK_REMOTE_CACHE_DIR"
+    )]
+    cache_dir: Option<PathBuf>,
+
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Only use cached responses and local files, never the network"
+    )]
+    offline: bool,
+}
+
+impl RegistryArgs {
+    fn fetch_options(&self) -> RemoteFetchOptions {
+        let env = RemoteFetchOptions::from_env();
+        RemoteFetchOptions {
+            cache_dir: self.cache_dir.clone().or(env.cache_dir),
+            offline: self.offline || env.offline,
+        }
+    }
+
+    async fn load(&self) -> Result<DotrainRegistry> {
+        DotrainRegistry::new_with_options(self.registry_url.clone(), &self.fetch_options())
+            .await
+            .map_err(|err| anyhow!(err.to_readable_msg()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use httpmock::MockServer;
+    use rain_orderbook_app_settings::remote::source::sha256_hex;
+
+    pub const SETTINGS: &str = r#"version: 3
+networks:
+  base:
+    rpcs:
+      - https://mainnet.base.org
+    chain-id: 8453
+    currency: ETH
+subgraphs:
+  base: https://example.com/subgraph
+orderbooks:
+  base:
+    address: 0xd2938e7c9fe3597f78832ce780feb61945c377d7
+    network: base
+    subgraph: base
+deployers:
+  base:
+    address: 0xC1A14cE2fd58A3A2f99deCb8eDd866204eE07f8D
+    network: base
+tokens:
+  token1:
+    address: 0x4200000000000000000000000000000000000042
+    network: base
+"#;
+
+    pub const ORDER: &str = r#"gui:
+  name: Fixed limit
+  description: Fixed limit order
+  short-description: Buy WETH with USDC
+  deployments:
+    base:
+      name: Base deployment
+      description: Base deployment description
+      short-description: Sell USDC on Base
+      deposits:
+        - token: token1
+      fields:
+        - binding: price
+          name: Price
+scenarios:
+  base:
+    deployer: base
+orders:
+  base:
+    orderbook: base
+    inputs:
+      - token: token1
+    outputs:
+      - token: token1
+deployments:
+  base:
+    scenario: base
+    order: base
+---
+#price !
+#calculate-io
+_ _: 0 0;
+#handle-io
+:;
+#handle-add-order
+:;"#;
+
+    /// Serves a registry with a single `fixed-limit` order, pinned to its
+    /// sha256 when `pinned` is set.
+    pub fn mock_registry(server: &MockServer, pinned: bool) -> RegistryArgs {
+        let (path, pin) = match pinned {
+            true => ("/pinned.txt", sha256_hex(ORDER.as_bytes())),
+            false => ("/registry.txt", String::new()),
+        };
+        let registry = format!(
+            "{}\nfixed-limit {} {}",
+            server.url("/settings.yaml"),
+            server.url("/fixed-limit.rain"),
+            pin
+        );
+        server.mock(|when, then| {
+            when.method("GET").path(path);
+            then.status(200).body(registry);
+        });
+        server.mock(|when, then| {
+            when.method("GET").path("/settings.yaml");
+            then.status(200).body(SETTINGS);
+        });
+        server.mock(|when, then| {
+            when.method("GET").path("/fixed-limit.rain");
+            then.status(200).body(ORDER);
+        });
+
+        RegistryArgs {
+            registry_url: server.url(path),
+            cache_dir: None,
+            offline: false,
+        }
+    }
+
+    #[test]
+    fn verify_command() {
+        Registry::command().debug_assert();
+    }
+
+    #[test]
+    fn test_fetch_options() {
+        let args = RegistryArgs {
+            registry_url: "https://example.com/registry.txt".to_string(),
+            cache_dir: Some(PathBuf::from("/tmp/registry-cache")),
+            offline: true,
+        };
+        let options = args.fetch_options();
+        assert_eq!(
+            options.cache_dir,
+            Some(PathBuf::from("/tmp/registry-cache"))
+        );
+        assert!(options.offline);
+    }
+}
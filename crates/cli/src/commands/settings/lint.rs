@@ -0,0 +1,220 @@
+use crate::execute::Execute;
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use rain_orderbook_app_settings::lint::{LintReport, LintSeverity};
+use rain_orderbook_common::dotrain::RainDocument;
+use rain_orderbook_common::settings_lint::lint_sources;
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+#[derive(Debug, clap::ValueEnum, Clone, PartialEq)]
+pub enum MinSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl From<MinSeverity> for LintSeverity {
+    fn from(value: MinSeverity) -> Self {
+        match value {
+            MinSeverity::Info => LintSeverity::Info,
+            MinSeverity::Warning => LintSeverity::Warning,
+            MinSeverity::Error => LintSeverity::Error,
+        }
+    }
+}
+
+#[derive(Parser, Clone)]
+pub struct Lint {
+    #[arg(
+        short = 'f',
+        long,
+        help = "Path to a .rain file whose frontmatter should be linted"
+    )]
+    dotrain_file: Option<PathBuf>,
+
+    #[arg(
+        short = 'c',
+        long,
+        help = "Path to a settings yaml file, can be repeated"
+    )]
+    settings_file: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Also run checks that need an rpc, such as token decimals and deployment blocks"
+    )]
+    onchain: bool,
+
+    #[arg(
+        short = 's',
+        long,
+        help = "Minimum severity to report",
+        default_value = "info"
+    )]
+    min_severity: MinSeverity,
+}
+
+impl Lint {
+    fn read_sources(&self) -> Result<Vec<String>> {
+        let mut sources = vec![];
+        if let Some(dotrain_file) = &self.dotrain_file {
+            let dotrain = read_to_string(dotrain_file).map_err(|e| anyhow!(e))?;
+            let frontmatter = RainDocument::get_front_matter(&dotrain)
+                .filter(|frontmatter| !frontmatter.trim().is_empty())
+                .ok_or(anyhow!(
+                    "{} has no frontmatter to lint",
+                    dotrain_file.display()
+                ))?;
+            sources.push(frontmatter.to_string());
+        }
+        for settings_file in &self.settings_file {
+            sources.push(read_to_string(settings_file).map_err(|e| anyhow!(e))?);
+        }
+        if sources.is_empty() {
+            return Err(anyhow!(
+                "Provide a .rain file with --dotrain-file or a settings file with --settings-file"
+            ));
+        }
+        Ok(sources)
+    }
+}
+
+fn print_report(report: &LintReport) {
+    for issue in &report.issues {
+        println!("{}", issue);
+    }
+    println!(
+        "{} error(s), {} warning(s), {} info",
+        report.count(LintSeverity::Error),
+        report.count(LintSeverity::Warning),
+        report.count(LintSeverity::Info)
+    );
+}
+
+impl Execute for Lint {
+    async fn execute(&self) -> Result<()> {
+        let sources = self.read_sources()?;
+        let report = lint_sources(sources, self.onchain)
+            .await
+            .map_err(|e| anyhow!(e.to_readable_msg()))?
+            .with_min_severity(self.min_severity.clone().into());
+
+        print_report(&report);
+
+        if report.has_errors() {
+            return Err(anyhow!("Settings lint failed"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use tempfile::NamedTempFile;
+
+    const SETTINGS: &str = r#"
+networks:
+    mainnet:
+        rpcs:
+            - https://mainnet.infura.io
+        chain-id: 1
+tokens:
+    token1:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000002
+"#;
+
+    #[test]
+    fn verify_cli() {
+        Lint::command().debug_assert();
+    }
+
+    #[test]
+    fn test_cli_args() {
+        let cmd = Lint::command();
+        let result = cmd.get_matches_from(vec![
+            "cmd",
+            "-c",
+            "./a.yaml",
+            "-c",
+            "./b.yaml",
+            "--onchain",
+            "-s",
+            "warning",
+        ]);
+        assert_eq!(
+            result
+                .get_many::<PathBuf>("settings_file")
+                .unwrap()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![PathBuf::from("./a.yaml"), PathBuf::from("./b.yaml")]
+        );
+        assert!(result.get_flag("onchain"));
+        assert_eq!(
+            result.get_one::<MinSeverity>("min_severity"),
+            Some(&MinSeverity::Warning)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_happy() {
+        let settings_file = NamedTempFile::new().unwrap();
+        std::fs::write(settings_file.path(), SETTINGS).unwrap();
+
+        let lint = Lint {
+            dotrain_file: None,
+            settings_file: vec![settings_file.path().to_path_buf()],
+            onchain: false,
+            min_severity: MinSeverity::Info,
+        };
+        assert!(lint.execute().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_execute_errors() {
+        let settings_file = NamedTempFile::new().unwrap();
+        std::fs::write(
+            settings_file.path(),
+            SETTINGS.replace("network: mainnet", "network: missing"),
+        )
+        .unwrap();
+
+        let lint = Lint {
+            dotrain_file: None,
+            settings_file: vec![settings_file.path().to_path_buf()],
+            onchain: false,
+            min_severity: MinSeverity::Error,
+        };
+        assert!(lint.execute().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_dotrain_without_frontmatter() {
+        let dotrain_file = NamedTempFile::new().unwrap();
+        std::fs::write(dotrain_file.path(), "#calculate-io\n_ _: 0 0;").unwrap();
+
+        let lint = Lint {
+            dotrain_file: Some(dotrain_file.path().to_path_buf()),
+            settings_file: vec![],
+            onchain: false,
+            min_severity: MinSeverity::Info,
+        };
+        let err = lint.execute().await.unwrap_err();
+        assert!(err.to_string().contains("has no frontmatter to lint"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_no_sources() {
+        let lint = Lint {
+            dotrain_file: None,
+            settings_file: vec![],
+            onchain: false,
+            min_severity: MinSeverity::Info,
+        };
+        assert!(lint.execute().await.is_err());
+    }
+}
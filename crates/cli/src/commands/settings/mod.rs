@@ -0,0 +1,36 @@
+mod lint;
+mod schema;
+
+use crate::execute::Execute;
+use anyhow::Result;
+use clap::Parser;
+use lint::Lint;
+use schema::Schema;
+
+#[derive(Parser)]
+pub enum Settings {
+    #[command(about = "Lint settings for unused, unreachable and inconsistent definitions")]
+    Lint(Lint),
+    #[command(about = "Print the JSON Schema of the orderbook or dotrain yaml")]
+    Schema(Schema),
+}
+
+impl Execute for Settings {
+    async fn execute(&self) -> Result<()> {
+        match self {
+            Settings::Lint(lint) => lint.execute().await,
+            Settings::Schema(schema) => schema.execute().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_command() {
+        Settings::command().debug_assert();
+    }
+}
@@ -0,0 +1,102 @@
+use crate::execute::Execute;
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use rain_orderbook_app_settings::schema::{settings_schema, SettingsSchemaKind};
+use std::fs::write;
+use std::path::PathBuf;
+
+#[derive(Debug, clap::ValueEnum, Clone, PartialEq)]
+pub enum SchemaKind {
+    Orderbook,
+    Dotrain,
+}
+
+impl From<SchemaKind> for SettingsSchemaKind {
+    fn from(value: SchemaKind) -> Self {
+        match value {
+            SchemaKind::Orderbook => SettingsSchemaKind::Orderbook,
+            SchemaKind::Dotrain => SettingsSchemaKind::Dotrain,
+        }
+    }
+}
+
+#[derive(Parser, Clone)]
+pub struct Schema {
+    #[arg(
+        short = 'k',
+        long,
+        help = "Which yaml source the schema should describe",
+        default_value = "dotrain"
+    )]
+    kind: SchemaKind,
+
+    #[arg(
+        short = 'o',
+        long,
+        help = "Path to write the schema to, prints to stdout if omitted"
+    )]
+    output: Option<PathBuf>,
+}
+
+impl Execute for Schema {
+    async fn execute(&self) -> Result<()> {
+        let schema = settings_schema(self.kind.clone().into());
+        let schema = serde_json::to_string_pretty(&schema)?;
+
+        match &self.output {
+            Some(output) => write(output, schema).map_err(|e| anyhow!(e))?,
+            None => println!("{}", schema),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use serde_json::Value;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn verify_cli() {
+        Schema::command().debug_assert();
+    }
+
+    #[test]
+    fn test_cli_args() {
+        let cmd = Schema::command();
+        let result = cmd.get_matches_from(vec!["cmd", "-k", "orderbook", "-o", "./schema.json"]);
+        assert_eq!(
+            result.get_one::<SchemaKind>("kind"),
+            Some(&SchemaKind::Orderbook)
+        );
+        assert_eq!(
+            result.get_one::<PathBuf>("output"),
+            Some(&PathBuf::from("./schema.json"))
+        );
+
+        let cmd = Schema::command();
+        let result = cmd.get_matches_from(vec!["cmd"]);
+        assert_eq!(
+            result.get_one::<SchemaKind>("kind"),
+            Some(&SchemaKind::Dotrain)
+        );
+        assert_eq!(result.get_one::<PathBuf>("output"), None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_writes_schema() {
+        let output = NamedTempFile::new().unwrap();
+
+        let schema = Schema {
+            kind: SchemaKind::Orderbook,
+            output: Some(output.path().to_path_buf()),
+        };
+        schema.execute().await.unwrap();
+
+        let written: Value =
+            serde_json::from_str(&std::fs::read_to_string(output.path()).unwrap()).unwrap();
+        assert_eq!(written, settings_schema(SettingsSchemaKind::Orderbook));
+    }
+}
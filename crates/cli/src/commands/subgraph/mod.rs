@@ -1,7 +1,7 @@
 use crate::execute::Execute;
 use anyhow::Result;
 use clap::Parser;
-use rain_orderbook_subgraph_client::validate::validate_subgraph_schema;
+use rain_orderbook_subgraph_client::validate::diff_subgraph_schema;
 
 #[derive(Parser)]
 pub enum Subgraph {
@@ -15,9 +15,19 @@ pub enum Subgraph {
 impl Execute for Subgraph {
     async fn execute(&self) -> Result<()> {
         match self {
-            Subgraph::Validate { subgraph_url } => Ok(validate_subgraph_schema(subgraph_url)
-                .await
-                .map(|v| println!("--- {}valid subgraph ---", if v { "" } else { "in" }))?),
+            Subgraph::Validate { subgraph_url } => {
+                let diff = diff_subgraph_schema(subgraph_url).await?;
+                print!("{diff}");
+                let status = if diff.is_identical() {
+                    "valid"
+                } else if diff.is_compatible() {
+                    "compatible"
+                } else {
+                    "invalid"
+                };
+                println!("--- {status} subgraph ---");
+                Ok(())
+            }
         }
     }
 }
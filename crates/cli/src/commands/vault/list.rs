@@ -122,6 +122,7 @@ mod tests {
                 hide_zero_balance: Some(true),
                 order_hash: None,
                 tokens: vec![],
+                ..Default::default()
             },
         };
 
@@ -152,6 +153,7 @@ mod tests {
                 hide_zero_balance: Some(true),
                 order_hash: None,
                 tokens: vec![],
+                ..Default::default()
             },
         };
 
@@ -176,6 +178,7 @@ mod tests {
                 hide_zero_balance: Some(true),
                 order_hash: None,
                 tokens: vec![],
+                ..Default::default()
             },
         };
 
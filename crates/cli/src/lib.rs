@@ -1,4 +1,6 @@
-use crate::commands::{Chart, Order, Subgraph, Trade, Vault, Words};
+use crate::commands::{
+    Chart, Clear, Deploy, Order, Registry, Settings, Subgraph, Trade, Vault, Words,
+};
 use crate::execute::Execute;
 use anyhow::Result;
 use clap::Subcommand;
@@ -22,11 +24,22 @@ pub enum Orderbook {
     #[command(subcommand)]
     Trade(Trade),
 
+    #[command(subcommand)]
+    Clear(Clear),
+
     #[command(subcommand)]
     Subgraph(Subgraph),
 
+    #[command(subcommand)]
+    Settings(Settings),
+
+    #[command(subcommand)]
+    Registry(Registry),
+
     Chart(Chart),
 
+    Deploy(Deploy),
+
     Quote(Quoter),
 
     Words(Words),
@@ -38,9 +51,13 @@ impl Orderbook {
             Orderbook::Order(order) => order.execute().await,
             Orderbook::Vault(vault) => vault.execute().await,
             Orderbook::Trade(trade) => trade.execute().await,
+            Orderbook::Clear(clear) => clear.execute().await,
             Orderbook::Chart(chart) => chart.execute().await,
+            Orderbook::Deploy(deploy) => deploy.execute().await,
             Orderbook::Quote(quote) => quote.execute().await,
             Orderbook::Subgraph(subgraph) => subgraph.execute().await,
+            Orderbook::Settings(settings) => settings.execute().await,
+            Orderbook::Registry(registry) => registry.execute().await,
             Orderbook::Words(words) => words.execute().await,
         }
     }
@@ -1,7 +1,10 @@
 use clap::Args;
 use rain_orderbook_common::subgraph::SubgraphArgs;
 use rain_orderbook_subgraph_client::{
-    types::common::{SgBytes, SgOrdersListFilterArgs, SgVaultsListFilterArgs},
+    types::common::{
+        SgBytes, SgOrdersListFilterArgs, SgOrdersSortBy, SgSortDirection, SgVaultsListFilterArgs,
+        SgVaultsSortBy,
+    },
     SgPaginationArgs,
 };
 
@@ -57,11 +60,12 @@ impl From<CliPaginationArgs> for SgPaginationArgs {
         Self {
             page: val.page,
             page_size: val.page_size,
+            cursor: None,
         }
     }
 }
 
-#[derive(Args, Clone)]
+#[derive(Args, Clone, Default)]
 pub struct CliFilterArgs {
     #[arg(
         long,
@@ -89,6 +93,115 @@ pub struct CliFilterArgs {
         value_delimiter = ','
     )]
     pub tokens: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Filter orders by input token addresses (comma-separated)",
+        value_delimiter = ','
+    )]
+    pub input_tokens: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Filter orders by output token addresses (comma-separated)",
+        value_delimiter = ','
+    )]
+    pub output_tokens: Vec<String>,
+
+    #[arg(long, help = "Filter orders added at or after this unix timestamp")]
+    pub added_after: Option<u64>,
+
+    #[arg(long, help = "Filter orders added at or before this unix timestamp")]
+    pub added_before: Option<u64>,
+
+    #[arg(long, help = "Filter orders by whether they have been traded")]
+    pub has_trades: Option<bool>,
+
+    #[arg(
+        long,
+        help = "Filter orders and vaults by orderbook addresses (comma-separated)",
+        value_delimiter = ','
+    )]
+    pub orderbooks: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Exclude orders and vaults of these owner addresses (comma-separated)",
+        value_delimiter = ','
+    )]
+    pub exclude_owners: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Filter vaults by vault ids in hex (comma-separated)",
+        value_delimiter = ','
+    )]
+    pub vault_ids: Vec<String>,
+
+    #[arg(long, help = "Filter vaults with at least this balance")]
+    pub min_balance: Option<String>,
+
+    #[arg(long, help = "Filter vaults with at most this balance")]
+    pub max_balance: Option<String>,
+
+    #[arg(long, help = "Sort orders by", value_enum, default_value = "added")]
+    pub sort_orders_by: CliOrdersSortBy,
+
+    #[arg(long, help = "Sort vaults by", value_enum, default_value = "id")]
+    pub sort_vaults_by: CliVaultsSortBy,
+
+    #[arg(long, help = "Sort direction", value_enum, default_value = "desc")]
+    pub sort_direction: CliSortDirection,
+}
+
+#[derive(Debug, clap::ValueEnum, Clone, Copy, PartialEq, Default)]
+pub enum CliOrdersSortBy {
+    #[default]
+    Added,
+    LastTrade,
+    TradeCount,
+}
+
+impl From<CliOrdersSortBy> for SgOrdersSortBy {
+    fn from(val: CliOrdersSortBy) -> Self {
+        match val {
+            CliOrdersSortBy::Added => SgOrdersSortBy::AddedAt,
+            CliOrdersSortBy::LastTrade => SgOrdersSortBy::LastTrade,
+            CliOrdersSortBy::TradeCount => SgOrdersSortBy::TradeCount,
+        }
+    }
+}
+
+#[derive(Debug, clap::ValueEnum, Clone, Copy, PartialEq, Default)]
+pub enum CliVaultsSortBy {
+    #[default]
+    Id,
+    Balance,
+}
+
+impl From<CliVaultsSortBy> for SgVaultsSortBy {
+    fn from(val: CliVaultsSortBy) -> Self {
+        match val {
+            CliVaultsSortBy::Id => SgVaultsSortBy::Id,
+            CliVaultsSortBy::Balance => SgVaultsSortBy::Balance,
+        }
+    }
+}
+
+#[derive(Debug, clap::ValueEnum, Clone, Copy, PartialEq, Default)]
+pub enum CliSortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl From<CliSortDirection> for SgSortDirection {
+    fn from(val: CliSortDirection) -> Self {
+        match val {
+            CliSortDirection::Asc => SgSortDirection::Asc,
+            CliSortDirection::Desc => SgSortDirection::Desc,
+        }
+    }
 }
 
 impl From<CliFilterArgs> for SgOrdersListFilterArgs {
@@ -98,6 +211,15 @@ impl From<CliFilterArgs> for SgOrdersListFilterArgs {
             active: val.active,
             order_hash: val.order_hash.map(SgBytes),
             tokens: val.tokens,
+            input_tokens: val.input_tokens,
+            output_tokens: val.output_tokens,
+            added_after: val.added_after,
+            added_before: val.added_before,
+            has_trades: val.has_trades,
+            orderbooks: val.orderbooks.into_iter().map(SgBytes).collect(),
+            exclude_owners: val.exclude_owners.into_iter().map(SgBytes).collect(),
+            sort_by: val.sort_orders_by.into(),
+            sort_direction: val.sort_direction.into(),
         }
     }
 }
@@ -108,6 +230,13 @@ impl From<CliFilterArgs> for SgVaultsListFilterArgs {
             owners: val.owners.into_iter().map(SgBytes).collect(),
             hide_zero_balance: val.hide_zero_balance.unwrap_or(true),
             tokens: val.tokens,
+            orderbooks: val.orderbooks.into_iter().map(SgBytes).collect(),
+            exclude_owners: val.exclude_owners.into_iter().map(SgBytes).collect(),
+            vault_ids: val.vault_ids.into_iter().map(SgBytes).collect(),
+            min_balance: val.min_balance,
+            max_balance: val.max_balance,
+            sort_by: val.sort_vaults_by.into(),
+            sort_direction: val.sort_direction.into(),
         }
     }
 }
@@ -147,6 +276,7 @@ mod tests {
             hide_zero_balance: Some(false),
             order_hash: Some("0x789".to_string()),
             tokens: tokens.clone(),
+            ..Default::default()
         };
         let filter_args: SgOrdersListFilterArgs = cli_args.into();
         assert_eq!(
@@ -168,6 +298,7 @@ mod tests {
             hide_zero_balance: Some(false),
             order_hash: Some("0x789".to_string()),
             tokens: tokens.clone(),
+            ..Default::default()
         };
         let filter_args: SgVaultsListFilterArgs = cli_args.into();
         assert_eq!(
@@ -177,4 +308,42 @@ mod tests {
         assert!(!filter_args.hide_zero_balance);
         assert_eq!(filter_args.tokens, tokens);
     }
+
+    #[test]
+    fn test_from_cli_filter_args_sort_and_extra_filters() {
+        let cli_args = CliFilterArgs {
+            input_tokens: vec!["0xabc".to_string()],
+            added_after: Some(1700000000),
+            has_trades: Some(false),
+            orderbooks: vec!["0xorderbook".to_string()],
+            exclude_owners: vec!["0x123".to_string()],
+            vault_ids: vec!["0x01".to_string()],
+            min_balance: Some("1".to_string()),
+            sort_orders_by: CliOrdersSortBy::LastTrade,
+            sort_vaults_by: CliVaultsSortBy::Balance,
+            sort_direction: CliSortDirection::Asc,
+            ..Default::default()
+        };
+
+        let order_args: SgOrdersListFilterArgs = cli_args.clone().into();
+        assert_eq!(order_args.input_tokens, vec!["0xabc".to_string()]);
+        assert_eq!(order_args.added_after, Some(1700000000));
+        assert_eq!(order_args.has_trades, Some(false));
+        assert_eq!(
+            order_args.orderbooks,
+            vec![SgBytes("0xorderbook".to_string())]
+        );
+        assert_eq!(
+            order_args.exclude_owners,
+            vec![SgBytes("0x123".to_string())]
+        );
+        assert_eq!(order_args.sort_by, SgOrdersSortBy::LastTrade);
+        assert_eq!(order_args.sort_direction, SgSortDirection::Asc);
+
+        let vault_args: SgVaultsListFilterArgs = cli_args.into();
+        assert_eq!(vault_args.vault_ids, vec![SgBytes("0x01".to_string())]);
+        assert_eq!(vault_args.min_balance, Some("1".to_string()));
+        assert_eq!(vault_args.sort_by, SgVaultsSortBy::Balance);
+        assert_eq!(vault_args.sort_direction, SgSortDirection::Asc);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
     dotrain_order::DotrainOrderError,
+    meta::{OrderGuiMeta, OrderGuiMetaError},
     rainlang::compose_to_rainlang,
     transaction::{TransactionArgs, TransactionArgsError},
 };
@@ -68,6 +69,8 @@ pub enum AddOrderArgsError {
     OutputTokenNotFound(String),
     #[error("Invalid input args: {0}")]
     InvalidArgs(String),
+    #[error(transparent)]
+    OrderGuiMetaError(#[from] OrderGuiMetaError),
 }
 
 impl From<DotrainOrderError> for AddOrderArgsError {
@@ -91,6 +94,9 @@ pub struct AddOrderArgs {
     pub outputs: Vec<IOV2>,
     pub deployer: Address,
     pub bindings: HashMap<String, String>,
+    /// GUI deployment and dotrain source to embed in the order meta
+    #[serde(default)]
+    pub gui: Option<OrderGuiMeta>,
 }
 
 impl AddOrderArgs {
@@ -133,6 +139,7 @@ impl AddOrderArgs {
             outputs,
             deployer: deployment.scenario.deployer.address,
             bindings: deployment.scenario.bindings.to_owned(),
+            gui: None,
         })
     }
 
@@ -157,20 +164,27 @@ impl AddOrderArgs {
         Ok(rainlang_parsed.bytecode.into())
     }
 
-    /// Generate RainlangSource meta
+    /// Sets the GUI deployment and dotrain source to embed in the order meta
+    pub fn with_gui_meta(mut self, gui: OrderGuiMeta) -> Self {
+        self.gui = Some(gui);
+        self
+    }
+
+    /// Generate RainlangSource meta, followed by the GUI items when set
     fn try_generate_meta(&self, rainlang: String) -> Result<Vec<u8>, AddOrderArgsError> {
-        let meta_doc = RainMetaDocumentV1Item {
+        let mut meta_docs = vec![RainMetaDocumentV1Item {
             payload: ByteBuf::from(rainlang.as_bytes()),
             magic: KnownMagic::RainlangSourceV1,
             content_type: ContentType::OctetStream,
             content_encoding: ContentEncoding::None,
             content_language: ContentLanguage::None,
-        };
-        let meta_doc_bytes = RainMetaDocumentV1Item::cbor_encode_seq(
-            &vec![meta_doc],
-            KnownMagic::RainMetaDocumentV1,
-        )
-        .map_err(AddOrderArgsError::RainMetaError)?;
+        }];
+        if let Some(gui) = &self.gui {
+            meta_docs.extend(gui.to_meta_items()?);
+        }
+        let meta_doc_bytes =
+            RainMetaDocumentV1Item::cbor_encode_seq(&meta_docs, KnownMagic::RainMetaDocumentV1)
+                .map_err(AddOrderArgsError::RainMetaError)?;
 
         Ok(meta_doc_bytes)
     }
@@ -358,7 +372,7 @@ impl AddOrderArgs {
 #[cfg(all(test, not(target_family = "wasm")))]
 mod tests {
     use super::*;
-    use crate::dotrain_order::DotrainOrder;
+    use crate::{dotrain_order::DotrainOrder, meta::GuiDeploymentMeta};
     use alloy::primitives::Bytes;
     use rain_orderbook_app_settings::{
         deployer::DeployerCfg,
@@ -396,6 +410,7 @@ price: 2e18;
             outputs: vec![],
             bindings: HashMap::new(),
             deployer: Address::default(),
+            gui: None,
         };
 
         let meta_bytes = args.try_generate_meta(dotrain_body).unwrap();
@@ -422,6 +437,7 @@ price: 2e18;
             outputs: vec![],
             bindings: HashMap::new(),
             deployer: Address::default(),
+            gui: None,
         };
         let meta_bytes = args.try_generate_meta("".to_string()).unwrap();
         assert_eq!(
@@ -434,6 +450,36 @@ price: 2e18;
         );
     }
 
+    #[test]
+    fn test_try_generate_meta_with_gui() {
+        let gui = OrderGuiMeta {
+            deployment: GuiDeploymentMeta {
+                deployment_key: "some-deployment".to_string(),
+                deployment_name: "Some deployment".to_string(),
+                registry: None,
+                order_key: None,
+                field_values: vec![],
+                state: "state".to_string(),
+            },
+            dotrain: "gui:\n  name: Some order\n---\n#calculate-io\n_ _: 0 0;".to_string(),
+        };
+        let args = AddOrderArgs {
+            dotrain: "".into(),
+            inputs: vec![],
+            outputs: vec![],
+            bindings: HashMap::new(),
+            deployer: Address::default(),
+            gui: None,
+        }
+        .with_gui_meta(gui.clone());
+
+        let meta_bytes = args.try_generate_meta("_ _: 0 0;".to_string()).unwrap();
+        let items = RainMetaDocumentV1Item::cbor_decode(&meta_bytes).unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].payload.as_slice(), "_ _: 0 0;".as_bytes());
+        assert_eq!(OrderGuiMeta::try_decode(&meta_bytes).unwrap(), Some(gui));
+    }
+
     #[tokio::test]
     async fn test_add_order_random_vault_id_generation() {
         let network = NetworkCfg {
@@ -469,6 +515,9 @@ price: 2e18;
             decimals: Some(18),
             label: None,
             symbol: Some("Token1".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token2 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -478,6 +527,9 @@ price: 2e18;
             decimals: Some(18),
             label: None,
             symbol: Some("Token2".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token3 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -487,6 +539,9 @@ price: 2e18;
             decimals: Some(18),
             label: None,
             symbol: Some("Token3".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token1_arc = Arc::new(token1);
         let token2_arc = Arc::new(token2);
@@ -583,6 +638,9 @@ _ _: 0 0;
             decimals: Some(18),
             label: None,
             symbol: Some("Token1".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token2 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -592,6 +650,9 @@ _ _: 0 0;
             decimals: Some(18),
             label: None,
             symbol: Some("Token2".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token3 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -601,6 +662,9 @@ _ _: 0 0;
             decimals: Some(18),
             label: None,
             symbol: Some("Token3".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token1_arc = Arc::new(token1);
         let token2_arc = Arc::new(token2);
@@ -738,6 +802,9 @@ _ _: 0 0;
             decimals: Some(18),
             label: None,
             symbol: Some("Token1".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token2 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -747,6 +814,9 @@ _ _: 0 0;
             decimals: Some(18),
             label: None,
             symbol: Some("Token2".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token3 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -756,6 +826,9 @@ _ _: 0 0;
             decimals: Some(18),
             label: None,
             symbol: Some("Token3".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token1_arc = Arc::new(token1);
         let token2_arc = Arc::new(token2);
@@ -1071,6 +1144,9 @@ _ _: 16 52;
             decimals: Some(18),
             label: None,
             symbol: Some("Token1".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token2 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -1080,6 +1156,9 @@ _ _: 16 52;
             decimals: Some(18),
             label: None,
             symbol: Some("Token2".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token3 = TokenCfg {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
@@ -1089,6 +1168,9 @@ _ _: 16 52;
             decimals: Some(18),
             label: None,
             symbol: Some("Token3".to_string()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         };
         let token1_arc = Arc::new(token1);
         let token2_arc = Arc::new(token2);
@@ -1298,6 +1380,7 @@ _ _: key1 key2;
                 ("key1".to_string(), "10".to_string()),
                 ("key2".to_string(), "20".to_string()),
             ]),
+            gui: None,
         };
         let rainlang = add_order_args.compose_to_rainlang().unwrap();
         assert_eq!(
@@ -1317,6 +1400,7 @@ _ _: key1 key2;
                 ("key1".to_string(), "10".to_string()),
                 ("key2".to_string(), "20".to_string()),
             ]),
+            gui: None,
         };
         let err = add_order_args.compose_to_rainlang().unwrap_err();
         assert!(matches!(
@@ -1359,6 +1443,7 @@ _ _: key1 key2;
             outputs: vec![],
             deployer: Address::random(),
             bindings: HashMap::new(),
+            gui: None,
         };
         let err = add_order_args.compose_to_rainlang().unwrap_err();
         assert!(matches!(
@@ -1406,6 +1491,7 @@ _ _: 0 0;
             }],
             deployer: *local_evm.deployer.address(),
             bindings: HashMap::new(),
+            gui: None,
         };
 
         let add_order_call = addOrder3Call {
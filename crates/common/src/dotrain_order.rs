@@ -13,6 +13,7 @@ use rain_orderbook_app_settings::yaml::{
     dotrain::DotrainYaml, orderbook::OrderbookYaml, YamlError, YamlParsable,
 };
 use rain_orderbook_app_settings::{
+    remote::source::RemoteFetchOptions,
     remote_networks::{ParseRemoteNetworksError, RemoteNetworksCfg},
     yaml::dotrain::DotrainYamlValidation,
 };
@@ -316,8 +317,11 @@ impl DotrainOrder {
 
         let mut dotrain_yaml = DotrainYaml::new(sources.clone(), DotrainYamlValidation::default())?;
 
-        let remote_networks =
-            RemoteNetworksCfg::fetch_networks(orderbook_yaml.get_remote_networks()?).await?;
+        let remote_networks = RemoteNetworksCfg::fetch_networks_with_options(
+            orderbook_yaml.get_remote_networks()?,
+            &RemoteFetchOptions::from_env(),
+        )
+        .await?;
         if !remote_networks.is_empty() {
             orderbook_yaml
                 .cache
@@ -329,7 +333,12 @@ impl DotrainOrder {
 
         if let Some(remote_tokens_cfg) = orderbook_yaml.get_remote_tokens()? {
             let networks = orderbook_yaml.get_networks()?;
-            let remote_tokens = RemoteTokensCfg::fetch_tokens(&networks, remote_tokens_cfg).await?;
+            let remote_tokens = RemoteTokensCfg::fetch_tokens_with_options(
+                &networks,
+                remote_tokens_cfg,
+                &RemoteFetchOptions::from_env(),
+            )
+            .await?;
             dotrain_yaml.cache.update_remote_tokens(remote_tokens);
         }
 
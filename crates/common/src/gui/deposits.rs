@@ -295,9 +295,8 @@ mod tests {
     };
     use crate::gui::validation;
     use std::str::FromStr;
-    use wasm_bindgen_test::wasm_bindgen_test;
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_gui_deposit() {
         let gui = initialize_gui(None).await;
 
@@ -336,7 +335,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_deposits() {
         let mut gui = initialize_gui(None).await;
 
@@ -354,7 +353,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_deposit() {
         let mut gui = initialize_gui(None).await;
 
@@ -399,7 +398,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_unset_deposit() {
         let mut gui = initialize_gui(None).await;
 
@@ -413,7 +412,7 @@ mod tests {
         assert_eq!(gui.get_deposits().unwrap().len(), 0);
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_deposit_presets() {
         let gui = initialize_gui(None).await;
 
@@ -429,7 +428,7 @@ mod tests {
             ]
         );
     }
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_missing_deposits() {
         let gui = initialize_gui(None).await;
 
@@ -437,7 +436,7 @@ mod tests {
         assert_eq!(missing_deposits, vec!["token1".to_string()]);
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_has_any_deposit() {
         let mut gui = initialize_gui(None).await;
 
@@ -451,7 +450,7 @@ mod tests {
         assert!(has_any_deposit);
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_check_deposits() {
         let mut gui = initialize_gui(None).await;
 
@@ -483,7 +482,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_minimum_validation() {
         let mut gui = initialize_validation_gui().await;
         let result = gui
@@ -511,7 +510,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_maximum_validation() {
         let mut gui = initialize_validation_gui().await;
         let result = gui
@@ -539,7 +538,7 @@ mod tests {
         }
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_exclusive_bounds() {
         let mut gui = initialize_validation_gui().await;
         let result = gui.set_deposit("token3".to_string(), "0".to_string()).await;
@@ -584,7 +583,7 @@ mod tests {
         }
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_multiple_constraints() {
         let mut gui = initialize_validation_gui().await;
 
@@ -622,7 +621,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_no_validation() {
         let mut gui = initialize_validation_gui().await;
         let result = gui.set_deposit("token6".to_string(), "0".to_string()).await;
@@ -639,7 +638,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_invalid_formats() {
         let mut gui = initialize_validation_gui().await;
 
@@ -674,7 +673,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_edge_cases() {
         let mut gui = initialize_validation_gui().await;
         let result = gui
@@ -695,7 +694,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_save_deposit_with_presets_and_validation() {
         let mut gui = initialize_validation_gui().await;
         let result = gui
@@ -29,19 +29,107 @@ impl DotrainOrderGui {
         let deployment = self.get_current_deployment()?;
 
         for field in deployment.fields.iter() {
-            if self.field_values.contains_key(&field.binding) {
+            if self.field_values.contains_key(&field.binding)
+                || !self.is_field_visible_at(&deployment.fields, field, 0)?
+            {
                 continue;
             }
 
-            match &field.default {
+            match self.resolve_field_default(&deployment.fields, field, 0)? {
                 Some(default_value) => {
-                    self.set_field_value(field.binding.clone(), default_value.clone())?;
+                    self.set_field_value(field.binding.clone(), default_value)?;
                 }
-                None => return Err(GuiError::FieldValueNotSet(field.name.clone())),
+                None if self.is_field_required_at(&deployment.fields, field, 0)? => {
+                    return Err(GuiError::FieldValueNotSet(field.name.clone()))
+                }
+                None => {}
             }
         }
         Ok(())
     }
+
+    /// Value used for a field: the value set by the user while the field is
+    /// shown, otherwise its default
+    pub(crate) fn effective_field_value(
+        &self,
+        fields: &[GuiFieldDefinitionCfg],
+        binding: &str,
+        depth: usize,
+    ) -> Result<Option<String>, GuiError> {
+        // Every hop follows a reference to another field, so a longer chain means a cycle
+        if depth > fields.len() {
+            return Err(GuiError::FieldReferenceCycle(binding.to_string()));
+        }
+        let field = fields
+            .iter()
+            .find(|field| field.binding == binding)
+            .ok_or(GuiError::FieldBindingNotFound(binding.to_string()))?;
+
+        if self.field_values.contains_key(binding)
+            && self.is_field_visible_at(fields, field, depth)?
+        {
+            return Ok(Some(self.get_field_value(binding.to_string())?.value));
+        }
+        self.resolve_field_default(fields, field, depth)
+    }
+
+    /// Default of a field, taken from its `default-from` field when that one has a value
+    pub(crate) fn resolve_field_default(
+        &self,
+        fields: &[GuiFieldDefinitionCfg],
+        field: &GuiFieldDefinitionCfg,
+        depth: usize,
+    ) -> Result<Option<String>, GuiError> {
+        if let Some(source) = &field.default_from {
+            if let Some(value) = self.effective_field_value(fields, source, depth + 1)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(field.default.clone())
+    }
+
+    fn conditions_met(
+        &self,
+        fields: &[GuiFieldDefinitionCfg],
+        conditions: &Option<Vec<GuiFieldConditionCfg>>,
+        depth: usize,
+    ) -> Result<bool, GuiError> {
+        for condition in conditions.iter().flatten() {
+            let value = match (&condition.field, &condition.select_token) {
+                (Some(binding), _) => self.effective_field_value(fields, binding, depth + 1)?,
+                (None, Some(key)) => self
+                    .dotrain_order
+                    .orderbook_yaml()
+                    .get_token(key)
+                    .ok()
+                    .map(|token| token.address.to_string()),
+                (None, None) => None,
+            };
+            if !condition.is_met(value.as_deref()) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub(crate) fn is_field_visible_at(
+        &self,
+        fields: &[GuiFieldDefinitionCfg],
+        field: &GuiFieldDefinitionCfg,
+        depth: usize,
+    ) -> Result<bool, GuiError> {
+        self.conditions_met(fields, &field.show_if, depth)
+    }
+
+    pub(crate) fn is_field_required_at(
+        &self,
+        fields: &[GuiFieldDefinitionCfg],
+        field: &GuiFieldDefinitionCfg,
+        depth: usize,
+    ) -> Result<bool, GuiError> {
+        Ok(self.is_field_visible_at(fields, field, depth)?
+            && self.conditions_met(fields, &field.required_if, depth)?)
+    }
 }
 
 #[wasm_export]
@@ -77,9 +165,7 @@ impl DotrainOrderGui {
     ) -> Result<(), GuiError> {
         let field_definition = self.get_field_definition(&field)?;
 
-        if let Some(validation) = &field_definition.validation {
-            validation::validate_field_value(&field_definition.name, &value, validation)?;
-        }
+        validation::validate_field(&field_definition, &value)?;
 
         let value = match field_definition.presets.as_ref() {
             Some(presets) => match presets.iter().position(|p| p.value == value) {
@@ -389,12 +475,75 @@ impl DotrainOrderGui {
         let mut missing_field_values = Vec::new();
 
         for field in deployment.fields.iter() {
-            if !self.field_values.contains_key(&field.binding) {
+            if !self.field_values.contains_key(&field.binding)
+                && self.is_field_required_at(&deployment.fields, field, 0)?
+            {
                 missing_field_values.push(field.clone());
             }
         }
         Ok(missing_field_values)
     }
+
+    /// Checks whether a field is currently shown.
+    ///
+    /// Fields with `show-if` conditions are hidden until every condition holds.
+    /// Hidden fields aren't required, aren't saved in the serialized state and
+    /// bind their default instead of any value set earlier.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.isFieldVisible("trailing-stop-distance");
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// if (result.value) {
+    ///   // Render the input
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "isFieldVisible",
+        unchecked_return_type = "boolean",
+        return_description = "True when all show-if conditions of the field hold"
+    )]
+    pub fn is_field_visible(
+        &self,
+        #[wasm_export(param_description = "Field binding identifier to check")] field: String,
+    ) -> Result<bool, GuiError> {
+        let deployment = self.get_current_deployment()?;
+        let field_definition = self.get_field_definition(&field)?;
+        self.is_field_visible_at(&deployment.fields, &field_definition, 0)
+    }
+
+    /// Checks whether a field must be given a value before deploying.
+    ///
+    /// A field is required when it's visible and all of its `required-if`
+    /// conditions hold. Required fields with a default are filled in automatically.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.isFieldRequired("trailing-stop-distance");
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const isRequired = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "isFieldRequired",
+        unchecked_return_type = "boolean",
+        return_description = "True when the field is visible and its required-if conditions hold"
+    )]
+    pub fn is_field_required(
+        &self,
+        #[wasm_export(param_description = "Field binding identifier to check")] field: String,
+    ) -> Result<bool, GuiError> {
+        let deployment = self.get_current_deployment()?;
+        let field_definition = self.get_field_definition(&field)?;
+        self.is_field_required_at(&deployment.fields, &field_definition, 0)
+    }
 }
 
 #[cfg(test)]
@@ -402,9 +551,8 @@ mod tests {
     use super::*;
     use crate::gui::tests::{initialize_gui, initialize_validation_gui};
     use crate::gui::validation;
-    use wasm_bindgen_test::wasm_bindgen_test;
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_get_field_value() {
         let mut gui = initialize_gui(None).await;
 
@@ -424,7 +572,7 @@ mod tests {
         assert!(field_value.is_preset);
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_get_all_field_values() {
         let mut gui = initialize_gui(None).await;
 
@@ -475,6 +623,10 @@ mod tests {
             default: Some(String::from("some-default-value")),
             show_custom_field: None,
             validation: None,
+            previous_bindings: None,
+            show_if: None,
+            required_if: None,
+            default_from: None,
         }
     }
 
@@ -503,10 +655,14 @@ mod tests {
             default: None,
             show_custom_field: Some(true),
             validation: None,
+            previous_bindings: None,
+            show_if: None,
+            required_if: None,
+            default_from: None,
         }
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_unset_field_value() {
         let mut gui = initialize_gui(None).await;
 
@@ -522,7 +678,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_field_definition() {
         let gui = initialize_gui(None).await;
 
@@ -543,7 +699,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_all_field_definitions() {
         let gui = initialize_gui(None).await;
 
@@ -561,7 +717,7 @@ mod tests {
         assert_eq!(field_definitions[0], get_binding_2());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_missing_field_values() {
         let mut gui = initialize_gui(None).await;
 
@@ -578,7 +734,7 @@ mod tests {
         assert_eq!(field_values[0], get_binding_2());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_check_field_values() {
         let mut gui = initialize_gui(None).await;
 
@@ -594,7 +750,7 @@ mod tests {
         assert!(res.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_number_minimum_maximum() {
         let mut gui = initialize_validation_gui().await;
 
@@ -636,7 +792,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_number_exclusive_bounds() {
         let mut gui = initialize_validation_gui().await;
 
@@ -679,7 +835,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_number_complex_constraints() {
         let mut gui = initialize_validation_gui().await;
 
@@ -693,7 +849,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_number_invalid_formats() {
         let mut gui = initialize_validation_gui().await;
 
@@ -734,7 +890,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_string_length_constraints() {
         let mut gui = initialize_validation_gui().await;
 
@@ -779,7 +935,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_string_edge_cases() {
         let mut gui = initialize_validation_gui().await;
 
@@ -821,7 +977,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_boolean() {
         let mut gui = initialize_validation_gui().await;
 
@@ -849,7 +1005,7 @@ mod tests {
         }
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_preset_with_validation() {
         let mut gui = initialize_validation_gui().await;
 
@@ -878,7 +1034,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_string_preset_with_validation() {
         let mut gui = initialize_validation_gui().await;
 
@@ -916,7 +1072,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_value_no_validation() {
         let mut gui = initialize_validation_gui().await;
 
@@ -943,7 +1099,7 @@ mod tests {
         }
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_set_field_values_batch_with_validation() {
         let mut gui = initialize_validation_gui().await;
 
@@ -992,7 +1148,7 @@ mod tests {
         assert!(field_result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_very_precise_decimal_validation() {
         let mut gui = initialize_validation_gui().await;
 
@@ -1006,3 +1162,209 @@ mod tests {
         assert!(result.is_ok());
     }
 }
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod condition_tests {
+    use super::*;
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+
+    fn get_dotrain() -> String {
+        format!(
+            r#"
+version: {}
+networks:
+    some-network:
+        rpcs:
+            - http://localhost:8545
+        chain-id: 123
+tokens:
+    token1:
+        network: some-network
+        address: 0x0000000000000000000000000000000000000001
+        decimals: 18
+deployers:
+    some-deployer:
+        network: some-network
+        address: 0xF14E09601A47552De6aBd3A0B165607FaFd2B5Ba
+orderbooks:
+    some-orderbook:
+        address: 0xc95A5f8eFe14d7a20BD2E5BAFEC4E71f8Ce0B9A6
+        network: some-network
+        subgraph: some-sg
+        deployment-block: 12345
+subgraphs:
+    some-sg: https://www.some-sg.com
+scenarios:
+    some-scenario:
+        deployer: some-deployer
+        bindings:
+            price: 0
+orders:
+    some-order:
+        deployer: some-deployer
+        inputs:
+            - token: token1
+        outputs:
+            - token: token1
+deployments:
+    some-deployment:
+        scenario: some-scenario
+        order: some-order
+gui:
+    name: Conditions
+    description: Conditional fields
+    deployments:
+        some-deployment:
+            name: Some deployment
+            description: Some deployment description
+            deposits:
+                - token: token1
+            fields:
+                - binding: price
+                  name: Price
+                - binding: trailing-stop
+                  name: Enable trailing stop
+                  default: "false"
+                  validation:
+                    type: boolean
+                - binding: stop-distance
+                  name: Stop distance
+                  show-if:
+                    - field: trailing-stop
+                      equals: "true"
+                - binding: stop-floor
+                  name: Stop floor
+                  default-from: price
+                  show-if:
+                    - field: trailing-stop
+                      equals: "true"
+                - binding: note
+                  name: Note
+                  required-if:
+                    - field: price
+                      one-of: ["1", "2"]
+---
+#calculate-io
+_ _: 0 0;
+#handle-io
+:;
+#handle-add-order
+:;
+"#,
+            SpecVersion::current()
+        )
+    }
+
+    async fn get_gui() -> DotrainOrderGui {
+        DotrainOrderGui::new_with_deployment(get_dotrain(), "some-deployment".to_string(), None)
+            .await
+            .unwrap()
+    }
+
+    fn missing_bindings(gui: &DotrainOrderGui) -> Vec<String> {
+        gui.get_missing_field_values()
+            .unwrap()
+            .into_iter()
+            .map(|field| field.binding)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_hidden_fields_are_not_required() {
+        let mut gui = get_gui().await;
+
+        assert!(!gui.is_field_visible("stop-distance".to_string()).unwrap());
+        assert!(!gui.is_field_required("stop-distance".to_string()).unwrap());
+        assert_eq!(missing_bindings(&gui), vec!["price", "trailing-stop"]);
+
+        gui.set_field_value("price".to_string(), "5".to_string())
+            .unwrap();
+        gui.check_field_values().unwrap();
+        assert_eq!(
+            gui.get_field_value("trailing-stop".to_string())
+                .unwrap()
+                .value,
+            "false"
+        );
+        assert!(gui.get_field_value("stop-distance".to_string()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shown_fields_are_required() {
+        let mut gui = get_gui().await;
+        gui.set_field_value("price".to_string(), "5".to_string())
+            .unwrap();
+        gui.set_field_value("trailing-stop".to_string(), "true".to_string())
+            .unwrap();
+
+        assert!(gui.is_field_visible("stop-distance".to_string()).unwrap());
+        assert_eq!(missing_bindings(&gui), vec!["stop-distance", "stop-floor"]);
+
+        let err = gui.check_field_values().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            GuiError::FieldValueNotSet("Stop distance".to_string()).to_string()
+        );
+
+        // The floor defaults to the price once the distance is set
+        gui.set_field_value("stop-distance".to_string(), "0.1".to_string())
+            .unwrap();
+        gui.check_field_values().unwrap();
+        assert_eq!(
+            gui.get_field_value("stop-floor".to_string()).unwrap().value,
+            "5"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_required_if() {
+        let mut gui = get_gui().await;
+        assert!(!gui.is_field_required("note".to_string()).unwrap());
+
+        gui.set_field_value("price".to_string(), "2".to_string())
+            .unwrap();
+        assert!(gui.is_field_visible("note".to_string()).unwrap());
+        assert!(gui.is_field_required("note".to_string()).unwrap());
+        assert!(missing_bindings(&gui).contains(&"note".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_hidden_values_are_not_serialized_or_bound() {
+        let mut gui = get_gui().await;
+        gui.set_field_value("price".to_string(), "5".to_string())
+            .unwrap();
+        gui.set_field_value("trailing-stop".to_string(), "true".to_string())
+            .unwrap();
+        gui.set_field_value("stop-distance".to_string(), "0.1".to_string())
+            .unwrap();
+        gui.set_field_value("trailing-stop".to_string(), "false".to_string())
+            .unwrap();
+
+        let state = gui.serialize_state().unwrap();
+        let restored = DotrainOrderGui::new_from_state(get_dotrain(), state, None, None)
+            .await
+            .unwrap();
+        assert!(restored
+            .get_field_value("stop-distance".to_string())
+            .is_err());
+        assert_eq!(
+            restored.get_field_value("price".to_string()).unwrap().value,
+            "5"
+        );
+
+        gui.update_scenario_bindings().unwrap();
+        let scenario = gui
+            .dotrain_order
+            .dotrain_yaml()
+            .get_scenario("some-scenario")
+            .unwrap();
+        assert_eq!(scenario.bindings.get("price"), Some(&"5".to_string()));
+        assert_eq!(
+            scenario.bindings.get("trailing-stop"),
+            Some(&"false".to_string())
+        );
+        assert!(!scenario.bindings.contains_key("stop-distance"));
+        // Hidden fields still bind their derived default
+        assert_eq!(scenario.bindings.get("stop-floor"), Some(&"5".to_string()));
+    }
+}
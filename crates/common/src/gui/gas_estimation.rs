@@ -0,0 +1,367 @@
+use super::{order_operations::DeploymentTransactionArgs, *};
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Bytes, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    serde::WithOtherFields,
+    sol_types::SolCall,
+    transports::{RpcError, TransportErrorKind},
+};
+use rain_error_decoding::AbiDecodedErrorType;
+use rain_math_float::Float;
+use rain_orderbook_bindings::{
+    provider::{mk_read_provider, ReadProvider},
+    OrderBook::multicallCall,
+};
+use std::str::FromStr;
+
+/// Native currencies are assumed to have 18 decimals, as on every EVM chain
+/// the orderbook is deployed to
+const NATIVE_DECIMALS: u8 = 18;
+
+/// Gas of a single deposit, used for the deposits of the deposit and add
+/// order step while the approvals they need aren't mined yet
+const DEPOSIT_FALLBACK_GAS: u64 = 150_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+pub enum DeploymentStepKind {
+    Approval,
+    DepositAndAddOrder,
+}
+impl_wasm_traits!(DeploymentStepKind);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentStepEstimate {
+    pub kind: DeploymentStepKind,
+    #[tsify(type = "string")]
+    pub to: Address,
+    /// Symbol of the approved token for approval steps
+    #[tsify(optional)]
+    pub symbol: Option<String>,
+    #[tsify(optional)]
+    pub gas: Option<u64>,
+    /// Formatted cost of the step at the suggested max fee per gas
+    #[tsify(optional)]
+    pub cost: Option<String>,
+    /// Decoded revert when the step would fail as it stands
+    #[tsify(optional)]
+    pub error: Option<String>,
+    /// True when the gas isn't an estimate of the whole step but adds fixed
+    /// gas for the parts that can't be estimated yet
+    pub fallback: bool,
+}
+impl_wasm_traits!(DeploymentStepEstimate);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSuggestion {
+    #[tsify(type = "string")]
+    pub max_fee_per_gas: U256,
+    #[tsify(type = "string")]
+    pub max_priority_fee_per_gas: U256,
+}
+impl_wasm_traits!(FeeSuggestion);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentCostEstimate {
+    pub chain_id: u32,
+    pub steps: Vec<DeploymentStepEstimate>,
+    pub fees: FeeSuggestion,
+    /// Gas of the steps that could be estimated
+    pub total_gas: u64,
+    /// Formatted total cost of the steps that could be estimated
+    pub total_cost: String,
+    #[tsify(optional)]
+    pub currency: Option<String>,
+    /// True when a step would revert or uses fallback gas, so the totals are
+    /// not an estimate of every transaction
+    pub partial: bool,
+}
+impl_wasm_traits!(DeploymentCostEstimate);
+
+impl DeploymentCostEstimate {
+    /// True when any step would revert, so the totals are incomplete
+    pub fn has_errors(&self) -> bool {
+        self.steps.iter().any(|step| step.error.is_some())
+    }
+}
+
+/// Node error responses are flagged on the step, anything else means the
+/// network could not be reached and fails the whole estimate.
+async fn step_error(err: RpcError<TransportErrorKind>) -> Result<String, GuiError> {
+    let Some(payload) = err.as_error_resp() else {
+        return Err(GuiError::GasEstimationFailed(err.to_string()));
+    };
+    match payload.as_revert_data() {
+        Some(data) => Ok(
+            match AbiDecodedErrorType::selector_registry_abi_decode(&data).await {
+                Ok(decoded) => decoded.to_string(),
+                Err(e) => e.to_string(),
+            },
+        ),
+        None => Ok(payload.message.to_string()),
+    }
+}
+
+fn format_cost(gas: u64, max_fee_per_gas: U256) -> Result<String, GuiError> {
+    let wei = U256::from(gas).saturating_mul(max_fee_per_gas);
+    Ok(Float::from_fixed_decimal(wei, NATIVE_DECIMALS)?.format()?)
+}
+
+async fn estimate_step(
+    provider: &ReadProvider,
+    owner: Address,
+    kind: DeploymentStepKind,
+    to: Address,
+    symbol: Option<String>,
+    calldata: Bytes,
+    max_fee_per_gas: U256,
+) -> Result<DeploymentStepEstimate, GuiError> {
+    let tx = TransactionRequest::default()
+        .with_from(owner)
+        .with_to(to)
+        .with_input(calldata);
+
+    let mut step = DeploymentStepEstimate {
+        kind,
+        to,
+        symbol,
+        gas: None,
+        cost: None,
+        error: None,
+        fallback: false,
+    };
+    match provider.estimate_gas(WithOtherFields::new(tx)).await {
+        Ok(gas) => {
+            step.gas = Some(gas);
+            step.cost = Some(format_cost(gas, max_fee_per_gas)?);
+        }
+        Err(err) => step.error = Some(step_error(err).await?),
+    }
+    Ok(step)
+}
+
+/// The deposits of the multicall pull tokens the pending approvals allow, so
+/// until those are mined only `addOrder3` can be estimated. The deposits are
+/// added at a fixed gas each.
+async fn estimate_deposit_and_add_order_fallback(
+    provider: &ReadProvider,
+    owner: Address,
+    orderbook_address: Address,
+    deployment_calldata: &Bytes,
+    max_fee_per_gas: U256,
+) -> Result<DeploymentStepEstimate, GuiError> {
+    // the multicall always starts with addOrder3 followed by the deposits
+    let mut calls = multicallCall::abi_decode(deployment_calldata)
+        .map_err(|err| GuiError::GasEstimationFailed(err.to_string()))?
+        .data
+        .into_iter();
+    let add_order = calls.next().ok_or(GuiError::GasEstimationFailed(
+        "Deployment multicall is missing the addOrder3 call".to_string(),
+    ))?;
+    let deposits = calls.len() as u64;
+
+    let mut step = estimate_step(
+        provider,
+        owner,
+        DeploymentStepKind::DepositAndAddOrder,
+        orderbook_address,
+        None,
+        add_order,
+        max_fee_per_gas,
+    )
+    .await?;
+    if let Some(gas) = step.gas {
+        let gas = gas.saturating_add(DEPOSIT_FALLBACK_GAS.saturating_mul(deposits));
+        step.gas = Some(gas);
+        step.cost = Some(format_cost(gas, max_fee_per_gas)?);
+        step.fallback = true;
+    }
+    Ok(step)
+}
+
+#[wasm_export]
+impl DotrainOrderGui {
+    /// Estimates gas and cost of every transaction needed to deploy the order.
+    ///
+    /// Builds the same transactions as `getDeploymentTransactionArgs`, estimates
+    /// each of them from the owner and prices them with the network's EIP-1559
+    /// fee suggestion. The deposit and add order step pulls tokens from the
+    /// owner, so while approvals are pending only adding the order is
+    /// estimated and the deposits get fixed gas. Such steps are flagged as
+    /// `fallback` and the estimate as `partial`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await gui.estimateDeploymentCost(walletAddress);
+    /// if (result.error) {
+    ///   console.error("Estimation failed:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { steps, fees, totalGas, totalCost, currency, partial } = result.value;
+    /// for (const { kind, symbol, gas, cost, error, fallback } of steps) {
+    ///   // Show the cost of each step or why it would revert
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "estimateDeploymentCost",
+        unchecked_return_type = "DeploymentCostEstimate",
+        return_description = "Gas and cost of each deployment step with the fee suggestion and totals"
+    )]
+    pub async fn estimate_deployment_cost(
+        &mut self,
+        #[wasm_export(param_description = "Wallet address that will deploy the order")]
+        owner: String,
+    ) -> Result<DeploymentCostEstimate, GuiError> {
+        let args = self.get_deployment_transaction_args(owner.clone()).await?;
+        self.estimate_deployment_transactions(Address::from_str(&owner)?, &args)
+            .await
+    }
+}
+
+impl DotrainOrderGui {
+    /// Estimates the given deployment transactions, so callers that send
+    /// them estimate exactly what they broadcast.
+    pub async fn estimate_deployment_transactions(
+        &self,
+        owner: Address,
+        args: &DeploymentTransactionArgs,
+    ) -> Result<DeploymentCostEstimate, GuiError> {
+        let DeploymentTransactionArgs {
+            approvals,
+            deployment_calldata,
+            orderbook_address,
+            chain_id,
+        } = args;
+        let network = self
+            .get_current_deployment()?
+            .deployment
+            .order
+            .network
+            .clone();
+
+        let provider = mk_read_provider(&network.rpcs)?;
+        let fees = provider
+            .estimate_eip1559_fees()
+            .await
+            .map_err(|err| GuiError::GasEstimationFailed(err.to_string()))?;
+        let fees = FeeSuggestion {
+            max_fee_per_gas: U256::from(fees.max_fee_per_gas),
+            max_priority_fee_per_gas: U256::from(fees.max_priority_fee_per_gas),
+        };
+
+        let mut steps = vec![];
+        for approval in approvals {
+            steps.push(
+                estimate_step(
+                    &provider,
+                    owner,
+                    DeploymentStepKind::Approval,
+                    approval.token,
+                    Some(approval.symbol.clone()),
+                    approval.calldata.clone(),
+                    fees.max_fee_per_gas,
+                )
+                .await?,
+            );
+        }
+        let mut deposit_and_add_order = estimate_step(
+            &provider,
+            owner,
+            DeploymentStepKind::DepositAndAddOrder,
+            *orderbook_address,
+            None,
+            deployment_calldata.clone(),
+            fees.max_fee_per_gas,
+        )
+        .await?;
+        if deposit_and_add_order.error.is_some() && !approvals.is_empty() {
+            deposit_and_add_order = estimate_deposit_and_add_order_fallback(
+                &provider,
+                owner,
+                *orderbook_address,
+                deployment_calldata,
+                fees.max_fee_per_gas,
+            )
+            .await?;
+        }
+        steps.push(deposit_and_add_order);
+
+        let total_gas = steps.iter().filter_map(|step| step.gas).sum::<u64>();
+        let partial = steps
+            .iter()
+            .any(|step| step.error.is_some() || step.fallback);
+        Ok(DeploymentCostEstimate {
+            chain_id: *chain_id,
+            total_cost: format_cost(total_gas, fees.max_fee_per_gas)?,
+            total_gas,
+            steps,
+            fees,
+            currency: network.currency,
+            partial,
+        })
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::gui::preview::tests::get_gui;
+    use rain_orderbook_test_fixtures::LocalEvm;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_estimate_deployment_cost() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let owner = local_evm.anvil.addresses()[0];
+        let mut gui = get_gui(&local_evm).await;
+
+        let estimate = gui
+            .estimate_deployment_cost(owner.to_string())
+            .await
+            .unwrap();
+        assert_eq!(estimate.chain_id, 123);
+        assert_eq!(estimate.steps.len(), 1);
+        assert_eq!(
+            estimate.steps[0].kind,
+            DeploymentStepKind::DepositAndAddOrder
+        );
+        assert_eq!(estimate.steps[0].to, *local_evm.orderbook.address());
+        assert!(estimate.steps[0].gas.unwrap() > 0);
+        assert!(!estimate.steps[0].fallback);
+        assert!(!estimate.has_errors());
+        assert!(!estimate.partial);
+        assert_eq!(estimate.total_gas, estimate.steps[0].gas.unwrap());
+        assert_eq!(
+            estimate.total_cost,
+            format_cost(estimate.total_gas, estimate.fees.max_fee_per_gas).unwrap()
+        );
+
+        gui.set_deposit("t2".to_string(), "10".to_string())
+            .await
+            .unwrap();
+
+        // the deposit can't be estimated until the approval is mined, so it
+        // gets fallback gas on top of the add order estimate
+        let estimate = gui
+            .estimate_deployment_cost(owner.to_string())
+            .await
+            .unwrap();
+        assert_eq!(estimate.steps.len(), 2);
+        assert_eq!(estimate.steps[0].kind, DeploymentStepKind::Approval);
+        assert_eq!(estimate.steps[0].symbol, Some("TOKEN2".to_string()));
+        assert!(estimate.steps[0].error.is_none());
+        assert!(estimate.steps[1].error.is_none());
+        assert!(estimate.steps[1].fallback);
+        assert!(estimate.steps[1].gas.unwrap() > DEPOSIT_FALLBACK_GAS);
+        assert!(!estimate.has_errors());
+        assert!(estimate.partial);
+        assert_eq!(
+            estimate.total_gas,
+            estimate.steps[0].gas.unwrap() + estimate.steps[1].gas.unwrap()
+        );
+    }
+}
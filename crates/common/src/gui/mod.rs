@@ -1,3 +1,9 @@
+use crate::{
+    dotrain::{types::patterns::FRONTMATTER_SEPARATOR, RainDocument},
+    dotrain_order::{DotrainOrder, DotrainOrderError},
+    erc20::ERC20,
+    utils::amount_formatter::AmountFormatterError,
+};
 use alloy::primitives::Address;
 use alloy_ethers_typecast::ReadableClientError;
 use base64::{engine::general_purpose::URL_SAFE, Engine};
@@ -6,8 +12,8 @@ use rain_math_float::FloatError;
 use rain_orderbook_app_settings::{
     deployment::DeploymentCfg,
     gui::{
-        GuiCfg, GuiDeploymentCfg, GuiFieldDefinitionCfg, GuiPresetCfg, NameAndDescriptionCfg,
-        ParseGuiConfigSourceError,
+        GuiCfg, GuiDeploymentCfg, GuiFieldConditionCfg, GuiFieldDefinitionCfg, GuiPresetCfg,
+        NameAndDescriptionCfg, ParseGuiConfigSourceError,
     },
     network::NetworkCfg,
     order::OrderCfg,
@@ -16,12 +22,6 @@ use rain_orderbook_app_settings::{
         YamlError, YamlParsable,
     },
 };
-use rain_orderbook_common::{
-    dotrain::{types::patterns::FRONTMATTER_SEPARATOR, RainDocument},
-    dotrain_order::{DotrainOrder, DotrainOrderError},
-    erc20::ERC20,
-    utils::amount_formatter::AmountFormatterError,
-};
 use serde::{Deserialize, Serialize};
 use std::io::prelude::*;
 use std::{
@@ -32,14 +32,19 @@ use strict_yaml_rust::StrictYaml;
 use thiserror::Error;
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*, wasm_export};
 
-mod deposits;
-mod field_values;
-mod order_operations;
-mod select_tokens;
-mod state_management;
-mod validation;
+pub mod deposits;
+pub mod field_values;
+pub mod gas_estimation;
+pub mod order_meta;
+pub mod order_operations;
+pub mod preview;
+pub mod select_tokens;
+pub mod state_envelope;
+pub mod state_management;
+pub mod validation;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
 pub struct TokenInfo {
     pub key: String,
     #[tsify(type = "string")]
@@ -47,6 +52,10 @@ pub struct TokenInfo {
     pub decimals: u8,
     pub name: String,
     pub symbol: String,
+    #[tsify(optional)]
+    pub logo_uri: Option<String>,
+    #[tsify(optional)]
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -58,6 +67,12 @@ pub struct DotrainOrderGui {
     deposits: BTreeMap<String, field_values::PairValue>,
     #[serde(skip)]
     state_update_callback: Option<js_sys::Function>,
+    #[serde(skip)]
+    migration_report: Option<state_management::StateMigrationReport>,
+    #[serde(skip)]
+    registry_origin: Option<order_meta::RegistryOrigin>,
+    #[serde(skip)]
+    embed_gui_meta: bool,
 }
 impl Default for DotrainOrderGui {
     fn default() -> Self {
@@ -67,6 +82,9 @@ impl Default for DotrainOrderGui {
             field_values: BTreeMap::new(),
             deposits: BTreeMap::new(),
             state_update_callback: None,
+            migration_report: None,
+            registry_origin: None,
+            embed_gui_meta: false,
         }
     }
 }
@@ -178,6 +196,9 @@ impl DotrainOrderGui {
             field_values: BTreeMap::new(),
             deposits: BTreeMap::new(),
             state_update_callback,
+            migration_report: None,
+            registry_origin: None,
+            embed_gui_meta: false,
         })
     }
 
@@ -297,6 +318,8 @@ impl DotrainOrderGui {
                 decimals: *decimals,
                 name: label.clone(),
                 symbol: symbol.clone(),
+                logo_uri: token.logo_uri.clone(),
+                tags: token.tags.clone(),
             }
         } else {
             let order_key = DeploymentCfg::parse_order_key(
@@ -319,6 +342,8 @@ impl DotrainOrderGui {
                 decimals: token.decimals.unwrap_or(onchain_info.decimals),
                 name: token.label.unwrap_or(onchain_info.name),
                 symbol: token.symbol.unwrap_or(onchain_info.symbol),
+                logo_uri: token.logo_uri,
+                tags: token.tags,
             }
         };
 
@@ -649,6 +674,18 @@ pub enum GuiError {
     BindingHasNoPresets(String),
     #[error("Token not in select tokens: {0}")]
     TokenNotInSelectTokens(String),
+    #[error("Field reference cycle: {0}")]
+    FieldReferenceCycle(String),
+    #[error("Invalid state signature: {0}")]
+    InvalidStateSignature(String),
+    #[error("State has no envelope")]
+    MissingStateEnvelope,
+    #[error("Invalid state envelope: {0}")]
+    InvalidStateEnvelope(String),
+    #[error("Gas estimation failed: {0}")]
+    GasEstimationFailed(String),
+    #[error("Order preview call failed: {0}")]
+    PreviewCallFailed(String),
     #[error("JavaScript error: {0}")]
     JsError(String),
     #[error(transparent)]
@@ -666,7 +703,7 @@ pub enum GuiError {
     #[error(transparent)]
     ReadableClientError(#[from] ReadableClientError),
     #[error(transparent)]
-    DepositError(#[from] rain_orderbook_common::deposit::DepositError),
+    DepositError(#[from] crate::deposit::DepositError),
     #[error(transparent)]
     ParseError(#[from] alloy::primitives::ruint::ParseError),
     #[error(transparent)]
@@ -676,13 +713,11 @@ pub enum GuiError {
     #[error(transparent)]
     UnitsError(#[from] alloy::primitives::utils::UnitsError),
     #[error(transparent)]
-    WritableTransactionExecuteError(
-        #[from] rain_orderbook_common::transaction::WritableTransactionExecuteError,
-    ),
+    WritableTransactionExecuteError(#[from] crate::transaction::WritableTransactionExecuteError),
     #[error(transparent)]
-    AddOrderArgsError(#[from] rain_orderbook_common::add_order::AddOrderArgsError),
+    AddOrderArgsError(#[from] crate::add_order::AddOrderArgsError),
     #[error(transparent)]
-    ERC20Error(#[from] rain_orderbook_common::erc20::Error),
+    ERC20Error(#[from] crate::erc20::Error),
     #[error(transparent)]
     SolTypesError(#[from] alloy::sol_types::Error),
     #[error(transparent)]
@@ -697,6 +732,10 @@ pub enum GuiError {
     AmountFormatterError(#[from] AmountFormatterError),
     #[error(transparent)]
     FloatError(#[from] FloatError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReadProviderError(#[from] rain_orderbook_bindings::provider::ReadProviderError),
 }
 
 impl GuiError {
@@ -742,6 +781,18 @@ impl GuiError {
                 format!("The binding '{}' does not have any presets configured in the YAML configuration.", binding),
             GuiError::TokenNotInSelectTokens(token) =>
                 format!("The token '{}' is not in the list of selectable tokens defined in the YAML configuration.", token),
+            GuiError::FieldReferenceCycle(field) =>
+                format!("The conditions or default of field '{}' reference each other in a loop. Please check your YAML configuration.", field),
+            GuiError::InvalidStateSignature(err) =>
+                format!("The signature on the shared state is invalid: {}", err),
+            GuiError::MissingStateEnvelope =>
+                "This state was not created as a shareable link. Please ask for a new link.".to_string(),
+            GuiError::InvalidStateEnvelope(err) =>
+                format!("The shared state link is damaged or incomplete: {}", err),
+            GuiError::GasEstimationFailed(err) =>
+                format!("Failed to estimate the deployment gas on the network: {}", err),
+            GuiError::PreviewCallFailed(err) =>
+                format!("Failed to simulate the order on the network: {}", err),
             GuiError::JsError(msg) =>
                 format!("A JavaScript error occurred: {}", msg),
             GuiError::DotrainOrderError(err) =>
@@ -784,6 +835,12 @@ impl GuiError {
             GuiError::FloatError(err) => {
                 format!("There was a problem with the float value: {err}")
             }
+            GuiError::SerdeJsonError(err) => {
+                format!("The shared state could not be decoded: {err}")
+            }
+            GuiError::ReadProviderError(err) => {
+                format!("Failed to connect to the network RPCs: {err}")
+            }
         }
     }
 }
@@ -808,7 +865,6 @@ mod tests {
     use super::*;
     use rain_orderbook_app_settings::spec_version::SpecVersion;
     use rain_orderbook_app_settings::yaml::FieldErrorKind;
-    use wasm_bindgen_test::wasm_bindgen_test;
 
     pub fn get_yaml() -> String {
         format!(
@@ -1255,7 +1311,7 @@ _ _: 0 0;
         .unwrap()
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_deployment_keys() {
         let deployment_keys = DotrainOrderGui::get_deployment_keys(get_yaml())
             .await
@@ -1270,7 +1326,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_new_with_deployment() {
         let res =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1291,7 +1347,7 @@ _ _: 0 0;
         assert_eq!(err.to_readable_msg(), "The deployment 'invalid-deployment' could not be found. Please select a valid deployment from your YAML configuration.");
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_gui_config() {
         let gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1372,7 +1428,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_current_deployment() {
         let gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1449,7 +1505,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_token_info_local() {
         let gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1488,7 +1544,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_all_token_infos_local() {
         let gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1513,7 +1569,7 @@ _ _: 0 0;
         assert_eq!(token_infos[1].symbol, "T2");
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_get_order_details() {
         let order_details = DotrainOrderGui::get_order_details(get_yaml()).unwrap();
         assert_eq!(order_details.name, "Fixed limit");
@@ -1612,7 +1668,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_get_deployment_details() {
         let deployment_details = DotrainOrderGui::get_deployment_details(get_yaml()).unwrap();
         assert_eq!(deployment_details.len(), 3);
@@ -1811,7 +1867,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_get_deployment_detail() {
         let deployment_detail =
             DotrainOrderGui::get_deployment_detail(get_yaml(), "some-deployment".to_string())
@@ -1827,7 +1883,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_current_deployment_detail() {
         let gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1846,7 +1902,7 @@ _ _: 0 0;
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_generate_dotrain_text() {
         let gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1864,7 +1920,7 @@ _ _: 0 0;
         assert_eq!(new_current_deployment, original_current_deployment);
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_composed_rainlang() {
         let mut gui =
             DotrainOrderGui::new_with_deployment(get_yaml(), "some-deployment".to_string(), None)
@@ -1876,14 +1932,17 @@ _ _: 0 0;
             "/* 0. calculate-io */ \n_ _: 0 0;\n\n/* 1. handle-io */ \n:;".to_string();
         assert_eq!(rainlang, expected_rainlang);
     }
+}
 
-    #[cfg(not(target_family = "wasm"))]
-    mod select_token_tests {
-        use super::*;
-        use httpmock::MockServer;
-        use serde_json::json;
+#[cfg(all(test, not(target_family = "wasm")))]
+mod select_token_tests {
+    use super::*;
+    use httpmock::MockServer;
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+    use rain_orderbook_app_settings::yaml::FieldErrorKind;
+    use serde_json::json;
 
-        pub const SELECT_TOKEN_YAML: &str = r#"
+    pub const SELECT_TOKEN_YAML: &str = r#"
 gui:
     name: Fixed limit
     description: Fixed limit order order
@@ -1962,11 +2021,11 @@ _ _: 0 0;
 :;
 "#;
 
-        #[tokio::test]
-        async fn test_get_token_info_remote() {
-            let server = MockServer::start_async().await;
-            let yaml = format!(
-                r#"
+    #[tokio::test]
+    async fn test_get_token_info_remote() {
+        let server = MockServer::start_async().await;
+        let yaml = format!(
+            r#"
 version: {spec_version}
 networks:
     some-network:
@@ -1977,67 +2036,66 @@ networks:
         currency: ETH
 {yaml}
 "#,
-                spec_version = SpecVersion::current(),
-                yaml = SELECT_TOKEN_YAML,
-                rpc_url = server.url("/rpc")
-            );
-
-            server.mock(|when, then| {
-                        when.method("POST").path("/rpc").body_contains("0x82ad56cb");
-                        then.json_body(json!({
-                            "jsonrpc": "2.0",
-                            "id": 1,
-                            "result": "0x00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000e000000000000000000000000000000000000000000000000000000000000001a0000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000007546f6b656e203100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000025431000000000000000000000000000000000000000000000000000000000000",
-                        }));
-                    });
-
-            let mut gui = DotrainOrderGui::new_with_deployment(
-                yaml.to_string(),
-                "some-deployment".to_string(),
-                None,
-            )
-            .await
-            .unwrap();
+            spec_version = SpecVersion::current(),
+            yaml = SELECT_TOKEN_YAML,
+            rpc_url = server.url("/rpc")
+        );
 
-            let err = gui.get_token_info("token3".to_string()).await.unwrap_err();
-            assert_eq!(
-                err.to_string(),
-                YamlError::Field {
-                    kind: FieldErrorKind::Missing("tokens".to_string()),
-                    location: "root".to_string(),
-                }
-                .to_string()
-            );
-            assert_eq!(
-                err.to_readable_msg(),
-                "YAML configuration error: Missing required field 'tokens' in root"
-            );
-
-            gui.set_select_token(
-                "token3".to_string(),
-                "0x0000000000000000000000000000000000000001".to_string(),
-            )
-            .await
-            .unwrap();
+        server.mock(|when, then| {
+                    when.method("POST").path("/rpc").body_contains("0x82ad56cb");
+                    then.json_body(json!({
+                        "jsonrpc": "2.0",
+                        "id": 1,
+                        "result": "0x00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000e000000000000000000000000000000000000000000000000000000000000001a0000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000007546f6b656e203100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000025431000000000000000000000000000000000000000000000000000000000000",
+                    }));
+                });
+
+        let mut gui = DotrainOrderGui::new_with_deployment(
+            yaml.to_string(),
+            "some-deployment".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
 
-            let token_info = gui.get_token_info("token3".to_string()).await.unwrap();
-            assert_eq!(
-                token_info.address.to_string(),
-                "0x0000000000000000000000000000000000000001"
-            );
-            assert_eq!(token_info.decimals, 6);
-            assert_eq!(token_info.name, "Token 1");
-            assert_eq!(token_info.symbol, "T1");
-
-            let token_infos = gui.get_all_token_infos().await.unwrap();
-            assert_eq!(token_infos.len(), 1);
-            assert_eq!(
-                token_infos[0].address.to_string(),
-                "0x0000000000000000000000000000000000000001"
-            );
-            assert_eq!(token_infos[0].decimals, 6);
-            assert_eq!(token_infos[0].name, "Token 1");
-            assert_eq!(token_infos[0].symbol, "T1");
-        }
+        let err = gui.get_token_info("token3".to_string()).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            YamlError::Field {
+                kind: FieldErrorKind::Missing("tokens".to_string()),
+                location: "root".to_string(),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            err.to_readable_msg(),
+            "YAML configuration error: Missing required field 'tokens' in root"
+        );
+
+        gui.set_select_token(
+            "token3".to_string(),
+            "0x0000000000000000000000000000000000000001".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let token_info = gui.get_token_info("token3".to_string()).await.unwrap();
+        assert_eq!(
+            token_info.address.to_string(),
+            "0x0000000000000000000000000000000000000001"
+        );
+        assert_eq!(token_info.decimals, 6);
+        assert_eq!(token_info.name, "Token 1");
+        assert_eq!(token_info.symbol, "T1");
+
+        let token_infos = gui.get_all_token_infos().await.unwrap();
+        assert_eq!(token_infos.len(), 1);
+        assert_eq!(
+            token_infos[0].address.to_string(),
+            "0x0000000000000000000000000000000000000001"
+        );
+        assert_eq!(token_infos[0].decimals, 6);
+        assert_eq!(token_infos[0].name, "Token 1");
+        assert_eq!(token_infos[0].symbol, "T1");
     }
 }
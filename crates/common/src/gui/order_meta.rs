@@ -0,0 +1,218 @@
+use super::*;
+use crate::{
+    add_order::AddOrderArgs,
+    meta::{GuiDeploymentMeta, GuiFieldValueMeta, OrderGuiMeta},
+};
+
+/// Registry, order key and the order's own `.rain` a GUI was opened from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct RegistryOrigin {
+    pub registry: String,
+    pub order_key: String,
+    pub dotrain: String,
+}
+
+impl DotrainOrderGui {
+    pub(crate) fn set_registry_origin(
+        &mut self,
+        registry: String,
+        order_key: String,
+        dotrain: String,
+    ) {
+        self.registry_origin = Some(RegistryOrigin {
+            registry,
+            order_key,
+            dotrain,
+        });
+    }
+
+    /// Add order args for the current deployment, with the GUI meta attached
+    /// when it was enabled with `setEmbedGuiMeta`
+    pub(super) async fn get_add_order_args(
+        &self,
+        deployment: &GuiDeploymentCfg,
+    ) -> Result<AddOrderArgs, GuiError> {
+        let args = AddOrderArgs::new_from_deployment(
+            self.dotrain_order.dotrain()?,
+            deployment.deployment.as_ref().clone(),
+        )
+        .await?;
+        if !self.embed_gui_meta {
+            return Ok(args);
+        }
+        Ok(args.with_gui_meta(self.get_order_gui_meta()?))
+    }
+}
+
+#[wasm_export]
+impl DotrainOrderGui {
+    /// Enables or disables embedding the GUI meta in added orders.
+    ///
+    /// Off by default. When enabled, the calldata generated for addOrder carries
+    /// the result of `getOrderGuiMeta` next to the Rainlang source, which makes the
+    /// transaction larger and costs more gas. The meta is public once the order is
+    /// added.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// gui.setEmbedGuiMeta(true);
+    /// const result = await gui.generateAddOrderCalldata();
+    /// ```
+    #[wasm_export(js_name = "setEmbedGuiMeta", unchecked_return_type = "void")]
+    pub fn set_embed_gui_meta(
+        &mut self,
+        #[wasm_export(param_description = "Whether added orders carry the GUI meta")] enabled: bool,
+    ) -> Result<(), GuiError> {
+        self.embed_gui_meta = enabled;
+        Ok(())
+    }
+
+    /// Builds the GUI meta that can be embedded in the order when it is added.
+    ///
+    /// The meta holds the deployment, the field values with presets resolved,
+    /// a shareable state and the order's dotrain source. For GUIs opened from a
+    /// registry the dotrain is the order's `.rain` without the registry settings,
+    /// so no rpc urls end up on chain; such orders are reopened from the registry
+    /// with `DotrainRegistry.getGuiFromState(meta.deployment.state)`. Other orders
+    /// are reopened with `DotrainOrderGui.newFromState(meta.dotrain, meta.deployment.state)`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.getOrderGuiMeta();
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { deployment, dotrain } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getOrderGuiMeta",
+        unchecked_return_type = "OrderGuiMeta",
+        return_description = "Deployment, field values, state and dotrain to embed in the order meta"
+    )]
+    pub fn get_order_gui_meta(&self) -> Result<OrderGuiMeta, GuiError> {
+        let deployment = self.get_current_deployment()?;
+        let details = self.get_current_deployment_details()?;
+
+        let field_values = self
+            .get_all_field_values()?
+            .into_iter()
+            .map(|field_value| GuiFieldValueMeta {
+                name: deployment
+                    .fields
+                    .iter()
+                    .find(|field| field.binding == field_value.field)
+                    .map(|field| field.name.clone())
+                    .unwrap_or_else(|| field_value.field.clone()),
+                binding: field_value.field,
+                value: field_value.value,
+            })
+            .collect();
+
+        let order_key = self
+            .registry_origin
+            .as_ref()
+            .map(|origin| origin.order_key.clone());
+        let dotrain = match &self.registry_origin {
+            Some(origin) => origin.dotrain.clone(),
+            None => self.dotrain_order.dotrain()?,
+        };
+        Ok(OrderGuiMeta {
+            deployment: GuiDeploymentMeta {
+                deployment_key: self.selected_deployment.clone(),
+                deployment_name: details.name,
+                registry: self
+                    .registry_origin
+                    .as_ref()
+                    .map(|origin| origin.registry.clone()),
+                state: self.serialize_shareable_state(order_key.clone(), None)?,
+                order_key,
+                field_values,
+            },
+            dotrain,
+        })
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::gui::preview::tests::get_gui;
+    use rain_orderbook_test_fixtures::LocalEvm;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_add_order_args_without_gui_meta() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let gui = get_gui(&local_evm).await;
+
+        let args = gui
+            .get_add_order_args(&gui.get_current_deployment().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(args.gui, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_order_gui_meta_registry_origin() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let mut gui = get_gui(&local_evm).await;
+        gui.set_registry_origin(
+            "https://example.com/registry.txt".to_string(),
+            "some-order".to_string(),
+            "order dotrain".to_string(),
+        );
+
+        let meta = gui.get_order_gui_meta().unwrap();
+        assert_eq!(meta.dotrain, "order dotrain");
+        assert_eq!(
+            meta.deployment.registry,
+            Some("https://example.com/registry.txt".to_string())
+        );
+        assert_eq!(meta.deployment.order_key, Some("some-order".to_string()));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_order_gui_meta_round_trip() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let mut gui = get_gui(&local_evm).await;
+        gui.set_embed_gui_meta(true).unwrap();
+
+        let meta = gui.get_order_gui_meta().unwrap();
+        assert_eq!(meta.dotrain, gui.dotrain_order.dotrain().unwrap());
+        assert_eq!(meta.deployment.deployment_key, gui.selected_deployment);
+        assert_eq!(meta.deployment.registry, None);
+        assert_eq!(meta.deployment.order_key, None);
+        assert_eq!(
+            meta.deployment.field_values,
+            vec![GuiFieldValueMeta {
+                binding: "max-amount".to_string(),
+                name: "Max amount".to_string(),
+                value: "16".to_string(),
+            }]
+        );
+
+        let add_order_call = gui
+            .get_add_order_args(&gui.get_current_deployment().unwrap())
+            .await
+            .unwrap()
+            .try_into_call(vec![local_evm.url()])
+            .await
+            .unwrap();
+        let decoded = OrderGuiMeta::try_decode(&add_order_call.config.meta)
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded, meta);
+
+        let restored =
+            DotrainOrderGui::new_from_state(decoded.dotrain, decoded.deployment.state, None, None)
+                .await
+                .unwrap();
+        assert_eq!(restored.selected_deployment, gui.selected_deployment);
+        assert_eq!(
+            restored.get_all_field_values().unwrap(),
+            gui.get_all_field_values().unwrap()
+        );
+    }
+}
@@ -1,19 +1,20 @@
 use super::*;
+use crate::{
+    add_order::AddOrderArgs, deposit::DepositArgs, erc20::ERC20, transaction::TransactionArgs,
+};
 use alloy::{
     primitives::{Bytes, B256, U256},
     sol_types::SolCall,
 };
 use rain_math_float::Float;
 use rain_orderbook_app_settings::{
+    gui::FieldValueValidationCfg,
     order::{OrderIOCfg, VaultType},
     orderbook::OrderbookCfg,
 };
 use rain_orderbook_bindings::{
     IOrderBookV5::deposit3Call, OrderBook::multicallCall, IERC20::approveCall,
 };
-use rain_orderbook_common::{
-    add_order::AddOrderArgs, deposit::DepositArgs, erc20::ERC20, transaction::TransactionArgs,
-};
 use std::ops::Sub;
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 use url::Url;
@@ -83,12 +84,12 @@ impl_wasm_traits!(ExtendedApprovalCalldata);
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
 #[serde(rename_all = "camelCase")]
 pub struct DeploymentTransactionArgs {
-    approvals: Vec<ExtendedApprovalCalldata>,
+    pub approvals: Vec<ExtendedApprovalCalldata>,
     #[tsify(type = "string")]
-    deployment_calldata: Bytes,
+    pub deployment_calldata: Bytes,
     #[tsify(type = "string")]
-    orderbook_address: Address,
-    chain_id: u32,
+    pub orderbook_address: Address,
+    pub chain_id: u32,
 }
 impl_wasm_traits!(DeploymentTransactionArgs);
 
@@ -112,7 +113,7 @@ pub struct VaultAndDeposit {
 
 #[wasm_export]
 impl DotrainOrderGui {
-    fn get_orderbook(&self) -> Result<Arc<OrderbookCfg>, GuiError> {
+    pub(crate) fn get_orderbook(&self) -> Result<Arc<OrderbookCfg>, GuiError> {
         let deployment = self.get_current_deployment()?;
         deployment
             .deployment
@@ -125,7 +126,7 @@ impl DotrainOrderGui {
             .cloned()
     }
 
-    fn get_transaction_args(&self) -> Result<TransactionArgs, GuiError> {
+    pub(crate) fn get_transaction_args(&self) -> Result<TransactionArgs, GuiError> {
         let orderbook = self.get_orderbook()?;
         Ok(TransactionArgs {
             orderbook_address: orderbook.address,
@@ -191,7 +192,67 @@ impl DotrainOrderGui {
         });
     }
 
-    fn prepare_calldata_generation(
+    /// Deposit args of the non zero deposits, `None` when the deployment has
+    /// no deposits configured
+    pub(crate) async fn get_deposit_args(&mut self) -> Result<Option<Vec<DepositArgs>>, GuiError> {
+        let deployment = self.prepare_calldata_generation(CalldataFunction::Deposit)?;
+
+        let vaults_and_deposits = self.get_vaults_and_deposits(&deployment).await?;
+        if vaults_and_deposits.is_empty() {
+            return Ok(None);
+        }
+
+        let mut deposits = Vec::new();
+        for VaultAndDeposit {
+            order_io,
+            deposit_amount,
+            index,
+        } in vaults_and_deposits
+        {
+            if deposit_amount.eq(Float::parse("0".to_string())?)? {
+                continue;
+            }
+
+            let token = order_io
+                .token
+                .as_ref()
+                .ok_or(GuiError::SelectTokensNotSet)?;
+            let vault_id = order_io
+                .vault_id
+                .ok_or(GuiError::VaultIdNotFound(index.to_string()))?;
+
+            let decimals = if let Some(decimals) = token.decimals {
+                decimals
+            } else {
+                let tx_args = self.get_transaction_args()?;
+                let rpcs = tx_args
+                    .rpcs
+                    .iter()
+                    .map(|rpc| Url::parse(rpc))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let erc20 = ERC20::new(rpcs, token.address);
+                erc20.decimals().await?
+            };
+
+            deposits.push(DepositArgs {
+                token: token.address,
+                amount: deposit_amount,
+                vault_id: vault_id.into(),
+                decimals,
+            });
+        }
+
+        Ok(Some(deposits))
+    }
+
+    /// Add order args for the current deployment with the field values, vault
+    /// ids and GUI meta applied
+    pub(crate) async fn prepare_add_order_args(&mut self) -> Result<AddOrderArgs, GuiError> {
+        let deployment = self.prepare_calldata_generation(CalldataFunction::AddOrder)?;
+        self.get_add_order_args(&deployment).await
+    }
+
+    pub(crate) fn prepare_calldata_generation(
         &mut self,
         calldata_function: CalldataFunction,
     ) -> Result<GuiDeploymentCfg, GuiError> {
@@ -369,15 +430,51 @@ impl DotrainOrderGui {
         self.dotrain_order
             .dotrain_yaml()
             .get_scenario(&deployment.deployment.scenario.key)?
-            .update_bindings(
-                self.field_values
-                    .keys()
-                    .map(|k| Ok((k.clone(), self.get_field_value(k.clone())?.value.clone())))
-                    .collect::<Result<HashMap<String, String>, GuiError>>()?,
-            )?;
+            .update_bindings(self.get_binding_values(&deployment.fields)?)?;
         Ok(())
     }
 
+    /// Values bound in the scenario for every field that has one. Hidden fields
+    /// fall back to their default so a switched off feature keeps a known value.
+    fn get_binding_values(
+        &self,
+        fields: &[GuiFieldDefinitionCfg],
+    ) -> Result<HashMap<String, String>, GuiError> {
+        let mut bindings = HashMap::new();
+        for field in fields {
+            if let Some(value) = self.effective_field_value(fields, &field.binding, 0)? {
+                bindings.insert(
+                    field.binding.clone(),
+                    self.get_binding_value(field, &value)?,
+                );
+            }
+        }
+        Ok(bindings)
+    }
+
+    /// Resolves a field value into the literal bound in the scenario, e.g. a
+    /// token amount scaled by the token decimals or a duration in seconds.
+    fn get_binding_value(
+        &self,
+        field: &GuiFieldDefinitionCfg,
+        value: &str,
+    ) -> Result<String, GuiError> {
+        let token_decimals = match &field.validation {
+            Some(FieldValueValidationCfg::TokenAmount { token, .. }) => {
+                self.dotrain_order
+                    .orderbook_yaml()
+                    .get_token(token)?
+                    .decimals
+            }
+            _ => None,
+        };
+        Ok(validation::field_value_literal(
+            field,
+            value,
+            token_decimals,
+        )?)
+    }
+
     /// Generates calldata for depositing tokens into orderbook vaults.
     ///
     /// Creates deposit calldatas for all configured deposits, automatically
@@ -405,53 +502,14 @@ impl DotrainOrderGui {
         return_description = "Deposit calldatas to execute or NoDeposits if none configured"
     )]
     pub async fn generate_deposit_calldatas(&mut self) -> Result<DepositCalldataResult, GuiError> {
-        let deployment = self.prepare_calldata_generation(CalldataFunction::Deposit)?;
-
-        let vaults_and_deposits = self.get_vaults_and_deposits(&deployment).await?;
-        if vaults_and_deposits.is_empty() {
+        let Some(deposits) = self.get_deposit_args().await? else {
             return Ok(DepositCalldataResult::NoDeposits);
-        }
+        };
 
         let mut calldatas = Vec::new();
-        for VaultAndDeposit {
-            order_io,
-            deposit_amount,
-            index,
-        } in vaults_and_deposits
-        {
-            if deposit_amount.eq(Float::parse("0".to_string())?)? {
-                continue;
-            }
-
-            let token = order_io
-                .token
-                .as_ref()
-                .ok_or(GuiError::SelectTokensNotSet)?;
-            let vault_id = order_io
-                .vault_id
-                .ok_or(GuiError::VaultIdNotFound(index.to_string()))?;
-
-            let decimals = if let Some(decimals) = token.decimals {
-                decimals
-            } else {
-                let tx_args = self.get_transaction_args()?;
-                let rpcs = tx_args
-                    .rpcs
-                    .iter()
-                    .map(|rpc| Url::parse(rpc))
-                    .collect::<Result<Vec<_>, _>>()?;
-                let erc20 = ERC20::new(rpcs, token.address);
-                erc20.decimals().await?
-            };
-
-            let deposit_args = DepositArgs {
-                token: token.address,
-                amount: deposit_amount,
-                vault_id: vault_id.into(),
-                decimals,
-            };
+        for deposit_args in deposits {
             let calldata = deposit3Call::try_from(deposit_args)
-                .map_err(rain_orderbook_common::deposit::DepositError::from)?
+                .map_err(crate::deposit::DepositError::from)?
                 .abi_encode();
             calldatas.push(Bytes::copy_from_slice(&calldata));
         }
@@ -484,15 +542,11 @@ impl DotrainOrderGui {
     pub async fn generate_add_order_calldata(
         &mut self,
     ) -> Result<AddOrderCalldataResult, GuiError> {
-        let deployment = self.prepare_calldata_generation(CalldataFunction::AddOrder)?;
-
-        let calldata = AddOrderArgs::new_from_deployment(
-            self.dotrain_order.dotrain()?,
-            deployment.deployment.as_ref().clone(),
-        )
-        .await?
-        .get_add_order_calldata(self.get_transaction_args()?)
-        .await?;
+        let calldata = self
+            .prepare_add_order_args()
+            .await?
+            .get_add_order_calldata(self.get_transaction_args()?)
+            .await?;
         return Ok(AddOrderCalldataResult(Bytes::copy_from_slice(&calldata)));
     }
 
@@ -777,9 +831,8 @@ impl DotrainOrderGui {
 mod tests {
     use super::*;
     use crate::gui::tests::{initialize_gui, initialize_gui_with_select_tokens};
-    use wasm_bindgen_test::wasm_bindgen_test;
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_generate_deposit_calldatas() {
         let mut gui = initialize_gui(Some("other-deployment".to_string())).await;
 
@@ -821,7 +874,7 @@ mod tests {
         }
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_missing_select_tokens() {
         let mut gui = initialize_gui_with_select_tokens().await;
 
@@ -872,7 +925,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_missing_field_values() {
         let mut gui = initialize_gui(None).await;
 
@@ -900,7 +953,7 @@ mod tests {
         );
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_get_vault_ids() {
         let gui = initialize_gui(None).await;
         let res = gui.get_vault_ids().unwrap();
@@ -934,7 +987,7 @@ mod tests {
         assert_eq!(res.0["output"]["token1"], Some(U256::from(888)));
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_has_any_vault_id() {
         let mut gui = initialize_gui(Some("other-deployment".to_string())).await;
         assert!(!gui.has_any_vault_id().unwrap());
@@ -947,7 +1000,7 @@ mod tests {
         assert!(gui.has_any_vault_id().unwrap());
     }
 
-    #[wasm_bindgen_test]
+    #[tokio::test]
     async fn test_update_scenario_bindings() {
         let mut gui = initialize_gui(Some("other-deployment".to_string())).await;
 
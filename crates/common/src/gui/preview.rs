@@ -0,0 +1,523 @@
+use super::{order_operations::CalldataFunction, *};
+use crate::deposit::DepositArgs;
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{keccak256, Bytes, B256, U256},
+    providers::Provider,
+    rpc::types::{state::StateOverride, TransactionRequest},
+    serde::WithOtherFields,
+    sol_types::{SolCall, SolValue},
+};
+use futures::future::join_all;
+use rain_error_decoding::AbiDecodedErrorType;
+use rain_orderbook_bindings::{
+    provider::{mk_read_provider, ReadProvider},
+    IOrderBookV5::{deposit3Call, quote2Call, OrderV4, QuoteV2},
+    OrderBook::multicallCall,
+    IERC20::{allowanceCall, balanceOfCall},
+};
+use rain_orderbook_quote::{error::FailedQuote, OrderQuoteValue, QuoteTarget};
+use std::str::FromStr;
+
+/// Simulated quote of one input/output pair of the previewed order
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPreviewQuote {
+    pub input_index: u32,
+    pub output_index: u32,
+    #[tsify(type = "string")]
+    pub input_token: Address,
+    #[tsify(type = "string")]
+    pub output_token: Address,
+    /// Formatted max output, capped by the output vault balance after deposits
+    #[tsify(optional)]
+    pub max_output: Option<String>,
+    /// Formatted io ratio
+    #[tsify(optional)]
+    pub io_ratio: Option<String>,
+    /// Decoded revert or reason the quote failed
+    #[tsify(optional)]
+    pub error: Option<String>,
+}
+impl_wasm_traits!(OrderPreviewQuote);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPreview {
+    #[tsify(type = "string")]
+    pub orderbook: Address,
+    #[tsify(type = "string")]
+    pub order_hash: B256,
+    /// Decoded revert of the addOrder3 and deposit calls, in which case no
+    /// quotes are simulated
+    #[tsify(optional)]
+    pub revert: Option<String>,
+    pub quotes: Vec<OrderPreviewQuote>,
+}
+impl_wasm_traits!(OrderPreview);
+
+/// Storage slots probed for the balance and allowance mappings of a token
+const MAX_PROBED_SLOT: u64 = 20;
+
+/// Runs the calls through the orderbook multicall with an `eth_call` from the
+/// owner, so nothing is committed. The inner result is the decoded revert.
+async fn simulate_multicall(
+    provider: &ReadProvider,
+    owner: Address,
+    orderbook: Address,
+    calls: Vec<Bytes>,
+    overrides: &StateOverride,
+) -> Result<Result<Vec<Bytes>, FailedQuote>, GuiError> {
+    let tx = TransactionRequest::default()
+        .with_from(owner)
+        .with_to(orderbook)
+        .with_input(multicallCall { data: calls }.abi_encode());
+
+    let mut call = provider.call(WithOtherFields::new(tx));
+    // not every rpc accepts state overrides, so they are only sent when needed
+    if !overrides.is_empty() {
+        call = call.overrides(overrides.clone());
+    }
+    match call.await {
+        Ok(res) => Ok(Ok(multicallCall::abi_decode_returns(&res)?)),
+        Err(err) => match err.as_error_resp().and_then(|e| e.as_revert_data()) {
+            Some(data) => Ok(Err(
+                match AbiDecodedErrorType::selector_registry_abi_decode(&data).await {
+                    Ok(e) => FailedQuote::RevertError(e),
+                    Err(e) => FailedQuote::RevertErrorDecodeFailed(e),
+                },
+            )),
+            None => Err(GuiError::PreviewCallFailed(err.to_string())),
+        },
+    }
+}
+
+async fn read_token_value<C: SolCall<Return = U256>>(
+    provider: &ReadProvider,
+    token: Address,
+    call: &C,
+    overrides: StateOverride,
+) -> Result<U256, GuiError> {
+    let tx = TransactionRequest::default()
+        .with_to(token)
+        .with_input(call.abi_encode());
+    let mut eth_call = provider.call(WithOtherFields::new(tx));
+    if !overrides.is_empty() {
+        eth_call = eth_call.overrides(overrides);
+    }
+    let res = eth_call
+        .await
+        .map_err(|err| GuiError::PreviewCallFailed(err.to_string()))?;
+    Ok(C::abi_decode_returns(&res)?)
+}
+
+/// Raises the value returned by `call` by `amount` through a storage
+/// override. The slot is found by writing the raised value to the slot
+/// derived from every mapping index up to [`MAX_PROBED_SLOT`] and reading it
+/// back, so only solidity mapping layouts are recognised.
+async fn top_up_override<C: SolCall<Return = U256>>(
+    provider: &ReadProvider,
+    token: Address,
+    call: &C,
+    amount: U256,
+    slot_for_index: impl Fn(U256) -> B256,
+) -> Result<Option<(B256, B256)>, GuiError> {
+    let current = read_token_value(provider, token, call, StateOverride::default()).await?;
+    let value = current.saturating_add(amount);
+    if value == current {
+        return Ok(None);
+    }
+
+    let probes = (0..MAX_PROBED_SLOT).map(|index| {
+        let slot = slot_for_index(U256::from(index));
+        let mut overrides = StateOverride::default();
+        overrides
+            .entry(token)
+            .or_default()
+            .state_diff
+            .get_or_insert_with(Default::default)
+            .insert(slot, B256::from(value));
+        async move {
+            let read = read_token_value(provider, token, call, overrides).await;
+            matches!(read, Ok(read) if read == value).then_some((slot, B256::from(value)))
+        }
+    });
+    Ok(join_all(probes).await.into_iter().flatten().next())
+}
+
+/// Tops up the owner's balance and orderbook allowance of every deposited
+/// token by the deposit amount, so the preview does not depend on the wallet
+/// being funded and approved. Tokens whose storage layout isn't recognised
+/// keep their real balance and allowance.
+async fn deposit_overrides(
+    provider: &ReadProvider,
+    owner: Address,
+    orderbook: Address,
+    deposits: &[DepositArgs],
+) -> Result<StateOverride, GuiError> {
+    let mut overrides = StateOverride::default();
+    for deposit in deposits {
+        let amount = deposit.amount.to_fixed_decimal(deposit.decimals)?;
+
+        // mapping(address => uint256) and mapping(address => mapping(address => uint256))
+        let (balance, allowance) = futures::try_join!(
+            top_up_override(
+                provider,
+                deposit.token,
+                &balanceOfCall { account: owner },
+                amount,
+                |index| keccak256((owner, index).abi_encode()),
+            ),
+            top_up_override(
+                provider,
+                deposit.token,
+                &allowanceCall {
+                    owner,
+                    spender: orderbook,
+                },
+                amount,
+                |index| keccak256((orderbook, keccak256((owner, index).abi_encode())).abi_encode()),
+            ),
+        )?;
+
+        if balance.is_none() && allowance.is_none() {
+            continue;
+        }
+        overrides
+            .entry(deposit.token)
+            .or_default()
+            .state_diff
+            .get_or_insert_with(Default::default)
+            .extend(balance.into_iter().chain(allowance));
+    }
+    Ok(overrides)
+}
+
+fn preview_quote(
+    target: &QuoteTarget,
+    result: Result<OrderQuoteValue, FailedQuote>,
+) -> Result<OrderPreviewQuote, GuiError> {
+    let config = &target.quote_config;
+    let input_index = config.inputIOIndex.to::<usize>();
+    let output_index = config.outputIOIndex.to::<usize>();
+    let mut quote = OrderPreviewQuote {
+        input_index: input_index as u32,
+        output_index: output_index as u32,
+        input_token: config.order.validInputs[input_index].token,
+        output_token: config.order.validOutputs[output_index].token,
+        max_output: None,
+        io_ratio: None,
+        error: None,
+    };
+    match result {
+        Ok(value) => {
+            quote.max_output = Some(value.max_output.format()?);
+            quote.io_ratio = Some(value.ratio.format()?);
+        }
+        Err(err) => quote.error = Some(err.to_string()),
+    }
+    Ok(quote)
+}
+
+#[wasm_export]
+impl DotrainOrderGui {
+    /// Simulates deploying the current configuration and quoting it, without
+    /// sending a transaction.
+    ///
+    /// The composed order is added, funded with the configured deposits and
+    /// quoted through the orderbook multicall in an `eth_call` from the owner,
+    /// once for every input/output pair. The owner's balance and allowance of
+    /// each deposited token are topped up in the call, so the preview works
+    /// before the wallet is funded or approved. Skip the deposits to preview
+    /// the order against empty vaults, in which case max outputs are zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await gui.previewOrder(walletAddress);
+    /// if (result.error) {
+    ///   console.error("Preview failed:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { revert, quotes } = result.value;
+    /// if (revert) {
+    ///   console.warn("Deployment would revert:", revert);
+    /// }
+    /// for (const { maxOutput, ioRatio, error } of quotes) {
+    ///   // Show the expected quote or why it fails
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "previewOrder",
+        unchecked_return_type = "OrderPreview",
+        return_description = "Simulated deployment revert and quotes for every order pair"
+    )]
+    pub async fn preview_order(
+        &mut self,
+        #[wasm_export(param_description = "Wallet address that will deploy the order")]
+        owner: String,
+        #[wasm_export(param_description = "Whether to simulate the deposits, defaults to true")]
+        include_deposits: Option<bool>,
+    ) -> Result<OrderPreview, GuiError> {
+        let owner = Address::from_str(&owner)?;
+        let deployment = self.prepare_calldata_generation(CalldataFunction::DepositAndAddOrder)?;
+        let orderbook = self.get_orderbook()?;
+
+        let add_order_call = self
+            .get_add_order_args(&deployment)
+            .await?
+            .try_into_call(self.get_transaction_args()?.rpcs)
+            .await?;
+
+        let mut setup_calls = vec![Bytes::from(add_order_call.abi_encode())];
+        let mut deposits = vec![];
+        if include_deposits.unwrap_or(true) {
+            if let Some(deposit_args) = self.get_deposit_args().await? {
+                for args in deposit_args {
+                    setup_calls.push(Bytes::from(
+                        deposit3Call::try_from(args.clone())
+                            .map_err(crate::deposit::DepositError::from)?
+                            .abi_encode(),
+                    ));
+                    deposits.push(args);
+                }
+            }
+        }
+
+        let config = add_order_call.config;
+        let order = OrderV4 {
+            owner,
+            evaluable: config.evaluable,
+            validInputs: config.validInputs,
+            validOutputs: config.validOutputs,
+            nonce: config.nonce,
+        };
+        let mut targets = vec![];
+        for (input_index, input) in order.validInputs.iter().enumerate() {
+            for (output_index, output) in order.validOutputs.iter().enumerate() {
+                // the orderbook never quotes a token against itself
+                if input.token == output.token {
+                    continue;
+                }
+                targets.push(QuoteTarget {
+                    quote_config: QuoteV2 {
+                        order: order.clone(),
+                        inputIOIndex: U256::from(input_index),
+                        outputIOIndex: U256::from(output_index),
+                        signedContext: vec![],
+                    },
+                    orderbook: orderbook.address,
+                });
+            }
+        }
+
+        let mut preview = OrderPreview {
+            orderbook: orderbook.address,
+            order_hash: QuoteTarget {
+                quote_config: QuoteV2 {
+                    order,
+                    ..Default::default()
+                },
+                orderbook: orderbook.address,
+            }
+            .get_order_hash(),
+            revert: None,
+            quotes: vec![],
+        };
+
+        let provider = mk_read_provider(&orderbook.network.rpcs)?;
+        let overrides = deposit_overrides(&provider, owner, orderbook.address, &deposits).await?;
+        if let Err(err) = simulate_multicall(
+            &provider,
+            owner,
+            orderbook.address,
+            setup_calls.clone(),
+            &overrides,
+        )
+        .await?
+        {
+            preview.revert = Some(err.to_string());
+            return Ok(preview);
+        }
+
+        // each pair gets its own call so one reverting quote does not hide the others
+        for target in targets {
+            let mut calls = setup_calls.clone();
+            calls.push(Bytes::from(
+                quote2Call {
+                    quoteConfig: target.quote_config.clone(),
+                }
+                .abi_encode(),
+            ));
+
+            let result =
+                match simulate_multicall(&provider, owner, orderbook.address, calls, &overrides)
+                    .await?
+                {
+                    Ok(results) => {
+                        let ret = results.last().ok_or(GuiError::PreviewCallFailed(
+                            "Missing quote return data".to_string(),
+                        ))?;
+                        let ret = quote2Call::abi_decode_returns(ret)?;
+                        if ret.exists {
+                            Ok(OrderQuoteValue::from(ret))
+                        } else {
+                            Err(FailedQuote::NonExistent)
+                        }
+                    }
+                    Err(err) => Err(err),
+                };
+            preview.quotes.push(preview_quote(&target, result)?);
+        }
+
+        Ok(preview)
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+pub(crate) mod tests {
+    use super::*;
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+    use rain_orderbook_test_fixtures::LocalEvm;
+
+    pub(crate) async fn get_gui(local_evm: &LocalEvm) -> DotrainOrderGui {
+        let dotrain = format!(
+            r#"
+version: {spec_version}
+networks:
+    some-network:
+        rpcs:
+            - {rpc_url}
+        chain-id: 123
+tokens:
+    t1:
+        network: some-network
+        address: {token1}
+        decimals: 18
+        label: Token1
+        symbol: TOKEN1
+    t2:
+        network: some-network
+        address: {token2}
+        decimals: 18
+        label: Token2
+        symbol: TOKEN2
+deployers:
+    some-deployer:
+        network: some-network
+        address: {deployer}
+orderbooks:
+    some-orderbook:
+        address: {orderbook}
+        network: some-network
+        subgraph: some-sg
+        deployment-block: 0
+subgraphs:
+    some-sg: https://www.some-sg.com
+scenarios:
+    some-scenario:
+        deployer: some-deployer
+        bindings:
+            max-amount: 0
+orders:
+    some-order:
+        deployer: some-deployer
+        orderbook: some-orderbook
+        inputs:
+            - token: t1
+        outputs:
+            - token: t2
+deployments:
+    some-deployment:
+        scenario: some-scenario
+        order: some-order
+gui:
+    name: Preview
+    description: Preview test
+    deployments:
+        some-deployment:
+            name: Some deployment
+            description: Some deployment description
+            deposits:
+                - token: t2
+            fields:
+                - binding: max-amount
+                  name: Max amount
+---
+#max-amount !Max output amount
+#calculate-io
+amount price: max-amount 52;
+#handle-io
+:;
+#handle-add-order
+:;
+"#,
+            spec_version = SpecVersion::current(),
+            rpc_url = local_evm.url(),
+            token1 = local_evm.tokens[0].address(),
+            token2 = local_evm.tokens[1].address(),
+            deployer = local_evm.deployer.address(),
+            orderbook = local_evm.orderbook.address(),
+        );
+        let mut gui =
+            DotrainOrderGui::new_with_deployment(dotrain, "some-deployment".to_string(), None)
+                .await
+                .unwrap();
+        gui.set_field_value("max-amount".to_string(), "16".to_string())
+            .unwrap();
+        gui
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_preview_order() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let owner = local_evm.anvil.addresses()[0];
+        let mut gui = get_gui(&local_evm).await;
+
+        let preview = gui
+            .preview_order(owner.to_string(), Some(false))
+            .await
+            .unwrap();
+        assert_eq!(preview.orderbook, *local_evm.orderbook.address());
+        assert!(preview.revert.is_none());
+        assert_eq!(preview.quotes.len(), 1);
+        assert_eq!(
+            preview.quotes[0].input_token,
+            *local_evm.tokens[0].address()
+        );
+        assert_eq!(
+            preview.quotes[0].output_token,
+            *local_evm.tokens[1].address()
+        );
+        // nothing deposited so the output is capped at zero
+        assert_eq!(preview.quotes[0].max_output, Some("0".to_string()));
+        assert_eq!(preview.quotes[0].io_ratio, Some("52".to_string()));
+        assert!(preview.quotes[0].error.is_none());
+
+        gui.set_deposit("t2".to_string(), "10".to_string())
+            .await
+            .unwrap();
+
+        // the deposit is not approved but the allowance is overridden
+        let preview = gui.preview_order(owner.to_string(), None).await.unwrap();
+        assert!(preview.revert.is_none());
+        assert_eq!(preview.quotes[0].max_output, Some("10".to_string()));
+        assert_eq!(preview.quotes[0].io_ratio, Some("52".to_string()));
+
+        // nor does the owner need to hold the tokens
+        let preview = gui
+            .preview_order(Address::random().to_string(), None)
+            .await
+            .unwrap();
+        assert!(preview.revert.is_none());
+        assert_eq!(preview.quotes[0].max_output, Some("10".to_string()));
+
+        // the real allowance is left untouched
+        let allowance = local_evm.tokens[1]
+            .allowance(owner, *local_evm.orderbook.address())
+            .call()
+            .await
+            .unwrap();
+        assert_eq!(allowance, U256::ZERO);
+    }
+}
@@ -1,12 +1,10 @@
 use super::*;
+use crate::{raindex_client::vaults::AccountBalance, utils::amount_formatter::format_amount_u256};
 use futures::StreamExt;
 use rain_orderbook_app_settings::{
     deployment::DeploymentCfg, gui::GuiSelectTokensCfg, network::NetworkCfg, order::OrderCfg,
     token::TokenCfg, yaml::YamlParsableHash,
 };
-use rain_orderbook_common::{
-    raindex_client::vaults::AccountBalance, utils::amount_formatter::format_amount_u256,
-};
 use std::str::FromStr;
 
 const MAX_CONCURRENT_FETCHES: usize = 5;
@@ -315,6 +313,8 @@ impl DotrainOrderGui {
                     decimals: *decimals,
                     name: label.clone(),
                     symbol: symbol.clone(),
+                    logo_uri: token.logo_uri.clone(),
+                    tags: token.tags.clone(),
                 });
             } else {
                 let erc20 = ERC20::new(network.rpcs.clone(), token.address);
@@ -326,6 +326,8 @@ impl DotrainOrderGui {
                         decimals: token.decimals.unwrap_or(token_info.decimals),
                         name: token.label.unwrap_or(token_info.name),
                         symbol: token.symbol.unwrap_or(token_info.symbol),
+                        logo_uri: token.logo_uri,
+                        tags: token.tags,
                     })
                 });
             }
@@ -458,15 +460,13 @@ impl DotrainOrderGui {
 
 #[cfg(test)]
 mod tests {
-    #[cfg(target_family = "wasm")]
-    mod wasm_tests {
+    mod local_tests {
         use crate::gui::{
             tests::{initialize_gui, initialize_gui_with_select_tokens},
             GuiError,
         };
-        use wasm_bindgen_test::wasm_bindgen_test;
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_select_tokens() {
             let gui = initialize_gui_with_select_tokens().await;
             let select_tokens = gui.get_select_tokens().unwrap();
@@ -479,7 +479,7 @@ mod tests {
             assert_eq!(select_tokens.len(), 0);
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_is_select_token_set() {
             let gui = initialize_gui_with_select_tokens().await;
             let is_select_token_set = gui.is_select_token_set("token3".to_string()).unwrap();
@@ -498,7 +498,7 @@ mod tests {
             assert!(is_select_token_set);
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_check_select_tokens() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -543,7 +543,7 @@ mod tests {
             assert!(gui.check_select_tokens().is_ok());
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_set_select_token() {
             let mut gui = initialize_gui_with_select_tokens().await;
             let err = gui
@@ -563,7 +563,7 @@ mod tests {
             );
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_remove_select_token() {
             let mut gui = initialize_gui_with_select_tokens().await;
             gui.add_record_to_yaml(
@@ -597,7 +597,7 @@ mod tests {
             );
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_are_all_tokens_selected() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -629,7 +629,7 @@ mod tests {
             assert!(are_all_tokens_selected);
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_all_tokens() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -676,7 +676,7 @@ mod tests {
             assert_eq!(tokens[1].symbol, "T4");
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_all_tokens_search_by_name() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -705,7 +705,7 @@ mod tests {
             assert_eq!(tokens[0].name, "Token 3");
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_all_tokens_search_by_symbol() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -731,7 +731,7 @@ mod tests {
             assert_eq!(tokens[0].symbol, "T4");
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_all_tokens_search_by_address() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -765,7 +765,7 @@ mod tests {
             );
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_all_tokens_search_partial_match() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -812,7 +812,7 @@ mod tests {
             assert_eq!(tokens.len(), 3);
         }
 
-        #[wasm_bindgen_test]
+        #[tokio::test]
         async fn test_get_all_tokens_search_empty_string() {
             let gui = initialize_gui_with_select_tokens().await;
 
@@ -833,10 +833,10 @@ mod tests {
     #[cfg(not(target_family = "wasm"))]
     mod non_wasm_tests {
         use crate::gui::{DotrainOrderGui, GuiError};
+        use crate::raindex_client::vaults::AccountBalance;
         use alloy::primitives::{Address, U256};
         use httpmock::MockServer;
         use rain_orderbook_app_settings::spec_version::SpecVersion;
-        use rain_orderbook_common::raindex_client::vaults::AccountBalance;
         use serde_json::json;
         use std::str::FromStr;
 
@@ -0,0 +1,522 @@
+use super::{state_management::SerializedGuiState, *};
+use alloy::primitives::{keccak256, Bytes, Signature};
+use rain_orderbook_app_settings::{gui::GuiPresetCfg, order::VaultType, token::TokenCfg};
+use std::str::FromStr;
+
+/// Version written into new state envelopes. Readers accept newer versions as
+/// long as the parts they know about still decode, unknown keys are ignored.
+pub const STATE_ENVELOPE_VERSION: u32 = 1;
+
+/// Outer layer of a shareable state, stored as gzipped json so keys can be
+/// added without breaking older readers.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct StateEnvelope {
+    version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    order_key: Option<String>,
+    deployment_key: String,
+    /// Json encoded [`SharedGuiState`], kept as text so the signed bytes
+    /// survive a round trip through readers that don't know every key
+    state: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<Bytes>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct SharedGuiState {
+    field_values: BTreeMap<String, GuiPresetCfg>,
+    deposits: BTreeMap<String, GuiPresetCfg>,
+    select_tokens: BTreeMap<String, TokenCfg>,
+    vault_ids: Vec<(VaultType, String, Option<String>)>,
+    dotrain_hash: String,
+}
+
+impl SharedGuiState {
+    fn from_serialized(state: SerializedGuiState) -> Self {
+        Self {
+            field_values: state.field_values,
+            deposits: state.deposits,
+            select_tokens: state.select_tokens,
+            vault_ids: state
+                .vault_ids
+                .into_iter()
+                .map(|((vault_type, token), vault_id)| (vault_type, token, vault_id))
+                .collect(),
+            dotrain_hash: state.dotrain_hash,
+        }
+    }
+
+    fn into_serialized(self, selected_deployment: String) -> SerializedGuiState {
+        SerializedGuiState {
+            field_values: self.field_values,
+            deposits: self.deposits,
+            select_tokens: self.select_tokens,
+            vault_ids: self
+                .vault_ids
+                .into_iter()
+                .map(|(vault_type, token, vault_id)| ((vault_type, token), vault_id))
+                .collect(),
+            dotrain_hash: self.dotrain_hash,
+            selected_deployment,
+        }
+    }
+}
+
+impl StateEnvelope {
+    /// EIP-191 message the creator signs, readable in a wallet prompt and
+    /// committing to the exact state bytes through their hash
+    fn signing_message(&self) -> String {
+        format!(
+            "Raindex deployment state\nVersion: {}\nOrder: {}\nDeployment: {}\nState: {}",
+            self.version,
+            self.order_key.as_deref().unwrap_or("-"),
+            self.deployment_key,
+            keccak256(self.state.as_bytes())
+        )
+    }
+
+    fn signer(&self) -> Result<Option<Address>, GuiError> {
+        let Some(signature) = &self.signature else {
+            return Ok(None);
+        };
+        let signature = Signature::try_from(signature.as_ref())
+            .map_err(|err| GuiError::InvalidStateSignature(err.to_string()))?;
+        let signer = signature
+            .recover_address_from_msg(self.signing_message())
+            .map_err(|err| GuiError::InvalidStateSignature(err.to_string()))?;
+        Ok(Some(signer))
+    }
+
+    /// Returns `None` for states without an envelope. Anything that reads as a
+    /// json object with a `version` key is an envelope, so a broken envelope is
+    /// reported as such instead of falling through to the legacy format.
+    fn decode(serialized: &str) -> Result<Option<Self>, GuiError> {
+        Self::decode_bytes(&decompress(serialized)?)
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Option<Self>, GuiError> {
+        let Ok(serde_json::Value::Object(json)) = serde_json::from_slice(bytes) else {
+            return Ok(None);
+        };
+        if !json.contains_key("version") {
+            return Ok(None);
+        }
+        serde_json::from_value(serde_json::Value::Object(json))
+            .map(Some)
+            .map_err(|err| GuiError::InvalidStateEnvelope(err.to_string()))
+    }
+
+    fn encode(&self) -> Result<String, GuiError> {
+        let bytes = serde_json::to_vec(self)?;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&bytes)?;
+        Ok(URL_SAFE.encode(encoder.finish()?))
+    }
+}
+
+fn decompress(serialized: &str) -> Result<Vec<u8>, GuiError> {
+    let compressed = URL_SAFE.decode(serialized)?;
+    let mut decoder = GzDecoder::new(&compressed[..]);
+    let mut bytes = Vec::new();
+    decoder.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// What a shareable state says about itself, readable without the dotrain
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct StateEnvelopeInfo {
+    pub version: u32,
+    /// Key of the order in the `DotrainRegistry` the state was created from
+    #[tsify(optional)]
+    pub order_key: Option<String>,
+    pub deployment_key: String,
+    /// Address that signed the state, recovered from its signature
+    #[tsify(optional, type = "string")]
+    pub signer: Option<Address>,
+}
+impl_wasm_traits!(StateEnvelopeInfo);
+
+impl DotrainOrderGui {
+    fn new_state_envelope(&self, order_key: Option<String>) -> Result<StateEnvelope, GuiError> {
+        let state = SharedGuiState::from_serialized(self.get_serialized_state()?);
+        Ok(StateEnvelope {
+            version: STATE_ENVELOPE_VERSION,
+            order_key,
+            deployment_key: self.selected_deployment.clone(),
+            state: serde_json::to_string(&state)?,
+            signature: None,
+        })
+    }
+
+    /// Decodes a state produced by either `serializeState` or
+    /// `serializeShareableState`, along with the signer of the envelope if it
+    /// is signed
+    pub(super) fn decode_serialized_state(
+        serialized: &str,
+    ) -> Result<(SerializedGuiState, Option<Address>), GuiError> {
+        let bytes = decompress(serialized)?;
+        match StateEnvelope::decode_bytes(&bytes)? {
+            Some(envelope) => {
+                let signer = envelope.signer()?;
+                let state: SharedGuiState = serde_json::from_str(&envelope.state)
+                    .map_err(|err| GuiError::InvalidStateEnvelope(err.to_string()))?;
+                Ok((state.into_serialized(envelope.deployment_key), signer))
+            }
+            None => Ok((bincode::deserialize(&bytes)?, None)),
+        }
+    }
+}
+
+#[wasm_export]
+impl DotrainOrderGui {
+    /// Returns the message the creator signs to vouch for a shareable state.
+    ///
+    /// Sign the returned text with `personal_sign` (EIP-191) and pass the
+    /// signature to `serializeShareableState` with the same order key. The state
+    /// must not change in between, or the signature will not match.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const message = gui.getShareableStateMessage("fixed-limit").value;
+    /// const signature = await walletClient.signMessage({ message });
+    /// const link = gui.serializeShareableState("fixed-limit", signature).value;
+    /// ```
+    #[wasm_export(
+        js_name = "getShareableStateMessage",
+        unchecked_return_type = "string",
+        return_description = "EIP-191 message committing to the current state"
+    )]
+    pub fn get_shareable_state_message(
+        &self,
+        #[wasm_export(
+            js_name = "orderKey",
+            param_description = "Registry order key to embed in the state"
+        )]
+        order_key: Option<String>,
+    ) -> Result<String, GuiError> {
+        Ok(self.new_state_envelope(order_key)?.signing_message())
+    }
+
+    /// Serializes the current state into a versioned envelope for sharing.
+    ///
+    /// Unlike `serializeState`, the envelope embeds the deployment key and
+    /// optionally the registry order key, so `DotrainRegistry.getGuiFromState`
+    /// can reopen the strategy from the link alone. New keys can be added to
+    /// the envelope without breaking older readers. `newFromState` accepts both
+    /// formats.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.serializeShareableState("fixed-limit");
+    /// if (result.error) {
+    ///   console.error("Serialization error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const link = `https://example.com/deploy?state=${result.value}`;
+    /// ```
+    #[wasm_export(
+        js_name = "serializeShareableState",
+        unchecked_return_type = "string",
+        return_description = "Compressed, base64-encoded state envelope"
+    )]
+    pub fn serialize_shareable_state(
+        &self,
+        #[wasm_export(
+            js_name = "orderKey",
+            param_description = "Registry order key to embed in the state"
+        )]
+        order_key: Option<String>,
+        #[wasm_export(
+            param_description = "Creator's EIP-191 signature of the message from getShareableStateMessage"
+        )]
+        signature: Option<String>,
+    ) -> Result<String, GuiError> {
+        let mut envelope = self.new_state_envelope(order_key)?;
+        if let Some(signature) = signature {
+            envelope.signature = Some(
+                Bytes::from_str(&signature)
+                    .map_err(|err| GuiError::InvalidStateSignature(err.to_string()))?,
+            );
+            envelope.signer()?;
+        }
+        envelope.encode()
+    }
+
+    /// Reads the version, keys and signer of a shareable state.
+    ///
+    /// Use it to show who created a link before restoring it. States from
+    /// `serializeState` have no envelope and are rejected.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = DotrainOrderGui.getStateEnvelopeInfo(link);
+    /// if (result.error) {
+    ///   console.error("Invalid link:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { orderKey, deploymentKey, signer } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getStateEnvelopeInfo",
+        unchecked_return_type = "StateEnvelopeInfo",
+        return_description = "Version, keys and recovered signer of the state"
+    )]
+    pub fn get_state_envelope_info(
+        #[wasm_export(param_description = "State from serializeShareableState")] serialized: String,
+    ) -> Result<StateEnvelopeInfo, GuiError> {
+        let envelope = StateEnvelope::decode(&serialized)?.ok_or(GuiError::MissingStateEnvelope)?;
+        Ok(StateEnvelopeInfo {
+            version: envelope.version,
+            signer: envelope.signer()?,
+            order_key: envelope.order_key,
+            deployment_key: envelope.deployment_key,
+        })
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use alloy::{
+        hex::encode_prefixed,
+        primitives::U256,
+        signers::{local::PrivateKeySigner, Signer},
+    };
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+
+    fn get_dotrain() -> String {
+        format!(
+            r#"
+version: {}
+networks:
+    some-network:
+        rpcs:
+            - http://localhost:8545
+        chain-id: 123
+tokens:
+    token1:
+        network: some-network
+        address: 0x0000000000000000000000000000000000000001
+        decimals: 18
+deployers:
+    some-deployer:
+        network: some-network
+        address: 0xF14E09601A47552De6aBd3A0B165607FaFd2B5Ba
+orderbooks:
+    some-orderbook:
+        address: 0xc95A5f8eFe14d7a20BD2E5BAFEC4E71f8Ce0B9A6
+        network: some-network
+        subgraph: some-sg
+        deployment-block: 12345
+subgraphs:
+    some-sg: https://www.some-sg.com
+scenarios:
+    some-scenario:
+        deployer: some-deployer
+        bindings:
+            price: 0
+orders:
+    some-order:
+        deployer: some-deployer
+        inputs:
+            - token: token1
+        outputs:
+            - token: token1
+deployments:
+    some-deployment:
+        scenario: some-scenario
+        order: some-order
+gui:
+    name: Envelope
+    description: Shareable state
+    deployments:
+        some-deployment:
+            name: Some deployment
+            description: Some deployment description
+            deposits:
+                - token: token1
+            fields:
+                - binding: price
+                  name: Price
+---
+#calculate-io
+_ _: 0 0;
+#handle-io
+:;
+#handle-add-order
+:;
+"#,
+            SpecVersion::current()
+        )
+    }
+
+    async fn get_gui() -> DotrainOrderGui {
+        let mut gui = DotrainOrderGui::new_with_deployment(
+            get_dotrain(),
+            "some-deployment".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        gui.set_field_value("price".to_string(), "3".to_string())
+            .unwrap();
+        gui.set_deposit("token1".to_string(), "10".to_string())
+            .await
+            .unwrap();
+        gui.set_vault_id(
+            VaultType::Input,
+            "token1".to_string(),
+            Some("5".to_string()),
+        )
+        .unwrap();
+        gui
+    }
+
+    #[tokio::test]
+    async fn test_shareable_state_round_trip() {
+        let gui = get_gui().await;
+        let state = gui
+            .serialize_shareable_state(Some("some-order".to_string()), None)
+            .unwrap();
+
+        let info = DotrainOrderGui::get_state_envelope_info(state.clone()).unwrap();
+        assert_eq!(
+            info,
+            StateEnvelopeInfo {
+                version: STATE_ENVELOPE_VERSION,
+                order_key: Some("some-order".to_string()),
+                deployment_key: "some-deployment".to_string(),
+                signer: None,
+            }
+        );
+
+        let restored = DotrainOrderGui::new_from_state(get_dotrain(), state, None, Some(true))
+            .await
+            .unwrap();
+        assert_eq!(
+            restored.get_field_value("price".to_string()).unwrap().value,
+            "3"
+        );
+        assert_eq!(restored.get_deposits().unwrap()[0].amount, "10");
+        assert_eq!(
+            restored.get_vault_ids().unwrap().0["input"]["token1"],
+            Some(U256::from(5))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_shareable_state() {
+        let gui = get_gui().await;
+        let signer = PrivateKeySigner::random();
+        let order_key = Some("some-order".to_string());
+
+        let message = gui.get_shareable_state_message(order_key.clone()).unwrap();
+        let signature = encode_prefixed(
+            signer
+                .sign_message(message.as_bytes())
+                .await
+                .unwrap()
+                .as_bytes(),
+        );
+        let state = gui
+            .serialize_shareable_state(order_key.clone(), Some(signature.clone()))
+            .unwrap();
+
+        let info = DotrainOrderGui::get_state_envelope_info(state.clone()).unwrap();
+        assert_eq!(info.signer, Some(signer.address()));
+        let restored = DotrainOrderGui::new_from_state(get_dotrain(), state, None, None)
+            .await
+            .unwrap();
+        let report = restored.get_state_migration_report().unwrap().unwrap();
+        assert_eq!(report.state_signer, Some(signer.address()));
+
+        // a signature over another order key recovers to someone else
+        let state = gui
+            .serialize_shareable_state(None, Some(signature.clone()))
+            .unwrap();
+        let info = DotrainOrderGui::get_state_envelope_info(state).unwrap();
+        assert_ne!(info.signer, Some(signer.address()));
+
+        let err = gui
+            .serialize_shareable_state(order_key, Some("0x1234".to_string()))
+            .unwrap_err();
+        assert!(matches!(err, GuiError::InvalidStateSignature(_)));
+    }
+
+    #[tokio::test]
+    async fn test_envelope_decoding_is_forward_compatible() {
+        let gui = get_gui().await;
+        let mut envelope = gui.new_state_envelope(None).unwrap();
+
+        // a newer writer with extra keys in both the envelope and the state
+        let mut state: serde_json::Value = serde_json::from_str(&envelope.state).unwrap();
+        state["someNewSetting"] = serde_json::json!(true);
+        envelope.state = state.to_string();
+        envelope.version = STATE_ENVELOPE_VERSION + 1;
+        let mut json = serde_json::to_value(&envelope).unwrap();
+        json["expiresAt"] = serde_json::json!(1700000000);
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&serde_json::to_vec(&json).unwrap())
+            .unwrap();
+        let serialized = URL_SAFE.encode(encoder.finish().unwrap());
+
+        let info = DotrainOrderGui::get_state_envelope_info(serialized.clone()).unwrap();
+        assert_eq!(info.version, STATE_ENVELOPE_VERSION + 1);
+        let restored = DotrainOrderGui::new_from_state(get_dotrain(), serialized, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            restored.get_field_value("price".to_string()).unwrap().value,
+            "3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broken_envelope_is_not_read_as_legacy_state() {
+        let gui = get_gui().await;
+        let mut envelope = gui.new_state_envelope(None).unwrap();
+        envelope.state = "{\"fieldValues\": 1}".to_string();
+        let serialized = envelope.encode().unwrap();
+
+        let err = DotrainOrderGui::new_from_state(get_dotrain(), serialized, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GuiError::InvalidStateEnvelope(_)));
+
+        let mut json = serde_json::to_value(gui.new_state_envelope(None).unwrap()).unwrap();
+        json["deploymentKey"] = serde_json::json!(1);
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&serde_json::to_vec(&json).unwrap())
+            .unwrap();
+        let serialized = URL_SAFE.encode(encoder.finish().unwrap());
+
+        let err = DotrainOrderGui::get_state_envelope_info(serialized.clone()).unwrap_err();
+        assert!(matches!(err, GuiError::InvalidStateEnvelope(_)));
+        let err = DotrainOrderGui::new_from_state(get_dotrain(), serialized, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GuiError::InvalidStateEnvelope(_)));
+    }
+
+    #[tokio::test]
+    async fn test_legacy_state_has_no_envelope() {
+        let gui = get_gui().await;
+        let state = gui.serialize_state().unwrap();
+
+        let err = DotrainOrderGui::get_state_envelope_info(state.clone()).unwrap_err();
+        assert!(matches!(err, GuiError::MissingStateEnvelope));
+        let restored = DotrainOrderGui::new_from_state(get_dotrain(), state, None, Some(true))
+            .await
+            .unwrap();
+        let report = restored.get_state_migration_report().unwrap().unwrap();
+        assert!(report.state_signer.is_none());
+    }
+}
@@ -0,0 +1,1163 @@
+use super::*;
+use rain_orderbook_app_settings::{
+    gui::GuiDepositCfg,
+    order::{OrderIOCfg, VaultType},
+    token::TokenCfg,
+};
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, RwLock};
+use strict_yaml_rust::StrictYaml;
+use wasm_bindgen::JsValue;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct AllGuiConfig {
+    pub field_definitions_without_defaults: Vec<GuiFieldDefinitionCfg>,
+    pub field_definitions_with_defaults: Vec<GuiFieldDefinitionCfg>,
+    pub deposits: Vec<GuiDepositCfg>,
+    pub order_inputs: Vec<OrderIOCfg>,
+    pub order_outputs: Vec<OrderIOCfg>,
+}
+impl_wasm_traits!(AllGuiConfig);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub(super) struct SerializedGuiState {
+    pub(super) field_values: BTreeMap<String, GuiPresetCfg>,
+    pub(super) deposits: BTreeMap<String, GuiPresetCfg>,
+    pub(super) select_tokens: BTreeMap<String, TokenCfg>,
+    pub(super) vault_ids: BTreeMap<(VaultType, String), Option<String>>,
+    pub(super) dotrain_hash: String,
+    pub(super) selected_deployment: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedField {
+    pub from: String,
+    pub to: String,
+}
+impl_wasm_traits!(RenamedField);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedVaultId {
+    pub vault_type: VaultType,
+    pub token: String,
+    pub vault_id: String,
+}
+impl_wasm_traits!(DroppedVaultId);
+
+/// Describes what happened to a saved state when it was restored against a
+/// dotrain that may have changed since the state was serialized.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct StateMigrationReport {
+    /// True when the dotrain hash differs from the one stored in the state
+    pub dotrain_changed: bool,
+    /// Field bindings that no longer exist in the deployment
+    pub dropped_fields: Vec<String>,
+    /// Fields restored through one of their `previous-bindings`
+    pub renamed_fields: Vec<RenamedField>,
+    /// Field bindings whose saved value fails the current validation rules
+    pub invalid_fields: Vec<String>,
+    /// Fields with no default that are left without a value by a changed dotrain
+    pub newly_required_fields: Vec<String>,
+    /// Deposit tokens that are no longer part of the deployment
+    pub dropped_deposits: Vec<String>,
+    /// Deposit tokens whose saved amount fails the current validation rules
+    pub invalid_deposits: Vec<String>,
+    /// Select token keys that are no longer selectable
+    pub dropped_select_tokens: Vec<String>,
+    /// Vault ids that could not be applied to the order anymore
+    pub dropped_vault_ids: Vec<DroppedVaultId>,
+    /// Address that signed the state, set only for signed shareable states
+    #[tsify(optional, type = "string")]
+    pub state_signer: Option<Address>,
+}
+impl_wasm_traits!(StateMigrationReport);
+
+impl StateMigrationReport {
+    /// True when every saved value was carried over unchanged
+    pub fn is_clean(&self) -> bool {
+        self.dropped_fields.is_empty()
+            && self.renamed_fields.is_empty()
+            && self.invalid_fields.is_empty()
+            && self.newly_required_fields.is_empty()
+            && self.dropped_deposits.is_empty()
+            && self.invalid_deposits.is_empty()
+            && self.dropped_select_tokens.is_empty()
+            && self.dropped_vault_ids.is_empty()
+    }
+}
+
+#[wasm_export]
+impl DotrainOrderGui {
+    pub(super) fn get_dotrain_hash(dotrain: String) -> Result<String, GuiError> {
+        let dotrain_bytes = bincode::serialize(&dotrain)?;
+        let hash = Sha256::digest(dotrain_bytes);
+        Ok(URL_SAFE.encode(hash))
+    }
+
+    fn create_preset(value: &field_values::PairValue, default_value: String) -> GuiPresetCfg {
+        if value.is_preset {
+            GuiPresetCfg {
+                id: value.value.clone(),
+                name: None,
+                value: default_value,
+            }
+        } else {
+            GuiPresetCfg {
+                id: "".to_string(),
+                name: None,
+                value: value.value.clone(),
+            }
+        }
+    }
+
+    fn preset_to_pair_value(preset: GuiPresetCfg) -> field_values::PairValue {
+        if !preset.id.is_empty() {
+            field_values::PairValue {
+                is_preset: true,
+                value: preset.id,
+            }
+        } else {
+            field_values::PairValue {
+                is_preset: false,
+                value: preset.value,
+            }
+        }
+    }
+
+    fn parse_vault_ids_for_order(
+        documents: Vec<Arc<RwLock<StrictYaml>>>,
+        order_key: &str,
+        is_input: bool,
+    ) -> Result<BTreeMap<(VaultType, String), Option<String>>, GuiError> {
+        let mut vault_ids = BTreeMap::new();
+        let r#type = if is_input {
+            VaultType::Input
+        } else {
+            VaultType::Output
+        };
+        for (token, vault_id) in OrderCfg::parse_vault_ids(documents, order_key, r#type)? {
+            vault_ids.insert((r#type, token), vault_id.as_ref().map(|v| v.to_string()));
+        }
+        Ok(vault_ids)
+    }
+
+    fn restore_select_tokens(
+        &self,
+        select_tokens: BTreeMap<String, TokenCfg>,
+        strict: bool,
+        report: &mut StateMigrationReport,
+    ) -> Result<(), GuiError> {
+        let deployment_select_tokens = GuiCfg::parse_select_tokens(
+            self.dotrain_order.dotrain_yaml().documents,
+            &self.selected_deployment,
+        )?;
+        for (key, token) in select_tokens {
+            let is_selectable = deployment_select_tokens
+                .as_ref()
+                .is_some_and(|select_tokens| select_tokens.iter().any(|st| st.key == key));
+            if !is_selectable {
+                if strict {
+                    return Err(match deployment_select_tokens {
+                        Some(_) => GuiError::TokenNotInSelectTokens(key),
+                        None => GuiError::SelectTokensNotSet,
+                    });
+                }
+                report.dropped_select_tokens.push(key);
+                continue;
+            }
+            if self.is_select_token_set(key.clone())? {
+                TokenCfg::remove_record_from_yaml(
+                    self.dotrain_order.orderbook_yaml().documents,
+                    &key,
+                )?;
+            }
+            TokenCfg::add_record_to_yaml(
+                self.dotrain_order.orderbook_yaml().documents,
+                &key,
+                &token.network.key,
+                &token.address.to_string(),
+                token.decimals.map(|d| d.to_string()).as_deref(),
+                token.label.map(|l| l.to_string()).as_deref(),
+                token.symbol.map(|s| s.to_string()).as_deref(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn restore_vault_ids(
+        &self,
+        vault_ids: BTreeMap<(VaultType, String), Option<String>>,
+        strict: bool,
+        report: &mut StateMigrationReport,
+    ) -> Result<(), GuiError> {
+        let order_key = DeploymentCfg::parse_order_key(
+            self.dotrain_order.dotrain_yaml().documents,
+            &self.selected_deployment,
+        )?;
+        for ((vault_type, token), vault_id) in vault_ids {
+            let result = self
+                .dotrain_order
+                .dotrain_yaml()
+                .get_order_for_gui_deployment(&order_key, &self.selected_deployment)
+                .and_then(|mut order| {
+                    order.update_vault_id(vault_type, token.clone(), vault_id.clone())
+                });
+            match (result, vault_id) {
+                (Ok(_), _) => {}
+                (Err(err), _) if strict => return Err(err.into()),
+                // Unset vault ids carry no user input, so losing them is not worth reporting
+                (Err(_), None) => {}
+                (Err(_), Some(vault_id)) => report.dropped_vault_ids.push(DroppedVaultId {
+                    vault_type,
+                    token,
+                    vault_id,
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Maps a saved field value onto the current field definition. Presets are
+    /// matched by value so reordered or inserted presets still resolve correctly.
+    fn migrate_field_value(
+        field: &GuiFieldDefinitionCfg,
+        value: String,
+    ) -> Result<field_values::PairValue, GuiError> {
+        validation::validate_field(field, &value)?;
+
+        let presets = field.presets.as_deref().unwrap_or_default();
+        Ok(match presets.iter().find(|preset| preset.value == value) {
+            Some(preset) => field_values::PairValue {
+                is_preset: true,
+                value: preset.id.clone(),
+            },
+            None => field_values::PairValue {
+                is_preset: false,
+                value,
+            },
+        })
+    }
+
+    fn restore_field_values(
+        &mut self,
+        mut saved_fields: BTreeMap<String, GuiPresetCfg>,
+        strict: bool,
+        report: &mut StateMigrationReport,
+    ) -> Result<(), GuiError> {
+        let deployment = self.get_current_deployment()?;
+        for field in deployment.fields.iter() {
+            let saved = match saved_fields.remove(&field.binding) {
+                Some(saved) => Some(saved),
+                None => field
+                    .previous_bindings
+                    .iter()
+                    .flatten()
+                    .find_map(|previous| {
+                        saved_fields
+                            .remove(previous)
+                            .map(|saved| (previous.clone(), saved))
+                    })
+                    .map(|(previous, saved)| {
+                        report.renamed_fields.push(RenamedField {
+                            from: previous,
+                            to: field.binding.clone(),
+                        });
+                        saved
+                    }),
+            };
+            let Some(saved) = saved else {
+                continue;
+            };
+
+            match Self::migrate_field_value(field, saved.value) {
+                Ok(value) => {
+                    self.field_values.insert(field.binding.clone(), value);
+                }
+                Err(err) if strict => return Err(err),
+                Err(_) => report.invalid_fields.push(field.binding.clone()),
+            }
+        }
+
+        if let Some(binding) = saved_fields.keys().next() {
+            if strict {
+                return Err(GuiError::FieldBindingNotFound(binding.clone()));
+            }
+        }
+        report.dropped_fields.extend(saved_fields.into_keys());
+
+        // Unset fields of an unchanged dotrain were already missing when the state was saved
+        if report.dotrain_changed {
+            for field in deployment.fields.iter() {
+                if !self.field_values.contains_key(&field.binding)
+                    && self.is_field_required_at(&deployment.fields, field, 0)?
+                    && self
+                        .resolve_field_default(&deployment.fields, field, 0)?
+                        .is_none()
+                {
+                    report.newly_required_fields.push(field.binding.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn restore_deposits(
+        &mut self,
+        saved_deposits: BTreeMap<String, GuiPresetCfg>,
+        strict: bool,
+        report: &mut StateMigrationReport,
+    ) -> Result<(), GuiError> {
+        for (token, saved) in saved_deposits {
+            let gui_deposit = match self.get_gui_deposit(&token) {
+                Ok(gui_deposit) => gui_deposit,
+                Err(err) if strict => return Err(err),
+                Err(_) => {
+                    report.dropped_deposits.push(token);
+                    continue;
+                }
+            };
+            let presets = gui_deposit.presets.unwrap_or_default();
+
+            // Preset deposits are matched by their saved amount; states saved before the
+            // amount was stored only carry the preset index
+            let amount = if !saved.id.is_empty() {
+                let amount = if saved.value.is_empty() {
+                    saved.id.parse::<usize>().ok().and_then(|i| presets.get(i))
+                } else {
+                    presets.iter().find(|preset| **preset == saved.value)
+                };
+                match amount {
+                    Some(amount) => amount.clone(),
+                    None if strict => return Err(GuiError::InvalidPreset),
+                    None => {
+                        report.invalid_deposits.push(token);
+                        continue;
+                    }
+                }
+            } else {
+                saved.value
+            };
+
+            if let Some(validation) = &gui_deposit.validation {
+                if let Err(err) = validation::validate_deposit_amount(&token, &amount, validation) {
+                    if strict {
+                        return Err(err.into());
+                    }
+                    report.invalid_deposits.push(token);
+                    continue;
+                }
+            }
+
+            let value = match presets.iter().position(|preset| *preset == amount) {
+                Some(index) => field_values::PairValue {
+                    is_preset: true,
+                    value: index.to_string(),
+                },
+                None => field_values::PairValue {
+                    is_preset: false,
+                    value: amount,
+                },
+            };
+            self.deposits.insert(token, value);
+        }
+        Ok(())
+    }
+
+    /// Exports the complete GUI state as a compressed, encoded string.
+    ///
+    /// Serializes all current configuration including field values, deposits,
+    /// selected tokens, and vault IDs into a compact format for persistence.
+    /// The output is gzipped and base64-encoded. Use `serializeShareableState`
+    /// for links that are opened by someone else or by a later release.
+    ///
+    /// ## State Contents
+    ///
+    /// - Field values with preset information
+    /// - Deposit amounts with preset references
+    /// - Selected token configurations
+    /// - Vault ID assignments
+    /// - Configuration hash for validation
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.serializeState();
+    /// if (result.error) {
+    ///   console.error("Serialization error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const state = result.value;
+    /// // Do something with the state
+    /// ```
+    #[wasm_export(
+        js_name = "serializeState",
+        unchecked_return_type = "string",
+        return_description = "Compressed, base64-encoded state data"
+    )]
+    pub fn serialize_state(&self) -> Result<String, GuiError> {
+        let bytes = bincode::serialize(&self.get_serialized_state()?)?;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&bytes)?;
+        let compressed = encoder.finish()?;
+
+        Ok(URL_SAFE.encode(compressed))
+    }
+
+    pub(super) fn get_serialized_state(&self) -> Result<SerializedGuiState, GuiError> {
+        let fields = self.get_current_deployment()?.fields;
+        let mut field_values = BTreeMap::new();
+        for (k, v) in self.field_values.iter() {
+            let is_visible = match fields.iter().find(|field| &field.binding == k) {
+                Some(field) => self.is_field_visible_at(&fields, field, 0)?,
+                None => true,
+            };
+            if !is_visible {
+                continue;
+            }
+            let preset = if v.is_preset {
+                let presets = GuiCfg::parse_field_presets(
+                    self.dotrain_order.dotrain_yaml().documents.clone(),
+                    &self.selected_deployment,
+                    k,
+                )?
+                .ok_or(GuiError::BindingHasNoPresets(k.clone()))?;
+                presets
+                    .iter()
+                    .find(|preset| preset.id == v.value)
+                    .ok_or(GuiError::InvalidPreset)?
+                    .clone()
+            } else {
+                Self::create_preset(v, String::default())
+            };
+            field_values.insert(k.clone(), preset);
+        }
+
+        let mut deposits = BTreeMap::new();
+        for (k, v) in self.deposits.iter() {
+            // Preset deposits keep their amount so a reordered preset list can be matched by value
+            let amount = if v.is_preset {
+                let index = v
+                    .value
+                    .parse::<usize>()
+                    .map_err(|_| GuiError::InvalidPreset)?;
+                self.get_gui_deposit(k)?
+                    .presets
+                    .ok_or(GuiError::PresetsNotSet)?
+                    .get(index)
+                    .ok_or(GuiError::InvalidPreset)?
+                    .clone()
+            } else {
+                String::default()
+            };
+            let preset = Self::create_preset(v, amount);
+            deposits.insert(k.clone(), preset);
+        }
+
+        let mut select_tokens: BTreeMap<String, TokenCfg> = BTreeMap::new();
+        if let Some(st) = GuiCfg::parse_select_tokens(
+            self.dotrain_order.dotrain_yaml().documents.clone(),
+            &self.selected_deployment,
+        )? {
+            for select_token in st {
+                if let Ok(token) = self
+                    .dotrain_order
+                    .orderbook_yaml()
+                    .get_token(&select_token.key)
+                {
+                    select_tokens.insert(select_token.key, token);
+                }
+            }
+        }
+
+        let order_key = DeploymentCfg::parse_order_key(
+            self.dotrain_order.dotrain_yaml().documents.clone(),
+            &self.selected_deployment,
+        )?;
+        let mut vault_ids = BTreeMap::new();
+        vault_ids.extend(Self::parse_vault_ids_for_order(
+            self.dotrain_order.dotrain_yaml().documents.clone(),
+            &order_key,
+            true,
+        )?);
+        vault_ids.extend(Self::parse_vault_ids_for_order(
+            self.dotrain_order.dotrain_yaml().documents.clone(),
+            &order_key,
+            false,
+        )?);
+
+        Ok(SerializedGuiState {
+            field_values,
+            deposits,
+            select_tokens,
+            vault_ids,
+            dotrain_hash: DotrainOrderGui::get_dotrain_hash(self.dotrain_order.dotrain()?)?,
+            selected_deployment: self.selected_deployment.clone(),
+        })
+    }
+
+    /// Restores a GUI instance from previously serialized state.
+    ///
+    /// Creates a new GUI instance with all configuration restored from a saved state.
+    /// Select tokens, vault ids, field values and deposits are matched by key against
+    /// the given dotrain, so a state saved against an earlier revision of the order
+    /// can still be restored.
+    ///
+    /// ## Migration
+    ///
+    /// Entries that no longer apply are skipped instead of failing the restore. Fields
+    /// renamed through `previous-bindings` keep their value, and values that fail the
+    /// current validation rules are left unset. Everything that changed is recorded in
+    /// the report returned by `getStateMigrationReport`.
+    ///
+    /// ## Security
+    ///
+    /// Pass `strict` to require the dotrain to be byte-for-byte identical to the one
+    /// the state was saved with and to reject any entry that doesn't apply. Use it
+    /// wherever a silently adjusted state must not reach a transaction.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await DotrainOrderGui.newFromState(dotrainYaml, savedState);
+    /// if (result.error) {
+    ///   console.error("Restore failed:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const gui = result.value;
+    /// const report = gui.getStateMigrationReport().value;
+    /// if (report?.droppedFields.length) {
+    ///   console.warn("Dropped fields:", report.droppedFields);
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "newFromState",
+        preserve_js_class,
+        return_description = "Fully restored GUI instance"
+    )]
+    pub async fn new_from_state(
+        #[wasm_export(param_description = "Dotrain content to restore the state against")]
+        dotrain: String,
+        #[wasm_export(param_description = "Previously serialized state string")] serialized: String,
+        #[wasm_export(param_description = "Optional callback for future state changes")]
+        state_update_callback: Option<js_sys::Function>,
+        #[wasm_export(
+            param_description = "Require an unchanged dotrain and fail on any entry that doesn't apply (defaults to false)"
+        )]
+        strict: Option<bool>,
+    ) -> Result<DotrainOrderGui, GuiError> {
+        let strict = strict.unwrap_or(false);
+        let (state, state_signer) = DotrainOrderGui::decode_serialized_state(&serialized)?;
+
+        let original_dotrain_hash = DotrainOrderGui::get_dotrain_hash(dotrain.clone())?;
+
+        let dotrain_changed = original_dotrain_hash != state.dotrain_hash;
+        if dotrain_changed && strict {
+            return Err(GuiError::DotrainMismatch);
+        }
+        let dotrain_order = DotrainOrder::create(dotrain.clone(), None).await?;
+
+        let keys = GuiCfg::parse_deployment_keys(dotrain_order.dotrain_yaml().documents.clone())?;
+        if !keys.contains(&state.selected_deployment) {
+            return Err(GuiError::DeploymentNotFound(state.selected_deployment));
+        }
+
+        let mut dotrain_order_gui = DotrainOrderGui {
+            dotrain_order,
+            field_values: BTreeMap::new(),
+            deposits: BTreeMap::new(),
+            selected_deployment: state.selected_deployment,
+            state_update_callback,
+            migration_report: None,
+            registry_origin: None,
+            embed_gui_meta: false,
+        };
+
+        let mut report = StateMigrationReport {
+            dotrain_changed,
+            state_signer,
+            ..Default::default()
+        };
+        dotrain_order_gui.restore_select_tokens(state.select_tokens, strict, &mut report)?;
+        dotrain_order_gui.restore_vault_ids(state.vault_ids, strict, &mut report)?;
+        dotrain_order_gui.restore_field_values(state.field_values, strict, &mut report)?;
+        dotrain_order_gui.restore_deposits(state.deposits, strict, &mut report)?;
+        dotrain_order_gui.migration_report = Some(report);
+
+        Ok(dotrain_order_gui)
+    }
+
+    /// Returns the report produced when this instance was restored from state.
+    ///
+    /// Lists the fields, deposits, select tokens and vault ids that were dropped,
+    /// renamed or rejected while restoring, plus the fields that still need a value.
+    /// Instances that weren't created with `newFromState` have no report.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.getStateMigrationReport();
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const report = result.value;
+    /// if (report && report.newlyRequiredFields.length > 0) {
+    ///   // Ask the user to fill in the new fields
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "getStateMigrationReport",
+        unchecked_return_type = "StateMigrationReport | undefined",
+        return_description = "Report from the last state restore, if any"
+    )]
+    pub fn get_state_migration_report(&self) -> Result<Option<StateMigrationReport>, GuiError> {
+        Ok(self.migration_report.clone())
+    }
+
+    /// Manually triggers the state update callback.
+    ///
+    /// Calls the registered state update callback with the current serialized state.
+    /// This is typically called automatically after state-changing operations,
+    /// but can be triggered manually if needed.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// // Manual state update trigger
+    /// const result = gui.executeStateUpdateCallback();
+    /// if (result.error) {
+    ///   console.error("Callback error:", result.error.readableMsg);
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "executeStateUpdateCallback",
+        unchecked_return_type = "void",
+        return_description = "Callback executed successfully or no callback registered"
+    )]
+    pub fn execute_state_update_callback(&self) -> Result<(), GuiError> {
+        if let Some(callback) = &self.state_update_callback {
+            let state = to_js_value(&self.serialize_state()?)?;
+            callback.call1(&JsValue::UNDEFINED, &state).map_err(|e| {
+                GuiError::JsError(format!("Failed to execute state update callback: {:?}", e))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Gets comprehensive configuration for complete UI initialization.
+    ///
+    /// Provides all configuration data needed to build a complete order interface,
+    /// organized by requirement type for progressive UI construction.
+    ///
+    /// ## Configuration Structure
+    ///
+    /// - `fieldDefinitionsWithoutDefaults` - Required fields needing user input
+    /// - `fieldDefinitionsWithDefaults` - Optional fields with fallback values
+    /// - `deposits` - Deposit configurations with tokens and presets
+    /// - `orderInputs` - Input token configurations
+    /// - `orderOutputs` - Output token configurations
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = gui.getAllGuiConfig();
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const config = result.value;
+    ///
+    /// // Build required fields section
+    /// config.fieldDefinitionsWithoutDefaults.forEach(field => {
+    ///   // Do something with the fields
+    /// });
+    ///
+    /// // Build optional fields section
+    /// if (config.fieldDefinitionsWithDefaults.length > 0) {
+    ///   // Do something with the fields
+    /// }
+    ///
+    /// // Build deposits section
+    /// config.deposits.forEach(deposit => {
+    ///   // Do something with the deposits
+    /// });
+    ///
+    /// // Show order preview
+    /// config.orderInputs.forEach(input => {
+    ///   // Do something with the order inputs
+    /// });
+    /// config.orderOutputs.forEach(output => {
+    ///   // Do something with the order outputs
+    /// });
+    /// ```
+    #[wasm_export(
+        js_name = "getAllGuiConfig",
+        unchecked_return_type = "AllGuiConfig",
+        return_description = "Complete configuration package"
+    )]
+    pub fn get_all_gui_config(&self) -> Result<AllGuiConfig, GuiError> {
+        let deployment = self.get_current_deployment()?;
+
+        let field_definitions_without_defaults = self.get_all_field_definitions(Some(false))?;
+        let field_definitions_with_defaults = self.get_all_field_definitions(Some(true))?;
+        let deposits = deployment.deposits;
+        let order_inputs = deployment.deployment.order.inputs.clone();
+        let order_outputs = deployment.deployment.order.outputs.clone();
+
+        Ok(AllGuiConfig {
+            field_definitions_without_defaults,
+            field_definitions_with_defaults,
+            deposits,
+            order_inputs,
+            order_outputs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::{
+        field_values::FieldValue,
+        tests::{get_yaml, initialize_gui_with_select_tokens},
+    };
+    use alloy::primitives::U256;
+    use rain_orderbook_app_settings::order::VaultType;
+
+    const SERIALIZED_STATE: &str = "H4sIAAAAAAAA_21QTWvCQBDN2tJS6EkKPRX6A7okm9XqCj14CE0RKgUpXnWzGsm6G5P1-0_4kyU6GzE4h3lv9r2dGabmnOMJcDxT0UxNMXFs3AESz6uafAQPnlMySx4AjU6Eore63XZeV89Q5XousBJmrbPE_nsDjI1JO64rNR_JWOem0_baTTdLOV5mcl84UJGRHR0Mwheg9cb_5lBJqI4eQR4UO7xTdG_r3i-tOZe42pWUAwhjqKr6peoz9gGUJnLXa03CYcvwUNM-_v5ZRIu80dzGAc8-Axb2ycrviuSv-_VqLyGk4AafmuJIpFJv50KZI9yAnizIAQAA";
+
+    #[tokio::test]
+    async fn test_serialize_state() {
+        let mut gui = initialize_gui_with_select_tokens().await;
+
+        gui.add_record_to_yaml(
+            "token3".to_string(),
+            "some-network".to_string(),
+            "0x1234567890123456789012345678901234567890".to_string(),
+            "18".to_string(),
+            "Token 3".to_string(),
+            "TKN3".to_string(),
+        );
+        gui.set_deposit("token3".to_string(), "100".to_string())
+            .await
+            .unwrap();
+        gui.set_field_value("binding-1".to_string(), "100".to_string())
+            .unwrap();
+        gui.set_field_value("binding-2".to_string(), "0".to_string())
+            .unwrap();
+        gui.set_vault_id(
+            VaultType::Input,
+            "token1".to_string(),
+            Some("199".to_string()),
+        )
+        .unwrap();
+        gui.set_vault_id(
+            VaultType::Output,
+            "token2".to_string(),
+            Some("299".to_string()),
+        )
+        .unwrap();
+
+        let state = gui.serialize_state().unwrap();
+        assert!(!state.is_empty());
+        assert_eq!(state, SERIALIZED_STATE);
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state() {
+        let gui =
+            DotrainOrderGui::new_from_state(get_yaml(), SERIALIZED_STATE.to_string(), None, None)
+                .await
+                .unwrap();
+
+        let report = gui.get_state_migration_report().unwrap().unwrap();
+        assert!(!report.dotrain_changed);
+        assert!(report.is_clean());
+        assert!(gui.is_select_token_set("token3".to_string()).unwrap());
+        assert_eq!(gui.get_deposits().unwrap()[0].amount, "100");
+        assert_eq!(
+            gui.get_field_value("binding-1".to_string()).unwrap(),
+            FieldValue {
+                field: "binding-1".to_string(),
+                value: "100".to_string(),
+                is_preset: false,
+            }
+        );
+        assert_eq!(
+            gui.get_field_value("binding-2".to_string()).unwrap(),
+            FieldValue {
+                field: "binding-2".to_string(),
+                value: "0".to_string(),
+                is_preset: true,
+            }
+        );
+        let vault_ids = gui.get_vault_ids().unwrap().0;
+        assert_eq!(
+            vault_ids.get("input").unwrap()["token1"],
+            Some(U256::from(199))
+        );
+        assert_eq!(
+            vault_ids.get("output").unwrap()["token2"],
+            Some(U256::from(299))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state_invalid_dotrain() {
+        let dotrain = r#"
+        dotrain:
+            name: Test
+            description: Test
+        "#;
+
+        let err = DotrainOrderGui::new_from_state(
+            dotrain.to_string(),
+            SERIALIZED_STATE.to_string(),
+            None,
+            Some(true),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.to_string(), GuiError::DotrainMismatch.to_string());
+        assert_eq!(
+            err.to_readable_msg(),
+            "There was a mismatch in the dotrain configuration. Please check your YAML configuration for consistency."
+        );
+    }
+}
+
+#[cfg(all(test, target_family = "wasm"))]
+mod callback_tests {
+    use super::*;
+    use crate::gui::tests::get_yaml;
+    use js_sys::{eval, Reflect};
+    use rain_orderbook_app_settings::order::VaultType;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    async fn test_execute_state_update_callback() {
+        eval(
+            r#"
+            globalThis.callbackCalled = false;
+            globalThis.receivedState = null;
+            globalThis.testCallback = function(state) {
+                globalThis.callbackCalled = true;
+                globalThis.receivedState = state;
+            };
+        "#,
+        )
+        .unwrap();
+
+        let global = js_sys::global();
+        let callback_js = Reflect::get(&global, &JsValue::from_str("testCallback"))
+            .expect("should have testCallback function on globalThis")
+            .dyn_into::<js_sys::Function>()
+            .expect("testCallback should be a function");
+
+        let mut gui = DotrainOrderGui::new_with_deployment(
+            get_yaml(),
+            "some-deployment".to_string(),
+            Some(callback_js.clone()),
+        )
+        .await
+        .unwrap();
+
+        let callback_called = Reflect::get(&global, &JsValue::from_str("callbackCalled"))
+            .expect("should have callbackCalled flag on globalThis");
+        assert_eq!(callback_called, JsValue::from_bool(false));
+
+        gui.set_deposit("token1".to_string(), "100".to_string())
+            .await
+            .unwrap();
+        gui.set_field_value("binding-1".to_string(), "100".to_string())
+            .unwrap();
+        gui.set_field_value("binding-2".to_string(), "582.1".to_string())
+            .unwrap();
+        gui.set_vault_id(
+            VaultType::Input,
+            "token1".to_string(),
+            Some("199".to_string()),
+        )
+        .unwrap();
+        gui.set_vault_id(
+            VaultType::Output,
+            "token2".to_string(),
+            Some("299".to_string()),
+        )
+        .unwrap();
+
+        let callback_called = Reflect::get(&global, &JsValue::from_str("callbackCalled"))
+            .expect("should have callbackCalled flag on globalThis");
+        assert_eq!(callback_called, JsValue::from_bool(true));
+
+        let received_state_js = Reflect::get(&global, &JsValue::from_str("receivedState"))
+            .expect("should have receivedState on globalThis");
+        assert!(received_state_js.is_string());
+        let received_state_rust: String = received_state_js.as_string().unwrap();
+        assert!(!received_state_rust.is_empty());
+
+        let expected_state = gui.serialize_state().unwrap();
+        assert_eq!(received_state_rust, expected_state);
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod migration_tests {
+    use super::*;
+    use alloy::primitives::U256;
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+
+    const FRONTMATTER: &str = r#"
+networks:
+    some-network:
+        rpcs:
+            - http://localhost:8545
+        chain-id: 123
+tokens:
+    token1:
+        network: some-network
+        address: 0x0000000000000000000000000000000000000001
+        decimals: 18
+    token2:
+        network: some-network
+        address: 0x0000000000000000000000000000000000000002
+        decimals: 6
+deployers:
+    some-deployer:
+        network: some-network
+        address: 0xF14E09601A47552De6aBd3A0B165607FaFd2B5Ba
+orderbooks:
+    some-orderbook:
+        address: 0xc95A5f8eFe14d7a20BD2E5BAFEC4E71f8Ce0B9A6
+        network: some-network
+        subgraph: some-sg
+        deployment-block: 12345
+subgraphs:
+    some-sg: https://www.some-sg.com
+scenarios:
+    some-scenario:
+        deployer: some-deployer
+orders:
+    some-order:
+        deployer: some-deployer
+        inputs:
+            - token: token1
+        outputs:
+            - token: token2
+deployments:
+    some-deployment:
+        scenario: some-scenario
+        order: some-order
+"#;
+
+    const GUI_V1: &str = r#"
+gui:
+    name: Migration
+    description: Migration test
+    deployments:
+        some-deployment:
+            name: Some deployment
+            description: Some deployment description
+            deposits:
+                - token: token1
+                  presets:
+                    - "10"
+                    - "20"
+                - token: token2
+            fields:
+                - binding: price
+                  name: Price
+                  presets:
+                    - value: "1"
+                    - value: "2"
+                - binding: amount
+                  name: Amount
+                - binding: old-fee
+                  name: Fee
+"#;
+
+    const GUI_V2: &str = r#"
+gui:
+    name: Migration
+    description: Migration test
+    deployments:
+        some-deployment:
+            name: Some deployment
+            description: Some deployment description
+            deposits:
+                - token: token1
+                  presets:
+                    - "5"
+                    - "10"
+                    - "20"
+                    - "30"
+            fields:
+                - binding: price
+                  name: Price
+                  presets:
+                    - value: "3"
+                    - value: "2"
+                    - value: "1"
+                - binding: amount
+                  name: Amount
+                  validation:
+                    type: number
+                    maximum: 40
+                - binding: fee
+                  name: Fee
+                  previous-bindings:
+                    - old-fee
+                - binding: spread
+                  name: Spread
+                - binding: slippage
+                  name: Slippage
+                  default: "0.01"
+"#;
+
+    fn dotrain(gui: &str) -> String {
+        format!(
+            "version: {}\n{}\n{}\n---\n#calculate-io\n_ _: 0 0;\n#handle-io\n:;\n#handle-add-order\n:;",
+            SpecVersion::current(),
+            FRONTMATTER,
+            gui
+        )
+    }
+
+    async fn serialized_v1_state() -> String {
+        let mut gui = DotrainOrderGui::new_with_deployment(
+            dotrain(GUI_V1),
+            "some-deployment".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        gui.set_field_value("price".to_string(), "2".to_string())
+            .unwrap();
+        gui.set_field_value("amount".to_string(), "50".to_string())
+            .unwrap();
+        gui.set_field_value("old-fee".to_string(), "0.5".to_string())
+            .unwrap();
+        gui.set_deposit("token1".to_string(), "20".to_string())
+            .await
+            .unwrap();
+        gui.set_deposit("token2".to_string(), "5".to_string())
+            .await
+            .unwrap();
+        gui.set_vault_id(
+            VaultType::Input,
+            "token1".to_string(),
+            Some("7".to_string()),
+        )
+        .unwrap();
+        gui.serialize_state().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state_unchanged_dotrain() {
+        let state = serialized_v1_state().await;
+
+        for strict in [None, Some(true)] {
+            let gui = DotrainOrderGui::new_from_state(dotrain(GUI_V1), state.clone(), None, strict)
+                .await
+                .unwrap();
+            let report = gui.get_state_migration_report().unwrap().unwrap();
+            assert!(!report.dotrain_changed);
+            assert!(report.is_clean());
+            assert_eq!(gui.field_values.len(), 3);
+            assert_eq!(gui.deposits.len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state_migrates_changed_dotrain() {
+        let state = serialized_v1_state().await;
+
+        let gui = DotrainOrderGui::new_from_state(dotrain(GUI_V2), state, None, None)
+            .await
+            .unwrap();
+        let report = gui.get_state_migration_report().unwrap().unwrap();
+
+        assert_eq!(
+            report,
+            StateMigrationReport {
+                dotrain_changed: true,
+                dropped_fields: vec![],
+                renamed_fields: vec![RenamedField {
+                    from: "old-fee".to_string(),
+                    to: "fee".to_string(),
+                }],
+                invalid_fields: vec!["amount".to_string()],
+                newly_required_fields: vec!["amount".to_string(), "spread".to_string()],
+                dropped_deposits: vec!["token2".to_string()],
+                invalid_deposits: vec![],
+                dropped_select_tokens: vec![],
+                dropped_vault_ids: vec![],
+                state_signer: None,
+            }
+        );
+
+        // The preset moved to a different index but keeps its value
+        let price = gui.get_field_value("price".to_string()).unwrap();
+        assert_eq!(price.value, "2");
+        assert!(price.is_preset);
+        assert_eq!(gui.field_values["price"].value, "1");
+
+        let fee = gui.get_field_value("fee".to_string()).unwrap();
+        assert_eq!(fee.value, "0.5");
+        assert!(!fee.is_preset);
+
+        assert!(gui.get_field_value("amount".to_string()).is_err());
+
+        // The deposit preset moved to a different index but keeps its amount
+        assert_eq!(gui.deposits["token1"].value, "2");
+        assert_eq!(gui.get_deposits().unwrap()[0].amount, "20");
+
+        let deployment = gui.get_current_deployment().unwrap();
+        assert_eq!(
+            deployment.deployment.order.inputs[0].vault_id,
+            Some(U256::from(7))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state_reports_removed_deposit_preset() {
+        let state = serialized_v1_state().await;
+        let gui_v3 = GUI_V2.replace("                    - \"20\"\n", "");
+
+        let gui = DotrainOrderGui::new_from_state(dotrain(&gui_v3), state, None, None)
+            .await
+            .unwrap();
+        let report = gui.get_state_migration_report().unwrap().unwrap();
+        assert_eq!(report.invalid_deposits, vec!["token1".to_string()]);
+        assert!(!gui.deposits.contains_key("token1"));
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state_strict_rejects_changed_dotrain() {
+        let state = serialized_v1_state().await;
+
+        let err = DotrainOrderGui::new_from_state(dotrain(GUI_V2), state, None, Some(true))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GuiError::DotrainMismatch));
+    }
+
+    #[tokio::test]
+    async fn test_new_from_state_missing_deployment() {
+        let state = serialized_v1_state().await;
+        let gui_v3 = GUI_V2.replace("        some-deployment:", "        other-deployment:");
+
+        let err = DotrainOrderGui::new_from_state(dotrain(&gui_v3), state, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            GuiError::DeploymentNotFound("some-deployment".to_string()).to_string()
+        );
+    }
+}
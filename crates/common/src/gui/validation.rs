@@ -1,5 +1,9 @@
+use alloy::primitives::Address;
 use rain_math_float::{Float, FloatError};
-use rain_orderbook_app_settings::gui::{DepositValidationCfg, FieldValueValidationCfg};
+use rain_orderbook_app_settings::gui::{
+    DepositValidationCfg, FieldValueValidationCfg, GuiFieldDefinitionCfg,
+};
+use std::str::FromStr;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -56,10 +60,48 @@ pub enum GuiValidationError {
     #[error("The {name} field contains an invalid boolean value: '{value}'. Please enter either 'true' or 'false'.")]
     InvalidBoolean { name: String, value: String },
 
+    #[error("The {name} field contains an invalid address: '{value}'. Please enter a 0x-prefixed 20 byte address.")]
+    InvalidAddress { name: String, value: String },
+
+    #[error("The {name} field address '{value}' has an invalid checksum. Please check the address or enter it in lowercase.")]
+    InvalidAddressChecksum { name: String, value: String },
+
+    #[error("The {name} field contains an invalid duration: '{value}'. Please enter seconds or a duration such as '1h30m'.")]
+    InvalidDuration { name: String, value: String },
+
+    #[error("The {name} field value '{value}' is not one of the available options.")]
+    NotInEnum { name: String, value: String },
+
+    #[error("The {name} field amount can't be converted as the decimals of token '{token}' are unknown. Please select the token or set its decimals in the YAML configuration.")]
+    TokenDecimalsNotSet { name: String, token: String },
+
     #[error(transparent)]
     FloatError(#[from] FloatError),
 }
 
+/// Validates a value against the field's validation rules, including the
+/// preset check for enum fields.
+pub fn validate_field(
+    field: &GuiFieldDefinitionCfg,
+    value: &str,
+) -> Result<(), GuiValidationError> {
+    match &field.validation {
+        Some(FieldValueValidationCfg::Enum) => {
+            let presets = field.presets.as_deref().unwrap_or_default();
+            if presets.iter().any(|preset| preset.value == value) {
+                Ok(())
+            } else {
+                Err(GuiValidationError::NotInEnum {
+                    name: field.name.clone(),
+                    value: value.to_string(),
+                })
+            }
+        }
+        Some(validation) => validate_field_value(&field.name, value, validation),
+        None => Ok(()),
+    }
+}
+
 pub fn validate_field_value(
     field_name: &str,
     value: &str,
@@ -84,22 +126,126 @@ pub fn validate_field_value(
             max_length,
         } => validate_string(field_name, value, min_length, max_length),
         FieldValueValidationCfg::Boolean => validate_boolean(field_name, value),
+        FieldValueValidationCfg::Address => parse_address(field_name, value).map(|_| ()),
+        FieldValueValidationCfg::TokenAmount {
+            minimum,
+            exclusive_minimum,
+            maximum,
+            exclusive_maximum,
+            ..
+        } => validate_number(
+            field_name,
+            value,
+            minimum,
+            exclusive_minimum,
+            maximum,
+            exclusive_maximum,
+        ),
+        FieldValueValidationCfg::Percentage { minimum, maximum } => {
+            validate_number(field_name, value, minimum, &None, maximum, &None)
+        }
+        FieldValueValidationCfg::Duration { minimum, maximum } => {
+            validate_duration(field_name, value, minimum, maximum)
+        }
+        // Enum values are checked against the field presets in `validate_field`
+        FieldValueValidationCfg::Enum => Ok(()),
     }
 }
 
-pub fn validate_deposit_amount(
-    token_name: &str,
-    amount: &str,
-    validation: &DepositValidationCfg,
-) -> Result<(), GuiValidationError> {
-    validate_number(
-        token_name,
-        amount,
-        &validation.minimum,
-        &validation.exclusive_minimum,
-        &validation.maximum,
-        &validation.exclusive_maximum,
-    )
+/// Parses an address, rejecting mixed case input whose EIP-55 checksum doesn't match
+pub fn parse_address(name: &str, value: &str) -> Result<Address, GuiValidationError> {
+    let value = value.trim();
+    let invalid = || GuiValidationError::InvalidAddress {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+
+    let digits = value.strip_prefix("0x").ok_or_else(invalid)?;
+    let address = Address::from_str(value).map_err(|_| invalid())?;
+
+    let is_mixed_case = digits.chars().any(|c| c.is_ascii_lowercase())
+        && digits.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case && address.to_checksum(None) != value {
+        return Err(GuiValidationError::InvalidAddressChecksum {
+            name: name.to_string(),
+            value: value.to_string(),
+        });
+    }
+    Ok(address)
+}
+
+/// Parses a duration into seconds. Accepts plain seconds or a sequence of
+/// amounts with `s`, `m`, `h`, `d` or `w` units, e.g. `1h30m`.
+pub fn parse_duration(name: &str, value: &str) -> Result<u64, GuiValidationError> {
+    let value = value.trim();
+    let invalid = || GuiValidationError::InvalidDuration {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Ok(seconds);
+    }
+
+    let mut seconds: u64 = 0;
+    let mut digits = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            _ => return Err(invalid()),
+        };
+        let amount = digits.parse::<u64>().map_err(|_| invalid())?;
+        seconds = amount
+            .checked_mul(unit)
+            .and_then(|amount| seconds.checked_add(amount))
+            .ok_or_else(invalid)?;
+        digits.clear();
+    }
+
+    if value.is_empty() || !digits.is_empty() {
+        return Err(invalid());
+    }
+    Ok(seconds)
+}
+
+/// Converts a validated field value into the literal substituted for its binding.
+/// Token amounts are scaled by `token_decimals` and fail without them.
+pub fn field_value_literal(
+    field: &GuiFieldDefinitionCfg,
+    value: &str,
+    token_decimals: Option<u8>,
+) -> Result<String, GuiValidationError> {
+    Ok(match (&field.validation, token_decimals) {
+        (Some(FieldValueValidationCfg::Address), _) => {
+            parse_address(&field.name, value)?.to_checksum(None)
+        }
+        (Some(FieldValueValidationCfg::TokenAmount { .. }), Some(decimals)) => {
+            Float::parse(value.to_string())?
+                .to_fixed_decimal(decimals)?
+                .to_string()
+        }
+        (Some(FieldValueValidationCfg::TokenAmount { token, .. }), None) => {
+            return Err(GuiValidationError::TokenDecimalsNotSet {
+                name: field.name.clone(),
+                token: token.clone(),
+            })
+        }
+        (Some(FieldValueValidationCfg::Percentage { .. }), _) => Float::parse(value.to_string())?
+            .mul(Float::parse("0.01".to_string())?)?
+            .format()?,
+        (Some(FieldValueValidationCfg::Duration { .. }), _) => {
+            parse_duration(&field.name, value)?.to_string()
+        }
+        _ => value.to_string(),
+    })
 }
 
 fn validate_number(
@@ -177,6 +323,37 @@ fn validate_number(
     Ok(())
 }
 
+fn validate_duration(
+    name: &str,
+    value: &str,
+    minimum: &Option<String>,
+    maximum: &Option<String>,
+) -> Result<(), GuiValidationError> {
+    let seconds = parse_duration(name, value)?;
+
+    if let Some(min) = minimum {
+        if seconds < parse_duration(name, min)? {
+            return Err(GuiValidationError::BelowMinimum {
+                name: name.to_string(),
+                value: value.to_string(),
+                minimum: min.clone(),
+            });
+        }
+    }
+
+    if let Some(max) = maximum {
+        if seconds > parse_duration(name, max)? {
+            return Err(GuiValidationError::AboveMaximum {
+                name: name.to_string(),
+                value: value.to_string(),
+                maximum: max.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_string(
     name: &str,
     value: &str,
@@ -222,10 +399,9 @@ fn validate_boolean(name: &str, value: &str) -> Result<(), GuiValidationError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rain_orderbook_app_settings::gui::FieldValueValidationCfg;
-    use wasm_bindgen_test::wasm_bindgen_test;
+    use rain_orderbook_app_settings::gui::{FieldValueValidationCfg, GuiPresetCfg};
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_minimum() {
         let result = validate_number(
             "Test Field",
@@ -269,7 +445,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_exclusive_minimum() {
         let result = validate_number("Price", "10", &None, &Some("10".to_string()), &None, &None);
         match &result {
@@ -296,7 +472,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_maximum() {
         let result = validate_number(
             "Amount",
@@ -340,7 +516,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_exclusive_maximum() {
         let result = validate_number(
             "Token Amount",
@@ -374,7 +550,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_combined_constraints() {
         let result = validate_number(
             "Complex Field",
@@ -413,7 +589,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_parsing() {
         let result = validate_number("Test Field", "100.5", &None, &None, &None, &None);
         assert!(result.is_ok());
@@ -444,7 +620,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_decimals() {
         let result = validate_number("USDC Amount", "100.123456", &None, &None, &None, &None);
         assert!(result.is_ok());
@@ -463,7 +639,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_edge_cases() {
         let result = validate_number("Amount", "0", &None, &None, &None, &None);
         assert!(result.is_ok());
@@ -485,7 +661,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_number_rejects_negative() {
         let result = validate_number("Amount", "-1", &None, &None, &None, &None);
         assert!(matches!(
@@ -506,7 +682,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_string_length() {
         let result = validate_string("Username", "hello", &Some(10), &None);
         match &result {
@@ -543,7 +719,7 @@ mod tests {
         }
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_string_edge_cases() {
         let result = validate_string("Field", "", &None, &None);
         assert!(result.is_ok());
@@ -567,7 +743,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_string_trimming() {
         let result = validate_string("Username", "  hello  ", &Some(3), &Some(10));
         assert!(result.is_ok());
@@ -594,7 +770,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_boolean() {
         let result = validate_boolean("Enable Feature", "true");
         assert!(result.is_ok());
@@ -636,7 +812,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_field_value_number() {
         let validation = FieldValueValidationCfg::Number {
             minimum: Some("10".to_string()),
@@ -661,7 +837,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_field_value_string() {
         let validation = FieldValueValidationCfg::String {
             min_length: Some(3),
@@ -684,7 +860,7 @@ mod tests {
         ));
     }
 
-    #[wasm_bindgen_test]
+    #[test]
     fn test_validate_field_value_boolean() {
         let validation = FieldValueValidationCfg::Boolean;
 
@@ -700,4 +876,154 @@ mod tests {
             Err(GuiValidationError::InvalidBoolean { .. })
         ));
     }
+
+    fn field(validation: FieldValueValidationCfg) -> GuiFieldDefinitionCfg {
+        GuiFieldDefinitionCfg {
+            binding: "binding".to_string(),
+            name: "Typed Field".to_string(),
+            description: None,
+            presets: Some(vec![
+                GuiPresetCfg {
+                    id: "0".to_string(),
+                    name: None,
+                    value: "fast".to_string(),
+                },
+                GuiPresetCfg {
+                    id: "1".to_string(),
+                    name: None,
+                    value: "slow".to_string(),
+                },
+            ]),
+            default: None,
+            show_custom_field: None,
+            validation: Some(validation),
+            previous_bindings: None,
+            show_if: None,
+            required_if: None,
+            default_from: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_address() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let address = parse_address("Recipient", checksummed).unwrap();
+        assert_eq!(address.to_checksum(None), checksummed);
+
+        assert!(parse_address("Recipient", &checksummed.to_lowercase()).is_ok());
+        assert!(parse_address("Recipient", "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED").is_ok());
+
+        let result = parse_address("Recipient", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD");
+        assert!(matches!(
+            result,
+            Err(GuiValidationError::InvalidAddressChecksum { .. })
+        ));
+
+        for value in [
+            "",
+            "0x1234",
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xzz",
+        ] {
+            assert!(matches!(
+                parse_address("Recipient", value),
+                Err(GuiValidationError::InvalidAddress { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("Cooldown", "90").unwrap(), 90);
+        assert_eq!(parse_duration("Cooldown", "45s").unwrap(), 45);
+        assert_eq!(parse_duration("Cooldown", "1h30m").unwrap(), 5400);
+        assert_eq!(parse_duration("Cooldown", "2d").unwrap(), 172800);
+        assert_eq!(parse_duration("Cooldown", "1w").unwrap(), 604800);
+
+        for value in ["", "1x", "h", "1h30", "-5", "1.5h"] {
+            assert!(matches!(
+                parse_duration("Cooldown", value),
+                Err(GuiValidationError::InvalidDuration { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_field_value_duration() {
+        let validation = FieldValueValidationCfg::Duration {
+            minimum: Some("1m".to_string()),
+            maximum: Some("1d".to_string()),
+        };
+
+        assert!(validate_field_value("Cooldown", "1h", &validation).is_ok());
+        assert!(matches!(
+            validate_field_value("Cooldown", "30s", &validation),
+            Err(GuiValidationError::BelowMinimum { .. })
+        ));
+        assert!(matches!(
+            validate_field_value("Cooldown", "2d", &validation),
+            Err(GuiValidationError::AboveMaximum { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_field_enum() {
+        let field = field(FieldValueValidationCfg::Enum);
+
+        assert!(validate_field(&field, "fast").is_ok());
+        assert!(matches!(
+            validate_field(&field, "medium"),
+            Err(GuiValidationError::NotInEnum { .. })
+        ));
+    }
+
+    #[test]
+    fn test_field_value_literal() {
+        let address = field(FieldValueValidationCfg::Address);
+        assert_eq!(
+            field_value_literal(&address, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", None)
+                .unwrap(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+
+        let amount = field(FieldValueValidationCfg::TokenAmount {
+            token: "token1".to_string(),
+            minimum: None,
+            exclusive_minimum: None,
+            maximum: None,
+            exclusive_maximum: None,
+        });
+        assert_eq!(
+            field_value_literal(&amount, "1.5", Some(6)).unwrap(),
+            "1500000"
+        );
+        assert!(matches!(
+            field_value_literal(&amount, "1.5", None),
+            Err(GuiValidationError::TokenDecimalsNotSet { .. })
+        ));
+
+        let percentage = field(FieldValueValidationCfg::Percentage {
+            minimum: None,
+            maximum: None,
+        });
+        assert_eq!(
+            field_value_literal(&percentage, "2.5", None).unwrap(),
+            "0.025"
+        );
+
+        let duration = field(FieldValueValidationCfg::Duration {
+            minimum: None,
+            maximum: None,
+        });
+        assert_eq!(
+            field_value_literal(&duration, "1h30m", None).unwrap(),
+            "5400"
+        );
+
+        let enumeration = field(FieldValueValidationCfg::Enum);
+        assert_eq!(
+            field_value_literal(&enumeration, "slow", None).unwrap(),
+            "slow"
+        );
+    }
 }
@@ -1,7 +1,13 @@
 use alloy::primitives::hex::{decode, FromHexError};
-use rain_metadata::{Error as RainMetadataError, KnownMagic, RainMetaDocumentV1Item};
+use rain_metadata::{
+    ContentEncoding, ContentLanguage, ContentType, Error as RainMetadataError, KnownMagic,
+    RainMetaDocumentV1Item,
+};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
 use std::string::FromUtf8Error;
 use thiserror::Error;
+use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 
 #[derive(Error, Debug)]
 pub enum TryDecodeRainlangSourceError {
@@ -44,6 +50,115 @@ impl TryDecodeRainlangSource for String {
     }
 }
 
+#[derive(Error, Debug)]
+pub enum OrderGuiMetaError {
+    #[error(transparent)]
+    FromUtf8Error(#[from] FromUtf8Error),
+    #[error(transparent)]
+    RainMetadataError(#[from] RainMetadataError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiFieldValueMeta {
+    pub binding: String,
+    pub name: String,
+    pub value: String,
+}
+impl_wasm_traits!(GuiFieldValueMeta);
+
+/// GUI deployment an order was created from, stored as a json meta item
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiDeploymentMeta {
+    pub deployment_key: String,
+    pub deployment_name: String,
+    /// Url of the registry the order was picked from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub registry: Option<String>,
+    /// Key of the order in that registry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub order_key: Option<String>,
+    /// Field values with their resolved presets, for display
+    #[serde(default)]
+    pub field_values: Vec<GuiFieldValueMeta>,
+    /// Shareable GUI state, restores the deployment together with the dotrain
+    pub state: String,
+}
+impl_wasm_traits!(GuiDeploymentMeta);
+
+/// GUI deployment and dotrain source embedded in an order's meta next to its
+/// Rainlang source. Both are needed to open the order again in the GUI.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderGuiMeta {
+    pub deployment: GuiDeploymentMeta,
+    pub dotrain: String,
+}
+impl_wasm_traits!(OrderGuiMeta);
+
+impl OrderGuiMeta {
+    /// The dotrain source goes under the DotrainV1 magic, the deployment json under
+    /// DotrainGuiStateV1
+    pub fn to_meta_items(&self) -> Result<Vec<RainMetaDocumentV1Item>, OrderGuiMetaError> {
+        Ok(vec![
+            RainMetaDocumentV1Item {
+                payload: ByteBuf::from(self.dotrain.as_bytes()),
+                magic: KnownMagic::DotrainV1,
+                content_type: ContentType::OctetStream,
+                content_encoding: ContentEncoding::None,
+                content_language: ContentLanguage::None,
+            },
+            RainMetaDocumentV1Item {
+                payload: ByteBuf::from(serde_json::to_vec(&self.deployment)?),
+                magic: KnownMagic::DotrainGuiStateV1,
+                content_type: ContentType::Json,
+                content_encoding: ContentEncoding::None,
+                content_language: ContentLanguage::None,
+            },
+        ])
+    }
+
+    /// Finds the GUI items in a decoded meta document, `None` when the order
+    /// wasn't deployed from a GUI
+    pub fn from_meta_items(
+        items: &[RainMetaDocumentV1Item],
+    ) -> Result<Option<Self>, OrderGuiMetaError> {
+        let mut dotrain = None;
+        let mut deployment = None;
+        for item in items {
+            match item.magic {
+                KnownMagic::DotrainV1 => {
+                    dotrain = Some(String::from_utf8(item.payload.to_vec())?);
+                }
+                KnownMagic::DotrainGuiStateV1 => {
+                    deployment = Some(serde_json::from_slice(&item.payload)?);
+                }
+                _ => {}
+            }
+        }
+        Ok(dotrain
+            .zip(deployment)
+            .map(|(dotrain, deployment)| OrderGuiMeta {
+                deployment,
+                dotrain,
+            }))
+    }
+
+    /// Decodes the GUI items from raw order meta bytes
+    pub fn try_decode(meta: &[u8]) -> Result<Option<Self>, OrderGuiMetaError> {
+        if !meta.starts_with(&KnownMagic::RainMetaDocumentV1.to_prefix_bytes()) {
+            return Ok(None);
+        }
+        let items = RainMetaDocumentV1Item::cbor_decode(meta)?;
+        Self::from_meta_items(&items)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rain_orderbook_subgraph_client::types::common::SgBytes;
@@ -104,4 +219,79 @@ io: if(
                 .to_string()
         );
     }
+
+    fn get_order_gui_meta() -> OrderGuiMeta {
+        OrderGuiMeta {
+            deployment: GuiDeploymentMeta {
+                deployment_key: "base".to_string(),
+                deployment_name: "Buy WETH with USDC on Base.".to_string(),
+                registry: Some("https://example.com/registry.txt".to_string()),
+                order_key: Some("fixed-limit".to_string()),
+                field_values: vec![GuiFieldValueMeta {
+                    binding: "fixed-io".to_string(),
+                    name: "USDC per WETH".to_string(),
+                    value: "1800".to_string(),
+                }],
+                state: "H4sIAAAAAAAA".to_string(),
+            },
+            dotrain: "gui:\n  name: Fixed limit\n---\n#calculate-io\n_ _: 0 0;".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_order_gui_meta_round_trip() {
+        let gui_meta = get_order_gui_meta();
+        let mut items = vec![RainMetaDocumentV1Item {
+            payload: ByteBuf::from(RAINLANG_SOURCE.as_bytes()),
+            magic: KnownMagic::RainlangSourceV1,
+            content_type: ContentType::OctetStream,
+            content_encoding: ContentEncoding::None,
+            content_language: ContentLanguage::None,
+        }];
+        items.extend(gui_meta.to_meta_items().unwrap());
+        let meta = RainMetaDocumentV1Item::cbor_encode_seq(&items, KnownMagic::RainMetaDocumentV1)
+            .unwrap();
+
+        let decoded = OrderGuiMeta::try_decode(&meta).unwrap();
+        assert_eq!(decoded, Some(gui_meta));
+
+        // the rainlang source stays the first item
+        let source = alloy::primitives::hex::encode_prefixed(&meta)
+            .try_decode_rainlangsource()
+            .unwrap();
+        assert_eq!(source, RAINLANG_SOURCE);
+    }
+
+    #[test]
+    fn test_order_gui_meta_missing() {
+        let meta = decode(META).unwrap();
+        assert_eq!(OrderGuiMeta::try_decode(&meta).unwrap(), None);
+        assert_eq!(OrderGuiMeta::try_decode(&[]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_order_gui_meta_items() {
+        let items = get_order_gui_meta().to_meta_items().unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(matches!(items[0].magic, KnownMagic::DotrainV1));
+        assert!(matches!(items[0].content_type, ContentType::OctetStream));
+        assert!(matches!(items[1].magic, KnownMagic::DotrainGuiStateV1));
+        assert!(matches!(items[1].content_type, ContentType::Json));
+    }
+
+    #[test]
+    fn test_order_gui_meta_needs_both_items() {
+        let items = get_order_gui_meta().to_meta_items().unwrap();
+        assert_eq!(OrderGuiMeta::from_meta_items(&items[..1]).unwrap(), None);
+        assert_eq!(OrderGuiMeta::from_meta_items(&items[1..]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_order_gui_meta_corrupt() {
+        let err = OrderGuiMeta::try_decode(&decode("0xff0a89c674ee7874").unwrap()).unwrap_err();
+        assert!(matches!(
+            err,
+            OrderGuiMetaError::RainMetadataError(RainMetadataError::CorruptMeta)
+        ));
+    }
 }
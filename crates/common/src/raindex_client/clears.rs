@@ -0,0 +1,602 @@
+use super::*;
+use crate::raindex_client::{
+    orders::RaindexOrder, trades::RaindexTrade, transactions::RaindexTransaction,
+    vaults::RaindexVaultBalanceChange,
+};
+use alloy::primitives::{Address, Bytes};
+use rain_math_float::Float;
+use rain_orderbook_subgraph_client::{
+    types::common::{SgBytes, SgClear, SgClearBounty, SgClearsListFilterArgs},
+    OrderbookSubgraphClient, SgPaginationArgs,
+};
+use std::str::FromStr;
+
+const DEFAULT_PAGE_SIZE: u16 = 100;
+
+/// One side of a clear: what the order received and sent, and the bounty
+/// the clearer took from it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexClearSide {
+    input_amount: Float,
+    formatted_input_amount: String,
+    output_amount: Float,
+    formatted_output_amount: String,
+    bounty_amount: Float,
+    formatted_bounty_amount: String,
+    bounty_vault_balance_change: Option<RaindexVaultBalanceChange>,
+}
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexClearSide {
+    #[wasm_bindgen(getter = inputAmount)]
+    pub fn input_amount(&self) -> Float {
+        self.input_amount
+    }
+    #[wasm_bindgen(getter = formattedInputAmount)]
+    pub fn formatted_input_amount(&self) -> String {
+        self.formatted_input_amount.clone()
+    }
+    #[wasm_bindgen(getter = outputAmount)]
+    pub fn output_amount(&self) -> Float {
+        self.output_amount
+    }
+    #[wasm_bindgen(getter = formattedOutputAmount)]
+    pub fn formatted_output_amount(&self) -> String {
+        self.formatted_output_amount.clone()
+    }
+    #[wasm_bindgen(getter = bountyAmount)]
+    pub fn bounty_amount(&self) -> Float {
+        self.bounty_amount
+    }
+    #[wasm_bindgen(getter = formattedBountyAmount)]
+    pub fn formatted_bounty_amount(&self) -> String {
+        self.formatted_bounty_amount.clone()
+    }
+    #[wasm_bindgen(getter = bountyVaultBalanceChange)]
+    pub fn bounty_vault_balance_change(&self) -> Option<RaindexVaultBalanceChange> {
+        self.bounty_vault_balance_change.clone()
+    }
+}
+#[cfg(not(target_family = "wasm"))]
+impl RaindexClearSide {
+    pub fn input_amount(&self) -> Float {
+        self.input_amount
+    }
+    pub fn formatted_input_amount(&self) -> String {
+        self.formatted_input_amount.clone()
+    }
+    pub fn output_amount(&self) -> Float {
+        self.output_amount
+    }
+    pub fn formatted_output_amount(&self) -> String {
+        self.formatted_output_amount.clone()
+    }
+    pub fn bounty_amount(&self) -> Float {
+        self.bounty_amount
+    }
+    pub fn formatted_bounty_amount(&self) -> String {
+        self.formatted_bounty_amount.clone()
+    }
+    pub fn bounty_vault_balance_change(&self) -> Option<RaindexVaultBalanceChange> {
+        self.bounty_vault_balance_change.clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexClear {
+    id: Bytes,
+    transaction: RaindexTransaction,
+    clearer: Address,
+    orderbook: Address,
+    alice: RaindexClearSide,
+    bob: RaindexClearSide,
+    trades: Vec<RaindexTrade>,
+}
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexClear {
+    #[wasm_bindgen(getter, unchecked_return_type = "Hex")]
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn transaction(&self) -> RaindexTransaction {
+        self.transaction.clone()
+    }
+    #[wasm_bindgen(getter, unchecked_return_type = "Address")]
+    pub fn clearer(&self) -> String {
+        self.clearer.to_string()
+    }
+    #[wasm_bindgen(getter, unchecked_return_type = "Address")]
+    pub fn orderbook(&self) -> String {
+        self.orderbook.to_string()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn alice(&self) -> RaindexClearSide {
+        self.alice.clone()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn bob(&self) -> RaindexClearSide {
+        self.bob.clone()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn trades(&self) -> Vec<RaindexTrade> {
+        self.trades.clone()
+    }
+}
+#[cfg(not(target_family = "wasm"))]
+impl RaindexClear {
+    pub fn id(&self) -> Bytes {
+        self.id.clone()
+    }
+    pub fn transaction(&self) -> RaindexTransaction {
+        self.transaction.clone()
+    }
+    pub fn clearer(&self) -> Address {
+        self.clearer
+    }
+    pub fn orderbook(&self) -> Address {
+        self.orderbook
+    }
+    pub fn alice(&self) -> RaindexClearSide {
+        self.alice.clone()
+    }
+    pub fn bob(&self) -> RaindexClearSide {
+        self.bob.clone()
+    }
+    pub fn trades(&self) -> Vec<RaindexTrade> {
+        self.trades.clone()
+    }
+}
+
+/// A page of clears and the cursor to fetch the next one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexClearsPage {
+    clears: Vec<RaindexClear>,
+    next_cursor: Option<String>,
+}
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexClearsPage {
+    #[wasm_bindgen(getter)]
+    pub fn clears(&self) -> Vec<RaindexClear> {
+        self.clears.clone()
+    }
+    #[wasm_bindgen(getter = nextCursor)]
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RaindexClearsPage {
+    pub fn clears(&self) -> Vec<RaindexClear> {
+        self.clears.clone()
+    }
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct GetClearsFilters {
+    /// Clears that traded the order with this hash
+    #[serde(default)]
+    #[tsify(optional, type = "Hex")]
+    pub order_hash: Option<Bytes>,
+    /// Clears submitted by this address
+    #[serde(default)]
+    #[tsify(optional, type = "Address")]
+    pub clearer: Option<Address>,
+    /// Clears included in this transaction
+    #[serde(default)]
+    #[tsify(optional, type = "Hex")]
+    pub transaction_hash: Option<Bytes>,
+}
+impl_wasm_traits!(GetClearsFilters);
+
+#[wasm_export]
+impl RaindexClient {
+    /// Fetches one page of clears of an orderbook, newest first
+    ///
+    /// Each clear holds the input and output amounts of both cleared orders,
+    /// the bounties taken by the clearer and the trades it produced. Filters
+    /// narrow the clears to an order, a clearer or a transaction.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await client.getClearsPage(
+    ///   1,
+    ///   "0x1234567890abcdef1234567890abcdef12345678",
+    ///   { clearer: "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd" }
+    /// );
+    /// if (result.error) {
+    ///   console.error("Cannot fetch clears:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { clears, nextCursor } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getClearsPage",
+        return_description = "Page of clears and the cursor of the next page",
+        unchecked_return_type = "RaindexClearsPage",
+        preserve_js_class
+    )]
+    pub async fn get_clears_page_wasm_binding(
+        &self,
+        #[wasm_export(
+            js_name = "chainId",
+            param_description = "The blockchain network ID of the orderbook"
+        )]
+        chain_id: u32,
+        #[wasm_export(
+            js_name = "orderbookAddress",
+            param_description = "Orderbook contract address",
+            unchecked_param_type = "Address"
+        )]
+        orderbook_address: String,
+        #[wasm_export(param_description = "Order, clearer and transaction filters (optional)")]
+        filters: Option<GetClearsFilters>,
+        #[wasm_export(param_description = "Cursor returned with the previous page (optional)")]
+        cursor: Option<String>,
+    ) -> Result<RaindexClearsPage, RaindexError> {
+        let orderbook_address = Address::from_str(&orderbook_address)?;
+        self.get_clears_page(
+            chain_id,
+            orderbook_address,
+            filters.unwrap_or_default(),
+            cursor,
+        )
+        .await
+    }
+}
+impl RaindexClient {
+    pub async fn get_clears_page(
+        &self,
+        chain_id: u32,
+        orderbook_address: Address,
+        filters: GetClearsFilters,
+        cursor: Option<String>,
+    ) -> Result<RaindexClearsPage, RaindexError> {
+        let client = self.get_orderbook_client(orderbook_address)?;
+        let order_id = match &filters.order_hash {
+            Some(order_hash) => Some(
+                client
+                    .order_detail_by_hash(SgBytes(order_hash.to_string()))
+                    .await?
+                    .id,
+            ),
+            None => None,
+        };
+        let filter_args = SgClearsListFilterArgs {
+            order_id,
+            clearer: filters.clearer.map(|clearer| SgBytes(clearer.to_string())),
+            transaction_id: filters
+                .transaction_hash
+                .map(|transaction_hash| SgBytes(transaction_hash.to_string())),
+        };
+        fetch_clears_page(chain_id, &client, filter_args, cursor).await
+    }
+
+    /// Fetches every clear matching the filters, newest first
+    pub async fn get_all_clears(
+        &self,
+        chain_id: u32,
+        orderbook_address: Address,
+        filters: GetClearsFilters,
+    ) -> Result<Vec<RaindexClear>, RaindexError> {
+        let mut clears = vec![];
+        let mut cursor = None;
+        loop {
+            let page = self
+                .get_clears_page(chain_id, orderbook_address, filters.clone(), cursor)
+                .await?;
+            clears.extend(page.clears);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(clears)
+    }
+}
+
+#[wasm_export]
+impl RaindexOrder {
+    /// Fetches one page of clears that traded this order, newest first
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await order.getClearsPage();
+    /// if (result.error) {
+    ///   console.error("Cannot fetch clears:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { clears, nextCursor } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getClearsPage",
+        return_description = "Page of clears and the cursor of the next page",
+        unchecked_return_type = "RaindexClearsPage",
+        preserve_js_class
+    )]
+    pub async fn get_clears_page(
+        &self,
+        #[wasm_export(param_description = "Cursor returned with the previous page (optional)")]
+        cursor: Option<String>,
+    ) -> Result<RaindexClearsPage, RaindexError> {
+        let client = self.get_orderbook_client()?;
+        let filter_args = SgClearsListFilterArgs {
+            order_id: Some(SgBytes(self.id().to_string())),
+            ..Default::default()
+        };
+        fetch_clears_page(self.chain_id(), &client, filter_args, cursor).await
+    }
+}
+
+async fn fetch_clears_page(
+    chain_id: u32,
+    client: &OrderbookSubgraphClient,
+    filter_args: SgClearsListFilterArgs,
+    cursor: Option<String>,
+) -> Result<RaindexClearsPage, RaindexError> {
+    let pagination_args = SgPaginationArgs {
+        page: 1,
+        page_size: DEFAULT_PAGE_SIZE,
+        cursor,
+    };
+    let clears = client
+        .clears_list(filter_args, pagination_args.clone())
+        .await?;
+    let next_cursor = pagination_args
+        .next_cursor(&clears)
+        .map_err(OrderbookSubgraphClientError::from)?;
+
+    let clears = clears
+        .into_iter()
+        .map(|clear| RaindexClear::try_from_sg_clear(chain_id, clear))
+        .collect::<Result<Vec<RaindexClear>, RaindexError>>()?;
+    Ok(RaindexClearsPage {
+        clears,
+        next_cursor,
+    })
+}
+
+impl RaindexClearSide {
+    fn try_from_sg_amounts(
+        chain_id: u32,
+        input_amount: &SgBytes,
+        output_amount: &SgBytes,
+        bounty_amount: &SgBytes,
+        bounty_vault_balance_change: Option<SgClearBounty>,
+    ) -> Result<Self, RaindexError> {
+        let input_amount = Float::from_hex(&input_amount.0)?;
+        let output_amount = Float::from_hex(&output_amount.0)?;
+        let bounty_amount = Float::from_hex(&bounty_amount.0)?;
+        Ok(Self {
+            input_amount,
+            formatted_input_amount: input_amount.format18()?,
+            output_amount,
+            formatted_output_amount: output_amount.format18()?,
+            bounty_amount,
+            formatted_bounty_amount: bounty_amount.format18()?,
+            bounty_vault_balance_change: bounty_vault_balance_change
+                .map(|bounty| RaindexVaultBalanceChange::try_from_sg_clear_bounty(chain_id, bounty))
+                .transpose()?,
+        })
+    }
+}
+
+impl RaindexClear {
+    pub fn try_from_sg_clear(chain_id: u32, clear: SgClear) -> Result<Self, RaindexError> {
+        Ok(RaindexClear {
+            id: Bytes::from_str(&clear.id.0)?,
+            transaction: RaindexTransaction::try_from(clear.transaction)?,
+            clearer: Address::from_str(&clear.sender.0)?,
+            orderbook: Address::from_str(&clear.orderbook.id.0)?,
+            alice: RaindexClearSide::try_from_sg_amounts(
+                chain_id,
+                &clear.alice_input_amount,
+                &clear.alice_output_amount,
+                &clear.alice_bounty_amount,
+                clear.alice_bounty_vault_balance_change,
+            )?,
+            bob: RaindexClearSide::try_from_sg_amounts(
+                chain_id,
+                &clear.bob_input_amount,
+                &clear.bob_output_amount,
+                &clear.bob_bounty_amount,
+                clear.bob_bounty_vault_balance_change,
+            )?,
+            trades: clear
+                .trades
+                .into_iter()
+                .map(|trade| RaindexTrade::try_from_sg_trade(chain_id, trade))
+                .collect::<Result<Vec<RaindexTrade>, RaindexError>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    #[cfg(not(target_family = "wasm"))]
+    use super::*;
+
+    #[cfg(not(target_family = "wasm"))]
+    mod non_wasm {
+        use super::*;
+        use crate::raindex_client::tests::{get_test_yaml, CHAIN_ID_1_ORDERBOOK_ADDRESS};
+        use alloy::primitives::U256;
+        use httpmock::MockServer;
+        use rain_orderbook_subgraph_client::utils::float::*;
+        use serde_json::{json, Value};
+
+        const CLEARER: &str = "0x2000000000000000000000000000000000000000";
+
+        fn get_clear_json() -> Value {
+            json!({
+                "id": "0x0123",
+                "aliceInputAmount": F2.as_hex(),
+                "aliceOutputAmount": F1.as_hex(),
+                "bobInputAmount": F1.as_hex(),
+                "bobOutputAmount": F3.as_hex(),
+                "aliceBountyAmount": F0.as_hex(),
+                "bobBountyAmount": F1.as_hex(),
+                "aliceBountyVaultBalanceChange": null,
+                "bobBountyVaultBalanceChange": {
+                    "id": "0x0456",
+                    "__typename": "ClearBounty",
+                    "amount": F1.as_hex(),
+                    "newVaultBalance": F1.as_hex(),
+                    "oldVaultBalance": F0.as_hex(),
+                    "vault": {
+                        "id": "0x0789",
+                        "vaultId": "1",
+                        "token": {
+                            "id": "0x12e605bc104e93b45e1ad99f9e555f659051c2bb",
+                            "address": "0x12e605bc104e93b45e1ad99f9e555f659051c2bb",
+                            "name": "Staked FLR",
+                            "symbol": "sFLR",
+                            "decimals": "18"
+                        }
+                    },
+                    "timestamp": "1734054063",
+                    "transaction": {
+                        "id": "0x0abc",
+                        "from": CLEARER,
+                        "blockNumber": "12345",
+                        "timestamp": "1734054063"
+                    },
+                    "orderbook": {
+                        "id": CHAIN_ID_1_ORDERBOOK_ADDRESS
+                    },
+                    "sender": CLEARER
+                },
+                "orderbook": {
+                    "id": CHAIN_ID_1_ORDERBOOK_ADDRESS
+                },
+                "trades": [],
+                "transaction": {
+                    "id": "0x0abc",
+                    "from": CLEARER,
+                    "blockNumber": "12345",
+                    "timestamp": "1734054063"
+                },
+                "sender": CLEARER
+            })
+        }
+
+        fn get_raindex_client(sg_url: &str) -> RaindexClient {
+            RaindexClient::new(
+                vec![get_test_yaml(
+                    sg_url,
+                    "localhost:3000",
+                    "http://localhost:3000",
+                    "http://localhost:3000",
+                )],
+                None,
+            )
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_get_clears_page_by_clearer() {
+            let sg_server = MockServer::start_async().await;
+            let mock = sg_server.mock(|when, then| {
+                when.path("/sg")
+                    .body_contains(format!("\"sender\":\"{CLEARER}\""));
+                then.status(200).json_body_obj(&json!({
+                    "data": { "clears": [get_clear_json()] }
+                }));
+            });
+
+            let raindex_client = get_raindex_client(&sg_server.url("/sg"));
+            let page = raindex_client
+                .get_clears_page(
+                    1,
+                    Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
+                    GetClearsFilters {
+                        clearer: Some(Address::from_str(CLEARER).unwrap()),
+                        ..Default::default()
+                    },
+                    None,
+                )
+                .await
+                .unwrap();
+            mock.assert();
+            assert!(page.next_cursor().is_none());
+
+            let clears = page.clears();
+            assert_eq!(clears.len(), 1);
+            let clear = &clears[0];
+            assert_eq!(clear.id(), Bytes::from_str("0x0123").unwrap());
+            assert_eq!(clear.clearer(), Address::from_str(CLEARER).unwrap());
+            assert_eq!(
+                clear.orderbook(),
+                Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap()
+            );
+            assert_eq!(
+                clear.transaction().timestamp(),
+                U256::from_str("1734054063").unwrap()
+            );
+
+            let alice = clear.alice();
+            assert!(alice.input_amount().eq(F2).unwrap());
+            assert!(alice.output_amount().eq(F1).unwrap());
+            assert_eq!(alice.formatted_bounty_amount(), "0");
+            assert!(alice.bounty_vault_balance_change().is_none());
+
+            let bob = clear.bob();
+            assert!(bob.input_amount().eq(F1).unwrap());
+            assert!(bob.output_amount().eq(F3).unwrap());
+            assert_eq!(bob.formatted_bounty_amount(), "1");
+            let bounty = bob.bounty_vault_balance_change().unwrap();
+            assert_eq!(
+                bounty.r#type(),
+                crate::raindex_client::vaults::RaindexVaultBalanceChangeType::ClearBounty
+            );
+            assert_eq!(bounty.vault_id(), U256::from(1));
+            assert_eq!(bounty.formatted_new_balance(), "1");
+        }
+
+        #[tokio::test]
+        async fn test_get_clears_page_by_transaction() {
+            let sg_server = MockServer::start_async().await;
+            let mock = sg_server.mock(|when, then| {
+                when.path("/sg").body_contains("\"transaction\":\"0x0abc\"");
+                then.status(200).json_body_obj(&json!({
+                    "data": { "clears": [get_clear_json()] }
+                }));
+            });
+
+            let raindex_client = get_raindex_client(&sg_server.url("/sg"));
+            let clears = raindex_client
+                .get_all_clears(
+                    1,
+                    Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
+                    GetClearsFilters {
+                        transaction_hash: Some(Bytes::from_str("0x0abc").unwrap()),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+            mock.assert();
+            assert_eq!(clears.len(), 1);
+            assert_eq!(
+                clears[0].transaction().id(),
+                Bytes::from_str("0x0abc").unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,669 @@
+use super::*;
+use crate::{
+    add_order::AddOrderArgs, dotrain_order::DotrainOrder, gui::DotrainOrderGui,
+    raindex_client::orders::RaindexOrder,
+};
+use alloy::primitives::{Bytes, B256};
+use alloy::sol_types::SolCall;
+use rain_orderbook_app_settings::{deployment::DeploymentCfg, order::OrderIOCfg};
+use rain_orderbook_bindings::IOrderBookV5::{multicallCall, removeOrder3Call, OrderV4, IOV2};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+pub enum EditOrderChange {
+    Unchanged,
+    Removed,
+    Added,
+}
+impl_wasm_traits!(EditOrderChange);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RainlangDiffLine {
+    pub change: EditOrderChange,
+    pub line: String,
+}
+impl_wasm_traits!(RainlangDiffLine);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct EditOrderIoChange {
+    pub change: EditOrderChange,
+    #[tsify(type = "Address")]
+    pub token: Address,
+    #[tsify(type = "Hex")]
+    pub vault_id: B256,
+}
+impl_wasm_traits!(EditOrderIoChange);
+
+/// What the edit changes, to be shown before the transaction is signed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct EditOrderDiff {
+    /// Rainlang of the current order, if its meta carries it
+    #[tsify(optional)]
+    pub old_rainlang: Option<String>,
+    pub new_rainlang: String,
+    /// Line diff from the old to the new rainlang
+    pub rainlang: Vec<RainlangDiffLine>,
+    pub inputs: Vec<EditOrderIoChange>,
+    pub outputs: Vec<EditOrderIoChange>,
+}
+impl_wasm_traits!(EditOrderDiff);
+
+impl EditOrderDiff {
+    pub fn has_changes(&self) -> bool {
+        self.rainlang
+            .iter()
+            .any(|line| line.change != EditOrderChange::Unchanged)
+            || self
+                .inputs
+                .iter()
+                .chain(self.outputs.iter())
+                .any(|io| io.change != EditOrderChange::Unchanged)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct EditOrderCalldata {
+    #[tsify(type = "Address")]
+    pub orderbook: Address,
+    /// Multicall removing the current order and adding the new one
+    #[tsify(type = "Hex")]
+    pub calldata: Bytes,
+    /// Hash of the order being removed
+    #[tsify(type = "Hex")]
+    pub old_order_hash: Bytes,
+    pub diff: EditOrderDiff,
+}
+impl_wasm_traits!(EditOrderCalldata);
+
+/// Points every IO without an explicit vault id at the vault the current order
+/// uses for its token, on the same side first, so the balances stay with the
+/// order. `explicit_vault_ids` flags the IOs whose vault id was configured.
+fn reuse_vaults(
+    ios: &mut [IOV2],
+    explicit_vault_ids: &[bool],
+    same_side: &[IOV2],
+    other_side: &[IOV2],
+) {
+    for (io, _) in ios
+        .iter_mut()
+        .zip(explicit_vault_ids)
+        .filter(|(_, explicit)| !**explicit)
+    {
+        if let Some(existing) = same_side
+            .iter()
+            .chain(other_side.iter())
+            .find(|existing| existing.token == io.token)
+        {
+            io.vaultId = existing.vaultId;
+        }
+    }
+}
+
+fn diff_ios(old: &[IOV2], new: &[IOV2]) -> Vec<EditOrderIoChange> {
+    let same = |a: &IOV2, b: &IOV2| a.token == b.token && a.vaultId == b.vaultId;
+    let mut changes: Vec<EditOrderIoChange> = new
+        .iter()
+        .map(|io| EditOrderIoChange {
+            change: if old.iter().any(|old_io| same(old_io, io)) {
+                EditOrderChange::Unchanged
+            } else {
+                EditOrderChange::Added
+            },
+            token: io.token,
+            vault_id: io.vaultId,
+        })
+        .collect();
+    changes.extend(
+        old.iter()
+            .filter(|io| !new.iter().any(|new_io| same(new_io, io)))
+            .map(|io| EditOrderIoChange {
+                change: EditOrderChange::Removed,
+                token: io.token,
+                vault_id: io.vaultId,
+            }),
+    );
+    changes
+}
+
+/// Line diff over the longest common subsequence of both texts
+fn diff_lines(old: &str, new: &str) -> Vec<RainlangDiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] is the common subsequence length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |change, line: &str| RainlangDiffLine {
+        change,
+        line: line.to_string(),
+    };
+    let (mut i, mut j) = (0, 0);
+    let mut diff = vec![];
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(line(EditOrderChange::Unchanged, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(line(EditOrderChange::Removed, old[i]));
+            i += 1;
+        } else {
+            diff.push(line(EditOrderChange::Added, new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|l| line(EditOrderChange::Removed, l)));
+    diff.extend(new[j..].iter().map(|l| line(EditOrderChange::Added, l)));
+    diff
+}
+
+#[wasm_export]
+impl RaindexOrder {
+    /// Generates a multicall that replaces this order with one from an updated dotrain
+    ///
+    /// Orders can't be changed once added, so the multicall removes this order and
+    /// adds the new one in a single transaction. Inputs and outputs for tokens this
+    /// order already trades keep its vaults, so their balances stay with the order,
+    /// unless the dotrain sets their vault id. Only active orders can be edited and
+    /// the transaction must be sent by the order owner.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await order.getEditCalldata(updatedDotrain, "base");
+    /// if (result.error) {
+    ///   console.error("Cannot edit order:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { orderbook, calldata, diff } = result.value;
+    /// // Show diff.rainlang, diff.inputs and diff.outputs before sending calldata to orderbook
+    /// ```
+    #[wasm_export(
+        js_name = "getEditCalldata",
+        return_description = "Multicall calldata with the changes it makes",
+        unchecked_return_type = "EditOrderCalldata"
+    )]
+    pub async fn get_edit_calldata(
+        &self,
+        #[wasm_export(param_description = "Dotrain of the updated order")] dotrain: String,
+        #[wasm_export(
+            js_name = "deploymentKey",
+            param_description = "Deployment in the dotrain to add"
+        )]
+        deployment_key: String,
+    ) -> Result<EditOrderCalldata, RaindexError> {
+        let dotrain_order = DotrainOrder::create(dotrain.clone(), None).await?;
+        let deployment = dotrain_order
+            .dotrain_yaml()
+            .get_deployment(&deployment_key)?;
+        self.check_editable(&deployment)?;
+
+        let args = AddOrderArgs::new_from_deployment(dotrain, deployment.clone()).await?;
+        self.get_edit_calldata_for_args(args, &deployment).await
+    }
+
+    /// Generates a multicall that replaces this order with one from a GUI state
+    ///
+    /// Works like `getEditCalldata`, with the new order built by restoring the state
+    /// with `DotrainOrderGui.newFromState`. Pair it with `getGuiMeta` to edit an order
+    /// added from a GUI.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const guiMeta = order.getGuiMeta().value;
+    /// const gui = (await DotrainOrderGui.newFromState(guiMeta.dotrain, guiMeta.deployment.state)).value;
+    /// gui.setFieldValue("fixed-io", "1900");
+    /// const result = await order.getEditCalldataFromState(guiMeta.dotrain, gui.serializeState().value);
+    /// if (result.error) {
+    ///   console.error("Cannot edit order:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { orderbook, calldata, diff } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getEditCalldataFromState",
+        return_description = "Multicall calldata with the changes it makes",
+        unchecked_return_type = "EditOrderCalldata"
+    )]
+    pub async fn get_edit_calldata_from_state(
+        &self,
+        #[wasm_export(param_description = "Dotrain the state was created for")] dotrain: String,
+        #[wasm_export(param_description = "Serialized GUI state of the updated order")]
+        state: String,
+    ) -> Result<EditOrderCalldata, RaindexError> {
+        let mut gui = DotrainOrderGui::new_from_state(dotrain, state, None, None).await?;
+        // Read before the missing vault ids are filled in, so only the ones set
+        // in the state count as explicit
+        let deployment = gui.get_current_deployment()?.deployment;
+        self.check_editable(&deployment)?;
+        let args = gui.prepare_add_order_args().await?;
+
+        self.get_edit_calldata_for_args(args, &deployment).await
+    }
+}
+
+impl RaindexOrder {
+    fn check_editable(&self, deployment: &DeploymentCfg) -> Result<(), RaindexError> {
+        if !self.active() {
+            return Err(RaindexError::EditInactiveOrder);
+        }
+        let order_orderbook = Address::from_str(&self.orderbook().to_string())?;
+        match &deployment.order.orderbook {
+            Some(orderbook) if orderbook.address != order_orderbook => Err(
+                RaindexError::EditOrderbookMismatch(order_orderbook, orderbook.address),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    async fn get_edit_calldata_for_args(
+        &self,
+        mut args: AddOrderArgs,
+        deployment: &DeploymentCfg,
+    ) -> Result<EditOrderCalldata, RaindexError> {
+        let order: OrderV4 = self.try_into()?;
+        let explicit_vault_ids = |ios: &[OrderIOCfg]| -> Vec<bool> {
+            ios.iter().map(|io| io.vault_id.is_some()).collect()
+        };
+        reuse_vaults(
+            &mut args.inputs,
+            &explicit_vault_ids(&deployment.order.inputs),
+            &order.validInputs,
+            &order.validOutputs,
+        );
+        reuse_vaults(
+            &mut args.outputs,
+            &explicit_vault_ids(&deployment.order.outputs),
+            &order.validOutputs,
+            &order.validInputs,
+        );
+
+        let rpcs = self
+            .get_rpc_urls()?
+            .iter()
+            .map(|url| url.to_string())
+            .collect();
+        let new_rainlang = args.compose_to_rainlang()?;
+        let add_order_call = args.try_into_call(rpcs).await?;
+
+        let old_rainlang = self.rainlang();
+        let diff = EditOrderDiff {
+            rainlang: diff_lines(old_rainlang.as_deref().unwrap_or_default(), &new_rainlang),
+            old_rainlang,
+            new_rainlang,
+            inputs: diff_ios(&order.validInputs, &args.inputs),
+            outputs: diff_ios(&order.validOutputs, &args.outputs),
+        };
+
+        let remove_order_call = removeOrder3Call {
+            order,
+            tasks: vec![],
+        };
+        let calldata = multicallCall {
+            data: vec![
+                Bytes::from(remove_order_call.abi_encode()),
+                Bytes::from(add_order_call.abi_encode()),
+            ],
+        }
+        .abi_encode();
+
+        Ok(EditOrderCalldata {
+            orderbook: Address::from_str(&self.orderbook().to_string())?,
+            calldata: Bytes::from(calldata),
+            old_order_hash: Bytes::from_str(&self.order_hash().to_string())?,
+            diff,
+        })
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::raindex_client::tests::get_test_yaml;
+    use alloy::{
+        network::TransactionBuilder, primitives::U256, rpc::types::TransactionRequest,
+        serde::WithOtherFields, sol_types::SolValue,
+    };
+    use rain_orderbook_app_settings::spec_version::SpecVersion;
+    use rain_orderbook_subgraph_client::types::common::SgOrder;
+    use rain_orderbook_test_fixtures::{LocalEvm, Orderbook};
+    use serde_json::json;
+    use std::sync::{Arc, RwLock};
+
+    fn io(token: u8, vault_id: u8) -> IOV2 {
+        IOV2 {
+            token: Address::repeat_byte(token),
+            vaultId: B256::from(U256::from(vault_id)),
+        }
+    }
+
+    #[test]
+    fn test_reuse_vaults() {
+        let old_inputs = vec![io(1, 1)];
+        let old_outputs = vec![io(2, 2)];
+        let mut inputs = vec![io(1, 9), io(3, 9)];
+        let mut outputs = vec![io(1, 8), io(2, 8)];
+
+        reuse_vaults(&mut inputs, &[false, false], &old_inputs, &old_outputs);
+        reuse_vaults(&mut outputs, &[false, true], &old_outputs, &old_inputs);
+        assert_eq!(inputs, vec![io(1, 1), io(3, 9)]);
+        // The explicit vault id of the second output is kept
+        assert_eq!(outputs, vec![io(1, 1), io(2, 8)]);
+    }
+
+    #[test]
+    fn test_diff_ios() {
+        let changes = diff_ios(&[io(1, 1), io(2, 2)], &[io(1, 1), io(3, 3)]);
+        let changes: Vec<_> = changes
+            .into_iter()
+            .map(|io| (io.change, io.token))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (EditOrderChange::Unchanged, Address::repeat_byte(1)),
+                (EditOrderChange::Added, Address::repeat_byte(3)),
+                (EditOrderChange::Removed, Address::repeat_byte(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines("a\nb\nc", "a\nx\nc\nd");
+        let diff: Vec<_> = diff
+            .into_iter()
+            .map(|line| (line.change, line.line))
+            .collect();
+        assert_eq!(
+            diff,
+            vec![
+                (EditOrderChange::Unchanged, "a".to_string()),
+                (EditOrderChange::Removed, "b".to_string()),
+                (EditOrderChange::Added, "x".to_string()),
+                (EditOrderChange::Unchanged, "c".to_string()),
+                (EditOrderChange::Added, "d".to_string()),
+            ]
+        );
+        assert!(diff_lines("", "")
+            .iter()
+            .all(|line| line.change == EditOrderChange::Unchanged));
+    }
+
+    fn get_dotrain(local_evm: &LocalEvm, price: u32) -> String {
+        format!(
+            r#"
+version: {spec_version}
+networks:
+    some-network:
+        rpcs:
+            - {rpc_url}
+        chain-id: 123
+tokens:
+    t1:
+        network: some-network
+        address: {token1}
+        decimals: 18
+    t2:
+        network: some-network
+        address: {token2}
+        decimals: 18
+deployers:
+    some-deployer:
+        network: some-network
+        address: {deployer}
+orderbooks:
+    some-orderbook:
+        address: {orderbook}
+        network: some-network
+        subgraph: some-sg
+        deployment-block: 0
+subgraphs:
+    some-sg: https://www.some-sg.com
+scenarios:
+    some-scenario:
+        deployer: some-deployer
+orders:
+    some-order:
+        deployer: some-deployer
+        orderbook: some-orderbook
+        inputs:
+            - token: t1
+        outputs:
+            - token: t2
+deployments:
+    some-deployment:
+        scenario: some-scenario
+        order: some-order
+---
+#calculate-io
+amount price: 10 {price};
+#handle-io
+:;
+#handle-add-order
+:;
+"#,
+            spec_version = SpecVersion::current(),
+            rpc_url = local_evm.url(),
+            token1 = local_evm.tokens[0].address(),
+            token2 = local_evm.tokens[1].address(),
+            deployer = local_evm.deployer.address(),
+            orderbook = local_evm.orderbook.address(),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_get_edit_calldata() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let owner = local_evm.anvil.addresses()[0];
+
+        let dotrain = get_dotrain(&local_evm, 2);
+        let deployment = DotrainOrder::create(dotrain.clone(), None)
+            .await
+            .unwrap()
+            .dotrain_yaml()
+            .get_deployment("some-deployment")
+            .unwrap();
+        let add_order_call = AddOrderArgs::new_from_deployment(dotrain, deployment)
+            .await
+            .unwrap()
+            .try_into_call(vec![local_evm.url()])
+            .await
+            .unwrap();
+        let (added, _) = local_evm
+            .add_order(&add_order_call.abi_encode(), owner)
+            .await;
+
+        let sg_order: SgOrder = serde_json::from_value(json!({
+            "id": added.orderHash.to_string(),
+            "orderBytes": Bytes::from(added.order.abi_encode()).to_string(),
+            "orderHash": added.orderHash.to_string(),
+            "owner": owner.to_string(),
+            "outputs": [],
+            "inputs": [],
+            "orderbook": { "id": local_evm.orderbook.address().to_string() },
+            "active": true,
+            "timestampAdded": "0",
+            "meta": add_order_call.config.meta.to_string(),
+            "addEvents": [],
+            "trades": [],
+            "removeEvents": []
+        }))
+        .unwrap();
+        let raindex_client = RaindexClient::new(
+            vec![get_test_yaml(
+                "https://sg1.com",
+                "https://sg2.com",
+                &local_evm.url(),
+                "https://rpc2.com",
+            )],
+            None,
+        )
+        .unwrap();
+        let order = RaindexOrder::try_from_sg_order(
+            Arc::new(RwLock::new(raindex_client)),
+            1,
+            sg_order,
+            None,
+        )
+        .unwrap();
+
+        let edit = order
+            .get_edit_calldata(get_dotrain(&local_evm, 3), "some-deployment".to_string())
+            .await
+            .unwrap();
+        assert_eq!(edit.orderbook, *local_evm.orderbook.address());
+        assert!(edit.diff.has_changes());
+        assert!(edit.diff.rainlang.contains(&RainlangDiffLine {
+            change: EditOrderChange::Removed,
+            line: "amount price: 10 2;".to_string(),
+        }));
+        assert!(edit.diff.rainlang.contains(&RainlangDiffLine {
+            change: EditOrderChange::Added,
+            line: "amount price: 10 3;".to_string(),
+        }));
+        assert!(edit
+            .diff
+            .inputs
+            .iter()
+            .chain(edit.diff.outputs.iter())
+            .all(|io| io.change == EditOrderChange::Unchanged));
+
+        let receipt = local_evm
+            .send_transaction(WithOtherFields::new(
+                TransactionRequest::default()
+                    .with_from(owner)
+                    .with_to(edit.orderbook)
+                    .with_input(edit.calldata),
+            ))
+            .await
+            .unwrap();
+        let logs = receipt.inner.inner.logs();
+        let removed = logs
+            .iter()
+            .find_map(|log| log.log_decode::<Orderbook::RemoveOrderV3>().ok())
+            .unwrap()
+            .inner
+            .data;
+        assert_eq!(removed.orderHash, added.orderHash);
+        let readded = logs
+            .iter()
+            .find_map(|log| log.log_decode::<Orderbook::AddOrderV3>().ok())
+            .unwrap()
+            .inner
+            .data;
+        assert_ne!(readded.orderHash, added.orderHash);
+        assert_eq!(
+            readded.order.validInputs[0].vaultId,
+            added.order.validInputs[0].vaultId
+        );
+        assert_eq!(
+            readded.order.validOutputs[0].vaultId,
+            added.order.validOutputs[0].vaultId
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_get_edit_calldata_inactive_order() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let order_json = json!({
+            "id": "0x01",
+            "orderBytes": "0x",
+            "orderHash": "0x01",
+            "owner": Address::ZERO.to_string(),
+            "outputs": [],
+            "inputs": [],
+            "orderbook": { "id": local_evm.orderbook.address().to_string() },
+            "active": false,
+            "timestampAdded": "0",
+            "meta": null,
+            "addEvents": [],
+            "trades": [],
+            "removeEvents": []
+        });
+        let raindex_client = RaindexClient::new(
+            vec![get_test_yaml(
+                "https://sg1.com",
+                "https://sg2.com",
+                &local_evm.url(),
+                "https://rpc2.com",
+            )],
+            None,
+        )
+        .unwrap();
+        let order = RaindexOrder::try_from_sg_order(
+            Arc::new(RwLock::new(raindex_client)),
+            1,
+            serde_json::from_value(order_json).unwrap(),
+            None,
+        )
+        .unwrap();
+
+        let err = order
+            .get_edit_calldata(get_dotrain(&local_evm, 3), "some-deployment".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RaindexError::EditInactiveOrder));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 10)]
+    async fn test_get_edit_calldata_orderbook_mismatch() {
+        let local_evm = LocalEvm::new_with_tokens(2).await;
+        let order_json = json!({
+            "id": "0x01",
+            "orderBytes": "0x",
+            "orderHash": "0x01",
+            "owner": Address::ZERO.to_string(),
+            "outputs": [],
+            "inputs": [],
+            "orderbook": { "id": Address::repeat_byte(0x11).to_string() },
+            "active": true,
+            "timestampAdded": "0",
+            "meta": null,
+            "addEvents": [],
+            "trades": [],
+            "removeEvents": []
+        });
+        let raindex_client = RaindexClient::new(
+            vec![get_test_yaml(
+                "https://sg1.com",
+                "https://sg2.com",
+                &local_evm.url(),
+                "https://rpc2.com",
+            )],
+            None,
+        )
+        .unwrap();
+        let order = RaindexOrder::try_from_sg_order(
+            Arc::new(RwLock::new(raindex_client)),
+            1,
+            serde_json::from_value(order_json).unwrap(),
+            None,
+        )
+        .unwrap();
+
+        let err = order
+            .get_edit_calldata(get_dotrain(&local_evm, 3), "some-deployment".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RaindexError::EditOrderbookMismatch(order_orderbook, orderbook)
+                if order_orderbook == Address::repeat_byte(0x11)
+                    && orderbook == *local_evm.orderbook.address()
+        ));
+    }
+}
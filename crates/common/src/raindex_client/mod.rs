@@ -1,6 +1,10 @@
 use crate::{
-    add_order::AddOrderArgsError, deposit::DepositError, dotrain_order::DotrainOrderError,
-    meta::TryDecodeRainlangSourceError, transaction::WritableTransactionExecuteError,
+    add_order::AddOrderArgsError,
+    deposit::DepositError,
+    dotrain_order::DotrainOrderError,
+    meta::{OrderGuiMetaError, TryDecodeRainlangSourceError},
+    subgraph::subgraph_transport_config,
+    transaction::WritableTransactionExecuteError,
     utils::amount_formatter::AmountFormatterError,
 };
 use alloy::{
@@ -11,31 +15,48 @@ use alloy::{
     },
 };
 use rain_math_float::FloatError;
-use rain_orderbook_app_settings::yaml::{
-    orderbook::{OrderbookYaml, OrderbookYamlValidation},
-    YamlError, YamlParsable,
+use rain_orderbook_app_settings::{
+    remote::source::RemoteFetchOptions,
+    remote_tokens::{ParseRemoteTokensError, RemoteTokensCfg},
+    token::TokenCfg,
+    yaml::{
+        orderbook::{OrderbookYaml, OrderbookYamlValidation},
+        YamlError, YamlParsable,
+    },
 };
 use rain_orderbook_subgraph_client::{
-    types::order_detail_traits::OrderDetailError, MultiSubgraphArgs, OrderbookSubgraphClient,
-    OrderbookSubgraphClientError,
+    types::order_detail_traits::OrderDetailError, MultiOrderbookSubgraphClient, MultiSubgraphArgs,
+    OrderbookSubgraphClient, OrderbookSubgraphClientError,
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, num::ParseIntError, str::FromStr};
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap},
+    num::ParseIntError,
+    str::FromStr,
+};
 use thiserror::Error;
 use tsify::Tsify;
 use url::Url;
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*, wasm_export};
 
 pub mod add_orders;
+pub mod clears;
+pub mod edit_orders;
 pub mod order_quotes;
 pub mod orderbook_yaml;
 pub mod orders;
+pub mod pair_depth;
+pub mod portfolio;
 pub mod remove_orders;
+pub mod subgraph_health;
 pub mod trades;
 pub mod transactions;
 pub mod vaults;
 pub mod vaults_list;
 
+/// Configured tokens keyed by chain id and address
+pub(crate) type TokenCfgs = HashMap<(u32, Address), TokenCfg>;
+
 #[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
 pub struct ChainIds(#[tsify(type = "number[]")] pub Vec<u32>);
 impl_wasm_traits!(ChainIds);
@@ -121,6 +142,45 @@ impl RaindexClient {
         Ok(RaindexClient { orderbook_yaml })
     }
 
+    /// Fetches the token lists configured with `using-tokens-from`
+    ///
+    /// Returns a client that also knows the tokens of the lists, so vault
+    /// tokens get their logos, tags and bridged addresses from them. The
+    /// client returned by `new` only knows the tokens of the yaml.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await client.withRemoteTokens();
+    /// if (result.error) {
+    ///   console.error("Cannot load token lists:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const clientWithTokens = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "withRemoteTokens",
+        return_description = "Client that also knows the tokens of the configured token lists",
+        preserve_js_class
+    )]
+    pub async fn with_remote_tokens(&self) -> Result<RaindexClient, RaindexError> {
+        let mut client = self.clone();
+        if let Some(remote_tokens_cfg) = self.orderbook_yaml.get_remote_tokens()? {
+            let networks = self.orderbook_yaml.get_networks()?;
+            let remote_tokens = RemoteTokensCfg::fetch_tokens_with_options(
+                &networks,
+                remote_tokens_cfg,
+                &RemoteFetchOptions::from_env(),
+            )
+            .await?;
+            client
+                .orderbook_yaml
+                .cache
+                .update_remote_tokens(remote_tokens);
+        }
+        Ok(client)
+    }
+
     fn get_multi_subgraph_args(
         &self,
         chain_ids: Option<Vec<u32>>,
@@ -138,6 +198,7 @@ impl RaindexClient {
                             MultiSubgraphArgs {
                                 url: orderbook.subgraph.url.clone(),
                                 name: network.label.clone().unwrap_or(network.key.clone()),
+                                transport: subgraph_transport_config(&orderbook.subgraph),
                             },
                         );
                     }
@@ -159,6 +220,7 @@ impl RaindexClient {
                             .push(MultiSubgraphArgs {
                                 url: orderbook.subgraph.url.clone(),
                                 name: network.label.clone().unwrap_or(network.key.clone()),
+                                transport: subgraph_transport_config(&orderbook.subgraph),
                             });
                     }
                 }
@@ -172,6 +234,23 @@ impl RaindexClient {
         Ok(result)
     }
 
+    /// Builds a client over the given subgraphs, pinned to the block number if
+    /// one is given. Block numbers are chain specific, so pinning is only
+    /// allowed when the subgraphs are all on the same chain.
+    fn get_multi_orderbook_client(
+        &self,
+        multi_subgraph_args: &BTreeMap<u32, Vec<MultiSubgraphArgs>>,
+        block_number: Option<u64>,
+    ) -> Result<MultiOrderbookSubgraphClient, RaindexError> {
+        if block_number.is_some() && multi_subgraph_args.len() > 1 {
+            return Err(RaindexError::BlockNumberRequiresSingleChain);
+        }
+        Ok(MultiOrderbookSubgraphClient::new(
+            multi_subgraph_args.values().flatten().cloned().collect(),
+        )
+        .at_block(block_number))
+    }
+
     #[wasm_export(skip)]
     pub fn get_orderbook_client(
         &self,
@@ -180,13 +259,40 @@ impl RaindexClient {
         let orderbook = self
             .orderbook_yaml
             .get_orderbook_by_address(orderbook_address)?;
-        Ok(OrderbookSubgraphClient::new(orderbook.subgraph.url.clone()))
+        Ok(OrderbookSubgraphClient::with_transport(
+            orderbook.subgraph.url.clone(),
+            subgraph_transport_config(&orderbook.subgraph),
+        ))
     }
 
     fn get_rpc_urls_for_chain(&self, chain_id: u32) -> Result<Vec<Url>, RaindexError> {
         let network = self.orderbook_yaml.get_network_by_chain_id(chain_id)?;
         Ok(network.rpcs.clone())
     }
+
+    /// Configured tokens keyed by chain id and address, used to attach token
+    /// list metadata such as logos and tags to subgraph tokens. Reads build
+    /// it once and look up every token they return in it. When several keys
+    /// configure the same token, the smallest key wins.
+    pub(crate) fn get_token_cfgs(&self) -> TokenCfgs {
+        let mut token_cfgs = TokenCfgs::new();
+        let Ok(tokens) = self.orderbook_yaml.get_tokens() else {
+            return token_cfgs;
+        };
+        for token in tokens.into_values() {
+            match token_cfgs.entry((token.network.chain_id, token.address)) {
+                Entry::Occupied(mut entry) => {
+                    if token.key < entry.get().key {
+                        entry.insert(token);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(token);
+                }
+            }
+        }
+        token_cfgs
+    }
 }
 
 #[derive(Error, Debug)]
@@ -212,6 +318,8 @@ pub enum RaindexError {
     #[error(transparent)]
     TryDecodeRainlangSourceError(#[from] TryDecodeRainlangSourceError),
     #[error(transparent)]
+    OrderGuiMetaError(#[from] OrderGuiMetaError),
+    #[error(transparent)]
     U256ParseError(#[from] ParseError),
     #[error(transparent)]
     I256ParseError(#[from] ParseSignedError),
@@ -231,6 +339,8 @@ pub enum RaindexError {
     WritableTransactionExecuteError(#[from] WritableTransactionExecuteError),
     #[error(transparent)]
     DepositArgsError(#[from] DepositError),
+    #[error(transparent)]
+    ParseRemoteTokensError(#[from] ParseRemoteTokensError),
     #[error("Orderbook not found for address: {0} on chain ID: {1}")]
     OrderbookNotFound(String, u32),
     #[error(transparent)]
@@ -255,6 +365,16 @@ pub enum RaindexError {
     MissingErc20Decimals(String),
     #[error(transparent)]
     AmountFormatterError(#[from] AmountFormatterError),
+    #[error(transparent)]
+    GuiError(Box<GuiError>),
+    #[error("Deployment orderbook {1} does not match the order's orderbook {0}")]
+    EditOrderbookMismatch(Address, Address),
+    #[error("Only active orders can be edited")]
+    EditInactiveOrder,
+    #[error("A block number can only be used when querying a single chain")]
+    BlockNumberRequiresSingleChain,
+    #[error("The input and output tokens of a pair must be different")]
+    SamePairTokens,
 }
 
 impl From<DotrainOrderError> for RaindexError {
@@ -263,6 +383,12 @@ impl From<DotrainOrderError> for RaindexError {
     }
 }
 
+impl From<GuiError> for RaindexError {
+    fn from(err: GuiError) -> Self {
+        Self::GuiError(Box::new(err))
+    }
+}
+
 impl RaindexError {
     pub fn to_readable_msg(&self) -> String {
         match self {
@@ -303,6 +429,9 @@ impl RaindexError {
             RaindexError::TryDecodeRainlangSourceError(err) => {
                 format!("Failed to decode Rainlang source: {}. The source code may be corrupted or incompatible.", err)
             }
+            RaindexError::OrderGuiMetaError(err) => {
+                format!("Failed to decode the order's GUI meta: {}", err)
+            }
             RaindexError::U256ParseError(err) => {
                 format!(
                     "Invalid number format: {}. Please provide a valid numeric value.",
@@ -333,6 +462,9 @@ impl RaindexError {
             RaindexError::DepositArgsError(err) => {
                 format!("Failed to create deposit arguments: {}", err)
             }
+            RaindexError::ParseRemoteTokensError(err) => {
+                format!("Failed to load the tokens of the token lists: {}", err)
+            }
             RaindexError::OrderbookNotFound(address, chain_id) => {
                 format!(
                     "Orderbook not found for address: {} on chain ID: {}",
@@ -365,6 +497,19 @@ impl RaindexError {
                 format!("Missing decimal information for the token address: {token}")
             }
             RaindexError::AmountFormatterError(err) => format!("Amount formatter error: {err}"),
+            RaindexError::GuiError(err) => err.to_readable_msg(),
+            RaindexError::EditOrderbookMismatch(order_orderbook, orderbook) => format!(
+                "The deployment adds the order to orderbook {orderbook}, but the order being edited is on orderbook {order_orderbook}."
+            ),
+            RaindexError::EditInactiveOrder => {
+                "This order has been removed and can no longer be edited.".to_string()
+            }
+            RaindexError::BlockNumberRequiresSingleChain => {
+                "Block numbers differ between chains, so a block number can only be used when querying a single chain.".to_string()
+            }
+            RaindexError::SamePairTokens => {
+                "The input and output tokens of a pair must be different. Please choose two different tokens.".to_string()
+            }
         }
     }
 }
@@ -1,8 +1,9 @@
 use super::*;
 use crate::raindex_client::vaults_list::RaindexVaultsList;
 use crate::{
-    meta::TryDecodeRainlangSource,
+    meta::{OrderGuiMeta, TryDecodeRainlangSource},
     raindex_client::{
+        subgraph_health::{RaindexSubgraphMeta, SubgraphMetaRead},
         transactions::RaindexTransaction,
         vaults::{RaindexVault, RaindexVaultType},
     },
@@ -12,11 +13,13 @@ use rain_orderbook_subgraph_client::{
     // performance::{vol::VaultVolume, OrderPerformance},
     types::{
         common::{
-            SgBigInt, SgBytes, SgOrder, SgOrderAsIO, SgOrderbook, SgOrdersListFilterArgs, SgVault,
+            SgBigInt, SgBytes, SgOrder, SgOrderAsIO, SgOrderWithSubgraphName, SgOrderbook,
+            SgOrdersListFilterArgs, SgOrdersSortBy, SgSortDirection, SgVault,
         },
         // Id,
     },
     MultiOrderbookSubgraphClient,
+    MultiSubgraphStatus,
     OrderbookSubgraphClient,
     SgPaginationArgs,
 };
@@ -58,6 +61,7 @@ pub struct RaindexOrder {
     rainlang: Option<String>,
     transaction: Option<RaindexTransaction>,
     trades_count: u16,
+    block_number: Option<u64>,
 }
 
 fn get_io_by_type(order: &RaindexOrder, vault_type: RaindexVaultType) -> Vec<RaindexVault> {
@@ -120,6 +124,11 @@ impl RaindexOrder {
     pub fn trades_count(&self) -> u16 {
         self.trades_count
     }
+    /// Block the order was fetched at, `undefined` for the latest indexed state
+    #[wasm_bindgen(getter = blockNumber)]
+    pub fn block_number(&self) -> Option<u64> {
+        self.block_number
+    }
 
     #[wasm_bindgen(getter = vaultsList)]
     pub fn vaults_list(&self) -> RaindexVaultsList {
@@ -179,6 +188,9 @@ impl RaindexOrder {
     pub fn trades_count(&self) -> u16 {
         self.trades_count
     }
+    pub fn block_number(&self) -> Option<u64> {
+        self.block_number
+    }
     pub fn vaults_list(&self) -> RaindexVaultsList {
         RaindexVaultsList::new(get_vaults_with_type(
             self.inputs.clone(),
@@ -242,7 +254,9 @@ impl RaindexOrder {
     #[wasm_export(skip)]
     pub fn get_orderbook_client(&self) -> Result<OrderbookSubgraphClient, RaindexError> {
         let raindex_client = self.read_raindex_client()?;
-        raindex_client.get_orderbook_client(self.orderbook)
+        Ok(raindex_client
+            .get_orderbook_client(self.orderbook)?
+            .at_block(self.block_number))
     }
 
     #[wasm_export(skip)]
@@ -363,6 +377,39 @@ impl RaindexOrder {
         let sg_order = self.clone().into_sg_order()?;
         Ok(sg_order)
     }
+
+    /// Decodes the GUI deployment the order was created from out of its meta
+    ///
+    /// Orders added from a GUI with `setEmbedGuiMeta` enabled carry the deployment
+    /// name, the selected field values, the GUI state and the order's dotrain next
+    /// to the Rainlang source. Returns undefined for orders added any other way.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = order.getGuiMeta();
+    /// if (result.error) {
+    ///   console.error("Invalid meta:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const guiMeta = result.value;
+    /// if (guiMeta) {
+    ///   const { deploymentName, fieldValues, state } = guiMeta.deployment;
+    ///   // Clone the order
+    ///   const gui = await DotrainOrderGui.newFromState(guiMeta.dotrain, state);
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "getGuiMeta",
+        return_description = "GUI deployment and dotrain the order was created from",
+        unchecked_return_type = "OrderGuiMeta | undefined"
+    )]
+    pub fn get_gui_meta(&self) -> Result<Option<OrderGuiMeta>, RaindexError> {
+        match &self.meta {
+            Some(meta) => Ok(OrderGuiMeta::try_decode(meta)?),
+            None => Ok(None),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
@@ -402,7 +449,9 @@ impl RaindexClient {
     ///
     /// Retrieves a list of orders from the specified network or all configured networks,
     /// with support for filtering by owner, status, and order hash. Results are paginated
-    /// for efficient data retrieval.
+    /// for efficient data retrieval. When a block number is given, the orders of a single
+    /// network are returned as they were at that block. With `includeMeta` the block
+    /// indexed by each queried subgraph is returned alongside the orders.
     ///
     /// ## Examples
     ///
@@ -419,13 +468,16 @@ impl RaindexClient {
     ///   console.error("Error fetching orders:", result.error.readableMsg);
     ///   return;
     /// }
-    /// const orders = result.value;
-    /// // Do something with orders
+    /// const { items, subgraphStatuses } = result.value;
+    /// for (const status of subgraphStatuses) {
+    ///   if (status.error) console.warn(`${status.subgraphName} unavailable:`, status.error);
+    /// }
+    /// // Do something with items
     /// ```
     #[wasm_export(
         js_name = "getOrders",
-        return_description = "Array of raindex order instances",
-        unchecked_return_type = "RaindexOrder[]",
+        return_description = "Raindex orders merged from all networks and the status of each subgraph",
+        unchecked_return_type = "RaindexOrdersList",
         preserve_js_class
     )]
     pub async fn get_orders(
@@ -441,53 +493,127 @@ impl RaindexClient {
         filters: Option<GetOrdersFilters>,
         #[wasm_export(param_description = "Page number for pagination (optional, defaults to 1)")]
         page: Option<u16>,
-    ) -> Result<Vec<RaindexOrder>, RaindexError> {
-        let raindex_client = Arc::new(RwLock::new(self.clone()));
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Return the orders as they were at this block (optional, requires a single chain, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
+        #[wasm_export(
+            js_name = "includeMeta",
+            param_description = "Also return the block indexed by the queried subgraphs (optional)"
+        )]
+        include_meta: Option<bool>,
+    ) -> Result<RaindexOrdersList, RaindexError> {
         let multi_subgraph_args =
             self.get_multi_subgraph_args(chain_ids.map(|ids| ids.0.to_vec()))?;
+        // The data of each subgraph is read at the block reported for it
+        let subgraph_meta = if include_meta.unwrap_or(false) {
+            self.get_subgraph_meta(&multi_subgraph_args).await
+        } else {
+            SubgraphMetaRead::default()
+        };
 
-        let client = MultiOrderbookSubgraphClient::new(
-            multi_subgraph_args.values().flatten().cloned().collect(),
-        );
+        let client = self
+            .get_multi_orderbook_client(&multi_subgraph_args, block_number)?
+            .at_subgraph_blocks(subgraph_meta.subgraph_blocks());
 
-        let orders = client
+        let result = client
             .orders_list(
-                filters
-                    .unwrap_or(GetOrdersFilters {
-                        owners: vec![],
-                        active: None,
-                        order_hash: None,
-                        tokens: None,
-                    })
-                    .try_into()?,
+                filters.unwrap_or_default().try_into()?,
                 SgPaginationArgs {
                     page: page.unwrap_or(1),
                     page_size: DEFAULT_PAGE_SIZE,
+                    cursor: None,
                 },
             )
             .await;
 
-        let orders = orders
-            .iter()
-            .map(|order| {
-                let chain_id = multi_subgraph_args
-                    .iter()
-                    .find(|(_, args)| args.iter().any(|arg| arg.name == order.subgraph_name))
-                    .map(|(chain_id, _)| *chain_id)
-                    .ok_or(RaindexError::SubgraphNotFound(
-                        order.subgraph_name.clone(),
-                        order.order.order_hash.0.clone(),
-                    ))?;
-                let order = RaindexOrder::try_from_sg_order(
-                    raindex_client.clone(),
-                    chain_id,
-                    order.order.clone(),
-                    None,
-                )?;
-                Ok(order)
-            })
-            .collect::<Result<Vec<RaindexOrder>, RaindexError>>()?;
-        Ok(orders)
+        Ok(RaindexOrdersList {
+            items: self.orders_from_sg_orders(&multi_subgraph_args, result.data, block_number)?,
+            subgraph_statuses: subgraph_meta.merge_statuses(result.statuses),
+            subgraph_meta: subgraph_meta.meta,
+        })
+    }
+
+    /// Queries one page of orders for infinite scrolling
+    ///
+    /// Works like `getOrders`, but continues from the cursor returned with the
+    /// previous page instead of a page number. Orders from all networks are
+    /// merged by the time they were added, and no order is skipped or repeated
+    /// when new orders are added between requests.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// let cursor = undefined;
+    /// do {
+    ///   const result = await client.getOrdersPage(undefined, { owners: [] }, cursor);
+    ///   if (result.error) {
+    ///     console.error("Error fetching orders:", result.error.readableMsg);
+    ///     return;
+    ///   }
+    ///   // Do something with result.value.orders
+    ///   cursor = result.value.nextCursor;
+    /// } while (cursor);
+    /// ```
+    #[wasm_export(
+        js_name = "getOrdersPage",
+        return_description = "Page of raindex orders and the cursor of the next page",
+        unchecked_return_type = "RaindexOrdersPage",
+        preserve_js_class
+    )]
+    pub async fn get_orders_page(
+        &self,
+        #[wasm_export(
+            js_name = "chainIds",
+            param_description = "Specific blockchain network to query (optional, queries all networks if not specified)"
+        )]
+        chain_ids: Option<ChainIds>,
+        #[wasm_export(
+            param_description = "Filtering criteria including owners, active status, and order hash (optional)"
+        )]
+        filters: Option<GetOrdersFilters>,
+        #[wasm_export(
+            param_description = "Cursor returned with the previous page (optional, starts from the newest order)"
+        )]
+        cursor: Option<String>,
+        #[wasm_export(
+            js_name = "includeMeta",
+            param_description = "Also return the block indexed by the queried subgraphs (optional)"
+        )]
+        include_meta: Option<bool>,
+    ) -> Result<RaindexOrdersPage, RaindexError> {
+        let multi_subgraph_args =
+            self.get_multi_subgraph_args(chain_ids.map(|ids| ids.0.to_vec()))?;
+        // The data of each subgraph is read at the block reported for it
+        let subgraph_meta = if include_meta.unwrap_or(false) {
+            self.get_subgraph_meta(&multi_subgraph_args).await
+        } else {
+            SubgraphMetaRead::default()
+        };
+
+        let client = MultiOrderbookSubgraphClient::new(
+            multi_subgraph_args.values().flatten().cloned().collect(),
+        )
+        .at_subgraph_blocks(subgraph_meta.subgraph_blocks());
+
+        let result = client
+            .orders_page(
+                filters.unwrap_or_default().try_into()?,
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: DEFAULT_PAGE_SIZE,
+                    cursor,
+                },
+            )
+            .await?;
+
+        Ok(RaindexOrdersPage {
+            orders: self.orders_from_sg_orders(&multi_subgraph_args, result.data.items, None)?,
+            next_cursor: result.data.next_cursor,
+            subgraph_statuses: subgraph_meta.merge_statuses(result.statuses),
+            subgraph_meta: subgraph_meta.meta,
+        })
     }
 
     /// Retrieves a specific order by its hash from a particular blockchain network
@@ -534,31 +660,174 @@ impl RaindexClient {
             unchecked_param_type = "Hex"
         )]
         order_hash: String,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Return the order as it was at this block (optional, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
     ) -> Result<RaindexOrder, RaindexError> {
         let orderbook_address = Address::from_str(&orderbook_address)?;
         let order_hash = Bytes::from_str(&order_hash)?;
-        self.get_order_by_hash(chain_id, orderbook_address, order_hash)
+        self.get_order_by_hash_at_block(chain_id, orderbook_address, order_hash, block_number)
             .await
     }
 }
 impl RaindexClient {
+    fn orders_from_sg_orders(
+        &self,
+        multi_subgraph_args: &BTreeMap<u32, Vec<MultiSubgraphArgs>>,
+        orders: Vec<SgOrderWithSubgraphName>,
+        block_number: Option<u64>,
+    ) -> Result<Vec<RaindexOrder>, RaindexError> {
+        let raindex_client = Arc::new(RwLock::new(self.clone()));
+        let token_cfgs = self.get_token_cfgs();
+        orders
+            .into_iter()
+            .map(|order| {
+                let chain_id = multi_subgraph_args
+                    .iter()
+                    .find(|(_, args)| args.iter().any(|arg| arg.name == order.subgraph_name))
+                    .map(|(chain_id, _)| *chain_id)
+                    .ok_or(RaindexError::SubgraphNotFound(
+                        order.subgraph_name.clone(),
+                        order.order.order_hash.0.clone(),
+                    ))?;
+                Ok(RaindexOrder::try_from_sg_order_with_token_cfgs(
+                    raindex_client.clone(),
+                    chain_id,
+                    order.order,
+                    None,
+                    &token_cfgs,
+                )?
+                .at_block(block_number))
+            })
+            .collect()
+    }
+
     pub async fn get_order_by_hash(
         &self,
         chain_id: u32,
         orderbook_address: Address,
         order_hash: Bytes,
+    ) -> Result<RaindexOrder, RaindexError> {
+        self.get_order_by_hash_at_block(chain_id, orderbook_address, order_hash, None)
+            .await
+    }
+
+    /// Fetches the order as it was at the given block, or the latest indexed
+    /// state of the order when no block is given
+    pub async fn get_order_by_hash_at_block(
+        &self,
+        chain_id: u32,
+        orderbook_address: Address,
+        order_hash: Bytes,
+        block_number: Option<u64>,
     ) -> Result<RaindexOrder, RaindexError> {
         let raindex_client = Arc::new(RwLock::new(self.clone()));
-        let client = self.get_orderbook_client(orderbook_address)?;
+        let client = self
+            .get_orderbook_client(orderbook_address)?
+            .at_block(block_number);
         let order = client
             .order_detail_by_hash(SgBytes(order_hash.to_string()))
             .await?;
-        let order = RaindexOrder::try_from_sg_order(raindex_client.clone(), chain_id, order, None)?;
+        let order = RaindexOrder::try_from_sg_order(raindex_client.clone(), chain_id, order, None)?
+            .at_block(block_number);
         Ok(order)
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+/// Orders merged from all queried networks and the status of each subgraph
+///
+/// A subgraph that failed contributes no orders, its error is reported in
+/// the subgraph statuses instead.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexOrdersList {
+    items: Vec<RaindexOrder>,
+    subgraph_meta: Vec<RaindexSubgraphMeta>,
+    subgraph_statuses: Vec<MultiSubgraphStatus>,
+}
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexOrdersList {
+    #[wasm_bindgen(getter)]
+    pub fn items(&self) -> Vec<RaindexOrder> {
+        self.items.clone()
+    }
+    #[wasm_bindgen(getter = subgraphMeta)]
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+    #[wasm_bindgen(getter = subgraphStatuses)]
+    pub fn subgraph_statuses(&self) -> Vec<MultiSubgraphStatus> {
+        self.subgraph_statuses.clone()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RaindexOrdersList {
+    pub fn items(&self) -> Vec<RaindexOrder> {
+        self.items.clone()
+    }
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+    pub fn subgraph_statuses(&self) -> Vec<MultiSubgraphStatus> {
+        self.subgraph_statuses.clone()
+    }
+}
+
+/// A page of orders and the cursor to fetch the next one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexOrdersPage {
+    orders: Vec<RaindexOrder>,
+    next_cursor: Option<String>,
+    subgraph_meta: Vec<RaindexSubgraphMeta>,
+    subgraph_statuses: Vec<MultiSubgraphStatus>,
+}
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexOrdersPage {
+    #[wasm_bindgen(getter)]
+    pub fn orders(&self) -> Vec<RaindexOrder> {
+        self.orders.clone()
+    }
+    #[wasm_bindgen(getter = nextCursor)]
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    #[wasm_bindgen(getter = subgraphMeta)]
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+    #[wasm_bindgen(getter = subgraphStatuses)]
+    pub fn subgraph_statuses(&self) -> Vec<MultiSubgraphStatus> {
+        self.subgraph_statuses.clone()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RaindexOrdersPage {
+    pub fn orders(&self) -> Vec<RaindexOrder> {
+        self.orders.clone()
+    }
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+    pub fn subgraph_statuses(&self) -> Vec<MultiSubgraphStatus> {
+        self.subgraph_statuses.clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Tsify)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrdersFilters {
     #[tsify(type = "Address[]")]
@@ -567,11 +836,58 @@ pub struct GetOrdersFilters {
     pub active: Option<bool>,
     #[tsify(optional, type = "Hex")]
     pub order_hash: Option<Bytes>,
+    /// Orders that use any of these tokens as input or output
     #[tsify(optional, type = "Address[]")]
     pub tokens: Option<Vec<Address>>,
+    /// Orders that use any of these tokens as input
+    #[serde(default)]
+    #[tsify(optional, type = "Address[]")]
+    pub input_tokens: Option<Vec<Address>>,
+    /// Orders that use any of these tokens as output
+    #[serde(default)]
+    #[tsify(optional, type = "Address[]")]
+    pub output_tokens: Option<Vec<Address>>,
+    /// Orders added at or after this unix timestamp, in seconds
+    #[serde(default)]
+    #[tsify(optional)]
+    pub added_after: Option<u64>,
+    /// Orders added at or before this unix timestamp, in seconds
+    #[serde(default)]
+    #[tsify(optional)]
+    pub added_before: Option<u64>,
+    #[serde(default)]
+    #[tsify(optional)]
+    pub has_trades: Option<bool>,
+    #[serde(default)]
+    #[tsify(optional, type = "Address[]")]
+    pub orderbook_addresses: Option<Vec<Address>>,
+    /// Orders of any owner except these
+    #[serde(default)]
+    #[tsify(optional, type = "Address[]")]
+    pub exclude_owners: Option<Vec<Address>>,
+    /// Sorting by the last trade or the trade count, or filtering for orders
+    /// without trades, fetches every matching order and only works with page
+    /// numbers
+    #[serde(default)]
+    #[tsify(optional)]
+    pub sort_by: SgOrdersSortBy,
+    #[serde(default)]
+    #[tsify(optional)]
+    pub sort_direction: SgSortDirection,
 }
 impl_wasm_traits!(GetOrdersFilters);
 
+pub(crate) fn addresses_to_lowercase(addresses: Option<Vec<Address>>) -> Vec<String> {
+    addresses
+        .map(|addresses| {
+            addresses
+                .into_iter()
+                .map(|address| address.to_string().to_lowercase())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 impl TryFrom<GetOrdersFilters> for SgOrdersListFilterArgs {
     type Error = RaindexError;
     fn try_from(filters: GetOrdersFilters) -> Result<Self, Self::Error> {
@@ -585,15 +901,24 @@ impl TryFrom<GetOrdersFilters> for SgOrdersListFilterArgs {
             order_hash: filters
                 .order_hash
                 .map(|order_hash| SgBytes(order_hash.to_string())),
-            tokens: filters
-                .tokens
-                .map(|tokens| {
-                    tokens
-                        .into_iter()
-                        .map(|token| token.to_string().to_lowercase())
-                        .collect()
-                })
-                .unwrap_or_default(),
+            tokens: addresses_to_lowercase(filters.tokens),
+            input_tokens: addresses_to_lowercase(filters.input_tokens),
+            output_tokens: addresses_to_lowercase(filters.output_tokens),
+            added_after: filters.added_after,
+            added_before: filters.added_before,
+            has_trades: filters.has_trades,
+            orderbooks: addresses_to_lowercase(filters.orderbook_addresses)
+                .into_iter()
+                .map(SgBytes)
+                .collect(),
+            exclude_owners: filters
+                .exclude_owners
+                .unwrap_or_default()
+                .into_iter()
+                .map(|owner| SgBytes(owner.to_string()))
+                .collect(),
+            sort_by: filters.sort_by,
+            sort_direction: filters.sort_direction,
         })
     }
 }
@@ -604,6 +929,28 @@ impl RaindexOrder {
         chain_id: u32,
         order: SgOrder,
         transaction: Option<RaindexTransaction>,
+    ) -> Result<Self, RaindexError> {
+        let token_cfgs = raindex_client
+            .read()
+            .map_err(|_| YamlError::ReadLockError)?
+            .get_token_cfgs();
+        Self::try_from_sg_order_with_token_cfgs(
+            raindex_client,
+            chain_id,
+            order,
+            transaction,
+            &token_cfgs,
+        )
+    }
+
+    /// Same as `try_from_sg_order`, with the configured tokens already looked
+    /// up, so converting many orders reads the yaml once
+    pub(crate) fn try_from_sg_order_with_token_cfgs(
+        raindex_client: Arc<RwLock<RaindexClient>>,
+        chain_id: u32,
+        order: SgOrder,
+        transaction: Option<RaindexTransaction>,
+        token_cfgs: &TokenCfgs,
     ) -> Result<Self, RaindexError> {
         let rainlang = order
             .meta
@@ -622,11 +969,12 @@ impl RaindexOrder {
                     .inputs
                     .iter()
                     .map(|v| {
-                        RaindexVault::try_from_sg_vault(
+                        RaindexVault::try_from_sg_vault_with_token_cfgs(
                             raindex_client.clone(),
                             chain_id,
                             v.clone(),
                             Some(RaindexVaultType::Input),
+                            token_cfgs,
                         )
                     })
                     .collect::<Result<Vec<RaindexVault>, RaindexError>>()?
@@ -636,11 +984,12 @@ impl RaindexOrder {
                     .outputs
                     .iter()
                     .map(|v| {
-                        RaindexVault::try_from_sg_vault(
+                        RaindexVault::try_from_sg_vault_with_token_cfgs(
                             raindex_client.clone(),
                             chain_id,
                             v.clone(),
                             Some(RaindexVaultType::Output),
+                            token_cfgs,
                         )
                     })
                     .collect::<Result<Vec<RaindexVault>, RaindexError>>()?
@@ -655,9 +1004,27 @@ impl RaindexOrder {
             rainlang,
             transaction,
             trades_count: order.trades.len() as u16,
+            block_number: None,
         })
     }
 
+    /// Pins the order and its vaults to the block they were fetched at, so
+    /// the queries made from them read the same state
+    pub(crate) fn at_block(mut self, block_number: Option<u64>) -> Self {
+        self.block_number = block_number;
+        self.inputs = self
+            .inputs
+            .into_iter()
+            .map(|vault| vault.at_block(block_number))
+            .collect();
+        self.outputs = self
+            .outputs
+            .into_iter()
+            .map(|vault| vault.at_block(block_number))
+            .collect();
+        self
+    }
+
     pub fn into_sg_order(self) -> Result<SgOrder, RaindexError> {
         #[cfg(target_family = "wasm")]
         let timestamp_added = self.timestamp_added.to_string();
@@ -702,10 +1069,14 @@ mod tests {
     #[cfg(not(target_family = "wasm"))]
     mod non_wasm {
         use super::*;
+        use crate::meta::{GuiDeploymentMeta, GuiFieldValueMeta};
         use crate::raindex_client::tests::{get_test_yaml, CHAIN_ID_1_ORDERBOOK_ADDRESS};
         use alloy::primitives::U256;
         use httpmock::MockServer;
         use rain_math_float::Float;
+        use rain_metadata::{
+            ContentEncoding, ContentLanguage, ContentType, KnownMagic, RainMetaDocumentV1Item,
+        };
         use rain_orderbook_subgraph_client::utils::float::*;
         use rain_orderbook_subgraph_client::{
             // performance::{
@@ -716,6 +1087,7 @@ mod tests {
                 SgVault,
             },
         };
+        use serde_bytes::ByteBuf;
         use serde_json::{json, Value};
 
         fn get_order1_json() -> Value {
@@ -1041,6 +1413,7 @@ mod tests {
                 active: None,
                 order_hash: None,
                 tokens: None,
+                ..Default::default()
             };
             let raindex_client = RaindexClient::new(
                 vec![get_test_yaml(
@@ -1054,10 +1427,15 @@ mod tests {
             )
             .unwrap();
             let result = raindex_client
-                .get_orders(None, Some(filter_args), Some(1))
+                .get_orders(None, Some(filter_args), Some(1), None, None)
                 .await
                 .unwrap();
 
+            let statuses = result.subgraph_statuses();
+            assert_eq!(statuses.len(), 2);
+            assert!(statuses.iter().all(|status| status.is_ok()));
+
+            let result = result.items();
             assert_eq!(result.len(), 2);
 
             let expected_order1 = RaindexOrder::try_from_sg_order(
@@ -1193,6 +1571,151 @@ mod tests {
             assert_eq!(order2.timestamp_added(), U256::from(0));
         }
 
+        #[tokio::test]
+        async fn test_get_orders_page() {
+            let sg_server = MockServer::start_async().await;
+            sg_server.mock(|when, then| {
+                when.path("/sg1").body_contains("SgOrdersListQuery");
+                then.status(200).json_body_obj(&json!({
+                  "data": {
+                    "orders": [get_order1_json()]
+                  }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg2").body_contains("SgOrdersListQuery");
+                then.status(200).json_body_obj(&json!({
+                  "data": {
+                    "orders": []
+                  }
+                }));
+            });
+            for (path, block) in [("/sg1", 100), ("/sg2", 200)] {
+                sg_server.mock(|when, then| {
+                    when.path(path).body_contains("SgMetaQuery");
+                    then.status(200).json_body_obj(&json!({
+                      "data": {
+                        "_meta": {
+                          "block": { "number": block, "hash": null, "timestamp": null },
+                          "deployment": "Qm123",
+                          "hasIndexingErrors": false
+                        }
+                      }
+                    }));
+                });
+            }
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+
+            let page = raindex_client
+                .get_orders_page(None, None, None, None)
+                .await
+                .unwrap();
+            assert_eq!(page.orders().len(), 1);
+            assert_eq!(
+                page.orders()[0].id,
+                Bytes::from_str(&get_order1().id.0).unwrap()
+            );
+            assert_eq!(page.next_cursor(), None);
+            assert!(page.subgraph_meta().is_empty());
+            assert_eq!(page.subgraph_statuses().len(), 2);
+
+            let page = raindex_client
+                .get_orders_page(Some(ChainIds(vec![1])), None, None, Some(true))
+                .await
+                .unwrap();
+            assert_eq!(page.orders().len(), 1);
+            let subgraph_meta = page.subgraph_meta();
+            assert_eq!(subgraph_meta.len(), 1);
+            assert_eq!(subgraph_meta[0].chain_id, 1);
+            assert_eq!(subgraph_meta[0].indexed_block, 100);
+            assert!(!subgraph_meta[0].has_indexing_errors);
+
+            let err = raindex_client
+                .get_orders_page(None, None, Some("invalid".to_string()), None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, RaindexError::OrderbookSubgraphClientError(_)));
+        }
+
+        #[tokio::test]
+        async fn test_get_orders_with_meta() {
+            let sg_server = MockServer::start_async().await;
+            sg_server.mock(|when, then| {
+                when.path("/sg1").body_contains("SgMetaQuery");
+                then.status(200).json_body_obj(&json!({
+                  "data": {
+                    "_meta": {
+                      "block": { "number": 100, "hash": null, "timestamp": null },
+                      "deployment": "Qm123",
+                      "hasIndexingErrors": false
+                    }
+                  }
+                }));
+            });
+            let pinned = sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains("SgOrdersListQuery")
+                    .body_contains(r#""block":{"number":100}"#);
+                then.status(200).json_body_obj(&json!({
+                  "data": {
+                    "orders": [get_order1_json()]
+                  }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg2").body_contains("SgMetaQuery");
+                then.status(500);
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg2").body_contains("SgOrdersListQuery");
+                then.status(200).json_body_obj(&json!({
+                  "data": {
+                    "orders": []
+                  }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+
+            let result = raindex_client
+                .get_orders(None, None, None, None, Some(true))
+                .await
+                .unwrap();
+            assert_eq!(result.items().len(), 1);
+            pinned.assert();
+
+            let subgraph_meta = result.subgraph_meta();
+            assert_eq!(subgraph_meta.len(), 1);
+            assert_eq!(subgraph_meta[0].indexed_block, 100);
+
+            let statuses = result.subgraph_statuses();
+            assert_eq!(statuses.len(), 2);
+            let failed: Vec<_> = statuses.iter().filter(|status| !status.is_ok()).collect();
+            assert_eq!(failed.len(), 1);
+            assert_eq!(failed[0].url.to_string(), sg_server.url("/sg2"));
+        }
+
         #[tokio::test]
         async fn test_get_order_by_hash() {
             let sg_server = MockServer::start_async().await;
@@ -1257,6 +1780,143 @@ mod tests {
                 res.vaults_list().items()[2].id(),
                 expected_order.inputs[1].id()
             );
+            assert_eq!(res.get_gui_meta().unwrap(), None);
+        }
+
+        #[test]
+        fn test_get_gui_meta() {
+            let gui_meta = OrderGuiMeta {
+                deployment: GuiDeploymentMeta {
+                    deployment_key: "flare".to_string(),
+                    deployment_name: "Sell sFLR on Flare".to_string(),
+                    registry: None,
+                    order_key: Some("auction".to_string()),
+                    field_values: vec![GuiFieldValueMeta {
+                        binding: "amount".to_string(),
+                        name: "Amount".to_string(),
+                        value: "100".to_string(),
+                    }],
+                    state: "H4sIAAAAAAAA".to_string(),
+                },
+                dotrain: "gui:\n  name: Auction\n---\n#calculate-io\n_ _: 0 0;".to_string(),
+            };
+            let mut items = vec![RainMetaDocumentV1Item {
+                payload: ByteBuf::from("_ _: 0 0;".as_bytes()),
+                magic: KnownMagic::RainlangSourceV1,
+                content_type: ContentType::OctetStream,
+                content_encoding: ContentEncoding::None,
+                content_language: ContentLanguage::None,
+            }];
+            items.extend(gui_meta.to_meta_items().unwrap());
+            let meta =
+                RainMetaDocumentV1Item::cbor_encode_seq(&items, KnownMagic::RainMetaDocumentV1)
+                    .unwrap();
+
+            let mut sg_order = get_order1();
+            sg_order.meta = Some(SgBytes(Bytes::from(meta).to_string()));
+            let order = RaindexOrder::try_from_sg_order(
+                Arc::new(RwLock::new(
+                    RaindexClient::new(
+                        vec![get_test_yaml(
+                            "http://localhost:3000/sg1",
+                            "http://localhost:3000/sg2",
+                            "http://localhost:3000/rpc1",
+                            "http://localhost:3000/rpc2",
+                        )],
+                        None,
+                    )
+                    .unwrap(),
+                )),
+                1,
+                sg_order,
+                None,
+            )
+            .unwrap();
+            assert_eq!(order.rainlang(), Some("_ _: 0 0;".to_string()));
+            assert_eq!(order.get_gui_meta().unwrap(), Some(gui_meta));
+        }
+
+        #[test]
+        fn test_get_orders_filters_to_sg_filter_args() {
+            let filters: GetOrdersFilters = serde_json::from_value(serde_json::json!({
+                "owners": [],
+                "inputTokens": ["0x1D80C49BBBCD1C0911346656B529DF9E5C2F783D"],
+                "addedAfter": 1700000000,
+                "hasTrades": true,
+                "orderbookAddresses": ["0x1234567890ABCDEF1234567890ABCDEF12345678"],
+                "excludeOwners": ["0x0000000000000000000000000000000000000001"],
+                "sortBy": "lastTrade",
+                "sortDirection": "asc"
+            }))
+            .unwrap();
+
+            let args = SgOrdersListFilterArgs::try_from(filters).unwrap();
+            assert!(args.tokens.is_empty());
+            assert_eq!(
+                args.input_tokens,
+                vec!["0x1d80c49bbbcd1c0911346656b529df9e5c2f783d".to_string()]
+            );
+            assert!(args.output_tokens.is_empty());
+            assert_eq!(args.added_after, Some(1700000000));
+            assert_eq!(args.added_before, None);
+            assert_eq!(args.has_trades, Some(true));
+            assert_eq!(
+                args.orderbooks,
+                vec![SgBytes(
+                    "0x1234567890abcdef1234567890abcdef12345678".to_string()
+                )]
+            );
+            assert_eq!(
+                args.exclude_owners,
+                vec![SgBytes(
+                    "0x0000000000000000000000000000000000000001".to_string()
+                )]
+            );
+            assert_eq!(args.sort_by, SgOrdersSortBy::LastTrade);
+            assert_eq!(args.sort_direction, SgSortDirection::Asc);
+        }
+
+        #[tokio::test]
+        async fn test_get_order_by_hash_at_block() {
+            let sg_server = MockServer::start_async().await;
+            let pinned = sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains(r#""block":{"number":1000}"#);
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "orders": [get_order1_json()]
+                    }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+            let order = raindex_client
+                .get_order_by_hash_at_block(
+                    1,
+                    Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
+                    Bytes::from_str("0x0123").unwrap(),
+                    Some(1000),
+                )
+                .await
+                .unwrap();
+            pinned.assert();
+
+            assert_eq!(order.block_number(), Some(1000));
+            assert!(order
+                .vaults_list()
+                .items()
+                .iter()
+                .all(|vault| vault.block_number() == Some(1000)));
         }
 
         #[tokio::test]
@@ -1308,6 +1968,7 @@ mod tests {
                 )
                 .await;
             assert!(res.is_ok());
+            assert_eq!(res.unwrap().get_gui_meta().unwrap(), None);
         }
 
         #[tokio::test]
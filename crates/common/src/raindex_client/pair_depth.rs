@@ -0,0 +1,559 @@
+use super::*;
+use alloy::primitives::{Bytes, U256};
+use rain_math_float::Float;
+use rain_orderbook_bindings::IOrderBookV5::{OrderV4, QuoteV2};
+use rain_orderbook_quote::{BatchQuoteTarget, OrderQuoteValue, QuoteTarget};
+use rain_orderbook_subgraph_client::{
+    types::common::{SgOrder, SgOrdersListFilterArgs},
+    utils::float::{F0, F1},
+    MultiSubgraphStatus,
+};
+use std::cmp::Ordering;
+use std::ops::Div;
+
+/// A quoted order in the pair book
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPairDepthLevel {
+    pub chain_id: u32,
+    #[tsify(type = "Address")]
+    pub orderbook: Address,
+    #[tsify(type = "Hex")]
+    pub order_hash: Bytes,
+    pub input_io_index: u32,
+    pub output_io_index: u32,
+    /// The quoted io ratio of the order, its input amount per unit of output
+    #[tsify(type = "Hex")]
+    pub ratio: Float,
+    pub formatted_ratio: String,
+    /// Price of the output token of the pair in units of its input token
+    #[tsify(type = "Hex")]
+    pub price: Float,
+    pub formatted_price: String,
+    /// Maximum amount the order can send, in its output token
+    #[tsify(type = "Hex")]
+    pub max_output: Float,
+    pub formatted_max_output: String,
+    /// Sum of the max outputs of this level and every better level
+    #[tsify(type = "Hex")]
+    pub cumulative_depth: Float,
+    pub formatted_cumulative_depth: String,
+}
+impl_wasm_traits!(RaindexPairDepthLevel);
+
+/// An order io pair of the book that could not be quoted
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPairDepthFailedQuote {
+    pub chain_id: u32,
+    #[tsify(type = "Address")]
+    pub orderbook: Address,
+    #[tsify(type = "Hex")]
+    pub order_hash: Bytes,
+    pub error: String,
+}
+impl_wasm_traits!(RaindexPairDepthFailedQuote);
+
+/// Order book of a token pair built from the quotes of active orders
+///
+/// Asks are orders that take the input token and send the output token,
+/// bids are orders that take the output token and send the input token.
+/// Both sides are priced in input token per output token and start with the
+/// best level, so asks are sorted by ascending price and bids by descending
+/// price.
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPairDepth {
+    #[tsify(type = "Address")]
+    pub input_token: Address,
+    #[tsify(type = "Address")]
+    pub output_token: Address,
+    pub bids: Vec<RaindexPairDepthLevel>,
+    pub asks: Vec<RaindexPairDepthLevel>,
+    pub failed_quotes: Vec<RaindexPairDepthFailedQuote>,
+    pub subgraph_statuses: Vec<MultiSubgraphStatus>,
+}
+impl_wasm_traits!(RaindexPairDepth);
+
+#[wasm_export]
+impl RaindexClient {
+    /// Builds the order book of a token pair across networks
+    ///
+    /// Gathers the active orders trading the pair in either direction, quotes
+    /// them and returns the quoted orders as sorted bid and ask levels with
+    /// their cumulative depth. Orders quoting no output are left out.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await client.getPairDepth(
+    ///   "0x1234567890abcdef1234567890abcdef12345678",
+    ///   "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd"
+    /// );
+    /// if (result.error) {
+    ///   console.error("Cannot build the pair book:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { bids, asks } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getPairDepth",
+        return_description = "Bid and ask levels of the pair",
+        unchecked_return_type = "RaindexPairDepth"
+    )]
+    pub async fn get_pair_depth_wasm_binding(
+        &self,
+        #[wasm_export(
+            js_name = "inputToken",
+            param_description = "Token the asks receive and the bids send",
+            unchecked_param_type = "Address"
+        )]
+        input_token: String,
+        #[wasm_export(
+            js_name = "outputToken",
+            param_description = "Token the asks send and the bids receive",
+            unchecked_param_type = "Address"
+        )]
+        output_token: String,
+        #[wasm_export(
+            js_name = "chainIds",
+            param_description = "Specific blockchain networks to query (optional, queries all networks if not specified)"
+        )]
+        chain_ids: Option<ChainIds>,
+    ) -> Result<RaindexPairDepth, RaindexError> {
+        let input_token = Address::from_str(&input_token)?;
+        let output_token = Address::from_str(&output_token)?;
+        self.get_pair_depth(input_token, output_token, chain_ids.map(|ids| ids.0))
+            .await
+    }
+}
+impl RaindexClient {
+    pub async fn get_pair_depth(
+        &self,
+        input_token: Address,
+        output_token: Address,
+        chain_ids: Option<Vec<u32>>,
+    ) -> Result<RaindexPairDepth, RaindexError> {
+        if input_token == output_token {
+            return Err(RaindexError::SamePairTokens);
+        }
+        let multi_subgraph_args = self.get_multi_subgraph_args(chain_ids)?;
+
+        let mut ask_quotes = vec![];
+        let mut bid_quotes = vec![];
+        let mut failed_quotes = vec![];
+        let mut subgraph_statuses: Vec<MultiSubgraphStatus> = vec![];
+        for (chain_id, subgraphs) in multi_subgraph_args {
+            let client = MultiOrderbookSubgraphClient::new(subgraphs);
+            let rpcs = self
+                .get_rpc_urls_for_chain(chain_id)?
+                .iter()
+                .map(|url| url.to_string())
+                .collect::<Vec<_>>();
+
+            for (order_input, order_output, quotes) in [
+                (input_token, output_token, &mut ask_quotes),
+                (output_token, input_token, &mut bid_quotes),
+            ] {
+                let result = client
+                    .orders_list_all(SgOrdersListFilterArgs {
+                        active: Some(true),
+                        input_tokens: vec![order_input.to_string().to_lowercase()],
+                        output_tokens: vec![order_output.to_string().to_lowercase()],
+                        ..Default::default()
+                    })
+                    .await;
+                merge_subgraph_statuses(&mut subgraph_statuses, result.statuses);
+
+                let targets = PairQuoteTargets::from_orders(
+                    chain_id,
+                    result.data.into_iter().map(|order| order.order).collect(),
+                    order_input,
+                    order_output,
+                )?;
+                let (quoted, failed) = targets.quote(rpcs.clone()).await;
+                quotes.extend(quoted);
+                failed_quotes.extend(failed);
+            }
+        }
+
+        Ok(RaindexPairDepth {
+            input_token,
+            output_token,
+            bids: build_levels(bid_quotes, true)?,
+            asks: build_levels(ask_quotes, false)?,
+            failed_quotes,
+            subgraph_statuses,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PairQuoteSource {
+    chain_id: u32,
+    orderbook: Address,
+    order_hash: Bytes,
+    input_io_index: u32,
+    output_io_index: u32,
+}
+
+#[derive(Debug, Clone)]
+struct PairQuote {
+    source: PairQuoteSource,
+    value: OrderQuoteValue,
+}
+
+/// Quote targets of every io pair of the orders that trade the given tokens
+struct PairQuoteTargets {
+    targets: Vec<QuoteTarget>,
+    sources: Vec<PairQuoteSource>,
+}
+
+impl PairQuoteTargets {
+    fn from_orders(
+        chain_id: u32,
+        orders: Vec<SgOrder>,
+        order_input: Address,
+        order_output: Address,
+    ) -> Result<Self, RaindexError> {
+        let mut targets = vec![];
+        let mut sources = vec![];
+        for order in orders {
+            let orderbook = Address::from_str(&order.orderbook.id.0)?;
+            let order_hash = Bytes::from_str(&order.order_hash.0)?;
+            let order_struct: OrderV4 = order.try_into()?;
+
+            for (input_index, input) in order_struct.validInputs.iter().enumerate() {
+                if input.token != order_input {
+                    continue;
+                }
+                for (output_index, output) in order_struct.validOutputs.iter().enumerate() {
+                    if output.token != order_output {
+                        continue;
+                    }
+                    targets.push(QuoteTarget {
+                        orderbook,
+                        quote_config: QuoteV2 {
+                            order: order_struct.clone(),
+                            inputIOIndex: U256::from(input_index),
+                            outputIOIndex: U256::from(output_index),
+                            signedContext: vec![],
+                        },
+                    });
+                    sources.push(PairQuoteSource {
+                        chain_id,
+                        orderbook,
+                        order_hash: order_hash.clone(),
+                        input_io_index: input_index as u32,
+                        output_io_index: output_index as u32,
+                    });
+                }
+            }
+        }
+        Ok(Self { targets, sources })
+    }
+
+    /// Quotes the targets in a single batch, a failed batch fails every target
+    async fn quote(self, rpcs: Vec<String>) -> (Vec<PairQuote>, Vec<RaindexPairDepthFailedQuote>) {
+        if self.targets.is_empty() {
+            return (vec![], vec![]);
+        }
+        let failed_quote = |source: PairQuoteSource, error: String| RaindexPairDepthFailedQuote {
+            chain_id: source.chain_id,
+            orderbook: source.orderbook,
+            order_hash: source.order_hash,
+            error,
+        };
+
+        match BatchQuoteTarget(self.targets)
+            .do_quote(rpcs, None, None, None)
+            .await
+        {
+            Ok(results) => {
+                let mut quotes = vec![];
+                let mut failed = vec![];
+                for (result, source) in results.into_iter().zip(self.sources) {
+                    match result {
+                        Ok(value) => quotes.push(PairQuote { source, value }),
+                        Err(e) => failed.push(failed_quote(source, e.to_string())),
+                    }
+                }
+                (quotes, failed)
+            }
+            Err(e) => (
+                vec![],
+                self.sources
+                    .into_iter()
+                    .map(|source| failed_quote(source, e.to_string()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Sorts the quotes of one side best first and accumulates their depth.
+///
+/// The best level has the lowest ratio on both sides: asks pay the least
+/// input token per output token, bids send the most input token per output
+/// token. Bid prices are the inverse of their ratio.
+fn build_levels(
+    quotes: Vec<PairQuote>,
+    invert_price: bool,
+) -> Result<Vec<RaindexPairDepthLevel>, RaindexError> {
+    let mut quotes = quotes
+        .into_iter()
+        .map(|quote| Ok(F0.lt(quote.value.max_output)?.then_some(quote)))
+        .collect::<Result<Vec<_>, RaindexError>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mut sort_error = None;
+    quotes.sort_by(|a, b| {
+        let ordering = a
+            .value
+            .ratio
+            .lt(b.value.ratio)
+            .and_then(|less| {
+                if less {
+                    Ok(Ordering::Less)
+                } else {
+                    b.value.ratio.lt(a.value.ratio).map(|greater| {
+                        if greater {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Equal
+                        }
+                    })
+                }
+            })
+            .unwrap_or_else(|e| {
+                sort_error.get_or_insert(e);
+                Ordering::Equal
+            });
+        ordering.then_with(|| a.source.order_hash.cmp(&b.source.order_hash))
+    });
+    if let Some(e) = sort_error {
+        return Err(e.into());
+    }
+
+    let mut cumulative_depth = F0;
+    let mut levels = vec![];
+    for PairQuote { source, value } in quotes {
+        cumulative_depth = (cumulative_depth + value.max_output)?;
+        let (price, formatted_price) = if !invert_price {
+            (value.ratio, value.ratio.format18()?)
+        } else if F0.eq(value.ratio)? {
+            (F0, "Infinity".to_string())
+        } else {
+            let price = F1.div(value.ratio)?;
+            (price, price.format18()?)
+        };
+        levels.push(RaindexPairDepthLevel {
+            chain_id: source.chain_id,
+            orderbook: source.orderbook,
+            order_hash: source.order_hash,
+            input_io_index: source.input_io_index,
+            output_io_index: source.output_io_index,
+            ratio: value.ratio,
+            formatted_ratio: value.ratio.format18()?,
+            price,
+            formatted_price,
+            max_output: value.max_output,
+            formatted_max_output: value.max_output.format18()?,
+            cumulative_depth,
+            formatted_cumulative_depth: cumulative_depth.format18()?,
+        });
+    }
+    Ok(levels)
+}
+
+/// Both sides query the same subgraphs, a subgraph is reported once and
+/// keeps the first error it returned
+fn merge_subgraph_statuses(
+    statuses: &mut Vec<MultiSubgraphStatus>,
+    new_statuses: Vec<MultiSubgraphStatus>,
+) {
+    for new_status in new_statuses {
+        match statuses.iter_mut().find(|status| {
+            status.url == new_status.url && status.subgraph_name == new_status.subgraph_name
+        }) {
+            Some(status) => {
+                status.latency_ms = status.latency_ms.saturating_add(new_status.latency_ms);
+                if status.error.is_none() {
+                    status.error = new_status.error;
+                }
+            }
+            None => statuses.push(new_status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rain_orderbook_subgraph_client::utils::float::{F0_5, F2, F3, F4};
+
+    fn pair_quote(order_hash: &str, ratio: Float, max_output: Float) -> PairQuote {
+        PairQuote {
+            source: PairQuoteSource {
+                chain_id: 1,
+                orderbook: Address::ZERO,
+                order_hash: Bytes::from_str(order_hash).unwrap(),
+                input_io_index: 0,
+                output_io_index: 0,
+            },
+            value: OrderQuoteValue { max_output, ratio },
+        }
+    }
+
+    fn status(url: &str, error: Option<&str>, latency_ms: u32) -> MultiSubgraphStatus {
+        MultiSubgraphStatus {
+            subgraph_name: "mainnet".to_string(),
+            url: Url::parse(url).unwrap(),
+            error: error.map(ToString::to_string),
+            latency_ms,
+        }
+    }
+
+    #[test]
+    fn test_build_levels_asks() {
+        let levels = build_levels(
+            vec![
+                pair_quote("0x03", F3, F1),
+                pair_quote("0x01", F2, F2),
+                pair_quote("0x02", F4, F0),
+            ],
+            false,
+        )
+        .unwrap();
+
+        // The order quoting no output is left out
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[0].order_hash, Bytes::from_str("0x01").unwrap());
+        assert_eq!(levels[0].formatted_price, "2");
+        assert_eq!(levels[0].formatted_cumulative_depth, "2");
+        assert_eq!(levels[1].order_hash, Bytes::from_str("0x03").unwrap());
+        assert_eq!(levels[1].formatted_price, "3");
+        assert_eq!(levels[1].formatted_cumulative_depth, "3");
+    }
+
+    #[test]
+    fn test_build_levels_bids_invert_price() {
+        let levels = build_levels(
+            vec![
+                pair_quote("0x01", F2, F1),
+                pair_quote("0x02", F0_5, F3),
+                pair_quote("0x03", F0, F1),
+            ],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(levels.len(), 3);
+        assert_eq!(levels[0].order_hash, Bytes::from_str("0x03").unwrap());
+        assert_eq!(levels[0].formatted_price, "Infinity");
+        assert_eq!(levels[1].order_hash, Bytes::from_str("0x02").unwrap());
+        assert_eq!(levels[1].formatted_ratio, "0.5");
+        assert_eq!(levels[1].formatted_price, "2");
+        assert_eq!(levels[1].formatted_cumulative_depth, "4");
+        assert_eq!(levels[2].order_hash, Bytes::from_str("0x01").unwrap());
+        assert_eq!(levels[2].formatted_price, "0.5");
+        assert_eq!(levels[2].formatted_cumulative_depth, "5");
+    }
+
+    #[test]
+    fn test_build_levels_ties_sorted_by_order_hash() {
+        let levels = build_levels(
+            vec![pair_quote("0x02", F1, F1), pair_quote("0x01", F1, F1)],
+            false,
+        )
+        .unwrap();
+        assert_eq!(levels[0].order_hash, Bytes::from_str("0x01").unwrap());
+        assert_eq!(levels[1].order_hash, Bytes::from_str("0x02").unwrap());
+    }
+
+    #[test]
+    fn test_merge_subgraph_statuses() {
+        let mut statuses = vec![];
+        merge_subgraph_statuses(
+            &mut statuses,
+            vec![
+                status("https://sg1.com", None, 10),
+                status("https://sg2.com", Some("first"), 5),
+            ],
+        );
+        merge_subgraph_statuses(
+            &mut statuses,
+            vec![
+                status("https://sg1.com", Some("timeout"), 20),
+                status("https://sg2.com", Some("second"), 5),
+            ],
+        );
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].error.as_deref(), Some("timeout"));
+        assert_eq!(statuses[0].latency_ms, 30);
+        assert_eq!(statuses[1].error.as_deref(), Some("first"));
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    mod non_wasm {
+        use super::*;
+        use crate::raindex_client::tests::get_test_yaml;
+
+        #[tokio::test]
+        async fn test_get_pair_depth_same_tokens() {
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    "http://localhost:3000/sg1",
+                    "http://localhost:3000/sg2",
+                    "http://localhost:3000/rpc1",
+                    "http://localhost:3000/rpc2",
+                )],
+                None,
+            )
+            .unwrap();
+            let token = Address::from_str("0x1d80c49bbbcd1c0911346656b529df9e5c2f783d").unwrap();
+            let result = raindex_client.get_pair_depth(token, token, None).await;
+            assert!(matches!(result, Err(RaindexError::SamePairTokens)));
+        }
+
+        #[tokio::test]
+        async fn test_get_pair_depth_without_orders() {
+            let sg_server = httpmock::MockServer::start_async().await;
+            let mock = sg_server.mock(|when, then| {
+                when.path("/sg").body_contains("\"active\":true");
+                then.status(200)
+                    .json_body_obj(&serde_json::json!({"data": {"orders": []}}));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg"),
+                    &sg_server.url("/sg"),
+                    "http://localhost:3000/rpc1",
+                    "http://localhost:3000/rpc2",
+                )],
+                None,
+            )
+            .unwrap();
+            let depth = raindex_client
+                .get_pair_depth(
+                    Address::from_str("0x1d80c49bbbcd1c0911346656b529df9e5c2f783d").unwrap(),
+                    Address::from_str("0x12e605bc104e93b45e1ad99f9e555f659051c2bb").unwrap(),
+                    Some(vec![1]),
+                )
+                .await
+                .unwrap();
+
+            // One query per side
+            mock.assert_hits(2);
+            assert!(depth.bids.is_empty());
+            assert!(depth.asks.is_empty());
+            assert!(depth.failed_quotes.is_empty());
+            assert_eq!(depth.subgraph_statuses.len(), 1);
+            assert!(depth.subgraph_statuses[0].is_ok());
+        }
+    }
+}
@@ -0,0 +1,814 @@
+use super::*;
+use crate::{erc20::ERC20, raindex_client::vaults::RaindexVaultBalanceChangeType};
+use alloy::primitives::{Bytes, U256};
+use futures::{stream, StreamExt};
+use rain_math_float::Float;
+use rain_orderbook_subgraph_client::{
+    types::{
+        common::{SgBytes, SgErc20, SgVaultWithSubgraphName, SgVaultsListFilterArgs},
+        Id,
+    },
+    utils::float::F0,
+    MultiSubgraphStatus,
+};
+use std::{collections::btree_map::Entry, ops::Sub};
+
+const MAX_CONCURRENT_HISTORIES: usize = 5;
+const MAX_CONCURRENT_WALLET_BALANCES: usize = 5;
+
+/// Holdings and flows of a single token of the owner on one network
+///
+/// Vault figures are summed over every vault of the owner holding the token,
+/// across all orderbooks of the network. Withdrawals and trade outflows are
+/// reported as positive amounts.
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPortfolioToken {
+    pub chain_id: u32,
+    #[tsify(type = "Address")]
+    pub token: Address,
+    #[tsify(optional)]
+    pub name: Option<String>,
+    #[tsify(optional)]
+    pub symbol: Option<String>,
+    pub decimals: u8,
+    #[tsify(type = "Address[]")]
+    pub orderbooks: Vec<Address>,
+    pub vault_count: u32,
+    #[tsify(type = "Hex")]
+    pub vault_balance: Float,
+    pub formatted_vault_balance: String,
+    /// Balance of the owner's wallet, missing if it could not be read
+    #[tsify(optional, type = "Hex")]
+    pub wallet_balance: Option<Float>,
+    #[tsify(optional)]
+    pub formatted_wallet_balance: Option<String>,
+    #[tsify(optional)]
+    pub wallet_balance_error: Option<String>,
+    /// Vault balance plus wallet balance, or only the vault balance if the
+    /// wallet balance could not be read
+    #[tsify(type = "Hex")]
+    pub total_balance: Float,
+    pub formatted_total_balance: String,
+    #[tsify(type = "Hex")]
+    pub deposits: Float,
+    pub formatted_deposits: String,
+    #[tsify(type = "Hex")]
+    pub withdrawals: Float,
+    pub formatted_withdrawals: String,
+    /// Deposits minus withdrawals
+    #[tsify(type = "Hex")]
+    pub net_deposits: Float,
+    pub formatted_net_deposits: String,
+    /// Amount the vaults received from trades
+    #[tsify(type = "Hex")]
+    pub trade_inflow: Float,
+    pub formatted_trade_inflow: String,
+    /// Amount the vaults sent in trades
+    #[tsify(type = "Hex")]
+    pub trade_outflow: Float,
+    pub formatted_trade_outflow: String,
+    /// Trade inflow minus trade outflow, the realized trade flow of the token
+    #[tsify(type = "Hex")]
+    pub net_trade_flow: Float,
+    pub formatted_net_trade_flow: String,
+    #[tsify(type = "Hex")]
+    pub clear_bounties: Float,
+    pub formatted_clear_bounties: String,
+}
+impl_wasm_traits!(RaindexPortfolioToken);
+
+/// Totals over every token of the portfolio
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPortfolioTotals {
+    pub chain_ids: Vec<u32>,
+    pub token_count: u32,
+    pub vault_count: u32,
+    pub deposit_count: u32,
+    pub withdrawal_count: u32,
+    pub trade_count: u32,
+    pub clear_bounty_count: u32,
+}
+impl_wasm_traits!(RaindexPortfolioTotals);
+
+/// A vault whose balance changes could not be fetched or read, its token
+/// flows are missing from the portfolio
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPortfolioFailedVault {
+    pub chain_id: u32,
+    #[tsify(type = "Address")]
+    pub orderbook: Address,
+    #[tsify(type = "Hex")]
+    pub id: Bytes,
+    pub error: String,
+}
+impl_wasm_traits!(RaindexPortfolioFailedVault);
+
+/// A token that could not be summed, for example because the subgraph has no
+/// decimals for it, its vaults are missing from the portfolio
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPortfolioFailedToken {
+    pub chain_id: u32,
+    #[tsify(type = "Address")]
+    pub token: Address,
+    pub error: String,
+}
+impl_wasm_traits!(RaindexPortfolioFailedToken);
+
+/// Holdings of an owner across networks and orderbooks, one entry per token
+#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexPortfolio {
+    #[tsify(type = "Address")]
+    pub owner: Address,
+    pub tokens: Vec<RaindexPortfolioToken>,
+    pub totals: RaindexPortfolioTotals,
+    pub failed_vaults: Vec<RaindexPortfolioFailedVault>,
+    pub failed_tokens: Vec<RaindexPortfolioFailedToken>,
+    pub subgraph_statuses: Vec<MultiSubgraphStatus>,
+}
+impl_wasm_traits!(RaindexPortfolio);
+
+#[wasm_export]
+impl RaindexClient {
+    /// Builds the portfolio of an owner across networks
+    ///
+    /// Groups the balances of all vaults of the owner by token and adds the
+    /// wallet balance of each token. The balance changes of the vaults are
+    /// summed into deposits, withdrawals, trade flow and clear bounties.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await client.getPortfolio(
+    ///   "0x1234567890abcdef1234567890abcdef12345678"
+    /// );
+    /// if (result.error) {
+    ///   console.error("Cannot build portfolio:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { tokens, totals } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getPortfolio",
+        return_description = "Per token holdings and flows of the owner",
+        unchecked_return_type = "RaindexPortfolio"
+    )]
+    pub async fn get_portfolio_wasm_binding(
+        &self,
+        #[wasm_export(
+            param_description = "Owner of the vaults and wallet",
+            unchecked_param_type = "Address"
+        )]
+        owner: String,
+        #[wasm_export(
+            js_name = "chainIds",
+            param_description = "Specific blockchain networks to query (optional, queries all networks if not specified)"
+        )]
+        chain_ids: Option<ChainIds>,
+    ) -> Result<RaindexPortfolio, RaindexError> {
+        let owner = Address::from_str(&owner)?;
+        self.get_portfolio(owner, chain_ids.map(|ids| ids.0)).await
+    }
+}
+impl RaindexClient {
+    pub async fn get_portfolio(
+        &self,
+        owner: Address,
+        chain_ids: Option<Vec<u32>>,
+    ) -> Result<RaindexPortfolio, RaindexError> {
+        let multi_subgraph_args = self.get_multi_subgraph_args(chain_ids)?;
+        let client = MultiOrderbookSubgraphClient::new(
+            multi_subgraph_args.values().flatten().cloned().collect(),
+        );
+        let result = client
+            .vaults_list_all(SgVaultsListFilterArgs {
+                owners: vec![SgBytes(owner.to_string().to_lowercase())],
+                ..Default::default()
+            })
+            .await;
+
+        let mut summaries: BTreeMap<(u32, Address), PortfolioTokenSummary> = BTreeMap::new();
+        let mut failed_tokens: BTreeMap<(u32, Address), String> = BTreeMap::new();
+        let mut histories = vec![];
+        for SgVaultWithSubgraphName {
+            vault,
+            subgraph_name,
+        } in result.data
+        {
+            let (chain_id, subgraph_args) = multi_subgraph_args
+                .iter()
+                .find_map(|(chain_id, args)| {
+                    args.iter()
+                        .find(|arg| arg.name == subgraph_name)
+                        .map(|arg| (*chain_id, arg))
+                })
+                .ok_or(RaindexError::SubgraphNotFound(
+                    subgraph_name.clone(),
+                    vault.id.0.clone(),
+                ))?;
+            let key = (chain_id, Address::from_str(&vault.token.address.0)?);
+            if failed_tokens.contains_key(&key) {
+                continue;
+            }
+            let orderbook = Address::from_str(&vault.orderbook.id.0)?;
+            let summary = match summaries.entry(key) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => match PortfolioTokenSummary::new(chain_id, &vault.token) {
+                    Ok(summary) => entry.insert(summary),
+                    Err(e) => {
+                        failed_tokens.insert(key, e.to_readable_msg());
+                        continue;
+                    }
+                },
+            };
+            summary.add_vault(orderbook, Float::from_hex(&vault.balance.0)?)?;
+
+            let id = Bytes::from_str(&vault.id.0)?;
+            // Read the history from the subgraph that listed the vault, the
+            // orderbook address alone may match an orderbook of another network
+            let subgraph_client = OrderbookSubgraphClient::with_transport(
+                subgraph_args.url.clone(),
+                subgraph_args.transport.clone(),
+            );
+            histories.push(async move {
+                let changes = subgraph_client
+                    .vault_balance_changes_list_all(Id::new(vault.id.0))
+                    .await;
+                (key, orderbook, id, changes)
+            });
+        }
+
+        let mut failed_vaults = vec![];
+        let histories: Vec<_> = stream::iter(histories)
+            .buffer_unordered(MAX_CONCURRENT_HISTORIES)
+            .collect()
+            .await;
+        for ((chain_id, token), orderbook, id, changes) in histories {
+            // Read every change before summing so a vault with an unreadable
+            // change adds none of its flows
+            let changes = changes.map_err(|e| e.to_string()).and_then(|changes| {
+                changes
+                    .into_iter()
+                    .map(|change| {
+                        let change_type: RaindexVaultBalanceChangeType =
+                            change.__typename.try_into()?;
+                        Ok((change_type, Float::from_hex(&change.amount.0)?))
+                    })
+                    .collect::<Result<Vec<_>, RaindexError>>()
+                    .map_err(|e| e.to_readable_msg())
+            });
+            match changes {
+                Ok(changes) => {
+                    if let Some(summary) = summaries.get_mut(&(chain_id, token)) {
+                        for (change_type, amount) in changes {
+                            summary.add_balance_change(change_type, amount)?;
+                        }
+                    }
+                }
+                Err(error) => failed_vaults.push(RaindexPortfolioFailedVault {
+                    chain_id,
+                    orderbook,
+                    id,
+                    error,
+                }),
+            }
+        }
+
+        let mut wallet_balances: BTreeMap<_, _> =
+            stream::iter(summaries.keys().map(|&(chain_id, token)| {
+                let rpcs = self.get_rpc_urls_for_chain(chain_id);
+                async move {
+                    let balance = match rpcs {
+                        Ok(rpcs) => ERC20::new(rpcs, token)
+                            .get_account_balance(owner)
+                            .await
+                            .map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_readable_msg()),
+                    };
+                    ((chain_id, token), balance)
+                }
+            }))
+            .buffer_unordered(MAX_CONCURRENT_WALLET_BALANCES)
+            .collect()
+            .await;
+
+        let mut totals = RaindexPortfolioTotals::default();
+        let mut tokens = vec![];
+        for (key, summary) in summaries {
+            let wallet_balance = wallet_balances
+                .remove(&key)
+                .unwrap_or_else(|| Err("wallet balance was not read".to_string()));
+            summary.add_to_totals(&mut totals);
+            tokens.push(summary.into_portfolio_token(wallet_balance)?);
+        }
+
+        Ok(RaindexPortfolio {
+            owner,
+            tokens,
+            totals,
+            failed_vaults,
+            failed_tokens: failed_tokens
+                .into_iter()
+                .map(|((chain_id, token), error)| RaindexPortfolioFailedToken {
+                    chain_id,
+                    token,
+                    error,
+                })
+                .collect(),
+            subgraph_statuses: result.statuses,
+        })
+    }
+}
+
+/// Running sums of the vaults and balance changes of one token
+#[derive(Debug, Clone)]
+struct PortfolioTokenSummary {
+    chain_id: u32,
+    token: Address,
+    name: Option<String>,
+    symbol: Option<String>,
+    decimals: u8,
+    orderbooks: Vec<Address>,
+    vault_count: u32,
+    vault_balance: Float,
+    deposits: Float,
+    withdrawals: Float,
+    trade_inflow: Float,
+    trade_outflow: Float,
+    clear_bounties: Float,
+    deposit_count: u32,
+    withdrawal_count: u32,
+    trade_count: u32,
+    clear_bounty_count: u32,
+}
+
+impl PortfolioTokenSummary {
+    fn new(chain_id: u32, token: &SgErc20) -> Result<Self, RaindexError> {
+        let address = Address::from_str(&token.address.0)?;
+        let decimals = token
+            .decimals
+            .as_ref()
+            .ok_or(RaindexError::MissingErc20Decimals(address.to_string()))?
+            .0
+            .parse::<u8>()?;
+        Ok(Self {
+            chain_id,
+            token: address,
+            name: token.name.clone(),
+            symbol: token.symbol.clone(),
+            decimals,
+            orderbooks: vec![],
+            vault_count: 0,
+            vault_balance: F0,
+            deposits: F0,
+            withdrawals: F0,
+            trade_inflow: F0,
+            trade_outflow: F0,
+            clear_bounties: F0,
+            deposit_count: 0,
+            withdrawal_count: 0,
+            trade_count: 0,
+            clear_bounty_count: 0,
+        })
+    }
+
+    fn add_vault(&mut self, orderbook: Address, balance: Float) -> Result<(), RaindexError> {
+        if !self.orderbooks.contains(&orderbook) {
+            self.orderbooks.push(orderbook);
+        }
+        self.vault_count += 1;
+        self.vault_balance = (self.vault_balance + balance)?;
+        Ok(())
+    }
+
+    /// Adds a signed balance change amount, withdrawals and trade outputs
+    /// are negative
+    fn add_balance_change(
+        &mut self,
+        change_type: RaindexVaultBalanceChangeType,
+        amount: Float,
+    ) -> Result<(), RaindexError> {
+        match change_type {
+            RaindexVaultBalanceChangeType::Deposit => {
+                self.deposits = (self.deposits + amount)?;
+                self.deposit_count += 1;
+            }
+            RaindexVaultBalanceChangeType::Withdrawal => {
+                self.withdrawals = self.withdrawals.sub(amount)?;
+                self.withdrawal_count += 1;
+            }
+            RaindexVaultBalanceChangeType::TradeVaultBalanceChange => {
+                if amount.lt(F0)? {
+                    self.trade_outflow = self.trade_outflow.sub(amount)?;
+                } else {
+                    self.trade_inflow = (self.trade_inflow + amount)?;
+                }
+                self.trade_count += 1;
+            }
+            RaindexVaultBalanceChangeType::ClearBounty => {
+                self.clear_bounties = (self.clear_bounties + amount)?;
+                self.clear_bounty_count += 1;
+            }
+            RaindexVaultBalanceChangeType::Unknown => {}
+        }
+        Ok(())
+    }
+
+    fn add_to_totals(&self, totals: &mut RaindexPortfolioTotals) {
+        if !totals.chain_ids.contains(&self.chain_id) {
+            totals.chain_ids.push(self.chain_id);
+        }
+        totals.token_count += 1;
+        totals.vault_count += self.vault_count;
+        totals.deposit_count += self.deposit_count;
+        totals.withdrawal_count += self.withdrawal_count;
+        totals.trade_count += self.trade_count;
+        totals.clear_bounty_count += self.clear_bounty_count;
+    }
+
+    fn into_portfolio_token(
+        self,
+        wallet_balance: Result<U256, String>,
+    ) -> Result<RaindexPortfolioToken, RaindexError> {
+        let (wallet_balance, wallet_balance_error) = match wallet_balance {
+            Ok(balance) => (
+                Some(Float::from_fixed_decimal(balance, self.decimals)?),
+                None,
+            ),
+            Err(e) => (None, Some(e)),
+        };
+        let total_balance = match wallet_balance {
+            Some(wallet_balance) => (self.vault_balance + wallet_balance)?,
+            None => self.vault_balance,
+        };
+        let net_deposits = self.deposits.sub(self.withdrawals)?;
+        let net_trade_flow = self.trade_inflow.sub(self.trade_outflow)?;
+
+        Ok(RaindexPortfolioToken {
+            chain_id: self.chain_id,
+            token: self.token,
+            name: self.name,
+            symbol: self.symbol,
+            decimals: self.decimals,
+            orderbooks: self.orderbooks,
+            vault_count: self.vault_count,
+            vault_balance: self.vault_balance,
+            formatted_vault_balance: self.vault_balance.format18()?,
+            formatted_wallet_balance: wallet_balance
+                .map(|balance| balance.format18())
+                .transpose()?,
+            wallet_balance,
+            wallet_balance_error,
+            total_balance,
+            formatted_total_balance: total_balance.format18()?,
+            deposits: self.deposits,
+            formatted_deposits: self.deposits.format18()?,
+            withdrawals: self.withdrawals,
+            formatted_withdrawals: self.withdrawals.format18()?,
+            net_deposits,
+            formatted_net_deposits: net_deposits.format18()?,
+            trade_inflow: self.trade_inflow,
+            formatted_trade_inflow: self.trade_inflow.format18()?,
+            trade_outflow: self.trade_outflow,
+            formatted_trade_outflow: self.trade_outflow.format18()?,
+            net_trade_flow,
+            formatted_net_trade_flow: net_trade_flow.format18()?,
+            clear_bounties: self.clear_bounties,
+            formatted_clear_bounties: self.clear_bounties.format18()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rain_orderbook_subgraph_client::{
+        types::common::SgBigInt,
+        utils::float::{F1, F2, F3, F5},
+    };
+
+    fn sg_erc20() -> SgErc20 {
+        SgErc20 {
+            id: SgBytes("token1".to_string()),
+            address: SgBytes("0x1d80c49bbbcd1c0911346656b529df9e5c2f783d".to_string()),
+            name: Some("Token 1".to_string()),
+            symbol: Some("TKN1".to_string()),
+            decimals: Some(SgBigInt("6".to_string())),
+        }
+    }
+
+    fn negative(amount: Float) -> Float {
+        F0.sub(amount).unwrap()
+    }
+
+    #[test]
+    fn test_summary_balance_changes() {
+        let mut summary = PortfolioTokenSummary::new(1, &sg_erc20()).unwrap();
+        summary.add_vault(Address::ZERO, F2).unwrap();
+        summary.add_vault(Address::ZERO, F1).unwrap();
+        for (change_type, amount) in [
+            (RaindexVaultBalanceChangeType::Deposit, F5),
+            (RaindexVaultBalanceChangeType::Withdrawal, negative(F2)),
+            (RaindexVaultBalanceChangeType::TradeVaultBalanceChange, F3),
+            (
+                RaindexVaultBalanceChangeType::TradeVaultBalanceChange,
+                negative(F5),
+            ),
+            (RaindexVaultBalanceChangeType::ClearBounty, F1),
+            (RaindexVaultBalanceChangeType::Unknown, F5),
+        ] {
+            summary.add_balance_change(change_type, amount).unwrap();
+        }
+
+        let mut totals = RaindexPortfolioTotals::default();
+        summary.add_to_totals(&mut totals);
+        assert_eq!(
+            totals,
+            RaindexPortfolioTotals {
+                chain_ids: vec![1],
+                token_count: 1,
+                vault_count: 2,
+                deposit_count: 1,
+                withdrawal_count: 1,
+                trade_count: 2,
+                clear_bounty_count: 1,
+            }
+        );
+
+        let token = summary
+            .into_portfolio_token(Ok(U256::from(1_500_000)))
+            .unwrap();
+        assert_eq!(token.orderbooks, vec![Address::ZERO]);
+        assert_eq!(token.vault_count, 2);
+        assert_eq!(token.formatted_vault_balance, "3");
+        assert_eq!(token.formatted_wallet_balance.as_deref(), Some("1.5"));
+        assert_eq!(token.formatted_total_balance, "4.5");
+        assert_eq!(token.formatted_deposits, "5");
+        assert_eq!(token.formatted_withdrawals, "2");
+        assert_eq!(token.formatted_net_deposits, "3");
+        assert_eq!(token.formatted_trade_inflow, "3");
+        assert_eq!(token.formatted_trade_outflow, "5");
+        assert_eq!(token.formatted_net_trade_flow, "-2");
+        assert_eq!(token.formatted_clear_bounties, "1");
+    }
+
+    #[test]
+    fn test_summary_without_wallet_balance() {
+        let mut summary = PortfolioTokenSummary::new(1, &sg_erc20()).unwrap();
+        summary.add_vault(Address::ZERO, F2).unwrap();
+
+        let token = summary
+            .into_portfolio_token(Err("rpc error".to_string()))
+            .unwrap();
+        assert!(token.wallet_balance.is_none());
+        assert_eq!(token.wallet_balance_error.as_deref(), Some("rpc error"));
+        assert_eq!(token.formatted_total_balance, "2");
+    }
+
+    #[test]
+    fn test_summary_missing_decimals() {
+        let mut erc20 = sg_erc20();
+        erc20.decimals = None;
+        let result = PortfolioTokenSummary::new(1, &erc20);
+        assert!(matches!(result, Err(RaindexError::MissingErc20Decimals(_))));
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    mod non_wasm {
+        use super::*;
+        use crate::raindex_client::tests::{get_test_yaml, CHAIN_ID_1_ORDERBOOK_ADDRESS};
+        use httpmock::MockServer;
+        use serde_json::{json, Value};
+
+        fn balance_change_json(typename: &str, amount: Float) -> Value {
+            json!({
+                "__typename": typename,
+                "amount": amount,
+                "newVaultBalance": F0,
+                "oldVaultBalance": F0,
+                "vault": {
+                    "id": "0x0123",
+                    "vaultId": "0x0123",
+                    "token": {
+                        "id": "token1",
+                        "address": "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d",
+                        "name": "Token 1",
+                        "symbol": "TKN1",
+                        "decimals": "18"
+                    }
+                },
+                "timestamp": "1734054063",
+                "transaction": {
+                    "id": "0x85857b5c6d0b277f9e971b6b45cab98720f90b8f24d65df020776d675b71fc22",
+                    "from": "0x7177b9d00bb5dbcaaf069cc63190902763783b09",
+                    "blockNumber": "34407047",
+                    "timestamp": "1734054063"
+                },
+                "orderbook": {
+                    "id": CHAIN_ID_1_ORDERBOOK_ADDRESS
+                }
+            })
+        }
+
+        #[tokio::test]
+        async fn test_get_portfolio() {
+            let sg_server = MockServer::start_async().await;
+            sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains("\"skip\":0")
+                    .body_contains("vaultBalanceChanges");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vaultBalanceChanges": [
+                            balance_change_json("Deposit", F3),
+                            balance_change_json("Withdrawal", negative(F1)),
+                        ]
+                    }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg1").body_contains("vaultBalanceChanges");
+                then.status(200)
+                    .json_body_obj(&json!({"data": {"vaultBalanceChanges": []}}));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains("0x0000000000000000000000000000000000000001");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vaults": [{
+                            "id": "0x0123",
+                            "owner": "0x0000000000000000000000000000000000000001",
+                            "vaultId": "0x0123",
+                            "balance": F2,
+                            "token": {
+                                "id": "token1",
+                                "address": "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d",
+                                "name": "Token 1",
+                                "symbol": "TKN1",
+                                "decimals": "18"
+                            },
+                            "orderbook": {
+                                "id": CHAIN_ID_1_ORDERBOOK_ADDRESS
+                            },
+                            "ordersAsOutput": [],
+                            "ordersAsInput": [],
+                            "balanceChanges": []
+                        }]
+                    }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+            let portfolio = raindex_client
+                .get_portfolio(
+                    Address::from_str("0x0000000000000000000000000000000000000001").unwrap(),
+                    Some(vec![1]),
+                )
+                .await
+                .unwrap();
+
+            assert!(portfolio.failed_vaults.is_empty());
+            assert!(portfolio.failed_tokens.is_empty());
+            assert_eq!(portfolio.subgraph_statuses.len(), 1);
+            assert!(portfolio.subgraph_statuses[0].is_ok());
+            assert_eq!(portfolio.tokens.len(), 1);
+
+            let token = &portfolio.tokens[0];
+            assert_eq!(token.chain_id, 1);
+            assert_eq!(token.symbol.as_deref(), Some("TKN1"));
+            assert_eq!(token.formatted_vault_balance, "2");
+            assert_eq!(token.formatted_deposits, "3");
+            assert_eq!(token.formatted_withdrawals, "1");
+            assert_eq!(token.formatted_net_deposits, "2");
+            // The rpc is not mocked, so the vault balance is the total
+            assert!(token.wallet_balance_error.is_some());
+            assert_eq!(token.formatted_total_balance, "2");
+
+            assert_eq!(portfolio.totals.chain_ids, vec![1]);
+            assert_eq!(portfolio.totals.vault_count, 1);
+            assert_eq!(portfolio.totals.deposit_count, 1);
+            assert_eq!(portfolio.totals.withdrawal_count, 1);
+        }
+
+        #[tokio::test]
+        async fn test_get_portfolio_reports_failed_tokens_and_vaults() {
+            let sg_server = MockServer::start_async().await;
+            sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains("\"skip\":0")
+                    .body_contains("vaultBalanceChanges");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vaultBalanceChanges": [
+                            balance_change_json("Deposit", F3),
+                            balance_change_json("Bogus", F1),
+                        ]
+                    }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg1").body_contains("vaultBalanceChanges");
+                then.status(200)
+                    .json_body_obj(&json!({"data": {"vaultBalanceChanges": []}}));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains("0x0000000000000000000000000000000000000001");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vaults": [
+                            {
+                                "id": "0x0123",
+                                "owner": "0x0000000000000000000000000000000000000001",
+                                "vaultId": "0x0123",
+                                "balance": F2,
+                                "token": {
+                                    "id": "token1",
+                                    "address": "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d",
+                                    "name": "Token 1",
+                                    "symbol": "TKN1",
+                                    "decimals": "18"
+                                },
+                                "orderbook": {
+                                    "id": CHAIN_ID_1_ORDERBOOK_ADDRESS
+                                },
+                                "ordersAsOutput": [],
+                                "ordersAsInput": [],
+                                "balanceChanges": []
+                            },
+                            {
+                                "id": "0x0456",
+                                "owner": "0x0000000000000000000000000000000000000001",
+                                "vaultId": "0x0456",
+                                "balance": F1,
+                                "token": {
+                                    "id": "token2",
+                                    "address": "0x12e605bc104e93b45e1ad99f9e555f659051c2bb",
+                                    "name": "Token 2",
+                                    "symbol": "TKN2"
+                                },
+                                "orderbook": {
+                                    "id": CHAIN_ID_1_ORDERBOOK_ADDRESS
+                                },
+                                "ordersAsOutput": [],
+                                "ordersAsInput": [],
+                                "balanceChanges": []
+                            }
+                        ]
+                    }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+            let portfolio = raindex_client
+                .get_portfolio(
+                    Address::from_str("0x0000000000000000000000000000000000000001").unwrap(),
+                    Some(vec![1]),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(portfolio.failed_tokens.len(), 1);
+            assert_eq!(
+                portfolio.failed_tokens[0].token,
+                Address::from_str("0x12e605bc104e93b45e1ad99f9e555f659051c2bb").unwrap()
+            );
+
+            assert_eq!(portfolio.failed_vaults.len(), 1);
+            assert_eq!(
+                portfolio.failed_vaults[0].id,
+                Bytes::from_str("0x0123").unwrap()
+            );
+
+            // The vault balance is kept but none of the unreadable flows
+            assert_eq!(portfolio.tokens.len(), 1);
+            let token = &portfolio.tokens[0];
+            assert_eq!(token.formatted_vault_balance, "2");
+            assert_eq!(token.formatted_deposits, "0");
+            assert_eq!(portfolio.totals.deposit_count, 0);
+        }
+    }
+}
@@ -45,10 +45,15 @@ impl RaindexClient {
             unchecked_param_type = "Hex"
         )]
         tx_hash: String,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Return the orders as they were at this block (optional, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
     ) -> Result<Vec<RaindexOrder>, RaindexError> {
         let orderbook_address = Address::from_str(&orderbook_address)?;
         let tx_hash = Bytes::from_str(&tx_hash)?;
-        self.get_remove_orders_for_transaction(chain_id, orderbook_address, tx_hash)
+        self.get_remove_orders_for_transaction(chain_id, orderbook_address, tx_hash, block_number)
             .await
     }
 }
@@ -58,9 +63,13 @@ impl RaindexClient {
         chain_id: u32,
         orderbook_address: Address,
         tx_hash: Bytes,
+        block_number: Option<u64>,
     ) -> Result<Vec<RaindexOrder>, RaindexError> {
         let raindex_client = Arc::new(RwLock::new(self.clone()));
-        let client = self.get_orderbook_client(orderbook_address)?;
+        let token_cfgs = self.get_token_cfgs();
+        let client = self
+            .get_orderbook_client(orderbook_address)?
+            .at_block(block_number);
 
         let orders = client
             .transaction_remove_orders(Id::new(tx_hash.to_string()))
@@ -69,12 +78,14 @@ impl RaindexClient {
         let orders = orders
             .into_iter()
             .map(|value| {
-                RaindexOrder::try_from_sg_order(
+                Ok(RaindexOrder::try_from_sg_order_with_token_cfgs(
                     raindex_client.clone(),
                     chain_id,
                     value.order,
                     Some(value.transaction.try_into()?),
-                )
+                    &token_cfgs,
+                )?
+                .at_block(block_number))
             })
             .collect::<Result<Vec<RaindexOrder>, RaindexError>>()?;
         Ok(orders)
@@ -251,6 +262,7 @@ mod tests {
                     1,
                     Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
                     Bytes::from_str("0x0123").unwrap(),
+                    None,
                 )
                 .await
                 .unwrap();
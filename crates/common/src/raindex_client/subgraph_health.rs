@@ -0,0 +1,460 @@
+use super::*;
+use alloy::providers::Provider;
+use rain_orderbook_app_settings::network::NetworkCfg;
+use rain_orderbook_bindings::provider::mk_read_provider;
+use rain_orderbook_subgraph_client::{types::meta::SgMeta, MultiSubgraphStatus};
+use std::time::Duration;
+
+const DEFAULT_WAIT_TIMEOUT_MS: u64 = 60_000;
+const WAIT_POLL_INTERVAL_MS: u64 = 2_000;
+
+/// Latest block indexed by an orderbook subgraph
+///
+/// Returned by the order, vault and trade list reads when `includeMeta` is
+/// set, the list is read at the reported block. A subgraph whose block cannot
+/// be read is reported in the subgraph statuses of multi-network reads. Reads
+/// of a single order, vault or transaction don't report it, pin them to a
+/// block with `blockNumber` or call `waitForIndexedBlock` first.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexSubgraphMeta {
+    pub chain_id: u32,
+    #[tsify(type = "string")]
+    pub subgraph_url: Url,
+    pub indexed_block: u64,
+    pub has_indexing_errors: bool,
+}
+impl_wasm_traits!(RaindexSubgraphMeta);
+impl RaindexSubgraphMeta {
+    fn from_sg_meta(chain_id: u32, subgraph_url: Url, meta: SgMeta) -> Self {
+        Self {
+            chain_id,
+            subgraph_url,
+            indexed_block: meta.indexed_block(),
+            has_indexing_errors: meta.has_indexing_errors,
+        }
+    }
+
+    pub(crate) async fn fetch(
+        chain_id: u32,
+        client: &OrderbookSubgraphClient,
+    ) -> Result<Self, RaindexError> {
+        let meta = client.meta().await?;
+        Ok(Self::from_sg_meta(chain_id, client.url().clone(), meta))
+    }
+}
+
+/// How far a subgraph lags behind the chain head of its network
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct RaindexSubgraphHealth {
+    pub chain_id: u32,
+    pub network_key: String,
+    pub subgraph_key: String,
+    #[tsify(type = "string")]
+    pub subgraph_url: Url,
+    #[tsify(optional)]
+    pub indexed_block: Option<u64>,
+    #[tsify(optional)]
+    pub head_block: Option<u64>,
+    /// Blocks the subgraph is behind the RPC head, set when both are known
+    #[tsify(optional)]
+    pub lag_blocks: Option<u64>,
+    pub has_indexing_errors: bool,
+    #[tsify(optional)]
+    pub error: Option<String>,
+}
+impl_wasm_traits!(RaindexSubgraphHealth);
+
+#[wasm_export]
+impl RaindexClient {
+    /// Compares the block indexed by every configured subgraph with the RPC head
+    ///
+    /// Use it to warn users that balances and orders may be stale. A subgraph
+    /// or RPC that cannot be reached is reported in `error` instead of failing
+    /// the whole call.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await client.getSubgraphHealth([137]);
+    /// if (result.error) {
+    ///   console.error("Cannot check subgraphs:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// for (const health of result.value) {
+    ///   if (health.lagBlocks > 10 || health.hasIndexingErrors) {
+    ///     // Show a stale data warning
+    ///   }
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "getSubgraphHealth",
+        return_description = "Indexing status of each configured subgraph",
+        unchecked_return_type = "RaindexSubgraphHealth[]"
+    )]
+    pub async fn get_subgraph_health(
+        &self,
+        #[wasm_export(
+            js_name = "chainIds",
+            param_description = "Networks to check (optional, checks all networks if not specified)"
+        )]
+        chain_ids: Option<ChainIds>,
+    ) -> Result<Vec<RaindexSubgraphHealth>, RaindexError> {
+        let mut networks = match chain_ids {
+            Some(ids) if !ids.0.is_empty() => ids
+                .0
+                .iter()
+                .map(|id| self.orderbook_yaml.get_network_by_chain_id(*id))
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) | None => self.orderbook_yaml.get_networks()?.into_values().collect(),
+        };
+        networks.sort_by_key(|network| network.chain_id);
+
+        let mut checks = vec![];
+        for network in networks {
+            let mut subgraph_keys = vec![];
+            for orderbook in self
+                .orderbook_yaml
+                .get_orderbooks_by_network_key(&network.key)?
+            {
+                if subgraph_keys.contains(&orderbook.subgraph.key) {
+                    continue;
+                }
+                subgraph_keys.push(orderbook.subgraph.key.clone());
+                checks.push(check_subgraph_health(
+                    network.clone(),
+                    orderbook.subgraph.key.clone(),
+                    OrderbookSubgraphClient::with_transport(
+                        orderbook.subgraph.url.clone(),
+                        subgraph_transport_config(&orderbook.subgraph),
+                    ),
+                ));
+            }
+        }
+
+        Ok(futures::future::join_all(checks).await)
+    }
+
+    /// Waits until the subgraph of an orderbook has indexed the given block
+    ///
+    /// Call it with the block of a mined transaction before reloading data so
+    /// the transaction is reflected in the results.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await client.waitForIndexedBlock(
+    ///   137,
+    ///   "0x1234567890abcdef1234567890abcdef12345678",
+    ///   receipt.blockNumber
+    /// );
+    /// if (result.error) {
+    ///   console.error("Subgraph is behind:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// // Reload orders and vaults
+    /// ```
+    #[wasm_export(
+        js_name = "waitForIndexedBlock",
+        return_description = "Subgraph status once the block is indexed",
+        unchecked_return_type = "RaindexSubgraphMeta"
+    )]
+    pub async fn wait_for_indexed_block(
+        &self,
+        #[wasm_export(js_name = "chainId", param_description = "Chain ID of the orderbook")]
+        chain_id: u32,
+        #[wasm_export(
+            js_name = "orderbookAddress",
+            param_description = "Orderbook contract address",
+            unchecked_param_type = "Address"
+        )]
+        orderbook_address: String,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Block the subgraph has to reach"
+        )]
+        block_number: u64,
+        #[wasm_export(
+            js_name = "timeoutMs",
+            param_description = "Maximum time to wait in milliseconds (optional, defaults to 60 seconds)"
+        )]
+        timeout_ms: Option<u64>,
+    ) -> Result<RaindexSubgraphMeta, RaindexError> {
+        // The same address can be used by orderbooks of different networks
+        let orderbook_address = Address::from_str(&orderbook_address)?;
+        let orderbook = self
+            .orderbook_yaml
+            .get_orderbooks()?
+            .into_values()
+            .find(|orderbook| {
+                orderbook.network.chain_id == chain_id && orderbook.address == orderbook_address
+            })
+            .ok_or(RaindexError::OrderbookNotFound(
+                orderbook_address.to_string(),
+                chain_id,
+            ))?;
+        let client = OrderbookSubgraphClient::with_transport(
+            orderbook.subgraph.url.clone(),
+            subgraph_transport_config(&orderbook.subgraph),
+        );
+        let meta = client
+            .wait_for_indexed_block(
+                block_number,
+                Duration::from_millis(WAIT_POLL_INTERVAL_MS),
+                Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_WAIT_TIMEOUT_MS)),
+            )
+            .await?;
+        Ok(RaindexSubgraphMeta::from_sg_meta(
+            chain_id,
+            client.url().clone(),
+            meta,
+        ))
+    }
+}
+/// Indexed blocks of the subgraphs behind a multi-subgraph read
+///
+/// A subgraph whose block cannot be read is left out of `meta` and reported
+/// in `failures`, the read of its data is not affected.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SubgraphMetaRead {
+    pub meta: Vec<RaindexSubgraphMeta>,
+    failures: Vec<MultiSubgraphStatus>,
+    blocks: HashMap<String, u64>,
+}
+impl SubgraphMetaRead {
+    /// Blocks to pin the data of each subgraph to, so the data is read at
+    /// exactly the reported block
+    pub fn subgraph_blocks(&self) -> HashMap<String, u64> {
+        self.blocks.clone()
+    }
+
+    /// Adds the meta failures to the statuses of the data queries
+    pub fn merge_statuses(
+        &self,
+        mut statuses: Vec<MultiSubgraphStatus>,
+    ) -> Vec<MultiSubgraphStatus> {
+        for failure in self.failures.iter() {
+            match statuses.iter_mut().find(|status| {
+                status.url == failure.url && status.subgraph_name == failure.subgraph_name
+            }) {
+                Some(status) => {
+                    if status.error.is_none() {
+                        status.error = failure.error.clone();
+                    }
+                }
+                None => statuses.push(failure.clone()),
+            }
+        }
+        statuses
+    }
+}
+
+impl RaindexClient {
+    /// Reads the indexed block of every subgraph behind a multi-network read
+    pub(crate) async fn get_subgraph_meta(
+        &self,
+        multi_subgraph_args: &BTreeMap<u32, Vec<MultiSubgraphArgs>>,
+    ) -> SubgraphMetaRead {
+        let futures = multi_subgraph_args.iter().flat_map(|(chain_id, args)| {
+            args.iter().map(move |arg| async move {
+                let client =
+                    OrderbookSubgraphClient::with_transport(arg.url.clone(), arg.transport.clone());
+                (arg, RaindexSubgraphMeta::fetch(*chain_id, &client).await)
+            })
+        });
+
+        let mut read = SubgraphMetaRead::default();
+        for (arg, result) in futures::future::join_all(futures).await {
+            match result {
+                Ok(meta) => {
+                    read.blocks.insert(arg.name.clone(), meta.indexed_block);
+                    read.meta.push(meta);
+                }
+                Err(err) => read.failures.push(MultiSubgraphStatus {
+                    subgraph_name: arg.name.clone(),
+                    url: arg.url.clone(),
+                    error: Some(format!("Failed to read the indexed block: {}", err)),
+                    latency_ms: 0,
+                }),
+            }
+        }
+        read
+    }
+}
+
+async fn check_subgraph_health(
+    network: NetworkCfg,
+    subgraph_key: String,
+    client: OrderbookSubgraphClient,
+) -> RaindexSubgraphHealth {
+    let head_block = async {
+        let provider = mk_read_provider(&network.rpcs).map_err(|e| e.to_string())?;
+        provider.get_block_number().await.map_err(|e| e.to_string())
+    };
+    let (meta, head_block) = futures::join!(client.meta(), head_block);
+
+    let mut errors = vec![];
+    let meta = meta
+        .map_err(|e| errors.push(format!("Failed to query subgraph: {e}")))
+        .ok();
+    let head_block = head_block
+        .map_err(|e| errors.push(format!("Failed to query RPC: {e}")))
+        .ok();
+    let indexed_block = meta.as_ref().map(|meta| meta.indexed_block());
+
+    RaindexSubgraphHealth {
+        chain_id: network.chain_id,
+        network_key: network.key,
+        subgraph_key,
+        subgraph_url: client.url().clone(),
+        indexed_block,
+        head_block,
+        lag_blocks: indexed_block
+            .zip(head_block)
+            .map(|(indexed, head)| head.saturating_sub(indexed)),
+        has_indexing_errors: meta.is_some_and(|meta| meta.has_indexing_errors),
+        error: (!errors.is_empty()).then(|| errors.join("; ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(target_family = "wasm"))]
+    use super::*;
+
+    #[cfg(not(target_family = "wasm"))]
+    mod non_wasm {
+        use super::*;
+        use crate::raindex_client::tests::{get_test_yaml, CHAIN_ID_1_ORDERBOOK_ADDRESS};
+        use httpmock::MockServer;
+        use serde_json::json;
+
+        fn meta_json(block: u64) -> serde_json::Value {
+            json!({
+                "data": {
+                    "_meta": {
+                        "block": { "number": block, "hash": null, "timestamp": null },
+                        "deployment": "Qm123",
+                        "hasIndexingErrors": false
+                    }
+                }
+            })
+        }
+
+        #[tokio::test]
+        async fn test_get_subgraph_health() {
+            let server = MockServer::start_async().await;
+            server.mock(|when, then| {
+                when.path("/sg1").body_contains("_meta");
+                then.status(200).json_body_obj(&meta_json(90));
+            });
+            server.mock(|when, then| {
+                when.path("/sg2");
+                then.status(404);
+            });
+            server.mock(|when, then| {
+                when.path("/rpc1").body_contains("eth_blockNumber");
+                then.json_body(json!({ "jsonrpc": "2.0", "id": 1, "result": "0x64" }));
+            });
+            server.mock(|when, then| {
+                when.path("/rpc2").body_contains("eth_blockNumber");
+                then.json_body(json!({ "jsonrpc": "2.0", "id": 1, "result": "0xc8" }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &server.url("/sg1"),
+                    &server.url("/sg2"),
+                    &server.url("/rpc1"),
+                    &server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+
+            let health = raindex_client.get_subgraph_health(None).await.unwrap();
+            assert_eq!(health.len(), 2);
+
+            assert_eq!(health[0].chain_id, 1);
+            assert_eq!(health[0].network_key, "mainnet");
+            assert_eq!(health[0].subgraph_key, "mainnet");
+            assert_eq!(health[0].indexed_block, Some(90));
+            assert_eq!(health[0].head_block, Some(100));
+            assert_eq!(health[0].lag_blocks, Some(10));
+            assert!(!health[0].has_indexing_errors);
+            assert_eq!(health[0].error, None);
+
+            assert_eq!(health[1].chain_id, 137);
+            assert_eq!(health[1].indexed_block, None);
+            assert_eq!(health[1].head_block, Some(200));
+            assert_eq!(health[1].lag_blocks, None);
+            assert!(health[1]
+                .error
+                .as_ref()
+                .unwrap()
+                .starts_with("Failed to query subgraph"));
+
+            let health = raindex_client
+                .get_subgraph_health(Some(ChainIds(vec![137])))
+                .await
+                .unwrap();
+            assert_eq!(health.len(), 1);
+            assert_eq!(health[0].chain_id, 137);
+        }
+
+        #[tokio::test]
+        async fn test_wait_for_indexed_block() {
+            let server = MockServer::start_async().await;
+            server.mock(|when, then| {
+                when.path("/sg1").body_contains("_meta");
+                then.status(200).json_body_obj(&meta_json(120));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &server.url("/sg1"),
+                    &server.url("/sg2"),
+                    "http://localhost:3000",
+                    "http://localhost:3000",
+                )],
+                None,
+            )
+            .unwrap();
+
+            let meta = raindex_client
+                .wait_for_indexed_block(1, CHAIN_ID_1_ORDERBOOK_ADDRESS.to_string(), 100, None)
+                .await
+                .unwrap();
+            assert_eq!(
+                meta,
+                RaindexSubgraphMeta {
+                    chain_id: 1,
+                    subgraph_url: Url::parse(&server.url("/sg1")).unwrap(),
+                    indexed_block: 120,
+                    has_indexing_errors: false,
+                }
+            );
+
+            let err = raindex_client
+                .wait_for_indexed_block(1, CHAIN_ID_1_ORDERBOOK_ADDRESS.to_string(), 200, Some(0))
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                RaindexError::OrderbookSubgraphClientError(
+                    OrderbookSubgraphClientError::IndexingTimeout {
+                        target: 200,
+                        indexed: 120
+                    }
+                )
+            ));
+
+            let err = raindex_client
+                .wait_for_indexed_block(137, CHAIN_ID_1_ORDERBOOK_ADDRESS.to_string(), 100, None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, RaindexError::OrderbookNotFound(_, 137)));
+        }
+    }
+}
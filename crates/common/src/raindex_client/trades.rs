@@ -1,6 +1,7 @@
 use super::*;
 use crate::raindex_client::{
-    orders::RaindexOrder, transactions::RaindexTransaction, vaults::RaindexVaultBalanceChange,
+    orders::RaindexOrder, subgraph_health::RaindexSubgraphMeta, transactions::RaindexTransaction,
+    vaults::RaindexVaultBalanceChange,
 };
 use alloy::primitives::{Address, Bytes, U256};
 use rain_orderbook_subgraph_client::{
@@ -83,6 +84,46 @@ impl RaindexTrade {
     }
 }
 
+/// A page of trades and the cursor to fetch the next one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexTradesPage {
+    trades: Vec<RaindexTrade>,
+    next_cursor: Option<String>,
+    subgraph_meta: Vec<RaindexSubgraphMeta>,
+}
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexTradesPage {
+    #[wasm_bindgen(getter)]
+    pub fn trades(&self) -> Vec<RaindexTrade> {
+        self.trades.clone()
+    }
+    #[wasm_bindgen(getter = nextCursor)]
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    #[wasm_bindgen(getter = subgraphMeta)]
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RaindexTradesPage {
+    pub fn trades(&self) -> Vec<RaindexTrade> {
+        self.trades.clone()
+    }
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+}
+
 #[wasm_export]
 impl RaindexOrder {
     /// Fetches trade history with optional time filtering
@@ -131,6 +172,7 @@ impl RaindexOrder {
                 SgPaginationArgs {
                     page: page.unwrap_or(1),
                     page_size: DEFAULT_PAGE_SIZE,
+                    cursor: None,
                 },
                 start_timestamp,
                 end_timestamp,
@@ -144,6 +186,83 @@ impl RaindexOrder {
         Ok(trades)
     }
 
+    /// Fetches one page of trades for infinite scrolling
+    ///
+    /// Works like `getTradesList`, but continues from the cursor returned with
+    /// the previous page instead of a page number.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await order.getTradesPage(undefined, undefined, cursor);
+    /// if (result.error) {
+    ///   console.error("Cannot fetch trades:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { trades, nextCursor } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getTradesPage",
+        return_description = "Page of trade records and the cursor of the next page",
+        unchecked_return_type = "RaindexTradesPage",
+        preserve_js_class
+    )]
+    pub async fn get_trades_page(
+        &self,
+        #[wasm_export(
+            js_name = "startTimestamp",
+            param_description = "Optional start time filter (Unix timestamp in seconds)"
+        )]
+        start_timestamp: Option<u64>,
+        #[wasm_export(
+            js_name = "endTimestamp",
+            param_description = "Optional end time filter (Unix timestamp in seconds)"
+        )]
+        end_timestamp: Option<u64>,
+        #[wasm_export(param_description = "Cursor returned with the previous page (optional)")]
+        cursor: Option<String>,
+        #[wasm_export(
+            js_name = "includeMeta",
+            param_description = "Also return the block indexed by the queried subgraphs (optional)"
+        )]
+        include_meta: Option<bool>,
+    ) -> Result<RaindexTradesPage, RaindexError> {
+        let client = self.get_orderbook_client()?;
+        // The data is read at the reported block
+        let (client, subgraph_meta) = if include_meta.unwrap_or(false) {
+            let meta = RaindexSubgraphMeta::fetch(self.chain_id(), &client).await?;
+            (client.at_block(Some(meta.indexed_block)), vec![meta])
+        } else {
+            (client, vec![])
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: DEFAULT_PAGE_SIZE,
+            cursor,
+        };
+        let trades = client
+            .order_trades_list(
+                Id::new(self.id().to_string()),
+                pagination_args.clone(),
+                start_timestamp,
+                end_timestamp,
+            )
+            .await?;
+        let next_cursor = pagination_args
+            .next_cursor(&trades)
+            .map_err(OrderbookSubgraphClientError::from)?;
+
+        let trades = trades
+            .into_iter()
+            .map(|trade| RaindexTrade::try_from_sg_trade(self.chain_id(), trade))
+            .collect::<Result<Vec<RaindexTrade>, RaindexError>>()?;
+        Ok(RaindexTradesPage {
+            trades,
+            next_cursor,
+            subgraph_meta,
+        })
+    }
+
     /// Fetches detailed information for a specific trade
     ///
     /// Retrieves complete information about a single trade including vault changes
@@ -270,6 +389,7 @@ mod test_helpers {
         use httpmock::MockServer;
         use rain_math_float::Float;
         use rain_orderbook_subgraph_client::utils::float::*;
+        use rain_orderbook_subgraph_client::SgPaginationCursor;
         use serde_json::{json, Value};
 
         fn get_order1_json() -> Value {
@@ -744,6 +864,67 @@ mod test_helpers {
             assert_eq!(trade2.id(), Bytes::from_str("0x0234").unwrap());
         }
 
+        #[tokio::test]
+        async fn test_get_order_trades_page() {
+            let sg_server = MockServer::start_async().await;
+            let trades_mock = sg_server.mock(|when, then| {
+                when.path("/sg")
+                    .body_contains("SgOrderTradesListQuery")
+                    .body_contains("\"timestampLte\":\"1700086400\"")
+                    .body_contains("\"excludeIds\":[\"0x0345\"]");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "trades": get_trades_json()
+                    }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg").body_contains("SgOrderDetailByHashQuery");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "orders": [get_order1_json()]
+                    }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg"),
+                    "http://localhost:3000",
+                    "http://localhost:3000",
+                    "http://localhost:3000",
+                )],
+                None,
+            )
+            .unwrap();
+            let order = raindex_client
+                .get_order_by_hash(
+                    1,
+                    Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
+                    Bytes::from_str("0x0123").unwrap(),
+                )
+                .await
+                .unwrap();
+
+            let cursor = SgPaginationCursor::Timestamp {
+                timestamp: "1700086400".to_string(),
+                ids: vec!["0x0345".to_string()],
+            };
+            let page = order
+                .get_trades_page(None, None, Some(cursor.encode()), None)
+                .await
+                .unwrap();
+            trades_mock.assert();
+            assert_eq!(page.trades().len(), 2);
+            assert_eq!(page.next_cursor(), None);
+
+            let err = order
+                .get_trades_page(None, None, Some("invalid".to_string()), None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, RaindexError::OrderbookSubgraphClientError(_)));
+        }
+
         #[tokio::test]
         async fn test_get_order_trade_detail() {
             let sg_server = MockServer::start_async().await;
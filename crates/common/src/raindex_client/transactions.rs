@@ -93,10 +93,16 @@ impl RaindexClient {
             unchecked_param_type = "Hex"
         )]
         tx_hash: String,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Read the transaction from the subgraph as it was at this block (optional, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
     ) -> Result<RaindexTransaction, RaindexError> {
         let orderbook_address = Address::from_str(&orderbook_address)?;
         let tx_hash = Bytes::from_str(&tx_hash)?;
-        self.get_transaction(orderbook_address, tx_hash).await
+        self.get_transaction(orderbook_address, tx_hash, block_number)
+            .await
     }
 }
 impl RaindexClient {
@@ -104,8 +110,11 @@ impl RaindexClient {
         &self,
         orderbook_address: Address,
         tx_hash: Bytes,
+        block_number: Option<u64>,
     ) -> Result<RaindexTransaction, RaindexError> {
-        let client = self.get_orderbook_client(orderbook_address)?;
+        let client = self
+            .get_orderbook_client(orderbook_address)?
+            .at_block(block_number);
         let transaction = client
             .transaction_detail(Id::new(tx_hash.to_string()))
             .await?;
@@ -168,6 +177,7 @@ mod test_helpers {
                 .get_transaction(
                     Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
                     Bytes::from_str("0x0123").unwrap(),
+                    None,
                 )
                 .await
                 .unwrap();
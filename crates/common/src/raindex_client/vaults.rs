@@ -3,7 +3,10 @@ use crate::{
     deposit::DepositArgs,
     erc20::ERC20,
     raindex_client::{
-        orders::RaindexOrderAsIO, transactions::RaindexTransaction, vaults_list::RaindexVaultsList,
+        orders::{addresses_to_lowercase, RaindexOrderAsIO},
+        subgraph_health::{RaindexSubgraphMeta, SubgraphMetaRead},
+        transactions::RaindexTransaction,
+        vaults_list::RaindexVaultsList,
     },
     transaction::TransactionArgs,
     utils::amount_formatter::format_amount_u256,
@@ -18,8 +21,9 @@ use rain_orderbook_subgraph_client::{
     // performance::vol::{VaultVolume, VolumeDetails},
     types::{
         common::{
-            SgBigInt, SgBytes, SgErc20, SgOrderAsIO, SgOrderbook, SgTradeVaultBalanceChange,
-            SgVault, SgVaultBalanceChangeUnwrapped, SgVaultsListFilterArgs,
+            SgBigInt, SgBytes, SgClearBounty, SgErc20, SgOrderAsIO, SgOrderbook, SgSortDirection,
+            SgTradeVaultBalanceChange, SgVault, SgVaultBalanceChangeUnwrapped,
+            SgVaultWithSubgraphName, SgVaultsListFilterArgs, SgVaultsSortBy,
         },
         Id,
     },
@@ -69,6 +73,7 @@ pub struct RaindexVault {
     orderbook: Address,
     orders_as_inputs: Vec<RaindexOrderAsIO>,
     orders_as_outputs: Vec<RaindexOrderAsIO>,
+    block_number: Option<u64>,
 }
 
 #[cfg(target_family = "wasm")]
@@ -123,6 +128,11 @@ impl RaindexVault {
     pub fn orders_as_outputs(&self) -> Vec<RaindexOrderAsIO> {
         self.orders_as_outputs.clone()
     }
+    /// Block the vault was fetched at, `undefined` for the latest indexed state
+    #[wasm_bindgen(getter = blockNumber)]
+    pub fn block_number(&self) -> Option<u64> {
+        self.block_number
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -160,6 +170,9 @@ impl RaindexVault {
     pub fn orders_as_outputs(&self) -> Vec<RaindexOrderAsIO> {
         self.orders_as_outputs.clone()
     }
+    pub fn block_number(&self) -> Option<u64> {
+        self.block_number
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -216,6 +229,9 @@ pub struct RaindexVaultToken {
     name: Option<String>,
     symbol: Option<String>,
     decimals: u8,
+    logo_uri: Option<String>,
+    tags: Option<Vec<String>>,
+    bridged_addresses: Option<BTreeMap<u32, Address>>,
 }
 
 #[cfg(target_family = "wasm")]
@@ -245,6 +261,21 @@ impl RaindexVaultToken {
     pub fn decimals(&self) -> u8 {
         self.decimals
     }
+    #[wasm_bindgen(getter = logoUri)]
+    pub fn logo_uri(&self) -> Option<String> {
+        self.logo_uri.clone()
+    }
+    #[wasm_bindgen(getter)]
+    pub fn tags(&self) -> Option<Vec<String>> {
+        self.tags.clone()
+    }
+    #[wasm_bindgen(
+        getter = bridgedAddresses,
+        unchecked_return_type = "Record<number, Address> | undefined"
+    )]
+    pub fn bridged_addresses(&self) -> Result<JsValue, RaindexError> {
+        Ok(to_js_value(&self.bridged_addresses)?)
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -267,6 +298,15 @@ impl RaindexVaultToken {
     pub fn decimals(&self) -> u8 {
         self.decimals
     }
+    pub fn logo_uri(&self) -> Option<String> {
+        self.logo_uri.clone()
+    }
+    pub fn tags(&self) -> Option<Vec<String>> {
+        self.tags.clone()
+    }
+    pub fn bridged_addresses(&self) -> Option<BTreeMap<u32, Address>> {
+        self.bridged_addresses.clone()
+    }
 }
 
 #[wasm_export]
@@ -277,7 +317,9 @@ impl RaindexVault {
             .raindex_client
             .read()
             .map_err(|_| YamlError::ReadLockError)?;
-        raindex_client.get_orderbook_client(self.orderbook)
+        Ok(raindex_client
+            .get_orderbook_client(self.orderbook)?
+            .at_block(self.block_number))
     }
 
     /// Fetches balance change history for a vault
@@ -313,6 +355,7 @@ impl RaindexVault {
                 SgPaginationArgs {
                     page: page.unwrap_or(1),
                     page_size: 1000,
+                    cursor: None,
                 },
             )
             .await?;
@@ -326,6 +369,70 @@ impl RaindexVault {
         Ok(balance_changes)
     }
 
+    /// Fetches one page of balance changes for infinite scrolling
+    ///
+    /// Works like `getBalanceChanges`, but continues from the cursor returned
+    /// with the previous page instead of a page number.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await vault.getBalanceChangesPage(cursor);
+    /// if (result.error) {
+    ///   console.error("Error fetching history:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const { balanceChanges, nextCursor } = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getBalanceChangesPage",
+        return_description = "Page of balance change events and the cursor of the next page",
+        unchecked_return_type = "RaindexVaultBalanceChangesPage",
+        preserve_js_class
+    )]
+    pub async fn get_balance_changes_page(
+        &self,
+        #[wasm_export(param_description = "Cursor returned with the previous page (optional)")]
+        cursor: Option<String>,
+        #[wasm_export(
+            js_name = "includeMeta",
+            param_description = "Also return the block indexed by the queried subgraphs (optional)"
+        )]
+        include_meta: Option<bool>,
+    ) -> Result<RaindexVaultBalanceChangesPage, RaindexError> {
+        let client = self.get_orderbook_client()?;
+        // The data is read at the reported block
+        let (client, subgraph_meta) = if include_meta.unwrap_or(false) {
+            let meta = RaindexSubgraphMeta::fetch(self.chain_id, &client).await?;
+            (client.at_block(Some(meta.indexed_block)), vec![meta])
+        } else {
+            (client, vec![])
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: DEFAULT_PAGE_SIZE,
+            cursor,
+        };
+        let balance_changes = client
+            .vault_balance_changes_list(Id::new(self.id.to_string()), pagination_args.clone())
+            .await?;
+        let next_cursor = pagination_args
+            .next_cursor(&balance_changes)
+            .map_err(OrderbookSubgraphClientError::from)?;
+
+        let balance_changes = balance_changes
+            .into_iter()
+            .map(|balance_change| {
+                RaindexVaultBalanceChange::try_from_sg_balance_change(self.chain_id, balance_change)
+            })
+            .collect::<Result<Vec<RaindexVaultBalanceChange>, RaindexError>>()?;
+        Ok(RaindexVaultBalanceChangesPage {
+            balance_changes,
+            next_cursor,
+            subgraph_meta,
+        })
+    }
+
     fn validate_amount(&self, amount: Float) -> Result<(), RaindexError> {
         let zero_float = Float::parse("0".to_string())?;
         if amount.is_zero()? {
@@ -762,6 +869,38 @@ impl RaindexVaultBalanceChange {
     }
 }
 
+impl RaindexVaultBalanceChange {
+    pub fn try_from_sg_clear_bounty(
+        chain_id: u32,
+        bounty: SgClearBounty,
+    ) -> Result<Self, RaindexError> {
+        let token = RaindexVaultToken::try_from_sg_erc20(chain_id, bounty.vault.token)?;
+
+        let amount = Float::from_hex(&bounty.amount.0)?;
+        let new_balance = Float::from_hex(&bounty.new_vault_balance.0)?;
+        let old_balance = Float::from_hex(&bounty.old_vault_balance.0)?;
+
+        let formatted_amount = amount.format18()?;
+        let formatted_new_balance = new_balance.format18()?;
+        let formatted_old_balance = old_balance.format18()?;
+
+        Ok(Self {
+            r#type: bounty.__typename.try_into()?,
+            vault_id: U256::from_str(&bounty.vault.vault_id.0)?,
+            token,
+            amount,
+            formatted_amount,
+            new_balance,
+            formatted_new_balance,
+            old_balance,
+            formatted_old_balance,
+            timestamp: U256::from_str(&bounty.timestamp.0)?,
+            transaction: RaindexTransaction::try_from(bounty.transaction)?,
+            orderbook: Address::from_str(&bounty.orderbook.id.0)?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[wasm_bindgen]
@@ -932,7 +1071,10 @@ impl RaindexClient {
     /// Fetches vault data from multiple subgraphs across different networks
     ///
     /// Queries multiple subgraphs simultaneously to retrieve vault information
-    /// across different networks.
+    /// across different networks. When a block number is given, the vaults of a
+    /// single network are returned as they were at that block, e.g. for month-end
+    /// statements. With `includeMeta` the block indexed by each queried subgraph is
+    /// returned alongside the vaults.
     ///
     /// ## Examples
     ///
@@ -947,12 +1089,13 @@ impl RaindexClient {
     ///   console.error("Error fetching vaults:", result.error.readableMsg);
     ///   return;
     /// }
-    /// const vaults = result.value;
-    /// // Do something with the vaults
+    /// const vaultsList = result.value;
+    /// // Do something with vaultsList.items, and report vaultsList.subgraphStatuses
+    /// // that have an error
     /// ```
     #[wasm_export(
         js_name = "getVaults",
-        return_description = "Array of raindex vault instances",
+        return_description = "Raindex vaults merged from all networks and the status of each subgraph",
         preserve_js_class
     )]
     pub async fn get_vaults(
@@ -969,48 +1112,123 @@ impl RaindexClient {
         #[wasm_export(param_description = "Optional page number (defaults to 1)")] page: Option<
             u16,
         >,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Return the vaults as they were at this block (optional, requires a single chain, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
+        #[wasm_export(
+            js_name = "includeMeta",
+            param_description = "Also return the block indexed by the queried subgraphs (optional)"
+        )]
+        include_meta: Option<bool>,
     ) -> Result<RaindexVaultsList, RaindexError> {
-        let raindex_client = Arc::new(RwLock::new(self.clone()));
         let multi_subgraph_args =
             self.get_multi_subgraph_args(chain_ids.map(|ids| ids.0.to_vec()))?;
-        let client = MultiOrderbookSubgraphClient::new(
-            multi_subgraph_args.values().flatten().cloned().collect(),
-        );
+        // The data of each subgraph is read at the block reported for it
+        let subgraph_meta = if include_meta.unwrap_or(false) {
+            self.get_subgraph_meta(&multi_subgraph_args).await
+        } else {
+            SubgraphMetaRead::default()
+        };
+        let client = self
+            .get_multi_orderbook_client(&multi_subgraph_args, block_number)?
+            .at_subgraph_blocks(subgraph_meta.subgraph_blocks());
 
-        let vaults = client
+        let result = client
             .vaults_list(
-                filters
-                    .unwrap_or(GetVaultsFilters {
-                        owners: vec![],
-                        hide_zero_balance: false,
-                        tokens: None,
-                    })
-                    .try_into()?,
+                filters.unwrap_or_default().try_into()?,
                 SgPaginationArgs {
                     page: page.unwrap_or(1),
                     page_size: DEFAULT_PAGE_SIZE,
+                    cursor: None,
                 },
             )
             .await;
 
-        let vaults = vaults
-            .iter()
-            .map(|vault| {
-                let chain_id = multi_subgraph_args
-                    .iter()
-                    .find(|(_, args)| args.iter().any(|arg| arg.name == vault.subgraph_name))
-                    .map(|(chain_id, _)| *chain_id)
-                    .unwrap();
-                let vault = RaindexVault::try_from_sg_vault(
-                    raindex_client.clone(),
-                    chain_id,
-                    vault.vault.clone(),
-                    None,
-                )?;
-                Ok(vault)
-            })
-            .collect::<Result<Vec<RaindexVault>, RaindexError>>()?;
-        Ok(RaindexVaultsList::new(vaults))
+        let vaults = self.vaults_from_sg_vaults(&multi_subgraph_args, result.data, block_number)?;
+        Ok(RaindexVaultsList::new(vaults)
+            .with_subgraph_statuses(subgraph_meta.merge_statuses(result.statuses))
+            .with_subgraph_meta(subgraph_meta.meta))
+    }
+
+    /// Fetches one page of vaults for infinite scrolling
+    ///
+    /// Works like `getVaults`, but continues from the cursor returned with the
+    /// previous page instead of a page number.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// let cursor = undefined;
+    /// do {
+    ///   const result = await client.getVaultsPage(undefined, { owners: [] }, cursor);
+    ///   if (result.error) {
+    ///     console.error("Error fetching vaults:", result.error.readableMsg);
+    ///     return;
+    ///   }
+    ///   // Do something with result.value.vaults
+    ///   cursor = result.value.nextCursor;
+    /// } while (cursor);
+    /// ```
+    #[wasm_export(
+        js_name = "getVaultsPage",
+        return_description = "Page of raindex vaults and the cursor of the next page",
+        preserve_js_class
+    )]
+    pub async fn get_vaults_page(
+        &self,
+        #[wasm_export(
+            js_name = "chainIds",
+            param_description = "Specific networks to query (optional)"
+        )]
+        chain_ids: Option<ChainIds>,
+        #[wasm_export(
+            param_description = "Optional filtering options including owners and hide_zero_balance"
+        )]
+        filters: Option<GetVaultsFilters>,
+        #[wasm_export(param_description = "Cursor returned with the previous page (optional)")]
+        cursor: Option<String>,
+        #[wasm_export(
+            js_name = "includeMeta",
+            param_description = "Also return the block indexed by the queried subgraphs (optional)"
+        )]
+        include_meta: Option<bool>,
+    ) -> Result<RaindexVaultsPage, RaindexError> {
+        let multi_subgraph_args =
+            self.get_multi_subgraph_args(chain_ids.map(|ids| ids.0.to_vec()))?;
+        // The data of each subgraph is read at the block reported for it
+        let subgraph_meta = if include_meta.unwrap_or(false) {
+            self.get_subgraph_meta(&multi_subgraph_args).await
+        } else {
+            SubgraphMetaRead::default()
+        };
+        let client = MultiOrderbookSubgraphClient::new(
+            multi_subgraph_args.values().flatten().cloned().collect(),
+        )
+        .at_subgraph_blocks(subgraph_meta.subgraph_blocks());
+
+        let result = client
+            .vaults_page(
+                filters.unwrap_or_default().try_into()?,
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: DEFAULT_PAGE_SIZE,
+                    cursor,
+                },
+            )
+            .await?;
+
+        Ok(RaindexVaultsPage {
+            vaults: RaindexVaultsList::new(self.vaults_from_sg_vaults(
+                &multi_subgraph_args,
+                result.data.items,
+                None,
+            )?)
+            .with_subgraph_statuses(subgraph_meta.merge_statuses(result.statuses)),
+            next_cursor: result.data.next_cursor,
+            subgraph_meta: subgraph_meta.meta,
+        })
     }
 
     /// Fetches detailed information for a specific vault
@@ -1056,10 +1274,16 @@ impl RaindexClient {
             unchecked_param_type = "Hex"
         )]
         vault_id: String,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Return the vault as it was at this block (optional, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
     ) -> Result<RaindexVault, RaindexError> {
         let orderbook_address = Address::from_str(&orderbook_address)?;
         let vault_id = Bytes::from_str(&vault_id)?;
-        self.get_vault(chain_id, orderbook_address, vault_id).await
+        self.get_vault_at_block(chain_id, orderbook_address, vault_id, block_number)
+            .await
     }
 
     /// Fetches all unique tokens that exist in vaults.
@@ -1091,15 +1315,20 @@ impl RaindexClient {
             param_description = "Specific networks to query (optional)"
         )]
         chain_ids: Option<ChainIds>,
+        #[wasm_export(
+            js_name = "blockNumber",
+            param_description = "Return the tokens known at this block (optional, requires a single chain, defaults to the latest indexed block)"
+        )]
+        block_number: Option<u64>,
     ) -> Result<Vec<RaindexVaultToken>, RaindexError> {
         let multi_subgraph_args =
             self.get_multi_subgraph_args(chain_ids.map(|ids| ids.0.to_vec()))?;
-        let client = MultiOrderbookSubgraphClient::new(
-            multi_subgraph_args.values().flatten().cloned().collect(),
-        );
+        let client = self.get_multi_orderbook_client(&multi_subgraph_args, block_number)?;
 
         let token_list = client.tokens_list().await;
+        let token_cfgs = self.get_token_cfgs();
         let tokens = token_list
+            .data
             .iter()
             .map(|v| {
                 let chain_id = multi_subgraph_args
@@ -1111,7 +1340,8 @@ impl RaindexClient {
                         v.token.address.0.clone(),
                     ))?;
                 let token = RaindexVaultToken::try_from_sg_erc20(chain_id, v.token.clone())?;
-                Ok(token)
+                let token_cfg = token_cfgs.get(&(chain_id, token.address));
+                Ok(token.with_token_cfg(token_cfg))
             })
             .collect::<Result<Vec<RaindexVaultToken>, RaindexError>>()?;
 
@@ -1119,24 +1349,148 @@ impl RaindexClient {
     }
 }
 impl RaindexClient {
+    fn vaults_from_sg_vaults(
+        &self,
+        multi_subgraph_args: &BTreeMap<u32, Vec<MultiSubgraphArgs>>,
+        vaults: Vec<SgVaultWithSubgraphName>,
+        block_number: Option<u64>,
+    ) -> Result<Vec<RaindexVault>, RaindexError> {
+        let raindex_client = Arc::new(RwLock::new(self.clone()));
+        let token_cfgs = self.get_token_cfgs();
+        vaults
+            .into_iter()
+            .map(|vault| {
+                let chain_id = multi_subgraph_args
+                    .iter()
+                    .find(|(_, args)| args.iter().any(|arg| arg.name == vault.subgraph_name))
+                    .map(|(chain_id, _)| *chain_id)
+                    .unwrap();
+                Ok(RaindexVault::try_from_sg_vault_with_token_cfgs(
+                    raindex_client.clone(),
+                    chain_id,
+                    vault.vault,
+                    None,
+                    &token_cfgs,
+                )?
+                .at_block(block_number))
+            })
+            .collect()
+    }
+
     pub async fn get_vault(
         &self,
         chain_id: u32,
         orderbook_address: Address,
         vault_id: Bytes,
     ) -> Result<RaindexVault, RaindexError> {
-        let client = self.get_orderbook_client(orderbook_address)?;
+        self.get_vault_at_block(chain_id, orderbook_address, vault_id, None)
+            .await
+    }
+
+    /// Fetches the vault as it was at the given block, or the latest indexed
+    /// state of the vault when no block is given
+    pub async fn get_vault_at_block(
+        &self,
+        chain_id: u32,
+        orderbook_address: Address,
+        vault_id: Bytes,
+        block_number: Option<u64>,
+    ) -> Result<RaindexVault, RaindexError> {
+        let client = self
+            .get_orderbook_client(orderbook_address)?
+            .at_block(block_number);
         let vault = RaindexVault::try_from_sg_vault(
             Arc::new(RwLock::new(self.clone())),
             chain_id,
             client.vault_detail(Id::new(vault_id.to_string())).await?,
             None,
-        )?;
+        )?
+        .at_block(block_number);
         Ok(vault)
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Tsify)]
+/// A page of vault balance changes and the cursor to fetch the next one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexVaultBalanceChangesPage {
+    balance_changes: Vec<RaindexVaultBalanceChange>,
+    next_cursor: Option<String>,
+    subgraph_meta: Vec<RaindexSubgraphMeta>,
+}
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexVaultBalanceChangesPage {
+    #[wasm_bindgen(getter = balanceChanges)]
+    pub fn balance_changes(&self) -> Vec<RaindexVaultBalanceChange> {
+        self.balance_changes.clone()
+    }
+    #[wasm_bindgen(getter = nextCursor)]
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    #[wasm_bindgen(getter = subgraphMeta)]
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RaindexVaultBalanceChangesPage {
+    pub fn balance_changes(&self) -> Vec<RaindexVaultBalanceChange> {
+        self.balance_changes.clone()
+    }
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+}
+
+/// A page of vaults and the cursor to fetch the next one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[wasm_bindgen]
+pub struct RaindexVaultsPage {
+    vaults: RaindexVaultsList,
+    next_cursor: Option<String>,
+    subgraph_meta: Vec<RaindexSubgraphMeta>,
+}
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+impl RaindexVaultsPage {
+    #[wasm_bindgen(getter)]
+    pub fn vaults(&self) -> RaindexVaultsList {
+        self.vaults.clone()
+    }
+    #[wasm_bindgen(getter = nextCursor)]
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    #[wasm_bindgen(getter = subgraphMeta)]
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RaindexVaultsPage {
+    pub fn vaults(&self) -> RaindexVaultsList {
+        self.vaults.clone()
+    }
+    pub fn next_cursor(&self) -> Option<String> {
+        self.next_cursor.clone()
+    }
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Tsify)]
 #[serde(rename_all = "camelCase")]
 pub struct GetVaultsFilters {
     #[tsify(type = "Address[]")]
@@ -1144,6 +1498,33 @@ pub struct GetVaultsFilters {
     pub hide_zero_balance: bool,
     #[tsify(optional, type = "Address[]")]
     pub tokens: Option<Vec<Address>>,
+    #[serde(default)]
+    #[tsify(optional, type = "Address[]")]
+    pub orderbook_addresses: Option<Vec<Address>>,
+    /// Vaults of any owner except these
+    #[serde(default)]
+    #[tsify(optional, type = "Address[]")]
+    pub exclude_owners: Option<Vec<Address>>,
+    /// Vault ids as decimal or hex strings
+    #[serde(default)]
+    #[tsify(optional, type = "string[]")]
+    pub vault_ids: Option<Vec<U256>>,
+    /// Inclusive lower bound of the balance, as a decimal string
+    #[serde(default)]
+    #[tsify(optional)]
+    pub min_balance: Option<String>,
+    /// Inclusive upper bound of the balance, as a decimal string
+    #[serde(default)]
+    #[tsify(optional)]
+    pub max_balance: Option<String>,
+    /// Balance bounds and sorting by balance fetch every matching vault and
+    /// only work with page numbers
+    #[serde(default)]
+    #[tsify(optional)]
+    pub sort_by: SgVaultsSortBy,
+    #[serde(default)]
+    #[tsify(optional)]
+    pub sort_direction: SgSortDirection,
 }
 impl_wasm_traits!(GetVaultsFilters);
 
@@ -1157,15 +1538,34 @@ impl TryFrom<GetVaultsFilters> for SgVaultsListFilterArgs {
                 .map(|owner| SgBytes(owner.to_string()))
                 .collect(),
             hide_zero_balance: filters.hide_zero_balance,
-            tokens: filters
-                .tokens
-                .map(|tokens| {
-                    tokens
-                        .into_iter()
-                        .map(|token| token.to_string().to_lowercase())
-                        .collect()
-                })
-                .unwrap_or_default(),
+            tokens: addresses_to_lowercase(filters.tokens),
+            orderbooks: addresses_to_lowercase(filters.orderbook_addresses)
+                .into_iter()
+                .map(SgBytes)
+                .collect(),
+            exclude_owners: filters
+                .exclude_owners
+                .unwrap_or_default()
+                .into_iter()
+                .map(|owner| SgBytes(owner.to_string()))
+                .collect(),
+            // Vault ids are stored as 32 byte values
+            vault_ids: filters
+                .vault_ids
+                .unwrap_or_default()
+                .into_iter()
+                .map(|vault_id| SgBytes(format!("{:#066x}", vault_id)))
+                .collect(),
+            min_balance: filters
+                .min_balance
+                .map(|balance| Float::parse(balance.clone()).map(|_| balance))
+                .transpose()?,
+            max_balance: filters
+                .max_balance
+                .map(|balance| Float::parse(balance.clone()).map(|_| balance))
+                .transpose()?,
+            sort_by: filters.sort_by,
+            sort_direction: filters.sort_direction,
         })
     }
 }
@@ -1176,8 +1576,32 @@ impl RaindexVault {
         chain_id: u32,
         vault: SgVault,
         vault_type: Option<RaindexVaultType>,
+    ) -> Result<Self, RaindexError> {
+        let token_cfgs = raindex_client
+            .read()
+            .map_err(|_| YamlError::ReadLockError)?
+            .get_token_cfgs();
+        Self::try_from_sg_vault_with_token_cfgs(
+            raindex_client,
+            chain_id,
+            vault,
+            vault_type,
+            &token_cfgs,
+        )
+    }
+
+    /// Same as `try_from_sg_vault`, with the configured tokens already looked
+    /// up, so converting many vaults reads the yaml once
+    pub(crate) fn try_from_sg_vault_with_token_cfgs(
+        raindex_client: Arc<RwLock<RaindexClient>>,
+        chain_id: u32,
+        vault: SgVault,
+        vault_type: Option<RaindexVaultType>,
+        token_cfgs: &TokenCfgs,
     ) -> Result<Self, RaindexError> {
         let token = RaindexVaultToken::try_from_sg_erc20(chain_id, vault.token)?;
+        let token_cfg = token_cfgs.get(&(chain_id, token.address));
+        let token = token.with_token_cfg(token_cfg);
 
         let balance = Float::from_hex(&vault.balance.0)?;
         let formatted_balance = balance.format18()?;
@@ -1203,9 +1627,17 @@ impl RaindexVault {
                 .iter()
                 .map(|order| RaindexOrderAsIO::try_from(order.clone()))
                 .collect::<Result<Vec<RaindexOrderAsIO>, RaindexError>>()?,
+            block_number: None,
         })
     }
 
+    /// Pins the vault to the block it was fetched at, so the queries made
+    /// from it read the same state
+    pub(crate) fn at_block(mut self, block_number: Option<u64>) -> Self {
+        self.block_number = block_number;
+        self
+    }
+
     pub fn with_vault_type(&self, vault_type: RaindexVaultType) -> Self {
         Self {
             raindex_client: self.raindex_client.clone(),
@@ -1220,6 +1652,7 @@ impl RaindexVault {
             orderbook: self.orderbook,
             orders_as_inputs: self.orders_as_inputs.clone(),
             orders_as_outputs: self.orders_as_outputs.clone(),
+            block_number: self.block_number,
         }
     }
 
@@ -1263,8 +1696,20 @@ impl RaindexVaultToken {
             name: erc20.name,
             symbol: erc20.symbol,
             decimals,
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         })
     }
+
+    fn with_token_cfg(mut self, token_cfg: Option<&TokenCfg>) -> Self {
+        if let Some(token_cfg) = token_cfg {
+            self.logo_uri = token_cfg.logo_uri.clone();
+            self.tags = token_cfg.tags.clone();
+            self.bridged_addresses = token_cfg.bridged_addresses.clone();
+        }
+        self
+    }
 }
 impl TryFrom<RaindexVaultToken> for SgErc20 {
     type Error = RaindexError;
@@ -1377,7 +1822,7 @@ mod tests {
             .unwrap();
 
             let result = raindex_client
-                .get_vaults(None, None, None)
+                .get_vaults(None, None, None, None, None)
                 .await
                 .unwrap()
                 .items();
@@ -1416,6 +1861,159 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn test_get_vaults_reports_failed_subgraph() {
+            let sg_server = MockServer::start_async().await;
+            sg_server.mock(|when, then| {
+                when.path("/sg1");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vaults": [get_vault1_json()]
+                    }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg2");
+                then.status(500);
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+
+            let result = raindex_client
+                .get_vaults(None, None, None, None, None)
+                .await
+                .unwrap();
+            assert_eq!(result.items().len(), 1);
+            assert_eq!(result.items()[0].chain_id, 1);
+
+            let statuses = result.subgraph_statuses();
+            assert_eq!(statuses.len(), 2);
+            let failed: Vec<_> = statuses.iter().filter(|status| !status.is_ok()).collect();
+            assert_eq!(failed.len(), 1);
+            assert_eq!(failed[0].url.to_string(), sg_server.url("/sg2"));
+        }
+
+        #[tokio::test]
+        async fn test_get_vaults_at_block() {
+            let sg_server = MockServer::start_async().await;
+            let pinned = sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains(r#""block":{"number":1000}"#);
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vaults": [get_vault1_json()]
+                    }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+
+            sg_server.mock(|when, then| {
+                when.path("/sg1").body_contains("_meta");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "_meta": {
+                            "block": { "number": 1200, "hash": null, "timestamp": null },
+                            "deployment": "Qm123",
+                            "hasIndexingErrors": false
+                        }
+                    }
+                }));
+            });
+
+            let result = raindex_client
+                .get_vaults(Some(ChainIds(vec![1])), None, None, Some(1000), None)
+                .await
+                .unwrap();
+            assert_eq!(result.items().len(), 1);
+            assert!(result.subgraph_meta().is_empty());
+            pinned.assert();
+
+            let result = raindex_client
+                .get_vaults(Some(ChainIds(vec![1])), None, None, Some(1000), Some(true))
+                .await
+                .unwrap();
+            assert_eq!(result.items().len(), 1);
+            let subgraph_meta = result.subgraph_meta();
+            assert_eq!(subgraph_meta.len(), 1);
+            assert_eq!(subgraph_meta[0].chain_id, 1);
+            assert_eq!(subgraph_meta[0].indexed_block, 1200);
+
+            let err = raindex_client
+                .get_vaults(None, None, None, Some(1000), None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, RaindexError::BlockNumberRequiresSingleChain));
+        }
+
+        #[tokio::test]
+        async fn test_get_vault_at_block_pins_balance_changes() {
+            let sg_server = MockServer::start_async().await;
+            let balance_changes = sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains("vaultBalanceChanges")
+                    .body_contains(r#""block":{"number":1000}"#);
+                then.status(200).json_body_obj(&json!({
+                    "data": { "vaultBalanceChanges": [] }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/sg1")
+                    .body_contains(r#""block":{"number":1000}"#);
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vault": get_vault1_json()
+                    }
+                }));
+            });
+
+            let raindex_client = RaindexClient::new(
+                vec![get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                )],
+                None,
+            )
+            .unwrap();
+            let vault = raindex_client
+                .get_vault_at_block(
+                    1,
+                    Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap(),
+                    Bytes::from_str("0x0123").unwrap(),
+                    Some(1000),
+                )
+                .await
+                .unwrap();
+            assert_eq!(vault.block_number(), Some(1000));
+
+            let result = vault.get_balance_changes(None).await.unwrap();
+            assert!(result.is_empty());
+            balance_changes.assert();
+        }
+
         #[tokio::test]
         async fn test_get_vault() {
             let sg_server = MockServer::start_async().await;
@@ -1471,6 +2069,78 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn test_get_vault_with_remote_tokens() {
+            let sg_server = MockServer::start_async().await;
+            sg_server.mock(|when, then| {
+                when.path("/sg1");
+                then.status(200).json_body_obj(&json!({
+                    "data": {
+                        "vault": get_vault1_json()
+                    }
+                }));
+            });
+            sg_server.mock(|when, then| {
+                when.path("/tokens");
+                then.status(200).json_body_obj(&json!({
+                    "name": "Rain",
+                    "timestamp": "2021-01-01T00:00:00.000Z",
+                    "version": { "major": 1, "minor": 0, "patch": 0 },
+                    "tokens": [{
+                        "chainId": 1,
+                        "address": "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d",
+                        "name": "Token 1",
+                        "symbol": "TKN1",
+                        "decimals": 18,
+                        "logoURI": "ipfs://logo",
+                        "extensions": {
+                            "bridgeInfo": {
+                                "137": {
+                                    "tokenAddress": "0x0000000000000000000000000000000000000002"
+                                }
+                            }
+                        }
+                    }]
+                }));
+            });
+
+            let yaml = format!(
+                "{}\nusing-tokens-from:\n    - {}\n",
+                get_test_yaml(
+                    &sg_server.url("/sg1"),
+                    &sg_server.url("/sg2"),
+                    // not used
+                    &sg_server.url("/rpc1"),
+                    &sg_server.url("/rpc2"),
+                ),
+                sg_server.url("/tokens")
+            );
+            let raindex_client = RaindexClient::new(vec![yaml], None).unwrap();
+            let orderbook = Address::from_str(CHAIN_ID_1_ORDERBOOK_ADDRESS).unwrap();
+
+            // the yaml client doesn't know the tokens of the lists
+            let vault = raindex_client
+                .get_vault(1, orderbook, Bytes::from_str("0x10").unwrap())
+                .await
+                .unwrap();
+            assert_eq!(vault.token.logo_uri, None);
+            assert_eq!(vault.token.bridged_addresses, None);
+
+            let raindex_client = raindex_client.with_remote_tokens().await.unwrap();
+            let vault = raindex_client
+                .get_vault(1, orderbook, Bytes::from_str("0x10").unwrap())
+                .await
+                .unwrap();
+            assert_eq!(vault.token.logo_uri, Some("ipfs://logo".to_string()));
+            assert_eq!(
+                vault.token.bridged_addresses,
+                Some(BTreeMap::from([(
+                    137,
+                    Address::from_str("0x0000000000000000000000000000000000000002").unwrap()
+                )]))
+            );
+        }
+
         #[tokio::test]
         async fn test_get_vault_missing_decimals() {
             let sg_server = MockServer::start_async().await;
@@ -2150,6 +2820,47 @@ mod tests {
             assert_eq!(result.0, U256::from(1));
         }
 
+        #[test]
+        fn test_get_vaults_filters_to_sg_filter_args() {
+            let filters: GetVaultsFilters = serde_json::from_value(json!({
+                "owners": [],
+                "hideZeroBalance": true,
+                "orderbookAddresses": ["0x1234567890ABCDEF1234567890ABCDEF12345678"],
+                "vaultIds": ["0x01", "2"],
+                "minBalance": "1.5",
+                "sortBy": "balance"
+            }))
+            .unwrap();
+
+            let args = SgVaultsListFilterArgs::try_from(filters).unwrap();
+            assert_eq!(
+                args.orderbooks,
+                vec![SgBytes(
+                    "0x1234567890abcdef1234567890abcdef12345678".to_string()
+                )]
+            );
+            assert_eq!(
+                args.vault_ids,
+                vec![
+                    SgBytes(format!("0x{}", "0".repeat(63) + "1")),
+                    SgBytes(format!("0x{}", "0".repeat(63) + "2")),
+                ]
+            );
+            assert_eq!(args.min_balance, Some("1.5".to_string()));
+            assert_eq!(args.max_balance, None);
+            assert_eq!(args.sort_by, SgVaultsSortBy::Balance);
+            assert_eq!(args.sort_direction, SgSortDirection::Desc);
+
+            let filters = GetVaultsFilters {
+                max_balance: Some("lots".to_string()),
+                ..Default::default()
+            };
+            assert!(matches!(
+                SgVaultsListFilterArgs::try_from(filters),
+                Err(RaindexError::Float(_))
+            ));
+        }
+
         #[tokio::test]
         async fn test_get_vaults_with_token_filter() {
             let sg_server = MockServer::start_async().await;
@@ -2190,10 +2901,11 @@ mod tests {
                     "0x1d80c49bbbcd1c0911346656b529df9e5c2f783d",
                 )
                 .unwrap()]),
+                ..Default::default()
             };
 
             let result = raindex_client
-                .get_vaults(None, Some(filters), None)
+                .get_vaults(None, Some(filters), None, None, None)
                 .await
                 .unwrap()
                 .items();
@@ -2245,10 +2957,11 @@ mod tests {
                     Address::from_str("0x1d80c49bbbcd1c0911346656b529df9e5c2f783d").unwrap(),
                     Address::from_str("0x12e605bc104e93b45e1ad99f9e555f659051c2bb").unwrap(),
                 ]),
+                ..Default::default()
             };
 
             let result = raindex_client
-                .get_vaults(None, Some(filters), None)
+                .get_vaults(None, Some(filters), None, None, None)
                 .await
                 .unwrap()
                 .items();
@@ -2305,7 +3018,10 @@ mod tests {
             .unwrap();
 
             // Test with specific chain filter (only chain 1)
-            let result = raindex_client.get_all_vault_tokens(None).await.unwrap();
+            let result = raindex_client
+                .get_all_vault_tokens(None, None)
+                .await
+                .unwrap();
 
             assert_eq!(result.len(), 2);
         }
@@ -2343,7 +3059,7 @@ mod tests {
 
             // Test with specific chain filter (only chain 1)
             let result = raindex_client
-                .get_all_vault_tokens(Some(ChainIds(vec![1])))
+                .get_all_vault_tokens(Some(ChainIds(vec![1])), None)
                 .await
                 .unwrap();
 
@@ -5,21 +5,40 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use wasm_bindgen_utils::prelude::*;
 
-use crate::raindex_client::vaults::RaindexVault;
+use crate::raindex_client::{subgraph_health::RaindexSubgraphMeta, vaults::RaindexVault};
 use once_cell::sync::Lazy;
+use rain_orderbook_subgraph_client::MultiSubgraphStatus;
 
 static ZERO_FLOAT: Lazy<Float> = Lazy::new(|| Float::parse("0".to_string()).unwrap());
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[wasm_bindgen]
-pub struct RaindexVaultsList(Vec<RaindexVault>);
+pub struct RaindexVaultsList {
+    vaults: Vec<RaindexVault>,
+    subgraph_meta: Vec<RaindexSubgraphMeta>,
+    subgraph_statuses: Vec<MultiSubgraphStatus>,
+}
 
 impl RaindexVaultsList {
     pub fn new(vaults: Vec<RaindexVault>) -> Self {
-        Self(vaults)
+        Self {
+            vaults,
+            subgraph_meta: vec![],
+            subgraph_statuses: vec![],
+        }
+    }
+    /// Attaches the block indexed by each subgraph the vaults were fetched from
+    pub fn with_subgraph_meta(mut self, subgraph_meta: Vec<RaindexSubgraphMeta>) -> Self {
+        self.subgraph_meta = subgraph_meta;
+        self
+    }
+    /// Attaches the status of each subgraph the vaults were fetched from
+    pub fn with_subgraph_statuses(mut self, subgraph_statuses: Vec<MultiSubgraphStatus>) -> Self {
+        self.subgraph_statuses = subgraph_statuses;
+        self
     }
     pub fn get_withdrawable_vaults(&self) -> Vec<&RaindexVault> {
-        self.0
+        self.vaults
             .iter()
             .filter(|vault| vault.balance().gt(*ZERO_FLOAT).unwrap_or(false))
             .collect()
@@ -29,7 +48,7 @@ impl RaindexVaultsList {
         use std::collections::HashSet;
         let ids_set: HashSet<String> = ids.into_iter().collect();
         let filtered_vaults = self
-            .0
+            .vaults
             .iter()
             .filter(|vault| ids_set.contains(&vault.id().to_string()))
             .cloned()
@@ -38,9 +57,9 @@ impl RaindexVaultsList {
     }
 
     pub fn concat(&self, other: &RaindexVaultsList) -> RaindexVaultsList {
-        let mut combined_vaults = Vec::with_capacity(self.0.len() + other.0.len());
-        combined_vaults.extend_from_slice(&self.0);
-        combined_vaults.extend_from_slice(&other.0);
+        let mut combined_vaults = Vec::with_capacity(self.vaults.len() + other.vaults.len());
+        combined_vaults.extend_from_slice(&self.vaults);
+        combined_vaults.extend_from_slice(&other.vaults);
         RaindexVaultsList::new(combined_vaults)
     }
 
@@ -80,7 +99,13 @@ impl RaindexVaultsList {
 #[cfg(not(target_family = "wasm"))]
 impl RaindexVaultsList {
     pub fn items(&self) -> Vec<RaindexVault> {
-        self.0.clone()
+        self.vaults.clone()
+    }
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
+    }
+    pub fn subgraph_statuses(&self) -> Vec<MultiSubgraphStatus> {
+        self.subgraph_statuses.clone()
     }
 }
 
@@ -99,7 +124,39 @@ impl RaindexVaultsList {
     /// ```
     #[wasm_bindgen(getter)]
     pub fn items(&self) -> Vec<RaindexVault> {
-        self.0.clone()
+        self.vaults.clone()
+    }
+
+    /// Returns the status of each subgraph the vaults were fetched from
+    ///
+    /// A subgraph that failed contributes no vaults and reports its error here.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const unavailable = vaultsList.subgraphStatuses.filter(status => status.error);
+    /// unavailable.forEach(status => {
+    ///   console.warn(`${status.subgraphName} unavailable: ${status.error}`);
+    /// });
+    /// ```
+    #[wasm_bindgen(getter = subgraphStatuses)]
+    pub fn subgraph_statuses(&self) -> Vec<MultiSubgraphStatus> {
+        self.subgraph_statuses.clone()
+    }
+
+    /// Returns the block indexed by each subgraph the vaults were fetched
+    /// from, empty unless it was requested with `includeMeta`
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// for (const meta of vaultsList.subgraphMeta) {
+    ///   console.log(`Chain ${meta.chainId} indexed up to block ${meta.indexedBlock}`);
+    /// }
+    /// ```
+    #[wasm_bindgen(getter = subgraphMeta)]
+    pub fn subgraph_meta(&self) -> Vec<RaindexSubgraphMeta> {
+        self.subgraph_meta.clone()
     }
 }
 
@@ -328,14 +385,17 @@ mod tests {
                 None,
             )
             .unwrap();
-            let vaults = raindex_client.get_vaults(None, None, None).await.unwrap();
+            let vaults = raindex_client
+                .get_vaults(None, None, None, None, None)
+                .await
+                .unwrap();
             vaults.items()
         }
 
         #[tokio::test]
         async fn test_get_vaults_not_empty() {
             let vaults_list = RaindexVaultsList::new(get_vaults().await);
-            assert_eq!(vaults_list.0.len(), 2);
+            assert_eq!(vaults_list.vaults.len(), 2);
         }
 
         #[tokio::test]
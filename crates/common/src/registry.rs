@@ -1,5 +1,8 @@
 use crate::gui::{DotrainOrderGui, GuiError};
 use rain_orderbook_app_settings::gui::NameAndDescriptionCfg;
+use rain_orderbook_app_settings::remote::source::{
+    fetch_remote_source, validate_sha256_pin, RemoteFetchOptions, RemoteSourceError,
+};
 use reqwest;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
@@ -17,12 +20,13 @@ use wasm_bindgen_utils::{prelude::*, wasm_export};
 ///
 /// The registry file follows a specific format:
 /// - **First line**: URL to shared settings YAML file (without a key)
-/// - **Subsequent lines**: Order entries in format "key url"
+/// - **Subsequent lines**: Order entries in format "key url", optionally followed by the
+///   sha256 of the .rain file to pin its content
 ///
 /// ```text
 /// https://example.com/shared-settings.yaml
 /// fixed-limit https://example.com/fixed-limit.rain
-/// auction-dca https://example.com/auction-dca.rain
+/// auction-dca https://example.com/auction-dca.rain 0x5f0c...e21a
 /// ```
 ///
 /// ## Content Merging
@@ -111,8 +115,16 @@ pub struct DotrainRegistry {
     /// This content is fetched in parallel during registry initialization and stored
     /// for quick access. It gets merged with `settings` content when creating GUIs.
     orders: HashMap<String, String>,
+
+    /// Pinned sha256 hashes of order .rain files, keyed by order key.
+    ///
+    /// Only entries with a third column in the registry file are present. The fetched
+    /// content of a pinned order must match its hash or the registry fails to load.
+    order_hashes: HashMap<String, String>,
 }
 
+type RegistryEntries = (String, Url, HashMap<String, Url>, HashMap<String, String>);
+
 #[derive(Error, Debug)]
 pub enum DotrainRegistryError {
     #[error("Failed to fetch registry from URL: {0}")]
@@ -132,6 +144,10 @@ pub enum DotrainRegistryError {
     #[error("Invalid URL: {0}")]
     UrlParseError(#[from] url::ParseError),
     #[error(transparent)]
+    RemoteSourceError(#[from] RemoteSourceError),
+    #[error("State has no registry order key")]
+    StateMissingOrderKey,
+    #[error(transparent)]
     GuiError(#[from] GuiError),
 }
 
@@ -162,6 +178,15 @@ impl DotrainRegistryError {
             DotrainRegistryError::UrlParseError(err) => {
                 format!("Invalid URL format: {}. Please ensure the URL is properly formatted.", err)
             }
+            DotrainRegistryError::RemoteSourceError(RemoteSourceError::IntegrityMismatch { url, expected, actual }) => {
+                format!("The content of {} does not match the sha256 pinned in the registry (expected {}, got {}). The file may have been modified.", url, expected, actual)
+            }
+            DotrainRegistryError::RemoteSourceError(err) => {
+                format!("Unable to load registry content: {}", err)
+            }
+            DotrainRegistryError::StateMissingOrderKey => {
+                "The shared state does not say which order it belongs to. Please ask for a link created from the registry.".to_string()
+            }
             DotrainRegistryError::GuiError(err) => err.to_readable_msg()
         }
     }
@@ -241,20 +266,7 @@ impl DotrainRegistry {
         )]
         registry_url: String,
     ) -> Result<DotrainRegistry, DotrainRegistryError> {
-        let registry_url = Url::parse(&registry_url)?;
-        let (registry_content, settings_url, order_urls) =
-            Self::fetch_and_parse_registry(&registry_url).await?;
-        let settings = Self::fetch_settings(&settings_url).await?;
-        let orders = Self::fetch_orders(&order_urls).await?;
-
-        Ok(DotrainRegistry {
-            registry_url,
-            registry: registry_content,
-            settings_url,
-            settings,
-            order_urls,
-            orders,
-        })
+        Self::new_with_options(registry_url, &RemoteFetchOptions::default()).await
     }
 
     /// Gets details for all orders in the registry.
@@ -406,28 +418,118 @@ impl DotrainRegistry {
         state_update_callback: Option<js_sys::Function>,
     ) -> Result<DotrainOrderGui, DotrainRegistryError> {
         let merged_content = self.merge_content_for_order(&order_key)?;
-        let gui = DotrainOrderGui::new_with_deployment(
+        let mut gui = DotrainOrderGui::new_with_deployment(
             merged_content,
             deployment_key,
             state_update_callback,
         )
         .await?;
+        let dotrain = self.orders[&order_key].clone();
+        gui.set_registry_origin(self.registry_url.to_string(), order_key, dotrain);
+        Ok(gui)
+    }
+
+    /// Reopens a shared deployment from a state created with `serializeShareableState`.
+    ///
+    /// The order and deployment keys are read from the state, the order's dotrain is
+    /// taken from this registry and the state is applied to it the same way as
+    /// `DotrainOrderGui.newFromState`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const state = new URLSearchParams(location.search).get("state");
+    /// const result = await registry.getGuiFromState(state);
+    /// if (result.error) {
+    ///   console.error("Cannot open link:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const gui = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getGuiFromState",
+        preserve_js_class,
+        unchecked_return_type = "DotrainOrderGui",
+        return_description = "DotrainOrderGui instance with the shared state applied"
+    )]
+    pub async fn get_gui_from_state(
+        &self,
+        #[wasm_export(param_description = "State from serializeShareableState")] serialized: String,
+        #[wasm_export(
+            js_name = "stateUpdateCallback",
+            param_description = "Optional function called on state changes"
+        )]
+        state_update_callback: Option<js_sys::Function>,
+    ) -> Result<DotrainOrderGui, DotrainRegistryError> {
+        let info = DotrainOrderGui::get_state_envelope_info(serialized.clone())?;
+        let order_key = info
+            .order_key
+            .ok_or(DotrainRegistryError::StateMissingOrderKey)?;
+        let merged_content = self.merge_content_for_order(&order_key)?;
+        let mut gui = DotrainOrderGui::new_from_state(
+            merged_content,
+            serialized,
+            state_update_callback,
+            None,
+        )
+        .await?;
+        let dotrain = self.orders[&order_key].clone();
+        gui.set_registry_origin(self.registry_url.to_string(), order_key, dotrain);
         Ok(gui)
     }
 }
 
 impl DotrainRegistry {
+    /// Loads the registry like [`DotrainRegistry::new`], with `options` deciding whether
+    /// responses are cached on disk and whether the network may be used at all.
+    pub async fn new_with_options(
+        registry_url: String,
+        options: &RemoteFetchOptions,
+    ) -> Result<DotrainRegistry, DotrainRegistryError> {
+        let registry_url = Url::parse(&registry_url)?;
+        let (registry_content, settings_url, order_urls, order_hashes) =
+            Self::fetch_and_parse_registry(&registry_url, options).await?;
+        let settings = Self::fetch_settings(&settings_url, options).await?;
+        let orders = Self::fetch_orders(&order_urls, &order_hashes, options).await?;
+
+        Ok(DotrainRegistry {
+            registry_url,
+            registry: registry_content,
+            settings_url,
+            settings,
+            order_urls,
+            orders,
+            order_hashes,
+        })
+    }
+
+    pub fn get_order_url(&self, order_key: &str) -> Option<&Url> {
+        self.order_urls.get(order_key)
+    }
+
+    pub fn get_order_hash(&self, order_key: &str) -> Option<&str> {
+        self.order_hashes.get(order_key).map(String::as_str)
+    }
+
+    /// Returns the shared settings followed by the order's .rain content, the same
+    /// dotrain that is used to build the order's GUI.
+    pub fn get_merged_dotrain(&self, order_key: &str) -> Result<String, DotrainRegistryError> {
+        self.merge_content_for_order(order_key)
+    }
+
     async fn fetch_and_parse_registry(
         registry_url: &Url,
-    ) -> Result<(String, Url, HashMap<String, Url>), DotrainRegistryError> {
-        let registry_content = Self::fetch_url_content(registry_url).await?;
-        let (settings_url, order_urls) = Self::parse_registry_content(&registry_content)?;
-        Ok((registry_content, settings_url, order_urls))
+        options: &RemoteFetchOptions,
+    ) -> Result<RegistryEntries, DotrainRegistryError> {
+        let registry_content = Self::fetch_url_content(registry_url, None, options).await?;
+        let (settings_url, order_urls, order_hashes) =
+            Self::parse_registry_content(&registry_content)?;
+        Ok((registry_content, settings_url, order_urls, order_hashes))
     }
 
     fn parse_registry_content(
         content: &str,
-    ) -> Result<(Url, HashMap<String, Url>), DotrainRegistryError> {
+    ) -> Result<(Url, HashMap<String, Url>, HashMap<String, String>), DotrainRegistryError> {
         let lines: Vec<&str> = content
             .lines()
             .map(|line| line.trim())
@@ -449,12 +551,13 @@ impl DotrainRegistry {
 
         let settings_url = Url::parse(first_line)?;
         let mut order_urls = HashMap::new();
+        let mut order_hashes = HashMap::new();
 
         for line in &lines[1..] {
             let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() != 2 {
+            if parts.len() != 2 && parts.len() != 3 {
                 return Err(DotrainRegistryError::InvalidRegistryFormat(format!(
-                    "Invalid order entry format: '{}'. Expected: 'key url'",
+                    "Invalid order entry format: '{}'. Expected: 'key url' or 'key url sha256'",
                     line
                 )));
             }
@@ -462,52 +565,64 @@ impl DotrainRegistry {
             let key = parts[0].to_string();
             let url = Url::parse(parts[1])?;
 
+            if let Some(hash) = parts.get(2) {
+                let hash = validate_sha256_pin(hash).map_err(|_| {
+                    DotrainRegistryError::InvalidRegistryFormat(format!(
+                        "Invalid sha256 '{}' for order '{}'",
+                        hash, key
+                    ))
+                })?;
+                order_hashes.insert(key.clone(), hash);
+            }
             order_urls.insert(key, url);
         }
 
-        Ok((settings_url, order_urls))
+        Ok((settings_url, order_urls, order_hashes))
     }
 
-    async fn fetch_url_content(url: &Url) -> Result<String, DotrainRegistryError> {
-        let response = reqwest::get(url.as_str())
-            .await
-            .map_err(|e| DotrainRegistryError::HttpError(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(DotrainRegistryError::HttpError(format!(
-                "HTTP {}",
-                response.status()
-            )));
-        }
-
-        response
-            .text()
+    async fn fetch_url_content(
+        url: &Url,
+        sha256: Option<&str>,
+        options: &RemoteFetchOptions,
+    ) -> Result<String, DotrainRegistryError> {
+        fetch_remote_source(url, sha256, options)
             .await
-            .map_err(|e| DotrainRegistryError::HttpError(e.to_string()))
+            .map_err(|err| match err {
+                RemoteSourceError::HttpStatus(_, status) => {
+                    DotrainRegistryError::HttpError(format!("HTTP {}", status))
+                }
+                RemoteSourceError::ReqwestError(err) => {
+                    DotrainRegistryError::HttpError(err.to_string())
+                }
+                err => DotrainRegistryError::RemoteSourceError(err),
+            })
     }
 
-    async fn fetch_settings(settings_url: &Url) -> Result<String, DotrainRegistryError> {
-        Self::fetch_url_content(settings_url).await
+    async fn fetch_settings(
+        settings_url: &Url,
+        options: &RemoteFetchOptions,
+    ) -> Result<String, DotrainRegistryError> {
+        Self::fetch_url_content(settings_url, None, options).await
     }
 
     async fn fetch_orders(
         order_urls: &HashMap<String, Url>,
+        order_hashes: &HashMap<String, String>,
+        options: &RemoteFetchOptions,
     ) -> Result<HashMap<String, String>, DotrainRegistryError> {
         use futures::future::join_all;
 
         let mut futures = Vec::new();
 
         for (key, url) in order_urls {
-            let key_clone = key.clone();
-            let url_clone = url.clone();
             futures.push(async move {
-                let content = reqwest::get(url_clone.as_str())
-                    .await
-                    .map_err(|e| DotrainRegistryError::HttpError(e.to_string()))?
-                    .text()
-                    .await
-                    .map_err(|e| DotrainRegistryError::HttpError(e.to_string()))?;
-                Ok::<(String, String), DotrainRegistryError>((key_clone, content))
+                let content = Self::fetch_url_content(
+                    url,
+                    order_hashes.get(key).map(String::as_str),
+                    options,
+                )
+                .await?;
+                Ok::<(String, String), DotrainRegistryError>((key.clone(), content))
             });
         }
 
@@ -687,7 +802,7 @@ _ _: 1 1;
 
         #[wasm_bindgen_test]
         fn test_parse_registry_content() {
-            let (settings_url, order_urls) =
+            let (settings_url, order_urls, order_hashes) =
                 DotrainRegistry::parse_registry_content(MOCK_REGISTRY_CONTENT).unwrap();
 
             assert_eq!(
@@ -703,6 +818,7 @@ _ _: 1 1;
                 order_urls.get("auction-dca").map(|u| u.to_string()),
                 Some("https://example.com/auction-dca.rain".to_string())
             );
+            assert!(order_hashes.is_empty());
         }
 
         #[wasm_bindgen_test]
@@ -739,6 +855,7 @@ _ _: 1 1;
                 .into_iter()
                 .collect(),
                 orders: HashMap::new(),
+                order_hashes: HashMap::new(),
             };
 
             let keys = registry.get_order_keys().unwrap();
@@ -772,6 +889,7 @@ _ _: 1 1;
                 ]
                 .into_iter()
                 .collect(),
+                order_hashes: HashMap::new(),
             };
 
             let result = registry.get_all_order_details();
@@ -804,6 +922,7 @@ _ _: 1 1;
                 orders: vec![("fixed-limit".to_string(), get_first_dotrain_content())]
                     .into_iter()
                     .collect(),
+                order_hashes: HashMap::new(),
             };
 
             let result = registry.get_deployment_details("fixed-limit".to_string());
@@ -832,6 +951,7 @@ _ _: 1 1;
                 settings: MOCK_SETTINGS_CONTENT.to_string(),
                 order_urls: HashMap::new(),
                 orders: HashMap::new(),
+                order_hashes: HashMap::new(),
             };
 
             let result = registry.get_deployment_details("non-existent".to_string());
@@ -861,6 +981,7 @@ _ _: 1 1;
                 orders: vec![("fixed-limit".to_string(), get_first_dotrain_content())]
                     .into_iter()
                     .collect(),
+                order_hashes: HashMap::new(),
             };
 
             assert_eq!(registry.registry_url(), "https://example.com/registry.txt");
@@ -884,6 +1005,7 @@ _ _: 1 1;
                 settings: "".to_string(),
                 order_urls: HashMap::new(),
                 orders: HashMap::new(),
+                order_hashes: HashMap::new(),
             };
 
             let result = registry.merge_content_for_order("non-existent");
@@ -927,6 +1049,7 @@ _ _: 1 1;
                 ]
                 .into_iter()
                 .collect(),
+                order_hashes: HashMap::new(),
             };
 
             let merged1 = registry.merge_content_for_order("first-order").unwrap();
@@ -969,6 +1092,7 @@ _ _: 1 1;
                 orders: vec![("test-order".to_string(), get_first_dotrain_content())]
                     .into_iter()
                     .collect(),
+                order_hashes: HashMap::new(),
             };
 
             let merged = registry.merge_content_for_order("test-order").unwrap();
@@ -981,6 +1105,7 @@ _ _: 1 1;
     mod non_wasm_tests {
         use super::*;
         use httpmock::MockServer;
+        use rain_orderbook_app_settings::remote::source::sha256_hex;
 
         #[tokio::test]
         async fn test_new_constructor() {
@@ -1058,10 +1183,13 @@ _ _: 1 1;
             });
 
             let registry_url = Url::parse(&format!("{}/registry.txt", server.url(""))).unwrap();
-            let (registry_content, settings_url, order_urls) =
-                DotrainRegistry::fetch_and_parse_registry(&registry_url)
-                    .await
-                    .unwrap();
+            let (registry_content, settings_url, order_urls, order_hashes) =
+                DotrainRegistry::fetch_and_parse_registry(
+                    &registry_url,
+                    &RemoteFetchOptions::default(),
+                )
+                .await
+                .unwrap();
 
             assert_eq!(registry_content, test_registry_content);
             assert_eq!(
@@ -1071,6 +1199,167 @@ _ _: 1 1;
             assert_eq!(order_urls.len(), 2);
             assert!(order_urls.contains_key("order1"));
             assert!(order_urls.contains_key("order2"));
+            assert!(order_hashes.is_empty());
+        }
+
+        #[test]
+        fn test_parse_registry_content_with_hashes() {
+            let hash = sha256_hex(b"order");
+            let content = format!(
+                "https://example.com/settings.yaml\npinned https://example.com/pinned.rain 0x{}\nunpinned https://example.com/unpinned.rain",
+                hash.to_uppercase()
+            );
+            let (_, order_urls, order_hashes) =
+                DotrainRegistry::parse_registry_content(&content).unwrap();
+            assert_eq!(order_urls.len(), 2);
+            assert_eq!(order_hashes.len(), 1);
+            assert_eq!(order_hashes.get("pinned"), Some(&hash));
+
+            let err = DotrainRegistry::parse_registry_content(
+                "https://example.com/settings.yaml\npinned https://example.com/pinned.rain abc",
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid registry format: Invalid sha256 'abc' for order 'pinned'"
+            );
+
+            let err = DotrainRegistry::parse_registry_content(
+                "https://example.com/settings.yaml\npinned https://example.com/pinned.rain abc extra",
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err,
+                DotrainRegistryError::InvalidRegistryFormat(_)
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_new_with_pinned_order() {
+            let server = MockServer::start_async().await;
+            let order_hash = sha256_hex(get_first_dotrain_content().as_bytes());
+
+            server.mock(|when, then| {
+                when.method("GET").path("/pinned.txt");
+                then.status(200).body(format!(
+                    "{}/settings.yaml\nfirst-order {}/first-order.rain {}",
+                    server.url(""),
+                    server.url(""),
+                    order_hash
+                ));
+            });
+            server.mock(|when, then| {
+                when.method("GET").path("/tampered.txt");
+                then.status(200).body(format!(
+                    "{}/settings.yaml\nfirst-order {}/first-order.rain {}",
+                    server.url(""),
+                    server.url(""),
+                    "0".repeat(64)
+                ));
+            });
+            server.mock(|when, then| {
+                when.method("GET").path("/settings.yaml");
+                then.status(200).body(MOCK_SETTINGS_CONTENT);
+            });
+            server.mock(|when, then| {
+                when.method("GET").path("/first-order.rain");
+                then.status(200).body(get_first_dotrain_content());
+            });
+
+            let registry = DotrainRegistry::new(server.url("/pinned.txt"))
+                .await
+                .unwrap();
+            assert_eq!(
+                registry.get_order_hash("first-order"),
+                Some(order_hash.as_str())
+            );
+            assert_eq!(
+                registry
+                    .get_order_url("first-order")
+                    .map(|url| url.to_string()),
+                Some(server.url("/first-order.rain"))
+            );
+
+            let err = DotrainRegistry::new(server.url("/tampered.txt"))
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                DotrainRegistryError::RemoteSourceError(
+                    RemoteSourceError::IntegrityMismatch { .. }
+                )
+            ));
+            assert!(err
+                .to_readable_msg()
+                .contains("does not match the sha256 pinned in the registry"));
+        }
+
+        #[tokio::test]
+        async fn test_new_with_cache_and_offline() {
+            let server = MockServer::start_async().await;
+            let cache_dir = tempfile::TempDir::new().unwrap();
+            let options = RemoteFetchOptions {
+                cache_dir: Some(cache_dir.path().to_path_buf()),
+                offline: false,
+            };
+
+            let mut mocks = vec![
+                server
+                    .mock_async(|when, then| {
+                        when.method("GET").path("/registry.txt");
+                        then.status(200).body(format!(
+                            "{}/settings.yaml\nfirst-order {}/first-order.rain",
+                            server.url(""),
+                            server.url("")
+                        ));
+                    })
+                    .await,
+                server
+                    .mock_async(|when, then| {
+                        when.method("GET").path("/settings.yaml");
+                        then.status(200).body(MOCK_SETTINGS_CONTENT);
+                    })
+                    .await,
+                server
+                    .mock_async(|when, then| {
+                        when.method("GET").path("/first-order.rain");
+                        then.status(200).body(get_first_dotrain_content());
+                    })
+                    .await,
+            ];
+
+            let registry = DotrainRegistry::new_with_options(server.url("/registry.txt"), &options)
+                .await
+                .unwrap();
+            for mock in mocks.iter_mut() {
+                mock.assert_hits_async(1).await;
+                mock.delete_async().await;
+            }
+
+            let offline = RemoteFetchOptions {
+                offline: true,
+                ..options
+            };
+            let cached = DotrainRegistry::new_with_options(server.url("/registry.txt"), &offline)
+                .await
+                .unwrap();
+            assert_eq!(cached, registry);
+            assert_eq!(
+                cached.get_merged_dotrain("first-order").unwrap(),
+                format!(
+                    "{}\n\n{}",
+                    MOCK_SETTINGS_CONTENT,
+                    get_first_dotrain_content()
+                )
+            );
+
+            let err = DotrainRegistry::new_with_options(server.url("/other.txt"), &offline)
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                DotrainRegistryError::RemoteSourceError(RemoteSourceError::OfflineCacheMiss(_))
+            ));
         }
 
         #[tokio::test]
@@ -1083,7 +1372,10 @@ _ _: 1 1;
             });
 
             let url = Url::parse(&format!("{}/test.txt", server.url(""))).unwrap();
-            let content = DotrainRegistry::fetch_url_content(&url).await.unwrap();
+            let content =
+                DotrainRegistry::fetch_url_content(&url, None, &RemoteFetchOptions::default())
+                    .await
+                    .unwrap();
 
             assert_eq!(content, "test content");
         }
@@ -1098,7 +1390,9 @@ _ _: 1 1;
             });
 
             let url = Url::parse(&format!("{}/error.txt", server.url(""))).unwrap();
-            let result = DotrainRegistry::fetch_url_content(&url).await;
+            let result =
+                DotrainRegistry::fetch_url_content(&url, None, &RemoteFetchOptions::default())
+                    .await;
 
             assert!(result.is_err());
             match result.err().unwrap() {
@@ -1119,7 +1413,9 @@ _ _: 1 1;
             });
 
             let url = Url::parse(&format!("{}/settings.yaml", server.url(""))).unwrap();
-            let settings = DotrainRegistry::fetch_settings(&url).await.unwrap();
+            let settings = DotrainRegistry::fetch_settings(&url, &RemoteFetchOptions::default())
+                .await
+                .unwrap();
 
             assert_eq!(settings, "test settings content");
         }
@@ -1151,7 +1447,13 @@ _ _: 1 1;
             .into_iter()
             .collect();
 
-            let orders = DotrainRegistry::fetch_orders(&order_urls).await.unwrap();
+            let orders = DotrainRegistry::fetch_orders(
+                &order_urls,
+                &HashMap::new(),
+                &RemoteFetchOptions::default(),
+            )
+            .await
+            .unwrap();
 
             assert_eq!(orders.len(), 2);
             assert_eq!(orders.get("order1").unwrap(), &get_first_dotrain_content());
@@ -1316,5 +1618,83 @@ _ _: 1 1;
                 _ => panic!("Expected OrderKeyNotFound error"),
             }
         }
+
+        #[tokio::test]
+        async fn test_get_gui_from_state() {
+            let server = MockServer::start_async().await;
+
+            let test_registry_content = format!(
+                "{}/settings.yaml\nfirst-order {}/first-order.rain\nsecond-order {}/second-order.rain",
+                server.url(""),
+                server.url(""),
+                server.url("")
+            );
+            server.mock(|when, then| {
+                when.method("GET").path("/registry.txt");
+                then.status(200).body(test_registry_content);
+            });
+            server.mock(|when, then| {
+                when.method("GET").path("/settings.yaml");
+                then.status(200).body(MOCK_SETTINGS_CONTENT);
+            });
+            server.mock(|when, then| {
+                when.method("GET").path("/first-order.rain");
+                then.status(200).body(get_first_dotrain_content());
+            });
+            server.mock(|when, then| {
+                when.method("GET").path("/second-order.rain");
+                then.status(200).body(get_second_dotrain_content());
+            });
+
+            let registry = DotrainRegistry::new(format!("{}/registry.txt", server.url("")))
+                .await
+                .unwrap();
+
+            let mut gui = registry
+                .get_gui("second-order".to_string(), "base".to_string(), None)
+                .await
+                .unwrap();
+            gui.set_field_value("test-binding".to_string(), "20".to_string())
+                .unwrap();
+            let state = gui
+                .serialize_shareable_state(Some("second-order".to_string()), None)
+                .unwrap();
+
+            let restored = registry.get_gui_from_state(state, None).await.unwrap();
+            assert_eq!(
+                restored.get_current_deployment().unwrap().name,
+                "Base order name"
+            );
+            assert_eq!(
+                restored
+                    .get_field_value("test-binding".to_string())
+                    .unwrap()
+                    .value,
+                "20"
+            );
+            assert!(restored
+                .get_state_migration_report()
+                .unwrap()
+                .unwrap()
+                .is_clean());
+
+            // the order meta records where the order came from
+            let gui_meta = restored.get_order_gui_meta().unwrap();
+            assert_eq!(
+                gui_meta.deployment.registry,
+                Some(format!("{}/registry.txt", server.url("")))
+            );
+            assert_eq!(
+                gui_meta.deployment.order_key,
+                Some("second-order".to_string())
+            );
+            assert_eq!(gui_meta.deployment.deployment_name, "Base order name");
+            // without the shared settings
+            assert_eq!(gui_meta.dotrain, registry.orders["second-order"]);
+
+            let state = gui.serialize_shareable_state(None, None).unwrap();
+            let err = registry.get_gui_from_state(state, None).await.unwrap_err();
+            assert!(matches!(err, DotrainRegistryError::StateMissingOrderKey));
+        }
     }
 }
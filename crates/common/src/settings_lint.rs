@@ -0,0 +1,428 @@
+use crate::erc20::ERC20;
+use crate::subgraph::subgraph_transport_config;
+use alloy::eips::BlockNumberOrTag;
+use alloy::providers::Provider;
+use futures::{stream, StreamExt};
+use rain_orderbook_app_settings::lint::{lint_settings, LintIssue, LintReport, LintRule};
+use rain_orderbook_app_settings::remote::source::RemoteFetchOptions;
+use rain_orderbook_app_settings::remote_networks::{ParseRemoteNetworksError, RemoteNetworksCfg};
+use rain_orderbook_app_settings::yaml::{
+    dotrain::{DotrainYaml, DotrainYamlValidation},
+    orderbook::{OrderbookYaml, OrderbookYamlValidation},
+    YamlError, YamlParsable,
+};
+use rain_orderbook_bindings::provider::mk_read_provider;
+use rain_orderbook_subgraph_client::OrderbookSubgraphClient;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Onchain checks that run at once, token lists can hold hundreds of tokens
+const MAX_CONCURRENT_CHECKS: usize = 10;
+
+#[derive(Error, Debug)]
+pub enum SettingsLintError {
+    #[error(transparent)]
+    YamlError(#[from] YamlError),
+    #[error(transparent)]
+    ParseRemoteNetworksError(#[from] ParseRemoteNetworksError),
+}
+
+impl SettingsLintError {
+    pub fn to_readable_msg(&self) -> String {
+        match self {
+            SettingsLintError::YamlError(e) => {
+                format!(
+                    "Error parsing the YAML configuration: {}",
+                    e.to_readable_msg()
+                )
+            }
+            SettingsLintError::ParseRemoteNetworksError(e) => {
+                format!("Error parsing the remote networks configuration: {e}")
+            }
+        }
+    }
+}
+
+/// Lints the given yaml sources. When `check_onchain` is set remote networks
+/// are fetched and the rpc backed rules are run as well.
+pub async fn lint_sources(
+    sources: Vec<String>,
+    check_onchain: bool,
+) -> Result<LintReport, SettingsLintError> {
+    let mut orderbook_yaml =
+        OrderbookYaml::new(sources.clone(), OrderbookYamlValidation::default())?;
+    let mut dotrain_yaml = DotrainYaml::new(sources, DotrainYamlValidation::default())?;
+
+    if !check_onchain {
+        return Ok(lint_settings(&orderbook_yaml, &dotrain_yaml));
+    }
+
+    let remote_networks = RemoteNetworksCfg::fetch_networks_with_options(
+        orderbook_yaml.get_remote_networks()?,
+        &RemoteFetchOptions::from_env(),
+    )
+    .await?;
+    if !remote_networks.is_empty() {
+        orderbook_yaml
+            .cache
+            .update_remote_networks(remote_networks.clone());
+        dotrain_yaml.cache.update_remote_networks(remote_networks);
+    }
+
+    Ok(lint_settings_with_onchain(&orderbook_yaml, &dotrain_yaml).await)
+}
+
+/// Runs the static lint rules followed by the rpc backed ones
+pub async fn lint_settings_with_onchain(
+    orderbook_yaml: &OrderbookYaml,
+    dotrain_yaml: &DotrainYaml,
+) -> LintReport {
+    let mut report = lint_settings(orderbook_yaml, dotrain_yaml);
+    report.extend(lint_onchain(orderbook_yaml).await);
+    report.sort();
+    report
+}
+
+/// Checks token decimals against `ERC20::decimals`, orderbook deployment
+/// blocks against the latest block of their network and the chain indexed by
+/// each orderbook's subgraph against the orderbook's network. Sections that
+/// fail to parse are skipped here since the static rules already report them.
+pub async fn lint_onchain(orderbook_yaml: &OrderbookYaml) -> LintReport {
+    let mut report = LintReport::default();
+
+    let tokens = orderbook_yaml.get_tokens().unwrap_or_default();
+    let decimals_checks = tokens
+        .values()
+        .filter(|token| token.decimals.is_some())
+        .map(|token| async move {
+            let onchain = ERC20::new(token.network.rpcs.clone(), token.address)
+                .decimals()
+                .await;
+            (token, onchain)
+        });
+    let decimals_results: Vec<_> = stream::iter(decimals_checks)
+        .buffer_unordered(MAX_CONCURRENT_CHECKS)
+        .collect()
+        .await;
+    for (token, onchain) in decimals_results {
+        let location = format!("token '{}'", token.key);
+        let decimals = token.decimals.unwrap_or_default();
+        match onchain {
+            Ok(onchain) if onchain != decimals => report.push(LintIssue::new(
+                LintRule::TokenDecimalsMismatch,
+                location,
+                format!(
+                    "Token defines {} decimals but the contract at {} reports {}",
+                    decimals, token.address, onchain
+                ),
+            )),
+            Ok(_) => {}
+            Err(err) => report.push(LintIssue::new(
+                LintRule::OnchainCheckFailed,
+                location,
+                format!("Could not fetch decimals: {}", err),
+            )),
+        }
+    }
+
+    let orderbooks = orderbook_yaml.get_orderbooks().unwrap_or_default();
+    let mut networks = HashMap::new();
+    for orderbook in orderbooks.values() {
+        networks
+            .entry(orderbook.network.key.clone())
+            .or_insert_with(|| orderbook.network.rpcs.clone());
+    }
+    let block_checks = networks.into_iter().map(|(key, rpcs)| async move {
+        let block_number = match mk_read_provider(&rpcs) {
+            Ok(provider) => provider
+                .get_block_number()
+                .await
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        (key, block_number)
+    });
+    let block_numbers: HashMap<String, Result<u64, String>> = stream::iter(block_checks)
+        .buffer_unordered(MAX_CONCURRENT_CHECKS)
+        .collect()
+        .await;
+
+    for (key, orderbook) in &orderbooks {
+        let location = format!("orderbook '{}'", key);
+        match block_numbers.get(&orderbook.network.key) {
+            Some(Ok(latest)) if orderbook.deployment_block > *latest => {
+                report.push(LintIssue::new(
+                    LintRule::DeploymentBlockInFuture,
+                    location,
+                    format!(
+                        "Deployment block {} is ahead of the latest block {} on network '{}'",
+                        orderbook.deployment_block, latest, orderbook.network.key
+                    ),
+                ))
+            }
+            Some(Err(err)) => report.push(LintIssue::new(
+                LintRule::OnchainCheckFailed,
+                location,
+                format!(
+                    "Could not fetch the latest block for network '{}': {}",
+                    orderbook.network.key, err
+                ),
+            )),
+            _ => {}
+        }
+    }
+
+    // A subgraph indexes the orderbook's chain when the hash of its latest
+    // indexed block matches the block with the same number on that chain
+    let mut subgraph_networks = HashMap::new();
+    for orderbook in orderbooks.values() {
+        subgraph_networks
+            .entry((
+                orderbook.subgraph.key.clone(),
+                orderbook.network.key.clone(),
+            ))
+            .or_insert_with(|| (orderbook.subgraph.clone(), orderbook.network.rpcs.clone()));
+    }
+    let subgraph_checks = subgraph_networks
+        .into_iter()
+        .map(|(key, (subgraph, rpcs))| async move {
+            let client = OrderbookSubgraphClient::with_transport(
+                subgraph.url.clone(),
+                subgraph_transport_config(&subgraph),
+            );
+            (key, check_subgraph_chain(&client, &rpcs).await)
+        });
+    let subgraph_chains: HashMap<(String, String), Result<Option<String>, String>> =
+        stream::iter(subgraph_checks)
+            .buffer_unordered(MAX_CONCURRENT_CHECKS)
+            .collect()
+            .await;
+
+    for (key, orderbook) in &orderbooks {
+        let location = format!("orderbook '{}'", key);
+        let check_key = (
+            orderbook.subgraph.key.clone(),
+            orderbook.network.key.clone(),
+        );
+        match subgraph_chains.get(&check_key) {
+            Some(Ok(Some(mismatch))) => report.push(LintIssue::new(
+                LintRule::OrderbookSubgraphNetworkMismatch,
+                location,
+                format!(
+                    "Subgraph '{}' does not index network '{}' (chain id {}): {}",
+                    orderbook.subgraph.key,
+                    orderbook.network.key,
+                    orderbook.network.chain_id,
+                    mismatch
+                ),
+            )),
+            Some(Err(err)) => report.push(LintIssue::new(
+                LintRule::OnchainCheckFailed,
+                location,
+                format!(
+                    "Could not check the chain of subgraph '{}': {}",
+                    orderbook.subgraph.key, err
+                ),
+            )),
+            _ => {}
+        }
+    }
+
+    report.sort();
+    report
+}
+
+/// Compares the latest block indexed by the subgraph with the block of the
+/// same number on the rpc, returning why they don't match
+async fn check_subgraph_chain(
+    client: &OrderbookSubgraphClient,
+    rpcs: &[url::Url],
+) -> Result<Option<String>, String> {
+    let meta = client.meta().await.map_err(|err| err.to_string())?;
+    let number = meta.indexed_block();
+    let Some(hash) = meta.block.hash else {
+        return Ok(None);
+    };
+    let provider = mk_read_provider(rpcs).map_err(|err| err.to_string())?;
+    let block = provider
+        .get_block_by_number(BlockNumberOrTag::Number(number))
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(match block {
+        None => Some(format!(
+            "block {} indexed by the subgraph does not exist",
+            number
+        )),
+        Some(block) if !block.header.hash.to_string().eq_ignore_ascii_case(&hash.0) => {
+            Some(format!(
+                "block {} has hash {} on the rpc but {} in the subgraph",
+                number, block.header.hash, hash.0
+            ))
+        }
+        Some(_) => None,
+    })
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use httpmock::MockServer;
+    use rain_orderbook_app_settings::lint::LintSeverity;
+    use serde_json::json;
+
+    fn get_yaml(rpc_url: &str, sg_url: &str) -> String {
+        format!(
+            r#"
+networks:
+    mainnet:
+        rpcs:
+            - {rpc_url}
+        chain-id: 1
+subgraphs:
+    mainnet: {sg_url}
+orderbooks:
+    mainnet:
+        address: 0x0000000000000000000000000000000000000001
+        deployment-block: 100
+tokens:
+    token1:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000002
+        decimals: 6
+"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_lint_onchain() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method("POST").path("/rpc").body_contains("0x313ce567");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x0000000000000000000000000000000000000000000000000000000000000012",
+            }));
+        });
+        server.mock(|when, then| {
+            when.method("POST")
+                .path("/rpc")
+                .body_contains("eth_blockNumber");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x10",
+            }));
+        });
+        server.mock(|when, then| {
+            when.method("POST")
+                .path("/rpc")
+                .body_contains("eth_getBlockByNumber");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": null,
+            }));
+        });
+        server.mock(|when, then| {
+            when.method("POST").path("/sg").body_contains("_meta");
+            then.json_body(json!({
+                "data": {
+                    "_meta": {
+                        "block": {
+                            "number": 12,
+                            "hash": "0x0000000000000000000000000000000000000000000000000000000000000abc",
+                            "timestamp": null
+                        },
+                        "deployment": "Qm123",
+                        "hasIndexingErrors": false
+                    }
+                }
+            }));
+        });
+
+        let report = lint_sources(
+            vec![get_yaml(&server.url("/rpc"), &server.url("/sg"))],
+            true,
+        )
+        .await
+        .unwrap();
+
+        let decimals = report
+            .issues
+            .iter()
+            .find(|issue| issue.rule == LintRule::TokenDecimalsMismatch)
+            .unwrap();
+        assert_eq!(decimals.location, "token 'token1'");
+        assert_eq!(decimals.severity, LintSeverity::Error);
+
+        let block = report
+            .issues
+            .iter()
+            .find(|issue| issue.rule == LintRule::DeploymentBlockInFuture)
+            .unwrap();
+        assert_eq!(block.location, "orderbook 'mainnet'");
+        assert!(report.has_errors());
+
+        // The block indexed by the subgraph does not exist on the orderbook's chain
+        let subgraph = report
+            .issues
+            .iter()
+            .find(|issue| issue.rule == LintRule::OrderbookSubgraphNetworkMismatch)
+            .unwrap();
+        assert_eq!(subgraph.location, "orderbook 'mainnet'");
+        assert!(subgraph.message.contains("block 12"));
+    }
+
+    #[tokio::test]
+    async fn test_lint_onchain_rpc_failure() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method("POST").path("/rpc");
+            then.status(500);
+        });
+
+        let report = lint_sources(
+            vec![get_yaml(&server.url("/rpc"), &server.url("/sg"))],
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(report
+            .issues
+            .iter()
+            .any(|issue| issue.rule == LintRule::OnchainCheckFailed
+                && issue.location == "token 'token1'"));
+        assert!(report
+            .issues
+            .iter()
+            .any(|issue| issue.rule == LintRule::OnchainCheckFailed
+                && issue.location == "orderbook 'mainnet'"));
+        assert!(!report
+            .issues
+            .iter()
+            .any(|issue| issue.rule == LintRule::TokenDecimalsMismatch));
+    }
+
+    #[tokio::test]
+    async fn test_lint_sources_offline() {
+        let report = lint_sources(
+            vec![get_yaml("http://localhost:1/rpc", "http://localhost:1/sg")],
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(report.issues.iter().all(|issue| !matches!(
+            issue.rule,
+            LintRule::TokenDecimalsMismatch
+                | LintRule::DeploymentBlockInFuture
+                | LintRule::OnchainCheckFailed
+        )));
+        assert!(report
+            .issues
+            .iter()
+            .any(|issue| issue.rule == LintRule::UnusedToken));
+    }
+}
@@ -1,4 +1,5 @@
-use rain_orderbook_subgraph_client::OrderbookSubgraphClient;
+use rain_orderbook_app_settings::subgraph::SubgraphCfg;
+use rain_orderbook_subgraph_client::{OrderbookSubgraphClient, SubgraphTransportConfig};
 use serde::{Deserialize, Serialize};
 use url::{ParseError, Url};
 
@@ -13,6 +14,19 @@ impl SubgraphArgs {
     }
 }
 
+/// Transport settings of a configured subgraph, falling back to the client
+/// defaults for timeout and retries when the yaml does not set them
+pub fn subgraph_transport_config(subgraph: &SubgraphCfg) -> SubgraphTransportConfig {
+    let default = SubgraphTransportConfig::default();
+    SubgraphTransportConfig {
+        timeout_ms: subgraph.timeout_ms.unwrap_or(default.timeout_ms),
+        max_retries: subgraph.max_retries.unwrap_or(default.max_retries),
+        headers: subgraph.headers.clone(),
+        fallback_urls: subgraph.fallback_urls.clone(),
+        ..default
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +71,25 @@ mod tests {
         let err = subgraph_args.to_subgraph_client().unwrap_err();
         assert_eq!(err, ParseError::RelativeUrlWithoutBase);
     }
+
+    #[test]
+    fn test_subgraph_transport_config() {
+        let config = subgraph_transport_config(&SubgraphCfg::default());
+        assert_eq!(config, SubgraphTransportConfig::default());
+
+        let subgraph = SubgraphCfg {
+            fallback_urls: vec![Url::parse("https://fallback.com").unwrap()],
+            headers: [("x-api-key".to_string(), "key".to_string())].into(),
+            timeout_ms: Some(1000),
+            ..SubgraphCfg::default()
+        };
+        let config = subgraph_transport_config(&subgraph);
+        assert_eq!(config.timeout_ms, 1000);
+        assert_eq!(
+            config.max_retries,
+            SubgraphTransportConfig::default().max_retries
+        );
+        assert_eq!(config.fallback_urls, subgraph.fallback_urls);
+        assert_eq!(config.headers, subgraph.headers);
+    }
 }
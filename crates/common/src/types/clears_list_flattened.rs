@@ -0,0 +1,181 @@
+use crate::{csv::TryIntoCsv, utils::timestamp::format_bigint_timestamp_display};
+use rain_math_float::Float;
+use rain_orderbook_subgraph_client::types::common::*;
+use serde::{Deserialize, Serialize};
+
+use super::FlattenError;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClearFlattened {
+    pub id: String,
+    pub timestamp: SgBigInt,
+    pub timestamp_display: String,
+    pub transaction: SgBytes,
+    pub clearer: SgBytes,
+    pub orderbook: SgBytes,
+    pub alice_input: SgBytes,
+    pub alice_input_display: String,
+    pub alice_output: SgBytes,
+    pub alice_output_display: String,
+    pub bob_input: SgBytes,
+    pub bob_input_display: String,
+    pub bob_output: SgBytes,
+    pub bob_output_display: String,
+    pub alice_bounty: SgBytes,
+    pub alice_bounty_display: String,
+    pub alice_bounty_token_symbol: Option<String>,
+    pub bob_bounty: SgBytes,
+    pub bob_bounty_display: String,
+    pub bob_bounty_token_symbol: Option<String>,
+}
+
+impl TryFrom<SgClear> for ClearFlattened {
+    type Error = FlattenError;
+
+    fn try_from(val: SgClear) -> Result<Self, Self::Error> {
+        let format_amount = |amount: &SgBytes| -> Result<String, FlattenError> {
+            Ok(Float::from_hex(&amount.0)?.format18()?)
+        };
+        let bounty_token_symbol = |bounty: &Option<SgClearBounty>| {
+            bounty
+                .as_ref()
+                .and_then(|bounty| bounty.vault.token.symbol.clone())
+        };
+        let timestamp = val.transaction.timestamp.clone();
+
+        Ok(Self {
+            id: val.id.0,
+            timestamp_display: format_bigint_timestamp_display(timestamp.0.clone())?,
+            timestamp,
+            transaction: val.transaction.id,
+            clearer: val.sender,
+            orderbook: val.orderbook.id,
+            alice_input_display: format_amount(&val.alice_input_amount)?,
+            alice_input: val.alice_input_amount,
+            alice_output_display: format_amount(&val.alice_output_amount)?,
+            alice_output: val.alice_output_amount,
+            bob_input_display: format_amount(&val.bob_input_amount)?,
+            bob_input: val.bob_input_amount,
+            bob_output_display: format_amount(&val.bob_output_amount)?,
+            bob_output: val.bob_output_amount,
+            alice_bounty_display: format_amount(&val.alice_bounty_amount)?,
+            alice_bounty: val.alice_bounty_amount,
+            alice_bounty_token_symbol: bounty_token_symbol(&val.alice_bounty_vault_balance_change),
+            bob_bounty_display: format_amount(&val.bob_bounty_amount)?,
+            bob_bounty: val.bob_bounty_amount,
+            bob_bounty_token_symbol: bounty_token_symbol(&val.bob_bounty_vault_balance_change),
+        })
+    }
+}
+
+impl TryIntoCsv<ClearFlattened> for Vec<ClearFlattened> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rain_math_float::FloatError;
+    use rain_orderbook_subgraph_client::utils::float::*;
+
+    fn mock_sg_transaction() -> SgTransaction {
+        SgTransaction {
+            id: SgBytes("tx001".to_string()),
+            from: SgBytes("0xclearer".to_string()),
+            block_number: SgBigInt("1000".to_string()),
+            timestamp: SgBigInt("1678886400".to_string()),
+        }
+    }
+
+    fn mock_sg_clear_bounty() -> SgClearBounty {
+        SgClearBounty {
+            id: SgBytes("bounty001".to_string()),
+            __typename: "ClearBounty".to_string(),
+            amount: SgBytes(F1.as_hex()),
+            new_vault_balance: SgBytes(F1.as_hex()),
+            old_vault_balance: SgBytes(F0.as_hex()),
+            vault: SgVaultBalanceChangeVault {
+                id: SgBytes("vault001".to_string()),
+                vault_id: SgBytes("1".to_string()),
+                token: SgErc20 {
+                    id: SgBytes("0xtoken".to_string()),
+                    address: SgBytes("0xtoken".to_string()),
+                    name: Some("Token".to_string()),
+                    symbol: Some("TKN".to_string()),
+                    decimals: Some(SgBigInt("18".to_string())),
+                },
+            },
+            timestamp: SgBigInt("1678886400".to_string()),
+            transaction: mock_sg_transaction(),
+            orderbook: SgOrderbook {
+                id: SgBytes("0xorderbook".to_string()),
+            },
+            sender: SgBytes("0xclearer".to_string()),
+        }
+    }
+
+    fn mock_sg_clear_default() -> SgClear {
+        SgClear {
+            id: SgBytes("clear001".to_string()),
+            alice_input_amount: SgBytes(F2.as_hex()),
+            alice_output_amount: SgBytes(F1.as_hex()),
+            bob_input_amount: SgBytes(F1.as_hex()),
+            bob_output_amount: SgBytes(F3.as_hex()),
+            alice_bounty_amount: SgBytes(F0.as_hex()),
+            bob_bounty_amount: SgBytes(F1.as_hex()),
+            alice_bounty_vault_balance_change: None,
+            bob_bounty_vault_balance_change: Some(mock_sg_clear_bounty()),
+            orderbook: SgOrderbook {
+                id: SgBytes("0xorderbook".to_string()),
+            },
+            trades: vec![],
+            transaction: mock_sg_transaction(),
+            sender: SgBytes("0xclearer".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_valid_sgclear_all_fields() {
+        let flattened = ClearFlattened::try_from(mock_sg_clear_default()).unwrap();
+
+        assert_eq!(flattened.id, "clear001");
+        assert_eq!(flattened.timestamp.0, "1678886400");
+        assert_eq!(
+            flattened.timestamp_display,
+            format_bigint_timestamp_display("1678886400".to_string()).unwrap()
+        );
+        assert_eq!(flattened.transaction.0, "tx001");
+        assert_eq!(flattened.clearer.0, "0xclearer");
+        assert_eq!(flattened.orderbook.0, "0xorderbook");
+        assert_eq!(flattened.alice_input_display, "2");
+        assert_eq!(flattened.alice_output_display, "1");
+        assert_eq!(flattened.bob_input_display, "1");
+        assert_eq!(flattened.bob_output_display, "3");
+        assert_eq!(flattened.alice_bounty_display, "0");
+        assert_eq!(flattened.alice_bounty_token_symbol, None);
+        assert_eq!(flattened.bob_bounty_display, "1");
+        assert_eq!(flattened.bob_bounty_token_symbol, Some("TKN".to_string()));
+    }
+
+    #[test]
+    fn test_unparseable_bounty_amount() {
+        let mut clear = mock_sg_clear_default();
+        clear.bob_bounty_amount = SgBytes("not_a_number".to_string());
+        let result = ClearFlattened::try_from(clear);
+        assert!(
+            matches!(
+                result,
+                Err(FlattenError::FloatError(FloatError::InvalidHex(_)))
+            ),
+            "Expected InvalidHex for unparseable bounty amount, got {result:?}",
+        );
+    }
+
+    #[test]
+    fn test_csv_header() {
+        let csv = vec![ClearFlattened::try_from(mock_sg_clear_default()).unwrap()]
+            .try_into_csv()
+            .unwrap();
+        assert!(csv.starts_with(
+            "id,timestamp,timestamp_display,transaction,clearer,orderbook,alice_input,"
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+mod clears_list_flattened;
 mod error;
 mod order_detail_extended;
 mod order_takes_list_flattened;
@@ -6,6 +7,7 @@ mod token_vault_flattened;
 mod vault;
 mod vault_balance_change_flattened;
 
+pub use clears_list_flattened::*;
 pub use error::*;
 pub use order_detail_extended::*;
 pub use order_takes_list_flattened::*;
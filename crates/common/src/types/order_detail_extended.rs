@@ -93,6 +93,7 @@ mod tests {
             }],
             trades: vec![SgOrderStructPartialTrade {
                 id: default_sg_bytes.clone(),
+                timestamp: default_big_int.clone(),
             }],
             remove_events: vec![SgRemoveOrder {
                 transaction: transaction.clone(),
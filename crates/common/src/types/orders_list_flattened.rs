@@ -186,6 +186,7 @@ mod tests {
             }],
             trades: vec![SgOrderStructPartialTrade {
                 id: SgBytes("trade-id-default".into()),
+                timestamp: SgBigInt("0".into()),
             }],
             remove_events: vec![],
         }
@@ -277,6 +278,7 @@ mod tests {
         });
         sg_order.trades.push(SgOrderStructPartialTrade {
             id: SgBytes("trade-id-another".into()),
+            timestamp: SgBigInt("0".into()),
         });
 
         let order_flattened = OrderFlattened::try_from(sg_order).unwrap();
@@ -2,12 +2,28 @@ use std::str::FromStr;
 
 use alloy::{hex::FromHexError, primitives::Address};
 use rain_orderbook_app_settings::{
+    accounts::AccountCfg,
+    chart::ChartCfg,
+    deployer::DeployerCfg,
+    deployment::DeploymentCfg,
+    gui::GuiCfg,
+    lint::{lint_settings, LintReport},
+    metaboard::MetaboardCfg,
+    network::NetworkCfg,
+    order::OrderCfg,
     orderbook::OrderbookCfg,
+    remote_networks::RemoteNetworksCfg,
+    remote_tokens::RemoteTokensCfg,
+    scenario::ScenarioCfg,
+    subgraph::SubgraphCfg,
+    token::TokenCfg,
     yaml::{
+        dotrain::DotrainYaml,
         orderbook::{OrderbookYaml as OrderbookYamlCfg, OrderbookYamlValidation},
         YamlError, YamlParsable,
     },
 };
+use rain_orderbook_common::settings_lint::lint_settings_with_onchain;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use wasm_bindgen_utils::prelude::*;
@@ -109,6 +125,503 @@ impl OrderbookYaml {
             Address::from_str(orderbook_address).map_err(OrderbookYamlError::FromHexError)?;
         Ok(self.yaml.get_orderbook_by_address(address)?)
     }
+
+    /// Lints the configuration for unused, unreachable and inconsistent definitions.
+    ///
+    /// Reports tokens not used by any order, scenarios without a deployment, orderbooks on
+    /// different chains sharing a subgraph, deployers on networks without an orderbook and
+    /// more. With `checkOnchain` enabled, token decimals are compared against the token
+    /// contracts and deployment blocks against each network's latest block.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = await orderbookYaml.lint(true);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const report = result.value;
+    /// for (const issue of report.issues) {
+    ///   console.log(`${issue.severity} [${issue.rule}] ${issue.location}: ${issue.message}`);
+    /// }
+    /// ```
+    #[wasm_export(
+        js_name = "lint",
+        unchecked_return_type = "LintReport",
+        return_description = "Lint issues sorted by descending severity"
+    )]
+    pub async fn lint(
+        &self,
+        #[wasm_export(
+            js_name = "checkOnchain",
+            param_description = "Optional boolean to also run checks that query the network rpcs (defaults to false)"
+        )]
+        check_onchain: Option<bool>,
+    ) -> Result<LintReport, OrderbookYamlError> {
+        let dotrain_yaml = DotrainYaml::from_orderbook_yaml(self.yaml.clone());
+        if check_onchain.unwrap_or(false) {
+            Ok(lint_settings_with_onchain(&self.yaml, &dotrain_yaml).await)
+        } else {
+            Ok(lint_settings(&self.yaml, &dotrain_yaml))
+        }
+    }
+
+    /// Creates or updates a network in the YAML configuration.
+    ///
+    /// The network is written under `networks` using its `key`. Existing entries are updated in
+    /// place so the order of keys in the document stays stable, new entries are appended.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const network = {
+    ///   key: "arbitrum",
+    ///   rpcs: ["https://arbitrum.infura.io"],
+    ///   chainId: 42161,
+    ///   label: "Arbitrum One",
+    /// };
+    /// const result = orderbookYaml.setNetwork(network);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setNetwork", unchecked_return_type = "void")]
+    pub fn set_network(
+        &self,
+        #[wasm_export(param_description = "The network configuration to write")]
+        network: NetworkCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&network)?)
+    }
+
+    /// Creates or updates a token in the YAML configuration.
+    ///
+    /// The token is written under `tokens` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const token = {
+    ///   key: "weth",
+    ///   network,
+    ///   address: "0x...",
+    ///   decimals: 18,
+    ///   symbol: "WETH",
+    /// };
+    /// const result = orderbookYaml.setToken(token);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setToken", unchecked_return_type = "void")]
+    pub fn set_token(
+        &self,
+        #[wasm_export(param_description = "The token configuration to write")] token: TokenCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&token)?)
+    }
+
+    /// Creates or updates a subgraph in the YAML configuration.
+    ///
+    /// The subgraph is written under `subgraphs` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const subgraph = { key: "arbitrum", url: "https://api.thegraph.com/subgraphs/name/arb" };
+    /// const result = orderbookYaml.setSubgraph(subgraph);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setSubgraph", unchecked_return_type = "void")]
+    pub fn set_subgraph(
+        &self,
+        #[wasm_export(param_description = "The subgraph configuration to write")]
+        subgraph: SubgraphCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&subgraph)?)
+    }
+
+    /// Creates or updates a metaboard in the YAML configuration.
+    ///
+    /// The metaboard is written under `metaboards` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const metaboard = { key: "arbitrum", url: "https://meta.example.com/arbitrum" };
+    /// const result = orderbookYaml.setMetaboard(metaboard);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setMetaboard", unchecked_return_type = "void")]
+    pub fn set_metaboard(
+        &self,
+        #[wasm_export(param_description = "The metaboard configuration to write")]
+        metaboard: MetaboardCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&metaboard)?)
+    }
+
+    /// Creates or updates an orderbook in the YAML configuration.
+    ///
+    /// The orderbook is written under `orderbooks` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const orderbook = orderbookYaml.getOrderbookByAddress("0x...").value;
+    /// orderbook.deploymentBlock = 12345;
+    /// const result = orderbookYaml.setOrderbook(orderbook);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setOrderbook", unchecked_return_type = "void")]
+    pub fn set_orderbook(
+        &self,
+        #[wasm_export(param_description = "The orderbook configuration to write")]
+        orderbook: OrderbookCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&orderbook)?)
+    }
+
+    /// Creates or updates a deployer in the YAML configuration.
+    ///
+    /// The deployer is written under `deployers` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const deployer = { key: "arbitrum", address: "0x...", network };
+    /// const result = orderbookYaml.setDeployer(deployer);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setDeployer", unchecked_return_type = "void")]
+    pub fn set_deployer(
+        &self,
+        #[wasm_export(param_description = "The deployer configuration to write")]
+        deployer: DeployerCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&deployer)?)
+    }
+
+    /// Creates or updates an account in the YAML configuration.
+    ///
+    /// The account is written under `accounts` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const account = { key: "admin", address: "0x..." };
+    /// const result = orderbookYaml.setAccount(account);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setAccount", unchecked_return_type = "void")]
+    pub fn set_account(
+        &self,
+        #[wasm_export(param_description = "The account configuration to write")]
+        account: AccountCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&account)?)
+    }
+
+    /// Creates or updates an order in the YAML configuration.
+    ///
+    /// The order is written under `orders` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const order = {
+    ///   key: "order1",
+    ///   inputs: [{ token, vaultId: "1" }],
+    ///   outputs: [{ token: otherToken }],
+    ///   network,
+    /// };
+    /// const result = orderbookYaml.setOrder(order);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setOrder", unchecked_return_type = "void")]
+    pub fn set_order(
+        &self,
+        #[wasm_export(param_description = "The order configuration to write")] order: OrderCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&order)?)
+    }
+
+    /// Creates or updates a scenario in the YAML configuration.
+    ///
+    /// The scenario is written under `scenarios` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const scenario = { key: "scenario1", bindings: { "max-amount": "100" }, deployer };
+    /// const result = orderbookYaml.setScenario(scenario);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setScenario", unchecked_return_type = "void")]
+    pub fn set_scenario(
+        &self,
+        #[wasm_export(param_description = "The scenario configuration to write")]
+        scenario: ScenarioCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&scenario)?)
+    }
+
+    /// Creates or updates a deployment in the YAML configuration.
+    ///
+    /// The deployment is written under `deployments` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const deployment = { key: "deployment1", order, scenario };
+    /// const result = orderbookYaml.setDeployment(deployment);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setDeployment", unchecked_return_type = "void")]
+    pub fn set_deployment(
+        &self,
+        #[wasm_export(param_description = "The deployment configuration to write")]
+        deployment: DeploymentCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&deployment)?)
+    }
+
+    /// Creates or updates the GUI section of the YAML configuration.
+    ///
+    /// The GUI is written under `gui` with every deployment it holds, deployments missing from
+    /// it are removed. Short descriptions are kept from the existing document.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// gui.name = "My strategy";
+    /// const result = orderbookYaml.setGui(gui);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setGui", unchecked_return_type = "void")]
+    pub fn set_gui(
+        &self,
+        #[wasm_export(param_description = "The GUI configuration to write")] gui: GuiCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&gui)?)
+    }
+
+    /// Creates or updates a chart in the YAML configuration.
+    ///
+    /// The chart is written under `charts` using its `key`. Plots are keyed by position, reusing
+    /// the keys of the existing chart.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const chart = { key: "chart1", scenario, metrics: [{ label: "Price", value: "0.1" }] };
+    /// const result = orderbookYaml.setChart(chart);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const yaml = orderbookYaml.getYamlStrings().value[0];
+    /// ```
+    #[wasm_export(js_name = "setChart", unchecked_return_type = "void")]
+    pub fn set_chart(
+        &self,
+        #[wasm_export(param_description = "The chart configuration to write")] chart: ChartCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&chart)?)
+    }
+
+    /// Creates or updates a remote network source in the YAML configuration.
+    ///
+    /// The source is written under `using-networks-from` using its `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const remoteNetworks = {
+    ///   key: "chainid",
+    ///   url: "https://chainid.network/chains.json",
+    ///   format: "chainid",
+    /// };
+    /// const result = orderbookYaml.setRemoteNetworks(remoteNetworks);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// ```
+    #[wasm_export(js_name = "setRemoteNetworks", unchecked_return_type = "void")]
+    pub fn set_remote_networks(
+        &self,
+        #[wasm_export(
+            js_name = "remoteNetworks",
+            param_description = "The remote network source to write"
+        )]
+        remote_networks: RemoteNetworksCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&remote_networks)?)
+    }
+
+    /// Replaces the token lists of the YAML configuration.
+    ///
+    /// The lists are written under `using-tokens-from`, pinned lists keep their sha256.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const remoteTokens = { urls: ["https://tokens.example.com/list.json"], sha256: {} };
+    /// const result = orderbookYaml.setRemoteTokens(remoteTokens);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// ```
+    #[wasm_export(js_name = "setRemoteTokens", unchecked_return_type = "void")]
+    pub fn set_remote_tokens(
+        &self,
+        #[wasm_export(
+            js_name = "remoteTokens",
+            param_description = "The token lists to write"
+        )]
+        remote_tokens: RemoteTokensCfg,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.write_entity(&remote_tokens)?)
+    }
+
+    /// Sets the spec version of the YAML configuration.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = orderbookYaml.setSpecVersion("3");
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// ```
+    #[wasm_export(js_name = "setSpecVersion", unchecked_return_type = "void")]
+    pub fn set_spec_version(
+        &self,
+        #[wasm_export(param_description = "The spec version to write")] version: String,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.set_spec_version(&version)?)
+    }
+
+    /// Enables or disables sentry error reporting in the YAML configuration.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = orderbookYaml.setSentry(false);
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// ```
+    #[wasm_export(js_name = "setSentry", unchecked_return_type = "void")]
+    pub fn set_sentry(
+        &self,
+        #[wasm_export(param_description = "Whether sentry is enabled")] enabled: bool,
+    ) -> Result<(), OrderbookYamlError> {
+        Ok(self.yaml.set_sentry(enabled)?)
+    }
+
+    /// Removes an entry from a section of the YAML configuration.
+    ///
+    /// Nested scenarios are addressed with dot separated keys, e.g. `parent.child`. GUI
+    /// deployments are removed with the `gui` section and the `deployments` key.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = orderbookYaml.removeEntry("tokens", "token1");
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const removed = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "removeEntry",
+        unchecked_return_type = "boolean",
+        return_description = "Whether an entry was removed"
+    )]
+    pub fn remove_entry(
+        &self,
+        #[wasm_export(param_description = "Section of the entry, e.g. `tokens` or `scenarios`")]
+        section: &str,
+        #[wasm_export(param_description = "Key of the entry within the section")] key: &str,
+    ) -> Result<bool, OrderbookYamlError> {
+        Ok(self.yaml.remove_entity(section, key)?)
+    }
+
+    /// Serializes the YAML documents back to strings.
+    ///
+    /// Returns one string per source passed to the constructor, reflecting any changes made
+    /// through the setters. Useful for generating deployment files from code.
+    ///
+    /// ## Examples
+    ///
+    /// ```javascript
+    /// const result = orderbookYaml.getYamlStrings();
+    /// if (result.error) {
+    ///   console.error("Error:", result.error.readableMsg);
+    ///   return;
+    /// }
+    /// const [settings] = result.value;
+    /// ```
+    #[wasm_export(
+        js_name = "getYamlStrings",
+        unchecked_return_type = "string[]",
+        return_description = "YAML string for each source document"
+    )]
+    pub fn get_yaml_strings(&self) -> Result<Vec<String>, OrderbookYamlError> {
+        Ok(self
+            .yaml
+            .documents
+            .iter()
+            .map(|document| OrderbookYamlCfg::get_yaml_string(document.clone()))
+            .collect::<Result<Vec<_>, _>>()?)
+    }
 }
 
 #[derive(Error, Debug)]
@@ -147,7 +660,7 @@ impl From<OrderbookYamlError> for WasmEncodedError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rain_orderbook_app_settings::spec_version::SpecVersion;
+    use rain_orderbook_app_settings::{lint::LintRule, spec_version::SpecVersion};
     use wasm_bindgen_test::wasm_bindgen_test;
 
     pub fn get_yaml() -> String {
@@ -276,4 +789,72 @@ mod tests {
             }
         }
     }
+
+    #[wasm_bindgen_test]
+    async fn test_orderbook_yaml_lint() {
+        let orderbook_yaml = OrderbookYaml::new(vec![get_yaml()], None).unwrap();
+        let report = orderbook_yaml.lint(None).await.unwrap();
+
+        assert!(!report.has_errors());
+        assert!(report.issues.iter().any(|issue| {
+            issue.rule == LintRule::UnusedToken && issue.location == "token 'token1'"
+        }));
+        assert!(report.issues.iter().any(|issue| {
+            issue.rule == LintRule::UnusedSubgraph && issue.location == "subgraph 'secondary'"
+        }));
+        assert!(report.issues.iter().any(|issue| {
+            issue.rule == LintRule::MetaboardWithoutNetwork
+                && issue.location == "metaboard 'board1'"
+        }));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_orderbook_yaml_set_and_remove_entries() {
+        let orderbook_yaml = OrderbookYaml::new(vec![get_yaml()], None).unwrap();
+
+        let mut network = orderbook_yaml.yaml.get_network("mainnet").unwrap();
+        network.label = Some("Mainnet".to_string());
+        orderbook_yaml.set_network(network).unwrap();
+
+        let mut token = orderbook_yaml.yaml.get_token("token1").unwrap();
+        token.key = "token2".to_string();
+        token.symbol = None;
+        orderbook_yaml.set_token(token).unwrap();
+
+        assert!(orderbook_yaml
+            .remove_entry("subgraphs", "secondary")
+            .unwrap());
+        assert!(!orderbook_yaml
+            .remove_entry("subgraphs", "secondary")
+            .unwrap());
+
+        let yaml = orderbook_yaml.get_yaml_strings().unwrap();
+        let reloaded = OrderbookYaml::new(yaml, None).unwrap();
+        assert_eq!(
+            reloaded.yaml.get_network("mainnet").unwrap().label,
+            Some("Mainnet".to_string())
+        );
+        assert_eq!(reloaded.yaml.get_token("token2").unwrap().symbol, None);
+        assert_eq!(reloaded.yaml.get_token_keys().unwrap().len(), 2);
+        assert!(reloaded.yaml.get_subgraph("secondary").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_orderbook_yaml_set_root_values() {
+        let orderbook_yaml = OrderbookYaml::new(vec![get_yaml()], None).unwrap();
+
+        orderbook_yaml.set_sentry(false).unwrap();
+        orderbook_yaml
+            .set_spec_version(SpecVersion::current())
+            .unwrap();
+        assert!(!orderbook_yaml.remove_entry("gui", "deployments").unwrap());
+
+        let yaml = orderbook_yaml.get_yaml_strings().unwrap();
+        let reloaded = OrderbookYaml::new(yaml, None).unwrap();
+        assert_eq!(reloaded.yaml.get_sentry().unwrap(), Some(false));
+        assert_eq!(
+            reloaded.yaml.get_spec_version().unwrap(),
+            SpecVersion::current()
+        );
+    }
 }
@@ -1,8 +1,10 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
     context::Context, default_document, optional_hash, require_string, FieldErrorKind, YamlError,
     YamlParsableHash,
 };
 use alloy::{hex::FromHexError, primitives::Address};
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
@@ -14,11 +16,13 @@ use thiserror::Error;
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct AccountCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
@@ -76,6 +80,16 @@ impl YamlParsableHash for AccountCfg {
     }
 }
 
+impl YamlWritable for AccountCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["accounts".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_string(self.address))
+    }
+}
+
 impl Default for AccountCfg {
     fn default() -> Self {
         Self {
@@ -48,12 +48,8 @@ impl BlockRangeCfg {
     }
 }
 
-// Serialize implementation for BlockRange
-impl Serialize for BlockRangeCfg {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
+impl fmt::Display for BlockRangeCfg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut range_string = String::new();
 
         match &self.start {
@@ -70,7 +66,17 @@ impl Serialize for BlockRangeCfg {
             BlockCfg::Number(n) => range_string.push_str(&n.to_string()),
         }
 
-        serializer.serialize_str(&range_string)
+        write!(f, "{}", range_string)
+    }
+}
+
+// Serialize implementation for BlockRange
+impl Serialize for BlockRangeCfg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
     }
 }
 
@@ -1,7 +1,9 @@
 use crate::{
     yaml::{
-        context::Context, default_document, get_hash_value, get_hash_value_as_option,
-        optional_hash, optional_string, optional_vec, require_hash, require_string, require_vec,
+        context::Context,
+        default_document, get_hash_value, get_hash_value_as_option, optional_hash, optional_string,
+        optional_vec, require_hash, require_string, require_vec,
+        writer::{yaml_hash, yaml_string, YamlWritable},
         FieldErrorKind, YamlError, YamlParsableHash,
     },
     *,
@@ -11,7 +13,7 @@ use std::{
     collections::HashMap,
     sync::{Arc, RwLock},
 };
-use strict_yaml_rust::StrictYaml;
+use strict_yaml_rust::{strict_yaml::Hash, StrictYaml};
 use thiserror::Error;
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
@@ -631,6 +633,211 @@ impl YamlParsableHash for ChartCfg {
     }
 }
 
+impl ChartCfg {
+    // plot keys aren't part of the parsed config, the ones of the existing
+    // chart are reused in order
+    fn plot_keys(&self) -> Result<Vec<String>, YamlError> {
+        let document = self.document.read().map_err(|_| YamlError::ReadLockError)?;
+        let plots = optional_hash(&document, "charts")
+            .and_then(|charts| charts.get(&StrictYaml::String(self.key.clone())))
+            .and_then(|chart| optional_hash(chart, "plots"))
+            .map(|plots| {
+                plots
+                    .keys()
+                    .filter_map(|key| key.as_str().map(|key| key.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(plots)
+    }
+}
+
+impl YamlWritable for ChartCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["charts".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        let plots = match &self.plots {
+            Some(plots) => {
+                let keys = self.plot_keys()?;
+                Some(StrictYaml::Hash(
+                    plots
+                        .iter()
+                        .enumerate()
+                        .map(|(index, plot)| {
+                            let key = keys
+                                .get(index)
+                                .cloned()
+                                .unwrap_or_else(|| format!("plot-{index}"));
+                            (yaml_string(key), plot_to_yaml(plot))
+                        })
+                        .collect::<Hash>(),
+                ))
+            }
+            None => None,
+        };
+
+        let metrics = self.metrics.as_ref().map(|metrics| {
+            StrictYaml::Array(
+                metrics
+                    .iter()
+                    .map(|metric| {
+                        yaml_hash([
+                            ("label", Some(yaml_string(&metric.label))),
+                            ("description", metric.description.as_ref().map(yaml_string)),
+                            ("unit-prefix", metric.unit_prefix.as_ref().map(yaml_string)),
+                            ("unit-suffix", metric.unit_suffix.as_ref().map(yaml_string)),
+                            ("value", Some(yaml_string(&metric.value))),
+                            ("precision", metric.precision.map(yaml_string)),
+                        ])
+                    })
+                    .collect(),
+            )
+        });
+
+        // scenarios named after the chart are picked up without a reference
+        let scenario = (self.scenario.key != self.key).then(|| yaml_string(&self.scenario.key));
+
+        Ok(yaml_hash([
+            ("scenario", scenario),
+            ("plots", plots),
+            ("metrics", metrics),
+        ]))
+    }
+}
+
+fn plot_to_yaml(plot: &PlotCfg) -> StrictYaml {
+    let axis_to_yaml = |axis: &AxisOptionsCfg| {
+        yaml_hash([
+            ("label", axis.label.as_ref().map(yaml_string)),
+            ("anchor", axis.anchor.as_ref().map(yaml_string)),
+            ("label-anchor", axis.label_anchor.as_ref().map(yaml_string)),
+            ("label-arrow", axis.label_arrow.as_ref().map(yaml_string)),
+        ])
+    };
+
+    yaml_hash([
+        ("title", plot.title.as_ref().map(yaml_string)),
+        ("subtitle", plot.subtitle.as_ref().map(yaml_string)),
+        (
+            "marks",
+            Some(StrictYaml::Array(
+                plot.marks.iter().map(mark_to_yaml).collect(),
+            )),
+        ),
+        ("x", plot.x.as_ref().map(axis_to_yaml)),
+        ("y", plot.y.as_ref().map(axis_to_yaml)),
+        ("margin", plot.margin.map(yaml_string)),
+        ("margin-left", plot.margin_left.map(yaml_string)),
+        ("margin-right", plot.margin_right.map(yaml_string)),
+        ("margin-top", plot.margin_top.map(yaml_string)),
+        ("margin-bottom", plot.margin_bottom.map(yaml_string)),
+        ("inset", plot.inset.map(yaml_string)),
+    ])
+}
+
+fn mark_to_yaml(mark: &MarkCfg) -> StrictYaml {
+    let string = |value: &Option<String>| value.as_ref().map(yaml_string);
+    let (mark_type, options) = match mark {
+        MarkCfg::Dot(DotOptionsCfg {
+            x,
+            y,
+            r,
+            fill,
+            stroke,
+            transform,
+        })
+        | MarkCfg::Line(LineOptionsCfg {
+            x,
+            y,
+            r,
+            fill,
+            stroke,
+            transform,
+        }) => (
+            if matches!(mark, MarkCfg::Dot(_)) {
+                "dot"
+            } else {
+                "line"
+            },
+            yaml_hash([
+                ("x", string(x)),
+                ("y", string(y)),
+                ("r", r.map(yaml_string)),
+                ("fill", string(fill)),
+                ("stroke", string(stroke)),
+                ("transform", transform.as_ref().map(transform_to_yaml)),
+            ]),
+        ),
+        MarkCfg::RectY(RectYOptionsCfg {
+            x0,
+            x1,
+            y0,
+            y1,
+            transform,
+        }) => (
+            "recty",
+            yaml_hash([
+                ("x0", string(x0)),
+                ("x1", string(x1)),
+                ("y0", string(y0)),
+                ("y1", string(y1)),
+                ("transform", transform.as_ref().map(transform_to_yaml)),
+            ]),
+        ),
+    };
+
+    yaml_hash([
+        ("type", Some(yaml_string(mark_type))),
+        ("options", Some(options)),
+    ])
+}
+
+fn transform_to_yaml(transform: &TransformCfg) -> StrictYaml {
+    let string = |value: &Option<String>| value.as_ref().map(yaml_string);
+    let (transform_type, outputs, options) = match transform {
+        TransformCfg::HexBin(HexBinTransformCfg { outputs, options }) => (
+            "hexbin",
+            outputs,
+            yaml_hash([
+                ("x", string(&options.x)),
+                ("y", string(&options.y)),
+                ("bin-width", options.bin_width.map(yaml_string)),
+            ]),
+        ),
+        TransformCfg::BinX(BinXTransformCfg { outputs, options }) => (
+            "binx",
+            outputs,
+            yaml_hash([
+                ("x", string(&options.x)),
+                ("thresholds", options.thresholds.map(yaml_string)),
+            ]),
+        ),
+    };
+
+    yaml_hash([
+        ("type", Some(yaml_string(transform_type))),
+        (
+            "content",
+            Some(yaml_hash([
+                (
+                    "outputs",
+                    Some(yaml_hash([
+                        ("x", string(&outputs.x)),
+                        ("y", string(&outputs.y)),
+                        ("r", outputs.r.map(yaml_string)),
+                        ("z", string(&outputs.z)),
+                        ("stroke", string(&outputs.stroke)),
+                        ("fill", string(&outputs.fill)),
+                    ])),
+                ),
+                ("options", Some(options)),
+            ])),
+        ),
+    ])
+}
+
 impl Default for ChartCfg {
     fn default() -> Self {
         ChartCfg {
@@ -1,5 +1,7 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::*;
 use alloy::primitives::Address;
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
@@ -15,11 +17,13 @@ use yaml::{
     FieldErrorKind, YamlError, YamlParsableHash,
 };
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "kebab-case")]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct DeployerCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
@@ -74,6 +78,19 @@ impl DeployerCfg {
     }
 }
 
+impl YamlWritable for DeployerCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["deployers".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_hash([
+            ("address", Some(yaml_string(self.address))),
+            ("network", Some(yaml_string(&self.network.key))),
+        ]))
+    }
+}
+
 impl Default for DeployerCfg {
     fn default() -> Self {
         DeployerCfg::dummy()
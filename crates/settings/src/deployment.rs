@@ -1,4 +1,6 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::*;
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
@@ -13,11 +15,13 @@ use yaml::{
     default_document, require_hash, require_string, FieldErrorKind, YamlError, YamlParsableHash,
 };
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct DeploymentCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     pub scenario: Arc<ScenarioCfg>,
@@ -145,6 +149,19 @@ impl YamlParsableHash for DeploymentCfg {
     }
 }
 
+impl YamlWritable for DeploymentCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["deployments".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_hash([
+            ("order", Some(yaml_string(&self.order.key))),
+            ("scenario", Some(yaml_string(&self.scenario.key))),
+        ]))
+    }
+}
+
 impl Default for DeploymentCfg {
     fn default() -> Self {
         Self {
@@ -2,15 +2,16 @@ use crate::{
     yaml::{
         context::{Context, GuiContextTrait},
         default_document, get_hash_value, get_hash_value_as_option, optional_hash, optional_string,
-        optional_vec, require_string, require_vec, FieldErrorKind, YamlError, YamlParsableHash,
-        YamlParseableValue,
+        optional_vec, require_string, require_vec,
+        writer::{yaml_hash, yaml_string, YamlWritable},
+        FieldErrorKind, YamlError, YamlParsableHash, YamlParseableValue,
     },
     DeploymentCfg, TokenCfg,
 };
 use alloy::primitives::{ruint::ParseError, utils::UnitsError};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     sync::{Arc, RwLock},
 };
 use strict_yaml_rust::{strict_yaml::Hash, StrictYaml};
@@ -40,10 +41,81 @@ pub enum FieldValueValidationCfg {
         max_length: Option<u32>,
     },
     Boolean,
+    /// EVM address, checksum verified when given in mixed case
+    Address,
+    /// Human readable amount of `token`, scaled by its decimals when bound
+    TokenAmount {
+        token: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        minimum: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        exclusive_minimum: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        maximum: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        exclusive_maximum: Option<String>,
+    },
+    /// Percentage such as `2.5`, bound as the fraction `0.025`
+    Percentage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        minimum: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        maximum: Option<String>,
+    },
+    /// Duration such as `1h30m` or `90`, bound as a number of seconds
+    Duration {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        minimum: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        maximum: Option<String>,
+    },
+    /// Value restricted to one of the field presets
+    Enum,
 }
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(FieldValueValidationCfg);
 
+/// Condition on another field's value or on a select token. Without a
+/// comparison the condition holds when the field or token is set.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "kebab-case")]
+pub struct GuiFieldConditionCfg {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub select_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub equals: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub not_equals: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub one_of: Option<Vec<String>>,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(GuiFieldConditionCfg);
+
+impl GuiFieldConditionCfg {
+    /// Checks the condition against the current value of the referenced field or token
+    pub fn is_met(&self, value: Option<&str>) -> bool {
+        match (value, &self.equals, &self.not_equals, &self.one_of) {
+            (None, _, Some(_), _) => true,
+            (None, _, _, _) => false,
+            (Some(value), Some(equals), _, _) => value.eq_ignore_ascii_case(equals),
+            (Some(value), _, Some(not_equals), _) => !value.eq_ignore_ascii_case(not_equals),
+            (Some(value), _, _, Some(one_of)) => {
+                one_of.iter().any(|v| value.eq_ignore_ascii_case(v))
+            }
+            (Some(value), None, None, None) => !value.is_empty(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "kebab-case")]
@@ -99,6 +171,19 @@ pub struct GuiFieldDefinitionSourceCfg {
     pub show_custom_field: Option<bool>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub validation: Option<FieldValueValidationCfg>,
+    /// Bindings this field was known by in earlier revisions of the order,
+    /// used to carry saved values over when a binding is renamed.
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub previous_bindings: Option<Vec<String>>,
+    /// The field is only shown, validated and bound when all conditions hold
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub show_if: Option<Vec<GuiFieldConditionCfg>>,
+    /// A shown field is only required when all conditions hold
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub required_if: Option<Vec<GuiFieldConditionCfg>>,
+    /// Binding of another field whose value is used as this field's default
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub default_from: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -190,6 +275,10 @@ impl GuiConfigSourceCfg {
                             default: field_source.default.clone(),
                             show_custom_field: field_source.show_custom_field,
                             validation: field_source.validation.clone(),
+                            previous_bindings: field_source.previous_bindings.clone(),
+                            show_if: field_source.show_if.clone(),
+                            required_if: field_source.required_if.clone(),
+                            default_from: field_source.default_from.clone(),
                         })
                     })
                     .collect::<Result<Vec<_>, ParseGuiConfigSourceError>>()?;
@@ -310,6 +399,19 @@ pub struct GuiFieldDefinitionCfg {
     pub show_custom_field: Option<bool>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub validation: Option<FieldValueValidationCfg>,
+    /// Bindings this field was known by in earlier revisions of the order,
+    /// used to carry saved values over when a binding is renamed.
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub previous_bindings: Option<Vec<String>>,
+    /// The field is only shown, validated and bound when all conditions hold
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub show_if: Option<Vec<GuiFieldConditionCfg>>,
+    /// A shown field is only required when all conditions hold
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub required_if: Option<Vec<GuiFieldConditionCfg>>,
+    /// Binding of another field whose value is used as this field's default
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub default_from: Option<String>,
 }
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(GuiFieldDefinitionCfg);
@@ -847,6 +949,35 @@ impl YamlParseableValue for GuiCfg {
                                 "validation for field index '{field_index}' in {location}"
                             ))
                         }).transpose()?;
+                        if validation == Some(FieldValueValidationCfg::Enum) && presets.as_ref().map_or(true, |p| p.is_empty()) {
+                            return Err(YamlError::Field {
+                                kind: FieldErrorKind::InvalidValue {
+                                    field: "validation".to_string(),
+                                    reason: "enum fields need at least one preset".to_string(),
+                                },
+                                location: format!("field index '{field_index}' in {location}"),
+                            });
+                        }
+
+                        let previous_bindings = optional_vec(field_yaml, "previous-bindings").map(|bindings| {
+                            bindings.iter().enumerate().map(|(binding_index, binding_yaml)| {
+                                binding_yaml.as_str().map(str::to_string).ok_or(YamlError::Field {
+                                    kind: FieldErrorKind::InvalidType {
+                                        field: "previous-bindings".to_string(),
+                                        expected: "a string".to_string(),
+                                    },
+                                    location: format!(
+                                        "previous binding index '{binding_index}' for field index '{field_index}' in {location}",
+                                    ),
+                                })
+                            })
+                            .collect::<Result<Vec<_>, YamlError>>()
+                        }).transpose()?;
+
+                        let field_location = format!("field index '{field_index}' in {location}");
+                        let show_if = parse_field_conditions(field_yaml, "show-if", &field_location)?;
+                        let required_if = parse_field_conditions(field_yaml, "required-if", &field_location)?;
+                        let default_from = optional_string(field_yaml, "default-from");
 
                         let gui_field_definition = GuiFieldDefinitionCfg {
                             binding,
@@ -856,10 +987,15 @@ impl YamlParseableValue for GuiCfg {
                             default,
                             show_custom_field,
                             validation,
+                            previous_bindings,
+                            show_if,
+                            required_if,
+                            default_from,
                         };
                         Ok(gui_field_definition)
                     })
                     .collect::<Result<Vec<_>, YamlError>>()?;
+                    validate_field_references(&fields, select_tokens.as_deref(), &location)?;
 
                     let gui_deployment = GuiDeploymentCfg {
                         document: document.clone(),
@@ -890,6 +1026,439 @@ impl YamlParseableValue for GuiCfg {
     }
 }
 
+// `short-description` isn't part of the parsed config and deposit tokens
+// that point at an unselected select-token are unresolved, so both are kept
+// from the existing document. Field names and descriptions are written as
+// parsed, with their select-token placeholders already interpolated.
+impl YamlWritable for GuiCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["gui".to_string()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        let deployments = self
+            .deployments
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(key, deployment)| Ok((yaml_string(key), deployment.to_yaml_value()?)))
+            .collect::<Result<Hash, YamlError>>()?;
+
+        Ok(yaml_hash([
+            ("name", Some(yaml_string(&self.name))),
+            ("description", Some(yaml_string(&self.description))),
+            ("deployments", Some(StrictYaml::Hash(deployments))),
+        ]))
+    }
+
+    fn preserved_keys(&self) -> &'static [&'static str] {
+        &[
+            "short-description",
+            "deployments.*.short-description",
+            "deployments.*.deposits.*.token",
+        ]
+    }
+}
+
+impl YamlWritable for GuiDeploymentCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec![
+            "gui".to_string(),
+            "deployments".to_string(),
+            self.key.clone(),
+        ]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        let strings = |values: &[String]| {
+            StrictYaml::Array(values.iter().map(yaml_string).collect::<Vec<_>>())
+        };
+
+        let deposits = self
+            .deposits
+            .iter()
+            .map(|deposit| {
+                yaml_hash([
+                    (
+                        "token",
+                        deposit.token.as_ref().map(|token| yaml_string(&token.key)),
+                    ),
+                    ("presets", deposit.presets.as_deref().map(strings)),
+                    (
+                        "validation",
+                        deposit.validation.as_ref().map(|validation| {
+                            yaml_hash([
+                                ("minimum", validation.minimum.as_ref().map(yaml_string)),
+                                (
+                                    "exclusive-minimum",
+                                    validation.exclusive_minimum.as_ref().map(yaml_string),
+                                ),
+                                ("maximum", validation.maximum.as_ref().map(yaml_string)),
+                                (
+                                    "exclusive-maximum",
+                                    validation.exclusive_maximum.as_ref().map(yaml_string),
+                                ),
+                            ])
+                        }),
+                    ),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let conditions = |conditions: &[GuiFieldConditionCfg]| {
+            StrictYaml::Array(
+                conditions
+                    .iter()
+                    .map(|condition| {
+                        yaml_hash([
+                            ("field", condition.field.as_ref().map(yaml_string)),
+                            (
+                                "select-token",
+                                condition.select_token.as_ref().map(yaml_string),
+                            ),
+                            ("equals", condition.equals.as_ref().map(yaml_string)),
+                            ("not-equals", condition.not_equals.as_ref().map(yaml_string)),
+                            ("one-of", condition.one_of.as_deref().map(strings)),
+                        ])
+                    })
+                    .collect(),
+            )
+        };
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                yaml_hash([
+                    ("binding", Some(yaml_string(&field.binding))),
+                    ("name", Some(yaml_string(&field.name))),
+                    ("description", field.description.as_ref().map(yaml_string)),
+                    (
+                        "presets",
+                        field.presets.as_ref().map(|presets| {
+                            StrictYaml::Array(
+                                presets
+                                    .iter()
+                                    .map(|preset| {
+                                        yaml_hash([
+                                            ("name", preset.name.as_ref().map(yaml_string)),
+                                            ("value", Some(yaml_string(&preset.value))),
+                                        ])
+                                    })
+                                    .collect(),
+                            )
+                        }),
+                    ),
+                    ("default", field.default.as_ref().map(yaml_string)),
+                    (
+                        "show-custom-field",
+                        field.show_custom_field.map(yaml_string),
+                    ),
+                    (
+                        "validation",
+                        field.validation.as_ref().map(field_validation_to_yaml),
+                    ),
+                    (
+                        "previous-bindings",
+                        field.previous_bindings.as_deref().map(strings),
+                    ),
+                    ("show-if", field.show_if.as_deref().map(conditions)),
+                    ("required-if", field.required_if.as_deref().map(conditions)),
+                    ("default-from", field.default_from.as_ref().map(yaml_string)),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let select_tokens = self.select_tokens.as_ref().map(|select_tokens| {
+            StrictYaml::Array(
+                select_tokens
+                    .iter()
+                    .map(|select_token| {
+                        yaml_hash([
+                            ("key", Some(yaml_string(&select_token.key))),
+                            ("name", select_token.name.as_ref().map(yaml_string)),
+                            (
+                                "description",
+                                select_token.description.as_ref().map(yaml_string),
+                            ),
+                        ])
+                    })
+                    .collect(),
+            )
+        });
+
+        Ok(yaml_hash([
+            ("name", Some(yaml_string(&self.name))),
+            ("description", Some(yaml_string(&self.description))),
+            ("deposits", Some(StrictYaml::Array(deposits))),
+            ("fields", Some(StrictYaml::Array(fields))),
+            ("select-tokens", select_tokens),
+        ]))
+    }
+
+    fn preserved_keys(&self) -> &'static [&'static str] {
+        &["short-description", "deposits.*.token"]
+    }
+}
+
+fn field_validation_to_yaml(validation: &FieldValueValidationCfg) -> StrictYaml {
+    let string = |value: &Option<String>| value.as_ref().map(yaml_string);
+    match validation {
+        FieldValueValidationCfg::Number {
+            minimum,
+            exclusive_minimum,
+            maximum,
+            exclusive_maximum,
+        } => yaml_hash([
+            ("type", Some(yaml_string("number"))),
+            ("minimum", string(minimum)),
+            ("exclusive-minimum", string(exclusive_minimum)),
+            ("maximum", string(maximum)),
+            ("exclusive-maximum", string(exclusive_maximum)),
+        ]),
+        FieldValueValidationCfg::String {
+            min_length,
+            max_length,
+        } => yaml_hash([
+            ("type", Some(yaml_string("string"))),
+            ("min-length", min_length.map(yaml_string)),
+            ("max-length", max_length.map(yaml_string)),
+        ]),
+        FieldValueValidationCfg::Boolean => yaml_hash([("type", Some(yaml_string("boolean")))]),
+        FieldValueValidationCfg::Address => yaml_hash([("type", Some(yaml_string("address")))]),
+        FieldValueValidationCfg::TokenAmount {
+            token,
+            minimum,
+            exclusive_minimum,
+            maximum,
+            exclusive_maximum,
+        } => yaml_hash([
+            ("type", Some(yaml_string("token-amount"))),
+            ("token", Some(yaml_string(token))),
+            ("minimum", string(minimum)),
+            ("exclusive-minimum", string(exclusive_minimum)),
+            ("maximum", string(maximum)),
+            ("exclusive-maximum", string(exclusive_maximum)),
+        ]),
+        FieldValueValidationCfg::Percentage { minimum, maximum } => yaml_hash([
+            ("type", Some(yaml_string("percentage"))),
+            ("minimum", string(minimum)),
+            ("maximum", string(maximum)),
+        ]),
+        FieldValueValidationCfg::Duration { minimum, maximum } => yaml_hash([
+            ("type", Some(yaml_string("duration"))),
+            ("minimum", string(minimum)),
+            ("maximum", string(maximum)),
+        ]),
+        FieldValueValidationCfg::Enum => yaml_hash([("type", Some(yaml_string("enum")))]),
+    }
+}
+
+fn parse_field_conditions(
+    field_yaml: &StrictYaml,
+    key: &str,
+    location: &str,
+) -> Result<Option<Vec<GuiFieldConditionCfg>>, YamlError> {
+    optional_vec(field_yaml, key)
+        .map(|conditions| {
+            conditions
+                .iter()
+                .enumerate()
+                .map(|(condition_index, condition_yaml)| {
+                    let location = format!("{key} index '{condition_index}' for {location}");
+                    condition_yaml.as_hash().ok_or(YamlError::Field {
+                        kind: FieldErrorKind::InvalidType {
+                            field: key.to_string(),
+                            expected: "a map".to_string(),
+                        },
+                        location: location.clone(),
+                    })?;
+
+                    let one_of = optional_vec(condition_yaml, "one-of")
+                        .map(|values| {
+                            values
+                                .iter()
+                                .map(|value| {
+                                    value.as_str().map(str::to_string).ok_or(YamlError::Field {
+                                        kind: FieldErrorKind::InvalidType {
+                                            field: "one-of".to_string(),
+                                            expected: "a list of strings".to_string(),
+                                        },
+                                        location: location.clone(),
+                                    })
+                                })
+                                .collect::<Result<Vec<_>, YamlError>>()
+                        })
+                        .transpose()?;
+                    let condition = GuiFieldConditionCfg {
+                        field: optional_string(condition_yaml, "field"),
+                        select_token: optional_string(condition_yaml, "select-token"),
+                        equals: optional_string(condition_yaml, "equals"),
+                        not_equals: optional_string(condition_yaml, "not-equals"),
+                        one_of,
+                    };
+
+                    if condition.field.is_some() == condition.select_token.is_some() {
+                        return Err(YamlError::Field {
+                            kind: FieldErrorKind::InvalidValue {
+                                field: key.to_string(),
+                                reason: "a condition needs exactly one of 'field' or 'select-token'"
+                                    .to_string(),
+                            },
+                            location,
+                        });
+                    }
+                    let comparisons = [
+                        condition.equals.is_some(),
+                        condition.not_equals.is_some(),
+                        condition.one_of.is_some(),
+                    ];
+                    if comparisons.iter().filter(|set| **set).count() > 1 {
+                        return Err(YamlError::Field {
+                            kind: FieldErrorKind::InvalidValue {
+                                field: key.to_string(),
+                                reason: "a condition can use only one of 'equals', 'not-equals' or 'one-of'"
+                                    .to_string(),
+                            },
+                            location,
+                        });
+                    }
+                    Ok(condition)
+                })
+                .collect::<Result<Vec<_>, YamlError>>()
+        })
+        .transpose()
+}
+
+/// Checks that conditions and derived defaults only point at other fields and
+/// select tokens of the same deployment
+fn validate_field_references(
+    fields: &[GuiFieldDefinitionCfg],
+    select_tokens: Option<&[GuiSelectTokensCfg]>,
+    location: &str,
+) -> Result<(), YamlError> {
+    let invalid_reference =
+        |field: &GuiFieldDefinitionCfg, key: &str, reason: String| YamlError::Field {
+            kind: FieldErrorKind::InvalidValue {
+                field: key.to_string(),
+                reason,
+            },
+            location: format!("field '{}' in {location}", field.binding),
+        };
+    let check_binding = |field: &GuiFieldDefinitionCfg, key: &str, binding: &str| {
+        if binding == field.binding {
+            return Err(invalid_reference(
+                field,
+                key,
+                "a field cannot reference itself".to_string(),
+            ));
+        }
+        if !fields.iter().any(|f| f.binding == binding) {
+            return Err(invalid_reference(
+                field,
+                key,
+                format!("unknown field '{binding}'"),
+            ));
+        }
+        Ok(())
+    };
+
+    for field in fields {
+        let conditions = [
+            ("show-if", &field.show_if),
+            ("required-if", &field.required_if),
+        ];
+        for (key, conditions) in conditions {
+            for condition in conditions.iter().flatten() {
+                if let Some(binding) = &condition.field {
+                    check_binding(field, key, binding)?;
+                }
+                if let Some(select_token) = &condition.select_token {
+                    let is_select_token = select_tokens
+                        .is_some_and(|tokens| tokens.iter().any(|t| &t.key == select_token));
+                    if !is_select_token {
+                        return Err(invalid_reference(
+                            field,
+                            key,
+                            format!("unknown select token '{select_token}'"),
+                        ));
+                    }
+                }
+            }
+        }
+        if let Some(binding) = &field.default_from {
+            check_binding(field, "default-from", binding)?;
+        }
+    }
+
+    let mut checked = HashSet::new();
+    for field in fields {
+        let Some(cycle) = find_reference_cycle(fields, &field.binding, &mut vec![], &mut checked)
+        else {
+            continue;
+        };
+        let start = fields
+            .iter()
+            .find(|f| f.binding == cycle[0])
+            .unwrap_or(field);
+        let key = field_references(start)
+            .into_iter()
+            .find(|(_, binding)| *binding == cycle[1])
+            .map_or("show-if", |(key, _)| key);
+        return Err(invalid_reference(
+            start,
+            key,
+            format!("circular reference {}", cycle.join(" -> ")),
+        ));
+    }
+    Ok(())
+}
+
+fn field_references(field: &GuiFieldDefinitionCfg) -> Vec<(&'static str, &str)> {
+    [
+        ("show-if", &field.show_if),
+        ("required-if", &field.required_if),
+    ]
+    .into_iter()
+    .flat_map(|(key, conditions)| {
+        conditions
+            .iter()
+            .flatten()
+            .filter_map(move |condition| condition.field.as_deref().map(|b| (key, b)))
+    })
+    .chain(field.default_from.as_deref().map(|b| ("default-from", b)))
+    .collect()
+}
+
+// Depth first walk over the field references, returns the bindings of the
+// first cycle found with the starting binding repeated at the end
+fn find_reference_cycle<'a>(
+    fields: &'a [GuiFieldDefinitionCfg],
+    binding: &'a str,
+    path: &mut Vec<&'a str>,
+    checked: &mut HashSet<&'a str>,
+) -> Option<Vec<&'a str>> {
+    if checked.contains(binding) {
+        return None;
+    }
+    if let Some(start) = path.iter().position(|b| *b == binding) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(binding);
+        return Some(cycle);
+    }
+    let field = fields.iter().find(|f| f.binding == binding)?;
+
+    path.push(binding);
+    for (_, reference) in field_references(field) {
+        if let Some(cycle) = find_reference_cycle(fields, reference, path, checked) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    checked.insert(binding);
+    None
+}
+
 fn parse_deposit_validation(yaml: &Hash) -> Result<DepositValidationCfg, YamlError> {
     Ok(DepositValidationCfg {
         minimum: yaml
@@ -911,6 +1480,15 @@ fn parse_deposit_validation(yaml: &Hash) -> Result<DepositValidationCfg, YamlErr
     })
 }
 
+const FIELD_VALIDATION_TYPES: &str =
+    "one of: number, string, boolean, address, token-amount, percentage, duration, enum";
+
+fn optional_hash_string(yaml: &Hash, key: &str) -> Option<String> {
+    get_hash_value_as_option(yaml, key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
 fn parse_field_validation(
     yaml: &Hash,
     location: &str,
@@ -966,10 +1544,33 @@ fn parse_field_validation(
                 })?,
         }),
         "boolean" => Ok(FieldValueValidationCfg::Boolean),
+        "address" => Ok(FieldValueValidationCfg::Address),
+        "token-amount" => Ok(FieldValueValidationCfg::TokenAmount {
+            token: get_hash_value_as_option(yaml, "token")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .ok_or(YamlError::Field {
+                    kind: FieldErrorKind::Missing("token".to_string()),
+                    location: location.to_string(),
+                })?,
+            minimum: optional_hash_string(yaml, "minimum"),
+            exclusive_minimum: optional_hash_string(yaml, "exclusive-minimum"),
+            maximum: optional_hash_string(yaml, "maximum"),
+            exclusive_maximum: optional_hash_string(yaml, "exclusive-maximum"),
+        }),
+        "percentage" => Ok(FieldValueValidationCfg::Percentage {
+            minimum: optional_hash_string(yaml, "minimum"),
+            maximum: optional_hash_string(yaml, "maximum"),
+        }),
+        "duration" => Ok(FieldValueValidationCfg::Duration {
+            minimum: optional_hash_string(yaml, "minimum"),
+            maximum: optional_hash_string(yaml, "maximum"),
+        }),
+        "enum" => Ok(FieldValueValidationCfg::Enum),
         _ => Err(YamlError::Field {
             kind: FieldErrorKind::InvalidType {
                 field: "type".to_string(),
-                expected: "one of: number, string, boolean".to_string(),
+                expected: FIELD_VALIDATION_TYPES.to_string(),
             },
             location: location.to_string(),
         }),
@@ -1021,6 +1622,10 @@ mod tests {
                             default: None,
                             show_custom_field: None,
                             validation: None,
+                            previous_bindings: None,
+                            show_if: None,
+                            required_if: None,
+                            default_from: None,
                         },
                         GuiFieldDefinitionSourceCfg {
                             binding: "test-binding-2".to_string(),
@@ -1039,6 +1644,10 @@ mod tests {
                             default: Some("0.015".to_string()),
                             show_custom_field: Some(true),
                             validation: None,
+                            previous_bindings: None,
+                            show_if: None,
+                            required_if: None,
+                            default_from: None,
                         },
                         GuiFieldDefinitionSourceCfg {
                             binding: "test-binding-3".to_string(),
@@ -1061,6 +1670,10 @@ mod tests {
                             default: Some("0.25".to_string()),
                             show_custom_field: Some(false),
                             validation: None,
+                            previous_bindings: None,
+                            show_if: None,
+                            required_if: None,
+                            default_from: None,
                         },
                     ],
                     select_tokens: Some(vec![GuiSelectTokensCfg {
@@ -2606,6 +3219,424 @@ gui:
         }
     }
 
+    #[test]
+    fn test_parse_field_validation_typed() {
+        let yaml_prefix = r#"
+networks:
+    network1:
+        rpcs:
+            - https://eth.llamarpc.com
+        chain-id: 1
+deployers:
+    deployer1:
+        address: 0x0000000000000000000000000000000000000000
+        network: network1
+scenarios:
+    scenario1:
+        bindings:
+            test: test
+        deployer: deployer1
+tokens:
+    token1:
+        address: 0x0000000000000000000000000000000000000001
+        network: network1
+orders:
+    order1:
+        inputs:
+            - token: token1
+        outputs:
+            - token: token1
+        deployer: deployer1
+deployments:
+    deployment1:
+        scenario: scenario1
+        order: order1
+"#;
+
+        let yaml = r#"
+gui:
+    name: test
+    description: test
+    deployments:
+        deployment1:
+            name: test
+            description: test
+            deposits:
+                - token: token1
+            fields:
+                - binding: recipient
+                  name: Recipient
+                  validation:
+                    type: address
+                - binding: amount
+                  name: Amount
+                  validation:
+                    type: token-amount
+                    token: token1
+                    minimum: "1"
+                - binding: fee
+                  name: Fee
+                  validation:
+                    type: percentage
+                    maximum: "5"
+                - binding: cooldown
+                  name: Cooldown
+                  validation:
+                    type: duration
+                    minimum: 1m
+                    maximum: 1d
+                - binding: mode
+                  name: Mode
+                  presets:
+                    - value: "0"
+                    - value: "1"
+                  validation:
+                    type: enum
+"#;
+        let gui = GuiCfg::parse_from_yaml_optional(
+            vec![get_document(&format!("{yaml_prefix}{yaml}"))],
+            None,
+        )
+        .unwrap()
+        .unwrap();
+
+        let fields = &gui.deployments.get("deployment1").unwrap().fields;
+        assert_eq!(fields[0].validation, Some(FieldValueValidationCfg::Address));
+        assert_eq!(
+            fields[1].validation,
+            Some(FieldValueValidationCfg::TokenAmount {
+                token: "token1".to_string(),
+                minimum: Some("1".to_string()),
+                exclusive_minimum: None,
+                maximum: None,
+                exclusive_maximum: None,
+            })
+        );
+        assert_eq!(
+            fields[2].validation,
+            Some(FieldValueValidationCfg::Percentage {
+                minimum: None,
+                maximum: Some("5".to_string()),
+            })
+        );
+        assert_eq!(
+            fields[3].validation,
+            Some(FieldValueValidationCfg::Duration {
+                minimum: Some("1m".to_string()),
+                maximum: Some("1d".to_string()),
+            })
+        );
+        assert_eq!(fields[4].validation, Some(FieldValueValidationCfg::Enum));
+
+        let yaml = r#"
+gui:
+    name: test
+    description: test
+    deployments:
+        deployment1:
+            name: test
+            description: test
+            deposits:
+                - token: token1
+            fields:
+                - binding: amount
+                  name: Amount
+                  validation:
+                    type: token-amount
+"#;
+        let error = GuiCfg::parse_from_yaml_optional(
+            vec![get_document(&format!("{yaml_prefix}{yaml}"))],
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::Missing("token".to_string()),
+                location: "validation for field index '0' in gui deployment 'deployment1'"
+                    .to_string(),
+            }
+        );
+
+        let yaml = r#"
+gui:
+    name: test
+    description: test
+    deployments:
+        deployment1:
+            name: test
+            description: test
+            deposits:
+                - token: token1
+            fields:
+                - binding: mode
+                  name: Mode
+                  validation:
+                    type: enum
+"#;
+        let error = GuiCfg::parse_from_yaml_optional(
+            vec![get_document(&format!("{yaml_prefix}{yaml}"))],
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "validation".to_string(),
+                    reason: "enum fields need at least one preset".to_string(),
+                },
+                location: "field index '0' in gui deployment 'deployment1'".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_field_condition_is_met() {
+        let is_set = GuiFieldConditionCfg {
+            field: Some("a".to_string()),
+            ..Default::default()
+        };
+        assert!(is_set.is_met(Some("x")));
+        assert!(!is_set.is_met(Some("")));
+        assert!(!is_set.is_met(None));
+
+        let equals = GuiFieldConditionCfg {
+            equals: Some("true".to_string()),
+            ..is_set.clone()
+        };
+        assert!(equals.is_met(Some("TRUE")));
+        assert!(!equals.is_met(Some("false")));
+        assert!(!equals.is_met(None));
+
+        let not_equals = GuiFieldConditionCfg {
+            not_equals: Some("0".to_string()),
+            ..is_set.clone()
+        };
+        assert!(not_equals.is_met(Some("1")));
+        assert!(!not_equals.is_met(Some("0")));
+        assert!(not_equals.is_met(None));
+
+        let one_of = GuiFieldConditionCfg {
+            one_of: Some(vec!["1".to_string(), "2".to_string()]),
+            ..is_set
+        };
+        assert!(one_of.is_met(Some("2")));
+        assert!(!one_of.is_met(Some("3")));
+        assert!(!one_of.is_met(None));
+    }
+
+    #[test]
+    fn test_parse_field_conditions() {
+        let yaml_prefix = r#"
+networks:
+    network1:
+        rpcs:
+            - https://eth.llamarpc.com
+        chain-id: 1
+deployers:
+    deployer1:
+        address: 0x0000000000000000000000000000000000000000
+        network: network1
+scenarios:
+    scenario1:
+        bindings:
+            test: test
+        deployer: deployer1
+tokens:
+    token1:
+        address: 0x0000000000000000000000000000000000000001
+        network: network1
+orders:
+    order1:
+        inputs:
+            - token: token1
+        outputs:
+            - token: token1
+        deployer: deployer1
+deployments:
+    deployment1:
+        scenario: scenario1
+        order: order1
+"#;
+        let parse = |fields: &str| {
+            let yaml = format!(
+                r#"
+gui:
+    name: test
+    description: test
+    deployments:
+        deployment1:
+            name: test
+            description: test
+            deposits:
+                - token: token1
+            select-tokens:
+                - key: token2
+            fields:
+{fields}"#
+            );
+            GuiCfg::parse_from_yaml_optional(
+                vec![get_document(&format!("{yaml_prefix}{yaml}"))],
+                None,
+            )
+        };
+
+        let gui = parse(
+            r#"
+                - binding: enabled
+                  name: Enabled
+                - binding: distance
+                  name: Distance
+                  default-from: enabled
+                  show-if:
+                    - field: enabled
+                      equals: "true"
+                    - select-token: token2
+                  required-if:
+                    - field: enabled
+                      one-of: ["1", "2"]
+"#,
+        )
+        .unwrap()
+        .unwrap();
+        let field = &gui.deployments["deployment1"].fields[1];
+        assert_eq!(
+            field.show_if,
+            Some(vec![
+                GuiFieldConditionCfg {
+                    field: Some("enabled".to_string()),
+                    equals: Some("true".to_string()),
+                    ..Default::default()
+                },
+                GuiFieldConditionCfg {
+                    select_token: Some("token2".to_string()),
+                    ..Default::default()
+                },
+            ])
+        );
+        assert_eq!(
+            field.required_if,
+            Some(vec![GuiFieldConditionCfg {
+                field: Some("enabled".to_string()),
+                one_of: Some(vec!["1".to_string(), "2".to_string()]),
+                ..Default::default()
+            }])
+        );
+        assert_eq!(field.default_from, Some("enabled".to_string()));
+
+        let error = parse(
+            r#"
+                - binding: distance
+                  name: Distance
+                  show-if:
+                    - field: missing
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "show-if".to_string(),
+                    reason: "unknown field 'missing'".to_string(),
+                },
+                location: "field 'distance' in gui deployment 'deployment1'".to_string(),
+            }
+        );
+
+        let error = parse(
+            r#"
+                - binding: distance
+                  name: Distance
+                  default-from: distance
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "default-from".to_string(),
+                    reason: "a field cannot reference itself".to_string(),
+                },
+                location: "field 'distance' in gui deployment 'deployment1'".to_string(),
+            }
+        );
+
+        let error = parse(
+            r#"
+                - binding: enabled
+                  name: Enabled
+                  show-if:
+                    - field: mode
+                - binding: distance
+                  name: Distance
+                  required-if:
+                    - field: enabled
+                - binding: mode
+                  name: Mode
+                  default-from: distance
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "show-if".to_string(),
+                    reason: "circular reference enabled -> mode -> distance -> enabled".to_string(),
+                },
+                location: "field 'enabled' in gui deployment 'deployment1'".to_string(),
+            }
+        );
+
+        let error = parse(
+            r#"
+                - binding: distance
+                  name: Distance
+                  required-if:
+                    - select-token: token3
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "required-if".to_string(),
+                    reason: "unknown select token 'token3'".to_string(),
+                },
+                location: "field 'distance' in gui deployment 'deployment1'".to_string(),
+            }
+        );
+
+        let error = parse(
+            r#"
+                - binding: enabled
+                  name: Enabled
+                - binding: distance
+                  name: Distance
+                  show-if:
+                    - field: enabled
+                      select-token: token2
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "show-if".to_string(),
+                    reason: "a condition needs exactly one of 'field' or 'select-token'"
+                        .to_string(),
+                },
+                location: "show-if index '0' for field index '1' in gui deployment 'deployment1'"
+                    .to_string(),
+            }
+        );
+    }
+
     #[test]
     fn test_parse_field_validation_errors() {
         let yaml_prefix = r#"
@@ -2703,7 +3734,7 @@ gui:
             YamlError::Field {
                 kind: FieldErrorKind::InvalidType {
                     field: "type".to_string(),
-                    expected: "one of: number, string, boolean".to_string()
+                    expected: FIELD_VALIDATION_TYPES.to_string()
                 },
                 location: "validation for field index '0' in gui deployment 'deployment1'"
                     .to_string(),
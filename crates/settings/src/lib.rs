@@ -4,6 +4,7 @@ pub mod chart;
 pub mod deployer;
 pub mod deployment;
 pub mod gui;
+pub mod lint;
 pub mod metaboard;
 pub mod network;
 pub mod order;
@@ -13,6 +14,7 @@ pub mod remote;
 pub mod remote_networks;
 pub mod remote_tokens;
 pub mod scenario;
+pub mod schema;
 pub mod sentry;
 pub mod spec_version;
 pub mod subgraph;
@@ -0,0 +1,842 @@
+use crate::yaml::{dotrain::DotrainYaml, orderbook::OrderbookYaml, require_hash, YamlError};
+use crate::{metaboard::MetaboardCfg, subgraph::SubgraphCfg};
+use crate::{DeployerCfg, NetworkCfg, OrderCfg, OrderbookCfg, ScenarioCfg, TokenCfg};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    sync::{Arc, RwLock},
+};
+use strict_yaml_rust::StrictYaml;
+#[cfg(target_family = "wasm")]
+use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+pub enum LintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(LintSeverity);
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintSeverity::Info => write!(f, "info"),
+            LintSeverity::Warning => write!(f, "warning"),
+            LintSeverity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// A section could not be parsed, rules depending on it were skipped
+    InvalidSection,
+    UnusedToken,
+    UnusedOrder,
+    UnusedNetwork,
+    UnusedSubgraph,
+    UnreachableScenario,
+    MetaboardWithoutNetwork,
+    DeployerWithoutOrderbook,
+    OrderbookSubgraphNetworkMismatch,
+    ConflictingTokenDefinitions,
+    /// Token decimals in yaml differ from the token contract
+    TokenDecimalsMismatch,
+    /// Orderbook deployment block is ahead of the network's latest block
+    DeploymentBlockInFuture,
+    /// An onchain check could not be performed
+    OnchainCheckFailed,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(LintRule);
+
+impl LintRule {
+    pub fn default_severity(&self) -> LintSeverity {
+        match self {
+            LintRule::InvalidSection
+            | LintRule::TokenDecimalsMismatch
+            | LintRule::DeploymentBlockInFuture => LintSeverity::Error,
+            LintRule::UnusedToken
+            | LintRule::UnusedOrder
+            | LintRule::UnreachableScenario
+            | LintRule::DeployerWithoutOrderbook
+            | LintRule::OrderbookSubgraphNetworkMismatch
+            | LintRule::ConflictingTokenDefinitions
+            | LintRule::OnchainCheckFailed => LintSeverity::Warning,
+            LintRule::UnusedNetwork
+            | LintRule::UnusedSubgraph
+            | LintRule::MetaboardWithoutNetwork => LintSeverity::Info,
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LintRule::InvalidSection => "invalid-section",
+            LintRule::UnusedToken => "unused-token",
+            LintRule::UnusedOrder => "unused-order",
+            LintRule::UnusedNetwork => "unused-network",
+            LintRule::UnusedSubgraph => "unused-subgraph",
+            LintRule::UnreachableScenario => "unreachable-scenario",
+            LintRule::MetaboardWithoutNetwork => "metaboard-without-network",
+            LintRule::DeployerWithoutOrderbook => "deployer-without-orderbook",
+            LintRule::OrderbookSubgraphNetworkMismatch => "orderbook-subgraph-network-mismatch",
+            LintRule::ConflictingTokenDefinitions => "conflicting-token-definitions",
+            LintRule::TokenDecimalsMismatch => "token-decimals-mismatch",
+            LintRule::DeploymentBlockInFuture => "deployment-block-in-future",
+            LintRule::OnchainCheckFailed => "onchain-check-failed",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+pub struct LintIssue {
+    pub severity: LintSeverity,
+    pub rule: LintRule,
+    pub location: String,
+    pub message: String,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(LintIssue);
+
+impl LintIssue {
+    pub fn new(rule: LintRule, location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: rule.default_severity(),
+            rule,
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            self.severity, self.rule, self.location, self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+pub struct LintReport {
+    pub issues: Vec<LintIssue>,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(LintReport);
+
+impl LintReport {
+    pub fn push(&mut self, issue: LintIssue) {
+        self.issues.push(issue);
+    }
+
+    pub fn extend(&mut self, other: LintReport) {
+        self.issues.extend(other.issues);
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.count(LintSeverity::Error) > 0
+    }
+
+    pub fn count(&self, severity: LintSeverity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+
+    pub fn with_min_severity(mut self, severity: LintSeverity) -> Self {
+        self.issues.retain(|issue| issue.severity >= severity);
+        self
+    }
+
+    /// Orders issues by descending severity, then by location and rule so
+    /// that reports are stable between runs
+    pub fn sort(&mut self) {
+        self.issues.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.location.cmp(&b.location))
+                .then_with(|| a.rule.to_string().cmp(&b.rule.to_string()))
+        });
+    }
+}
+
+/// Runs the static lint rules over the given settings. Only the yaml itself is
+/// inspected, checks that need an rpc live in the common crate.
+pub fn lint_settings(orderbook_yaml: &OrderbookYaml, dotrain_yaml: &DotrainYaml) -> LintReport {
+    let mut report = LintReport::default();
+    let documents = orderbook_yaml.documents.clone();
+
+    let networks = collect_section(
+        &mut report,
+        &documents,
+        "networks",
+        orderbook_yaml.get_networks(),
+    );
+    let tokens = collect_section(
+        &mut report,
+        &documents,
+        "tokens",
+        orderbook_yaml.get_tokens(),
+    );
+    let subgraphs = collect_section(
+        &mut report,
+        &documents,
+        "subgraphs",
+        orderbook_yaml.get_subgraphs(),
+    );
+    let orderbooks = collect_section(
+        &mut report,
+        &documents,
+        "orderbooks",
+        orderbook_yaml.get_orderbooks(),
+    );
+    let metaboards = collect_section(
+        &mut report,
+        &documents,
+        "metaboards",
+        orderbook_yaml.get_metaboards(),
+    );
+    let deployers = collect_section(
+        &mut report,
+        &documents,
+        "deployers",
+        orderbook_yaml.get_deployers(),
+    );
+    let scenarios = collect_section(
+        &mut report,
+        &documents,
+        "scenarios",
+        dotrain_yaml.get_scenarios(),
+    );
+
+    // Only the rules that follow references depend on them being readable
+    match References::from_documents(&documents) {
+        Ok(references) => {
+            lint_unused_tokens(&mut report, &documents, &tokens, &references);
+            lint_unused_orders(&mut report, &references);
+            lint_unreachable_scenarios(&mut report, &scenarios, &references);
+        }
+        Err(err) => report.push(LintIssue::new(
+            LintRule::InvalidSection,
+            "root",
+            err.to_readable_msg(),
+        )),
+    }
+
+    lint_unused_networks(&mut report, &networks, &tokens, &orderbooks, &deployers);
+    lint_unused_subgraphs(&mut report, &subgraphs, &orderbooks);
+    lint_metaboards(&mut report, &metaboards, &networks);
+    lint_deployers(&mut report, &deployers, &orderbooks);
+    lint_orderbook_subgraphs(&mut report, &orderbooks);
+    lint_conflicting_tokens(&mut report, &tokens);
+
+    report.sort();
+    report
+}
+
+/// Treats a section that is absent from every document as empty and reports
+/// any other parsing error, returning an empty map so the remaining rules can
+/// still run
+fn collect_section<T>(
+    report: &mut LintReport,
+    documents: &[Arc<RwLock<StrictYaml>>],
+    section: &str,
+    result: Result<HashMap<String, T>, YamlError>,
+) -> HashMap<String, T> {
+    match result {
+        Ok(values) => values,
+        Err(_) if raw_section_keys(documents, section).is_empty() => HashMap::new(),
+        Err(err) => {
+            report.push(LintIssue::new(
+                LintRule::InvalidSection,
+                section,
+                err.to_readable_msg(),
+            ));
+            HashMap::new()
+        }
+    }
+}
+
+/// Keys referenced by orders, deployments, charts and gui sections. These are
+/// read from the raw documents since gui deployments can reference select
+/// tokens that only resolve once a user picks them.
+#[derive(Debug, Default)]
+struct References {
+    orders: BTreeSet<String>,
+    order_tokens: BTreeSet<String>,
+    deployment_orders: BTreeSet<String>,
+    scenarios: BTreeSet<String>,
+    gui_tokens: BTreeSet<String>,
+}
+
+impl References {
+    fn from_documents(documents: &[Arc<RwLock<StrictYaml>>]) -> Result<Self, YamlError> {
+        let mut references = References::default();
+
+        for document in documents {
+            let document_read = document.read().map_err(|_| YamlError::ReadLockError)?;
+
+            if let Ok(orders) = require_hash(&document_read, Some("orders"), None) {
+                for (key, _) in orders {
+                    if let Some(key) = key.as_str() {
+                        references.orders.insert(key.to_string());
+                    }
+                }
+            }
+
+            if let Ok(deployments) = require_hash(&document_read, Some("deployments"), None) {
+                for (_, deployment) in deployments {
+                    if let Some(order) = deployment["order"].as_str() {
+                        references.deployment_orders.insert(order.to_string());
+                    }
+                    if let Some(scenario) = deployment["scenario"].as_str() {
+                        references.scenarios.insert(scenario.to_string());
+                    }
+                }
+            }
+
+            if let Ok(charts) = require_hash(&document_read, Some("charts"), None) {
+                for (key, chart) in charts {
+                    // Charts default to the scenario with the same key
+                    match chart["scenario"].as_str() {
+                        Some(scenario) => references.scenarios.insert(scenario.to_string()),
+                        None => references
+                            .scenarios
+                            .insert(key.as_str().unwrap_or_default().to_string()),
+                    };
+                }
+            }
+
+            if let Some(gui_deployments) = document_read["gui"]["deployments"].as_hash() {
+                for (_, gui_deployment) in gui_deployments {
+                    if let Some(deposits) = gui_deployment["deposits"].as_vec() {
+                        for deposit in deposits {
+                            if let Some(token) = deposit["token"].as_str() {
+                                references.gui_tokens.insert(token.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for order_key in &references.orders {
+            let token_keys = OrderCfg::parse_io_token_keys(documents.to_vec(), order_key)?;
+            references.order_tokens.extend(token_keys);
+        }
+
+        Ok(references)
+    }
+
+    fn is_scenario_used(&self, key: &str) -> bool {
+        let prefix = format!("{}.", key);
+        self.scenarios
+            .iter()
+            .any(|scenario| scenario == key || scenario.starts_with(&prefix))
+    }
+}
+
+fn raw_section_keys(documents: &[Arc<RwLock<StrictYaml>>], section: &str) -> BTreeSet<String> {
+    let mut keys = BTreeSet::new();
+    for document in documents {
+        if let Ok(document_read) = document.read() {
+            if let Ok(hash) = require_hash(&document_read, Some(section), None) {
+                for (key, _) in hash {
+                    if let Some(key) = key.as_str() {
+                        keys.insert(key.to_string());
+                    }
+                }
+            }
+        }
+    }
+    keys
+}
+
+fn lint_unused_tokens(
+    report: &mut LintReport,
+    documents: &[Arc<RwLock<StrictYaml>>],
+    tokens: &HashMap<String, TokenCfg>,
+    references: &References,
+) {
+    // Remote tokens are merged into the parsed tokens, only tokens written in
+    // the yaml itself are worth reporting
+    for key in raw_section_keys(documents, "tokens") {
+        if !tokens.contains_key(&key) {
+            continue;
+        }
+        if references.order_tokens.contains(&key) || references.gui_tokens.contains(&key) {
+            continue;
+        }
+        report.push(LintIssue::new(
+            LintRule::UnusedToken,
+            format!("token '{}'", key),
+            "Token is not used by any order or gui deposit",
+        ));
+    }
+}
+
+fn lint_unused_orders(report: &mut LintReport, references: &References) {
+    for key in &references.orders {
+        if !references.deployment_orders.contains(key) {
+            report.push(LintIssue::new(
+                LintRule::UnusedOrder,
+                format!("order '{}'", key),
+                "Order is not used by any deployment",
+            ));
+        }
+    }
+}
+
+fn lint_unreachable_scenarios(
+    report: &mut LintReport,
+    scenarios: &HashMap<String, ScenarioCfg>,
+    references: &References,
+) {
+    for key in scenarios.keys() {
+        if !references.is_scenario_used(key) {
+            report.push(LintIssue::new(
+                LintRule::UnreachableScenario,
+                format!("scenario '{}'", key),
+                "Scenario is not used by any deployment or chart",
+            ));
+        }
+    }
+}
+
+fn lint_unused_networks(
+    report: &mut LintReport,
+    networks: &HashMap<String, NetworkCfg>,
+    tokens: &HashMap<String, TokenCfg>,
+    orderbooks: &HashMap<String, OrderbookCfg>,
+    deployers: &HashMap<String, DeployerCfg>,
+) {
+    let used: BTreeSet<&str> = tokens
+        .values()
+        .map(|token| token.network.key.as_str())
+        .chain(orderbooks.values().map(|ob| ob.network.key.as_str()))
+        .chain(
+            deployers
+                .values()
+                .map(|deployer| deployer.network.key.as_str()),
+        )
+        .collect();
+
+    for key in networks.keys() {
+        if !used.contains(key.as_str()) {
+            report.push(LintIssue::new(
+                LintRule::UnusedNetwork,
+                format!("network '{}'", key),
+                "Network is not used by any token, orderbook or deployer",
+            ));
+        }
+    }
+}
+
+fn lint_unused_subgraphs(
+    report: &mut LintReport,
+    subgraphs: &HashMap<String, SubgraphCfg>,
+    orderbooks: &HashMap<String, OrderbookCfg>,
+) {
+    let used: BTreeSet<&str> = orderbooks
+        .values()
+        .map(|ob| ob.subgraph.key.as_str())
+        .collect();
+
+    for key in subgraphs.keys() {
+        if !used.contains(key.as_str()) {
+            report.push(LintIssue::new(
+                LintRule::UnusedSubgraph,
+                format!("subgraph '{}'", key),
+                "Subgraph is not used by any orderbook",
+            ));
+        }
+    }
+}
+
+fn lint_metaboards(
+    report: &mut LintReport,
+    metaboards: &HashMap<String, MetaboardCfg>,
+    networks: &HashMap<String, NetworkCfg>,
+) {
+    for key in metaboards.keys() {
+        if !networks.contains_key(key) {
+            report.push(LintIssue::new(
+                LintRule::MetaboardWithoutNetwork,
+                format!("metaboard '{}'", key),
+                "Metaboards are looked up by network key but no network with this key exists",
+            ));
+        }
+    }
+}
+
+fn lint_deployers(
+    report: &mut LintReport,
+    deployers: &HashMap<String, DeployerCfg>,
+    orderbooks: &HashMap<String, OrderbookCfg>,
+) {
+    let chain_ids: BTreeSet<u32> = orderbooks.values().map(|ob| ob.network.chain_id).collect();
+
+    for (key, deployer) in deployers {
+        if !chain_ids.contains(&deployer.network.chain_id) {
+            report.push(LintIssue::new(
+                LintRule::DeployerWithoutOrderbook,
+                format!("deployer '{}'", key),
+                format!(
+                    "No orderbook is defined on network '{}' (chain id {})",
+                    deployer.network.key, deployer.network.chain_id
+                ),
+            ));
+        }
+    }
+}
+
+fn lint_orderbook_subgraphs(report: &mut LintReport, orderbooks: &HashMap<String, OrderbookCfg>) {
+    // A subgraph indexes a single chain, so the orderbooks that reference
+    // the same subgraph must all be on that chain. Whether that chain is the
+    // orderbook's own is checked against the rpc by the onchain rules.
+    let mut by_subgraph: BTreeMap<&str, Vec<(&String, &OrderbookCfg)>> = BTreeMap::new();
+    for (key, orderbook) in orderbooks {
+        by_subgraph
+            .entry(orderbook.subgraph.key.as_str())
+            .or_default()
+            .push((key, orderbook));
+    }
+
+    for (subgraph, mut group) in by_subgraph {
+        let chain_ids: BTreeSet<u32> = group
+            .iter()
+            .map(|(_, orderbook)| orderbook.network.chain_id)
+            .collect();
+        if chain_ids.len() < 2 {
+            continue;
+        }
+        group.sort_by(|a, b| a.0.cmp(b.0));
+        let used_by = group
+            .iter()
+            .map(|(key, orderbook)| {
+                format!(
+                    "'{}' on network '{}' (chain id {})",
+                    key, orderbook.network.key, orderbook.network.chain_id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        for (key, _) in &group {
+            report.push(LintIssue::new(
+                LintRule::OrderbookSubgraphNetworkMismatch,
+                format!("orderbook '{}'", key),
+                format!(
+                    "Subgraph '{}' indexes a single chain but is used by orderbooks on different chains: {}",
+                    subgraph, used_by
+                ),
+            ));
+        }
+    }
+}
+
+fn lint_conflicting_tokens(report: &mut LintReport, tokens: &HashMap<String, TokenCfg>) {
+    let mut by_address: HashMap<(u32, String), Vec<&TokenCfg>> = HashMap::new();
+    for token in tokens.values() {
+        by_address
+            .entry((
+                token.network.chain_id,
+                token.address.to_string().to_lowercase(),
+            ))
+            .or_default()
+            .push(token);
+    }
+
+    for ((chain_id, address), mut group) in by_address {
+        let decimals: BTreeSet<u8> = group.iter().filter_map(|token| token.decimals).collect();
+        if decimals.len() < 2 {
+            continue;
+        }
+        group.sort_by(|a, b| a.key.cmp(&b.key));
+        let keys = group
+            .iter()
+            .map(|token| format!("'{}'", token.key))
+            .collect::<Vec<_>>()
+            .join(", ");
+        report.push(LintIssue::new(
+            LintRule::ConflictingTokenDefinitions,
+            format!("token {}", keys),
+            format!(
+                "Tokens share address {} on chain id {} but define different decimals: {}",
+                address,
+                chain_id,
+                decimals
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaml::{
+        dotrain::DotrainYamlValidation, orderbook::OrderbookYamlValidation, YamlParsable,
+    };
+
+    const YAML: &str = r#"
+networks:
+    mainnet:
+        rpcs:
+            - https://mainnet.infura.io
+        chain-id: 1
+    polygon:
+        rpcs:
+            - https://polygon.infura.io
+        chain-id: 137
+    unused:
+        rpcs:
+            - https://unused.infura.io
+        chain-id: 999
+    arbitrum:
+        rpcs:
+            - https://arbitrum.infura.io
+        chain-id: 42161
+subgraphs:
+    mainnet: https://api.thegraph.com/subgraphs/name/mainnet
+    polygon: https://api.thegraph.com/subgraphs/name/polygon
+    spare: https://api.thegraph.com/subgraphs/name/spare
+metaboards:
+    mainnet: https://meta.example.com/mainnet
+    goerli: https://meta.example.com/goerli
+orderbooks:
+    mainnet:
+        address: 0x0000000000000000000000000000000000000001
+        network: mainnet
+        subgraph: polygon
+        deployment-block: 1
+    arbitrum:
+        address: 0x0000000000000000000000000000000000000006
+        network: arbitrum
+        subgraph: polygon
+        deployment-block: 1
+deployers:
+    mainnet:
+        address: 0x0000000000000000000000000000000000000002
+        network: mainnet
+    polygon:
+        address: 0x0000000000000000000000000000000000000003
+        network: polygon
+tokens:
+    weth:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000004
+        decimals: 18
+    usdc:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000005
+        decimals: 6
+    usdc-copy:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000005
+        decimals: 18
+orders:
+    order1:
+        inputs:
+            - token: weth
+        outputs:
+            - token: usdc
+    order2:
+        inputs:
+            - token: weth
+        outputs:
+            - token: usdc
+scenarios:
+    scenario1:
+        deployer: mainnet
+        bindings:
+            key: value
+        scenarios:
+            child:
+                bindings:
+                    other: value
+    scenario2:
+        deployer: mainnet
+        bindings:
+            key: value
+deployments:
+    deployment1:
+        order: order1
+        scenario: scenario1.child
+"#;
+
+    fn lint_yaml(yaml: &str) -> LintReport {
+        let orderbook_yaml =
+            OrderbookYaml::new(vec![yaml.to_string()], OrderbookYamlValidation::default()).unwrap();
+        let dotrain_yaml =
+            DotrainYaml::new(vec![yaml.to_string()], DotrainYamlValidation::default()).unwrap();
+        lint_settings(&orderbook_yaml, &dotrain_yaml)
+    }
+
+    fn has_issue(report: &LintReport, rule: LintRule, location: &str) -> bool {
+        report
+            .issues
+            .iter()
+            .any(|issue| issue.rule == rule && issue.location == location)
+    }
+
+    #[test]
+    fn test_lint_settings() {
+        let report = lint_yaml(YAML);
+
+        assert!(has_issue(
+            &report,
+            LintRule::UnusedToken,
+            "token 'usdc-copy'"
+        ));
+        assert!(!has_issue(&report, LintRule::UnusedToken, "token 'weth'"));
+        assert!(has_issue(&report, LintRule::UnusedOrder, "order 'order2'"));
+        assert!(!has_issue(&report, LintRule::UnusedOrder, "order 'order1'"));
+        assert!(has_issue(
+            &report,
+            LintRule::UnreachableScenario,
+            "scenario 'scenario2'"
+        ));
+        assert!(!has_issue(
+            &report,
+            LintRule::UnreachableScenario,
+            "scenario 'scenario1'"
+        ));
+        assert!(!has_issue(
+            &report,
+            LintRule::UnreachableScenario,
+            "scenario 'scenario1.child'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::UnusedNetwork,
+            "network 'unused'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::UnusedSubgraph,
+            "subgraph 'spare'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::MetaboardWithoutNetwork,
+            "metaboard 'goerli'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::DeployerWithoutOrderbook,
+            "deployer 'polygon'"
+        ));
+        assert!(!has_issue(
+            &report,
+            LintRule::DeployerWithoutOrderbook,
+            "deployer 'mainnet'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::OrderbookSubgraphNetworkMismatch,
+            "orderbook 'mainnet'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::OrderbookSubgraphNetworkMismatch,
+            "orderbook 'arbitrum'"
+        ));
+        assert!(has_issue(
+            &report,
+            LintRule::ConflictingTokenDefinitions,
+            "token 'usdc', 'usdc-copy'"
+        ));
+        assert!(!report.has_errors());
+
+        // Sorted by descending severity
+        let severities = report
+            .issues
+            .iter()
+            .map(|issue| issue.severity)
+            .collect::<Vec<_>>();
+        let mut sorted = severities.clone();
+        sorted.sort_by(|a, b| b.cmp(a));
+        assert_eq!(severities, sorted);
+    }
+
+    #[test]
+    fn test_lint_settings_invalid_section() {
+        let yaml = r#"
+networks:
+    mainnet:
+        rpcs:
+            - https://mainnet.infura.io
+        chain-id: 1
+tokens:
+    weth:
+        network: missing
+        address: 0x0000000000000000000000000000000000000004
+"#;
+        let report = lint_yaml(yaml);
+        assert!(report.has_errors());
+        assert!(has_issue(&report, LintRule::InvalidSection, "tokens"));
+        // Absent sections are not reported
+        assert!(!has_issue(&report, LintRule::InvalidSection, "orderbooks"));
+    }
+
+    #[test]
+    fn test_lint_settings_invalid_references() {
+        let yaml = r#"
+networks:
+    mainnet:
+        rpcs:
+            - https://mainnet.infura.io
+        chain-id: 1
+orders:
+    order1:
+        inputs: weth
+"#;
+        let report = lint_yaml(yaml);
+        assert!(has_issue(&report, LintRule::InvalidSection, "root"));
+        // Rules that don't follow references still run
+        assert!(has_issue(
+            &report,
+            LintRule::UnusedNetwork,
+            "network 'mainnet'"
+        ));
+    }
+
+    #[test]
+    fn test_lint_report_min_severity() {
+        let report = lint_yaml(YAML).with_min_severity(LintSeverity::Warning);
+        assert!(report
+            .issues
+            .iter()
+            .all(|issue| issue.severity >= LintSeverity::Warning));
+        assert_eq!(report.count(LintSeverity::Info), 0);
+    }
+
+    #[test]
+    fn test_lint_issue_display() {
+        let issue = LintIssue::new(LintRule::UnusedToken, "token 'weth'", "message");
+        assert_eq!(
+            issue.to_string(),
+            "warning[unused-token] token 'weth': message"
+        );
+    }
+}
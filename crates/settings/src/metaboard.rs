@@ -1,7 +1,9 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
     context::Context, default_document, require_hash, require_string, FieldErrorKind, YamlError,
     YamlParsableHash,
 };
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
@@ -12,11 +14,13 @@ use url::{ParseError, Url};
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct MetaboardCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
@@ -134,6 +138,16 @@ impl YamlParsableHash for MetaboardCfg {
     }
 }
 
+impl YamlWritable for MetaboardCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["metaboards".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_string(&self.url))
+    }
+}
+
 impl Default for MetaboardCfg {
     fn default() -> Self {
         Self {
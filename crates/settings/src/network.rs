@@ -1,8 +1,10 @@
 use crate::yaml::context::Context;
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
     default_document, optional_string, require_hash, require_string, require_vec, FieldErrorKind,
     YamlError, YamlParsableHash,
 };
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::{
@@ -15,21 +17,26 @@ use url::{ParseError, Url};
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct NetworkCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(target_family = "wasm", tsify(type = "string[]"))]
     pub rpcs: Vec<Url>,
     pub chain_id: u32,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub label: Option<String>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub network_id: Option<u32>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub currency: Option<String>,
 }
 #[cfg(target_family = "wasm")]
@@ -258,6 +265,27 @@ impl YamlParsableHash for NetworkCfg {
     }
 }
 
+impl YamlWritable for NetworkCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["networks".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_hash([
+            (
+                "rpcs",
+                Some(StrictYaml::Array(
+                    self.rpcs.iter().map(yaml_string).collect(),
+                )),
+            ),
+            ("chain-id", Some(yaml_string(self.chain_id))),
+            ("label", self.label.as_ref().map(yaml_string)),
+            ("network-id", self.network_id.map(yaml_string)),
+            ("currency", self.currency.as_ref().map(yaml_string)),
+        ]))
+    }
+}
+
 impl Default for NetworkCfg {
     fn default() -> Self {
         NetworkCfg::dummy()
@@ -1,5 +1,7 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::{yaml::FieldErrorKind, *};
 use alloy::primitives::U256;
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeSet, HashMap},
@@ -41,19 +43,23 @@ pub struct OrderIOCfg {
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(OrderIOCfg);
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "kebab-case")]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct OrderCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     pub inputs: Vec<OrderIOCfg>,
     pub outputs: Vec<OrderIOCfg>,
     pub network: Arc<NetworkCfg>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub deployer: Option<Arc<DeployerCfg>>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub orderbook: Option<Arc<OrderbookCfg>>,
 }
 #[cfg(target_family = "wasm")]
@@ -828,6 +834,53 @@ impl YamlParsableHash for OrderCfg {
     }
 }
 
+impl YamlWritable for OrderCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["orders".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        let io_to_yaml = |field: &str, ios: &[OrderIOCfg]| {
+            ios.iter()
+                .enumerate()
+                .map(|(index, io)| {
+                    let token = io.token.as_ref().ok_or(YamlError::Field {
+                        kind: FieldErrorKind::Missing("token".to_string()),
+                        location: format!("index '{}' of {} in order '{}'", index, field, self.key),
+                    })?;
+                    Ok(yaml_hash([
+                        ("token", Some(yaml_string(&token.key))),
+                        ("vault-id", io.vault_id.map(yaml_string)),
+                    ]))
+                })
+                .collect::<Result<Vec<_>, YamlError>>()
+        };
+
+        Ok(yaml_hash([
+            (
+                "inputs",
+                Some(StrictYaml::Array(io_to_yaml("inputs", &self.inputs)?)),
+            ),
+            (
+                "outputs",
+                Some(StrictYaml::Array(io_to_yaml("outputs", &self.outputs)?)),
+            ),
+            (
+                "deployer",
+                self.deployer
+                    .as_ref()
+                    .map(|deployer| yaml_string(&deployer.key)),
+            ),
+            (
+                "orderbook",
+                self.orderbook
+                    .as_ref()
+                    .map(|orderbook| yaml_string(&orderbook.key)),
+            ),
+        ]))
+    }
+}
+
 impl Default for OrderCfg {
     fn default() -> Self {
         Self {
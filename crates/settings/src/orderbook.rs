@@ -1,7 +1,9 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::FieldErrorKind;
 use crate::*;
 use alloy::primitives::hex::FromHexError;
 use alloy::primitives::Address;
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::num::ParseIntError;
@@ -17,11 +19,13 @@ use yaml::{
     default_document, optional_string, require_hash, require_string, YamlError, YamlParsableHash,
 };
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct OrderbookCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
@@ -29,6 +33,7 @@ pub struct OrderbookCfg {
     pub network: Arc<NetworkCfg>,
     pub subgraph: Arc<SubgraphCfg>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub label: Option<String>,
     pub deployment_block: u64,
 }
@@ -186,6 +191,22 @@ impl YamlParsableHash for OrderbookCfg {
     }
 }
 
+impl YamlWritable for OrderbookCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["orderbooks".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_hash([
+            ("address", Some(yaml_string(self.address))),
+            ("network", Some(yaml_string(&self.network.key))),
+            ("subgraph", Some(yaml_string(&self.subgraph.key))),
+            ("label", self.label.as_ref().map(yaml_string)),
+            ("deployment-block", Some(yaml_string(self.deployment_block))),
+        ]))
+    }
+}
+
 impl Default for OrderbookCfg {
     fn default() -> Self {
         Self {
@@ -0,0 +1,456 @@
+use reqwest::{header, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+use url::Url;
+
+/// Controls how remote token lists and network registries are loaded.
+///
+/// With a `cache_dir` every successful response is stored on disk together
+/// with its `ETag`/`Last-Modified` headers, later requests revalidate against
+/// it and fall back to it, with a warning, when the host can't be reached.
+/// With `offline` set no request is made at all and only `file://` sources
+/// and cached responses can be used.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteFetchOptions {
+    pub cache_dir: Option<PathBuf>,
+    pub offline: bool,
+}
+
+impl RemoteFetchOptions {
+    pub const CACHE_DIR_ENV: &'static str = "RAIN_ORDERBOOK_REMOTE_CACHE_DIR";
+    pub const OFFLINE_ENV: &'static str = "RAIN_ORDERBOOK_OFFLINE";
+
+    /// Reads the options from `RAIN_ORDERBOOK_REMOTE_CACHE_DIR` and
+    /// `RAIN_ORDERBOOK_OFFLINE`, any value other than `false` or `0` enables
+    /// offline mode.
+    pub fn from_env() -> Self {
+        let cache_dir = std::env::var(Self::CACHE_DIR_ENV)
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        let offline = std::env::var(Self::OFFLINE_ENV)
+            .map(|value| !matches!(value.trim().to_lowercase().as_str(), "" | "false" | "0"))
+            .unwrap_or(false);
+
+        RemoteFetchOptions { cache_dir, offline }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CachedResponse {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+#[derive(Error, Debug)]
+pub enum RemoteSourceError {
+    #[error("Offline mode is enabled and no cached response exists for {0}")]
+    OfflineCacheMiss(Url),
+    #[error("Unsupported url scheme '{0}', expected http, https or file")]
+    UnsupportedScheme(String),
+    #[error("Invalid file url: {0}")]
+    InvalidFileUrl(Url),
+    #[error("Invalid sha256 pin '{0}', expected 64 hex characters")]
+    InvalidPin(String),
+    #[error("Content of {url} does not match the pinned hash, expected sha256 {expected} but got {actual}")]
+    IntegrityMismatch {
+        url: Url,
+        expected: String,
+        actual: String,
+    },
+    #[error("Request to {0} failed with status {1}")]
+    HttpStatus(Url, StatusCode),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// Normalizes a `sha256` pin from yaml, an optional `0x` prefix is accepted.
+pub fn validate_sha256_pin(value: &str) -> Result<String, RemoteSourceError> {
+    let pin = value.trim();
+    let pin = pin.strip_prefix("0x").unwrap_or(pin).to_lowercase();
+    if pin.len() != 64 || !pin.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(RemoteSourceError::InvalidPin(value.to_string()));
+    }
+    Ok(pin)
+}
+
+pub fn sha256_hex(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Loads the content of a remote source, honouring the cache and offline
+/// settings in `options` and checking it against `sha256` when pinned.
+pub async fn fetch_remote_source(
+    url: &Url,
+    sha256: Option<&str>,
+    options: &RemoteFetchOptions,
+) -> Result<String, RemoteSourceError> {
+    let expected = sha256.map(validate_sha256_pin).transpose()?;
+    let body = match url.scheme() {
+        #[cfg(not(target_family = "wasm"))]
+        "file" => {
+            let path = url
+                .to_file_path()
+                .map_err(|_| RemoteSourceError::InvalidFileUrl(url.clone()))?;
+            fs::read_to_string(path)?
+        }
+        "http" | "https" => fetch_http(url, expected.as_deref(), options).await?,
+        scheme => return Err(RemoteSourceError::UnsupportedScheme(scheme.to_string())),
+    };
+
+    if let Some(expected) = &expected {
+        check_sha256_pin(url, expected, &body)?;
+    }
+
+    Ok(body)
+}
+
+fn check_sha256_pin(url: &Url, expected: &str, body: &str) -> Result<(), RemoteSourceError> {
+    let actual = sha256_hex(body.as_bytes());
+    if actual != expected {
+        return Err(RemoteSourceError::IntegrityMismatch {
+            url: url.clone(),
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Falls back to the cached body when the host can't serve a fresh one
+fn stale_cache_or(
+    url: &Url,
+    cached: Option<CachedResponse>,
+    err: RemoteSourceError,
+) -> Result<String, RemoteSourceError> {
+    match cached {
+        Some(cached) => {
+            tracing::warn!("Using the cached response for {url}: {err}");
+            Ok(cached.body)
+        }
+        None => Err(err),
+    }
+}
+
+async fn fetch_http(
+    url: &Url,
+    sha256: Option<&str>,
+    options: &RemoteFetchOptions,
+) -> Result<String, RemoteSourceError> {
+    let cache_path = options
+        .cache_dir
+        .as_ref()
+        .map(|dir| dir.join(format!("{}.json", sha256_hex(url.as_str().as_bytes()))));
+    let cached = match &cache_path {
+        Some(path) => read_cache(path),
+        None => None,
+    };
+
+    if options.offline {
+        return cached
+            .map(|cached| cached.body)
+            .ok_or_else(|| RemoteSourceError::OfflineCacheMiss(url.clone()));
+    }
+
+    let mut request = reqwest::Client::new().get(url.clone());
+    if let Some(cached) = &cached {
+        if let Some(etag) = &cached.etag {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(err) => return stale_cache_or(url, cached, err.into()),
+    };
+
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED {
+        if let Some(cached) = cached {
+            return Ok(cached.body);
+        }
+    }
+    if !status.is_success() {
+        return stale_cache_or(
+            url,
+            cached,
+            RemoteSourceError::HttpStatus(url.clone(), status),
+        );
+    }
+
+    let header_value = |name: header::HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let etag = header_value(header::ETAG);
+    let last_modified = header_value(header::LAST_MODIFIED);
+    let body = response.text().await?;
+
+    // A body that fails the pin must not replace the last good cached copy
+    if let Some(expected) = sha256 {
+        check_sha256_pin(url, expected, &body)?;
+    }
+
+    if let Some(path) = &cache_path {
+        let cached = CachedResponse {
+            url: url.to_string(),
+            etag,
+            last_modified,
+            body: body.clone(),
+        };
+        if let Err(err) = write_cache(path, &cached) {
+            tracing::warn!("Failed to cache the response for {url}: {err}");
+        }
+    }
+
+    Ok(body)
+}
+
+fn read_cache(path: &Path) -> Option<CachedResponse> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_cache(path: &Path, cached: &CachedResponse) -> Result<(), RemoteSourceError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string(cached)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::MockServer;
+    use tempfile::TempDir;
+
+    const BODY: &str = r#"{"tokens":[]}"#;
+
+    fn options(dir: &TempDir, offline: bool) -> RemoteFetchOptions {
+        RemoteFetchOptions {
+            cache_dir: Some(dir.path().to_path_buf()),
+            offline,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_file_source() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tokens.json");
+        fs::write(&path, BODY).unwrap();
+        let url = Url::from_file_path(&path).unwrap();
+
+        let body = fetch_remote_source(&url, None, &RemoteFetchOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+
+        // file sources are still readable in offline mode
+        let body = fetch_remote_source(&url, None, &options(&dir, true))
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pinned_source() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tokens.json");
+        fs::write(&path, BODY).unwrap();
+        let url = Url::from_file_path(&path).unwrap();
+        let hash = sha256_hex(BODY.as_bytes());
+
+        let body = fetch_remote_source(
+            &url,
+            Some(&format!("0x{}", hash.to_uppercase())),
+            &RemoteFetchOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, BODY);
+
+        let pin = "0".repeat(64);
+        let err = fetch_remote_source(&url, Some(&pin), &RemoteFetchOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RemoteSourceError::IntegrityMismatch { expected, actual, .. }
+                if expected == pin && actual == hash
+        ));
+
+        let err = fetch_remote_source(&url, Some("abc"), &RemoteFetchOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RemoteSourceError::InvalidPin(pin) if pin == "abc"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_revalidates_cache() {
+        let server = MockServer::start_async().await;
+        let dir = TempDir::new().unwrap();
+        let url = Url::parse(&server.url("/tokens.json")).unwrap();
+
+        let mut mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tokens.json");
+                then.status(200)
+                    .header("etag", "\"v1\"")
+                    .header("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                    .body(BODY);
+            })
+            .await;
+        let body = fetch_remote_source(&url, None, &options(&dir, false))
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+        mock.assert_async().await;
+        mock.delete_async().await;
+
+        mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/tokens.json")
+                    .header("if-none-match", "\"v1\"")
+                    .header("if-modified-since", "Wed, 21 Oct 2015 07:28:00 GMT");
+                then.status(304);
+            })
+            .await;
+        let body = fetch_remote_source(&url, None, &options(&dir, false))
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+        mock.assert_async().await;
+        mock.delete_async().await;
+
+        // the cached response is used when the host is down
+        server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tokens.json");
+                then.status(503);
+            })
+            .await;
+        let body = fetch_remote_source(&url, None, &options(&dir, false))
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+
+        let err = fetch_remote_source(&url, None, &RemoteFetchOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RemoteSourceError::HttpStatus(_, StatusCode::SERVICE_UNAVAILABLE)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pin_mismatch_keeps_cache() {
+        let server = MockServer::start_async().await;
+        let dir = TempDir::new().unwrap();
+        let url = Url::parse(&server.url("/tokens.json")).unwrap();
+        let pin = sha256_hex(BODY.as_bytes());
+
+        let mut mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tokens.json");
+                then.status(200).body(BODY);
+            })
+            .await;
+        fetch_remote_source(&url, Some(&pin), &options(&dir, false))
+            .await
+            .unwrap();
+        mock.delete_async().await;
+
+        mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tokens.json");
+                then.status(200).body(r#"{"tokens":[{}]}"#);
+            })
+            .await;
+        let err = fetch_remote_source(&url, Some(&pin), &options(&dir, false))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RemoteSourceError::IntegrityMismatch { .. }));
+        mock.delete_async().await;
+
+        // the last good copy is still served offline
+        let body = fetch_remote_source(&url, Some(&pin), &options(&dir, true))
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_unwritable_cache() {
+        let server = MockServer::start_async().await;
+        let dir = TempDir::new().unwrap();
+        let url = Url::parse(&server.url("/tokens.json")).unwrap();
+        server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tokens.json");
+                then.status(200).body(BODY);
+            })
+            .await;
+
+        // a file where the cache directory should be makes every write fail
+        let cache_dir = dir.path().join("cache");
+        fs::write(&cache_dir, "").unwrap();
+        let options = RemoteFetchOptions {
+            cache_dir: Some(cache_dir),
+            offline: false,
+        };
+        let body = fetch_remote_source(&url, None, &options).await.unwrap();
+        assert_eq!(body, BODY);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_offline() {
+        let server = MockServer::start_async().await;
+        let dir = TempDir::new().unwrap();
+        let url = Url::parse(&server.url("/tokens.json")).unwrap();
+
+        let err = fetch_remote_source(&url, None, &options(&dir, true))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RemoteSourceError::OfflineCacheMiss(miss) if miss == url));
+
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/tokens.json");
+                then.status(200).body(BODY);
+            })
+            .await;
+        fetch_remote_source(&url, None, &options(&dir, false))
+            .await
+            .unwrap();
+
+        let body = fetch_remote_source(&url, None, &options(&dir, true))
+            .await
+            .unwrap();
+        assert_eq!(body, BODY);
+        mock.assert_hits_async(1).await;
+    }
+}
@@ -2,14 +2,15 @@ use crate::{NetworkCfg, TokenCfg};
 use alloy::primitives::Address;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
+    fmt,
     str::FromStr,
     sync::{Arc, RwLock},
 };
 use strict_yaml_rust::StrictYaml;
 use thiserror::Error;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Version {
     pub major: u64,
@@ -17,6 +18,34 @@ pub struct Version {
     pub patch: u64,
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeInfo {
+    pub token_address: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenExtensions {
+    /// Addresses of the bridged token keyed by the chain id as a string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bridge_info: Option<HashMap<String, BridgeInfo>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TagDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Token {
@@ -25,6 +54,12 @@ pub struct Token {
     pub name: String,
     pub symbol: String,
     pub decimals: u64,
+    #[serde(rename = "logoURI", default, skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<TokenExtensions>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
@@ -32,11 +67,33 @@ pub struct Token {
 pub struct Tokens {
     pub name: String,
     pub timestamp: String,
+    #[serde(default)]
     pub keywords: Vec<String>,
     pub version: Version,
     pub tokens: Vec<Token>,
-    #[serde(rename = "logoURI")]
-    pub logo_uri: String,
+    #[serde(rename = "logoURI", default, skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<HashMap<String, TagDefinition>>,
+}
+
+impl Tokens {
+    /// Replaces the tag ids of every token with the tag names defined by the
+    /// list, ids without a definition are kept as they are.
+    pub fn resolve_tags(&mut self) {
+        let Some(definitions) = &self.tags else {
+            return;
+        };
+        for token in &mut self.tokens {
+            if let Some(tags) = &mut token.tags {
+                for tag in tags.iter_mut() {
+                    if let Some(definition) = definitions.get(tag.as_str()) {
+                        *tag = definition.name.clone();
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl Token {
@@ -47,9 +104,50 @@ impl Token {
     ) -> Result<Option<TokenCfg>, RemoteTokensError> {
         match networks
             .values()
-            .find(|network| network.chain_id == self.chain_id)
+            .filter(|network| network.chain_id == self.chain_id)
+            .min_by(|a, b| a.key.cmp(&b.key))
         {
             Some(network) => {
+                // a malformed bridge entry only drops that entry, not the token
+                let bridged_addresses = self
+                    .extensions
+                    .as_ref()
+                    .and_then(|extensions| extensions.bridge_info.as_ref())
+                    .map(|bridge_info| {
+                        bridge_info
+                            .iter()
+                            .filter_map(|(chain_id, info)| {
+                                let entry = chain_id
+                                    .parse::<u32>()
+                                    .map_err(|e| {
+                                        RemoteTokensError::ParseBridgeInfoError(e.to_string())
+                                    })
+                                    .and_then(|chain_id| {
+                                        let address = Address::from_str(&info.token_address)
+                                            .map_err(|e| {
+                                                RemoteTokensError::ParseTokenAddressError(
+                                                    e.to_string(),
+                                                )
+                                            })?;
+                                        Ok((chain_id, address))
+                                    });
+                                match entry {
+                                    Ok(entry) => Some(entry),
+                                    Err(err) => {
+                                        tracing::warn!(
+                                            "Skipping bridge info '{chain_id}' of token {}: {err}",
+                                            self.address
+                                        );
+                                        None
+                                    }
+                                }
+                            })
+                            .collect::<BTreeMap<u32, Address>>()
+                    });
+                let decimals = u8::try_from(self.decimals).map_err(|_| {
+                    RemoteTokensError::ParseTokenDecimalsError(self.decimals.to_string())
+                })?;
+
                 let token_cfg = TokenCfg {
                     document: document.clone(),
                     key: format!(
@@ -61,9 +159,12 @@ impl Token {
                     network: Arc::new(network.clone()),
                     address: Address::from_str(&self.address)
                         .map_err(|e| RemoteTokensError::ParseTokenAddressError(e.to_string()))?,
-                    decimals: Some(self.decimals as u8),
+                    decimals: Some(decimals),
                     label: Some(self.name.clone()),
                     symbol: Some(self.symbol),
+                    logo_uri: self.logo_uri,
+                    tags: self.tags,
+                    bridged_addresses,
                 };
                 Ok(Some(token_cfg))
             }
@@ -76,6 +177,10 @@ impl Token {
 pub enum RemoteTokensError {
     #[error("Failed to parse token address: {0}")]
     ParseTokenAddressError(String),
+    #[error("Invalid token decimals: {0}")]
+    ParseTokenDecimalsError(String),
+    #[error("Failed to parse bridge info chain id: {0}")]
+    ParseBridgeInfoError(String),
     #[error("Failed to parse network: {0}")]
     ParseNetworkError(String),
     #[error("Network not found for chain_id: {0}")]
@@ -1,7 +1,12 @@
 use crate::remote::chains::{ChainId, ChainIdError};
+use crate::remote::source::{
+    fetch_remote_source, validate_sha256_pin, RemoteFetchOptions, RemoteSourceError,
+};
 use crate::yaml::context::Context;
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
-    default_document, require_hash, require_string, FieldErrorKind, YamlError, YamlParsableHash,
+    default_document, optional_string, require_hash, require_string, FieldErrorKind, YamlError,
+    YamlParsableHash,
 };
 use crate::NetworkCfg;
 use serde::{Deserialize, Serialize};
@@ -28,6 +33,8 @@ pub struct RemoteNetworksCfg {
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
     pub url: Url,
     pub format: String,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub sha256: Option<String>,
 }
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(RemoteNetworksCfg);
@@ -39,16 +46,26 @@ impl RemoteNetworksCfg {
 
     pub async fn fetch_networks(
         remote_networks: HashMap<String, RemoteNetworksCfg>,
+    ) -> Result<HashMap<String, NetworkCfg>, ParseRemoteNetworksError> {
+        Self::fetch_networks_with_options(remote_networks, &RemoteFetchOptions::default()).await
+    }
+
+    pub async fn fetch_networks_with_options(
+        remote_networks: HashMap<String, RemoteNetworksCfg>,
+        options: &RemoteFetchOptions,
     ) -> Result<HashMap<String, NetworkCfg>, ParseRemoteNetworksError> {
         let mut networks = HashMap::new();
 
         for (_, remote_network) in remote_networks {
             match remote_network.format.as_str() {
                 "chainid" => {
-                    let chains = reqwest::get(remote_network.url.to_string())
-                        .await?
-                        .json::<Vec<ChainId>>()
-                        .await?;
+                    let content = fetch_remote_source(
+                        &remote_network.url,
+                        remote_network.sha256.as_deref(),
+                        options,
+                    )
+                    .await?;
+                    let chains = serde_json::from_str::<Vec<ChainId>>(&content)?;
 
                     for chain in &chains {
                         let network: NetworkCfg = chain
@@ -109,11 +126,24 @@ impl YamlParsableHash for RemoteNetworksCfg {
                     let format =
                         require_string(network_yaml, Some("format"), Some(location.clone()))?;
 
+                    let sha256 = optional_string(network_yaml, "sha256")
+                        .map(|sha256| {
+                            validate_sha256_pin(&sha256).map_err(|e| YamlError::Field {
+                                kind: FieldErrorKind::InvalidValue {
+                                    field: "sha256".to_string(),
+                                    reason: e.to_string(),
+                                },
+                                location: location.clone(),
+                            })
+                        })
+                        .transpose()?;
+
                     let remote_network = RemoteNetworksCfg {
                         document: document.clone(),
                         key: key.clone(),
                         url,
                         format,
+                        sha256,
                     };
 
                     if remote_networks.contains_key(&key) {
@@ -131,6 +161,20 @@ impl YamlParsableHash for RemoteNetworksCfg {
     }
 }
 
+impl YamlWritable for RemoteNetworksCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["using-networks-from".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_hash([
+            ("url", Some(yaml_string(&self.url))),
+            ("format", Some(yaml_string(&self.format))),
+            ("sha256", self.sha256.as_ref().map(yaml_string)),
+        ]))
+    }
+}
+
 impl Default for RemoteNetworksCfg {
     fn default() -> Self {
         RemoteNetworksCfg {
@@ -138,12 +182,16 @@ impl Default for RemoteNetworksCfg {
             key: "".to_string(),
             url: Url::parse("https://example.com").unwrap(),
             format: "".to_string(),
+            sha256: None,
         }
     }
 }
 impl PartialEq for RemoteNetworksCfg {
     fn eq(&self, other: &Self) -> bool {
-        self.key == other.key && self.url == other.url && self.format == other.format
+        self.key == other.key
+            && self.url == other.url
+            && self.format == other.format
+            && self.sha256 == other.sha256
     }
 }
 
@@ -159,6 +207,10 @@ pub enum ParseRemoteNetworksError {
     ConflictingNetworks(String),
     #[error(transparent)]
     ChainIdError(#[from] ChainIdError),
+    #[error(transparent)]
+    RemoteSourceError(#[from] RemoteSourceError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
 }
 
 #[cfg(test)]
@@ -166,6 +218,7 @@ mod tests {
     use httpmock::MockServer;
 
     use super::*;
+    use crate::remote::source::sha256_hex;
     use crate::yaml::{tests::get_document, FieldErrorKind};
 
     #[test]
@@ -394,4 +447,71 @@ using-networks-from:
         );
         assert_eq!(network.chain_id, 234);
     }
+
+    #[tokio::test]
+    async fn test_fetch_pinned_remote_networks_from_file() {
+        let response = r#"[{"name":"Remote","chain":"remote-network","chainId":123,"rpc":["http://localhost:8085/rpc-url"],"networkId":123,"nativeCurrency":{"name":"Remote","symbol":"RN","decimals":18},"infoURL":"http://localhost:8085/info-url","shortName":"remote-network"}]"#;
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), response).unwrap();
+        let url = Url::from_file_path(file.path()).unwrap();
+
+        let yaml = format!(
+            r#"
+using-networks-from:
+    test:
+      url: {url}
+      format: chainid
+      sha256: 0x{}
+"#,
+            sha256_hex(response.as_bytes())
+        );
+        let remote_networks =
+            RemoteNetworksCfg::parse_all_from_yaml(vec![get_document(&yaml)], None).unwrap();
+        assert_eq!(
+            remote_networks["test"].sha256,
+            Some(sha256_hex(response.as_bytes()))
+        );
+
+        let options = RemoteFetchOptions {
+            cache_dir: None,
+            offline: true,
+        };
+        let networks =
+            RemoteNetworksCfg::fetch_networks_with_options(remote_networks.clone(), &options)
+                .await
+                .unwrap();
+        assert_eq!(networks["remote-network"].chain_id, 123);
+
+        let mut tampered = remote_networks;
+        tampered.get_mut("test").unwrap().sha256 = Some("1".repeat(64));
+        let err = RemoteNetworksCfg::fetch_networks_with_options(tampered, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRemoteNetworksError::RemoteSourceError(
+                RemoteSourceError::IntegrityMismatch { .. }
+            )
+        ));
+
+        let yaml = r#"
+using-networks-from:
+    test:
+      url: https://example.com
+      format: chainid
+      sha256: abc
+"#;
+        let error =
+            RemoteNetworksCfg::parse_all_from_yaml(vec![get_document(yaml)], None).unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "sha256".to_string(),
+                    reason: "Invalid sha256 pin 'abc', expected 64 hex characters".to_string(),
+                },
+                location: "using-networks-from 'test'".to_string(),
+            }
+        );
+    }
 }
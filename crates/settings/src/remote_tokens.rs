@@ -1,11 +1,17 @@
-use crate::remote::tokens::{RemoteTokensError, Token, Tokens};
+use crate::remote::source::{
+    fetch_remote_source, validate_sha256_pin, RemoteFetchOptions, RemoteSourceError,
+};
+use crate::remote::tokens::{RemoteTokensError, Tokens};
 use crate::yaml::context::Context;
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
-    default_document, optional_vec, require_string, FieldErrorKind, YamlError, YamlParseableValue,
+    default_document, optional_string, optional_vec, require_string, FieldErrorKind, YamlError,
+    YamlParseableValue,
 };
 use crate::{NetworkCfg, TokenCfg};
+use alloy::primitives::Address;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 use strict_yaml_rust::StrictYaml;
 use thiserror::Error;
@@ -20,6 +26,10 @@ pub struct RemoteTokensCfg {
     pub document: Arc<RwLock<StrictYaml>>,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
     pub urls: Vec<Url>,
+    /// Expected sha256 of the token list content, keyed by url
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(type = "Record<string, string>"))]
+    pub sha256: HashMap<String, String>,
 }
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(RemoteTokensCfg);
@@ -33,37 +43,77 @@ impl RemoteTokensCfg {
         networks: &HashMap<String, NetworkCfg>,
         remote_tokens: RemoteTokensCfg,
     ) -> Result<HashMap<String, TokenCfg>, ParseRemoteTokensError> {
-        let mut tokens: HashMap<String, TokenCfg> = HashMap::new();
-
-        for url in remote_tokens.urls {
-            let tokens_res = reqwest::get(url.to_string())
-                .await?
-                .json::<Tokens>()
-                .await?;
+        Self::fetch_tokens_with_options(networks, remote_tokens, &RemoteFetchOptions::default())
+            .await
+    }
 
-            let mut unique_tokens: HashMap<(u32, String), Token> = HashMap::new();
-            for token in &tokens_res.tokens {
-                let token_id = (token.chain_id, token.address.to_lowercase());
-                unique_tokens.insert(token_id, token.clone());
+    pub async fn fetch_tokens_with_options(
+        networks: &HashMap<String, NetworkCfg>,
+        remote_tokens: RemoteTokensCfg,
+        options: &RemoteFetchOptions,
+    ) -> Result<HashMap<String, TokenCfg>, ParseRemoteTokensError> {
+        let mut lists: Vec<Tokens> = Vec::new();
+        for url in &remote_tokens.urls {
+            let sha256 = remote_tokens.sha256.get(url.as_str());
+            let content = fetch_remote_source(url, sha256.map(String::as_str), options).await?;
+            let mut list = serde_json::from_str::<Tokens>(&content)?;
+            list.resolve_tags();
+
+            // the same list served from several urls is only used once, at its newest version
+            match lists.iter_mut().find(|existing| existing.name == list.name) {
+                Some(existing) => {
+                    if list.version > existing.version {
+                        *existing = list;
+                    }
+                }
+                None => lists.push(list),
             }
+        }
 
-            for token in unique_tokens.values() {
-                if let Some(token_cfg) = token
-                    .clone()
-                    .try_into_token_cfg(networks, remote_tokens.document.clone())?
-                {
-                    if tokens.contains_key(&token_cfg.key) {
-                        return Err(ParseRemoteTokensError::ConflictingTokens(
-                            token_cfg.key.clone(),
-                        ));
-                    }
-                    tokens.insert(token_cfg.key.clone(), token_cfg);
+        // lists are applied in the order they are listed in the yaml, so a token that is
+        // in several lists takes its metadata from the first one
+        let mut tokens: HashMap<String, TokenCfg> = HashMap::new();
+        let mut seen: HashSet<(u32, Address)> = HashSet::new();
+        for list in lists {
+            let mut list_tokens = list.tokens;
+            list_tokens.sort_by_key(|token| (token.chain_id, token.address.to_lowercase()));
+
+            for token in list_tokens {
+                // one malformed token doesn't invalidate the rest of the list
+                let mut token_cfg =
+                    match token.try_into_token_cfg(networks, remote_tokens.document.clone()) {
+                        Ok(Some(token_cfg)) => token_cfg,
+                        Ok(None) => continue,
+                        Err(err) => {
+                            tracing::warn!("Skipping a token of the '{}' list: {err}", list.name);
+                            continue;
+                        }
+                    };
+                if !seen.insert((token_cfg.network.chain_id, token_cfg.address)) {
+                    continue;
+                }
+                if tokens.contains_key(&token_cfg.key) {
+                    token_cfg.key = Self::unique_key(&tokens, &token_cfg);
                 }
+                tokens.insert(token_cfg.key.clone(), token_cfg);
             }
         }
 
         Ok(tokens)
     }
+
+    /// Resolves a key conflict between two different tokens by appending the
+    /// chain id, and a counter if that is still taken.
+    fn unique_key(tokens: &HashMap<String, TokenCfg>, token: &TokenCfg) -> String {
+        let key = format!("{}-{}", token.key, token.network.chain_id);
+        if !tokens.contains_key(&key) {
+            return key;
+        }
+        (2..)
+            .map(|index| format!("{key}-{index}"))
+            .find(|candidate| !tokens.contains_key(candidate))
+            .unwrap_or(key)
+    }
 }
 
 impl YamlParseableValue for RemoteTokensCfg {
@@ -79,14 +129,36 @@ impl YamlParseableValue for RemoteTokensCfg {
         _: Option<&Context>,
     ) -> Result<Option<RemoteTokensCfg>, YamlError> {
         let mut validated_urls: Vec<Url> = Vec::new();
+        let mut sha256 = HashMap::new();
         let mut document_index: usize = 0;
 
         for (index, document) in documents.iter().enumerate() {
             let document_read = document.read().map_err(|_| YamlError::ReadLockError)?;
 
             if let Some(urls) = optional_vec(&document_read, "using-tokens-from") {
-                for url in urls {
-                    let url = require_string(url, None, Some("using-tokens-from".to_string()))?;
+                for (url_index, source) in urls.iter().enumerate() {
+                    let (url, pin) = match source.as_hash() {
+                        Some(_) => {
+                            let location = format!("index '{url_index}' of using-tokens-from");
+                            let url = require_string(source, Some("url"), Some(location.clone()))?;
+                            let pin = optional_string(source, "sha256")
+                                .map(|pin| {
+                                    validate_sha256_pin(&pin).map_err(|e| YamlError::Field {
+                                        kind: FieldErrorKind::InvalidValue {
+                                            field: "sha256".to_string(),
+                                            reason: e.to_string(),
+                                        },
+                                        location,
+                                    })
+                                })
+                                .transpose()?;
+                            (url, pin)
+                        }
+                        None => (
+                            require_string(source, None, Some("using-tokens-from".to_string()))?,
+                            None,
+                        ),
+                    };
 
                     let validated_url =
                         RemoteTokensCfg::validate_url(&url).map_err(|e| YamlError::Field {
@@ -103,6 +175,9 @@ impl YamlParseableValue for RemoteTokensCfg {
                             "using-tokens-from".to_string(),
                         ));
                     }
+                    if let Some(pin) = pin {
+                        sha256.insert(validated_url.to_string(), pin);
+                    }
                     validated_urls.push(validated_url);
                 }
 
@@ -116,35 +191,62 @@ impl YamlParseableValue for RemoteTokensCfg {
 
         Ok(Some(RemoteTokensCfg {
             urls: validated_urls,
+            sha256,
             document: documents[document_index].clone(),
         }))
     }
 }
 
+impl YamlWritable for RemoteTokensCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["using-tokens-from".to_string()]
+    }
+
+    /// Pinned lists are written as maps with their sha256, the others as
+    /// plain urls
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(StrictYaml::Array(
+            self.urls
+                .iter()
+                .map(|url| match self.sha256.get(url.as_str()) {
+                    Some(pin) => yaml_hash([
+                        ("url", Some(yaml_string(url))),
+                        ("sha256", Some(yaml_string(pin))),
+                    ]),
+                    None => yaml_string(url),
+                })
+                .collect(),
+        ))
+    }
+}
+
 impl Default for RemoteTokensCfg {
     fn default() -> Self {
         RemoteTokensCfg {
             document: default_document(),
             urls: vec![Url::parse("http://example.com").unwrap()],
+            sha256: HashMap::new(),
         }
     }
 }
 impl PartialEq for RemoteTokensCfg {
     fn eq(&self, other: &Self) -> bool {
-        self.urls == other.urls
+        self.urls == other.urls && self.sha256 == other.sha256
     }
 }
 
 #[derive(Error, Debug)]
 pub enum ParseRemoteTokensError {
-    #[error("Conflicting remote token in response, a token with key '{0}' already exists")]
-    ConflictingTokens(String),
     #[error(transparent)]
     UrlParseError(ParseError),
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
     #[error(transparent)]
     RemoteTokensError(#[from] RemoteTokensError),
+    #[error(transparent)]
+    RemoteSourceError(#[from] RemoteSourceError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
 }
 
 #[cfg(test)]
@@ -155,7 +257,9 @@ mod tests {
     use httpmock::MockServer;
 
     use super::*;
+    use crate::remote::source::sha256_hex;
     use crate::yaml::{tests::get_document, FieldErrorKind};
+    use std::collections::BTreeMap;
 
     #[test]
     fn test_parse_remote_tokens_from_yaml() {
@@ -481,4 +585,263 @@ using-tokens-from:
         assert_eq!(token.network.key, "remote4-network");
         assert_eq!(token.network.chain_id, 456);
     }
+
+    #[tokio::test]
+    async fn test_fetch_pinned_remote_tokens() {
+        let response = r#"{"name":"Remote","timestamp":"2021-01-01T00:00:00.000Z","keywords":[],"version":{"major":1,"minor":0,"patch":0},"tokens":[{"chainId":123,"address":"0x0000000000000000000000000000000000000001","name":"Token1","symbol":"T1","decimals":18}],"logoURI":"http://localhost.com"}"#;
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), response).unwrap();
+        let url = Url::from_file_path(file.path()).unwrap();
+        let hash = sha256_hex(response.as_bytes());
+
+        let yaml = format!(
+            r#"
+using-tokens-from:
+  - url: {url}
+    sha256: {hash}
+  - https://tokens.example.com/list.json
+"#
+        );
+        let remote_tokens =
+            RemoteTokensCfg::parse_from_yaml_optional(vec![get_document(&yaml)], None)
+                .unwrap()
+                .unwrap();
+        assert_eq!(remote_tokens.urls.len(), 2);
+        assert_eq!(
+            remote_tokens.sha256,
+            HashMap::from([(url.to_string(), hash.clone())])
+        );
+
+        let networks = HashMap::from([(
+            "remote-network".to_string(),
+            NetworkCfg {
+                chain_id: 123,
+                key: "remote-network".to_string(),
+                ..NetworkCfg::default()
+            },
+        )]);
+        let options = RemoteFetchOptions {
+            cache_dir: None,
+            offline: true,
+        };
+
+        let mut pinned = remote_tokens.clone();
+        pinned.urls.truncate(1);
+        let tokens =
+            RemoteTokensCfg::fetch_tokens_with_options(&networks, pinned.clone(), &options)
+                .await
+                .unwrap();
+        assert_eq!(tokens.len(), 1);
+
+        // the second url can't be loaded without a cached copy
+        let err = RemoteTokensCfg::fetch_tokens_with_options(&networks, remote_tokens, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRemoteTokensError::RemoteSourceError(RemoteSourceError::OfflineCacheMiss(_))
+        ));
+
+        pinned.sha256.insert(url.to_string(), "2".repeat(64));
+        let err = RemoteTokensCfg::fetch_tokens_with_options(&networks, pinned, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRemoteTokensError::RemoteSourceError(RemoteSourceError::IntegrityMismatch { .. })
+        ));
+
+        let yaml = r#"
+using-tokens-from:
+  - url: https://tokens.example.com/list.json
+    sha256: nope
+"#;
+        let error =
+            RemoteTokensCfg::parse_from_yaml_optional(vec![get_document(yaml)], None).unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: "sha256".to_string(),
+                    reason: "Invalid sha256 pin 'nope', expected 64 hex characters".to_string(),
+                },
+                location: "index '0' of using-tokens-from".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_remote_token_list_metadata() {
+        let list = |name: &str, minor: u64, tokens: &str| {
+            let file = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(
+                file.path(),
+                format!(
+                    r#"{{"name":"{name}","timestamp":"2021-01-01T00:00:00.000Z","version":{{"major":1,"minor":{minor},"patch":0}},"tags":{{"stable":{{"name":"Stablecoin","description":"Pegged to a fiat currency"}}}},"tokens":{tokens}}}"#
+                ),
+            )
+            .unwrap();
+            file
+        };
+        let token = |address: &str, name: &str| {
+            format!(
+                r#"{{"chainId":123,"address":"{address}","name":"{name}","symbol":"T","decimals":6,"logoURI":"ipfs://logo","tags":["stable","unknown"],"extensions":{{"bridgeInfo":{{"234":{{"tokenAddress":"0x0000000000000000000000000000000000000002"}}}}}}}}"#
+            )
+        };
+        let address1 = "0x0000000000000000000000000000000000000001";
+        let address3 = "0x0000000000000000000000000000000000000003";
+
+        let old = list("Rain", 0, &format!("[{}]", token(address1, "Old")));
+        let new = list("Rain", 1, &format!("[{}]", token(address1, "New")));
+        let other = list(
+            "Other",
+            5,
+            &format!(
+                "[{},{}]",
+                token(address3, "Third"),
+                token(address1, "Other")
+            ),
+        );
+
+        let remote_tokens = RemoteTokensCfg {
+            urls: [&old, &new, &other]
+                .iter()
+                .map(|file| Url::from_file_path(file.path()).unwrap())
+                .collect(),
+            ..RemoteTokensCfg::default()
+        };
+        let networks = HashMap::from([(
+            "remote-network".to_string(),
+            NetworkCfg {
+                chain_id: 123,
+                key: "remote-network".to_string(),
+                ..NetworkCfg::default()
+            },
+        )]);
+
+        let tokens = RemoteTokensCfg::fetch_tokens(&networks, remote_tokens)
+            .await
+            .unwrap();
+        assert_eq!(tokens.len(), 2);
+
+        // the newest version of the "Rain" list wins, and it is applied before "Other"
+        let token = &tokens[&format!("remote-network-New-{address1}")];
+        assert_eq!(token.label, Some("New".to_string()));
+        assert_eq!(token.logo_uri, Some("ipfs://logo".to_string()));
+        assert_eq!(
+            token.tags,
+            Some(vec!["Stablecoin".to_string(), "unknown".to_string()])
+        );
+        assert_eq!(
+            token.bridged_addresses,
+            Some(BTreeMap::from([(
+                234,
+                Address::from_str("0x0000000000000000000000000000000000000002").unwrap()
+            )]))
+        );
+        assert!(tokens.contains_key(&format!("remote-network-Third-{address3}")));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_remote_tokens_skips_invalid_entries() {
+        let server = MockServer::start_async().await;
+        let yaml = format!(
+            r#"
+using-tokens-from:
+  - {}
+"#,
+            server.base_url()
+        );
+        let remote_tokens =
+            RemoteTokensCfg::parse_from_yaml_optional(vec![get_document(&yaml)], None)
+                .unwrap()
+                .unwrap();
+
+        let response = r#"
+{
+    "name": "Remote",
+    "timestamp": "2021-01-01T00:00:00.000Z",
+    "version": { "major": 1, "minor": 0, "patch": 0 },
+    "tokens": [
+        {
+            "chainId": 123,
+            "address": "0x0000000000000000000000000000000000000001",
+            "name": "Valid",
+            "symbol": "V",
+            "decimals": 18,
+            "extensions": {
+                "bridgeInfo": {
+                    "234": { "tokenAddress": "0x0000000000000000000000000000000000000002" },
+                    "mainnet": { "tokenAddress": "0x0000000000000000000000000000000000000003" },
+                    "345": { "tokenAddress": "invalid" }
+                }
+            }
+        },
+        {
+            "chainId": 123,
+            "address": "invalid",
+            "name": "BadAddress",
+            "symbol": "BA",
+            "decimals": 18
+        },
+        {
+            "chainId": 123,
+            "address": "0x0000000000000000000000000000000000000004",
+            "name": "BadDecimals",
+            "symbol": "BD",
+            "decimals": 300
+        }
+    ]
+}
+        "#;
+        server
+            .mock_async(|when, then| {
+                when.method("GET").path("/");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(response);
+            })
+            .await;
+
+        let networks = HashMap::from([(
+            "remote-network".to_string(),
+            NetworkCfg {
+                document: default_document(),
+                key: "remote-network".to_string(),
+                rpcs: vec![Url::parse("http://localhost:8085/rpc-url").unwrap()],
+                chain_id: 123,
+                label: None,
+                network_id: None,
+                currency: None,
+            },
+        )]);
+        let tokens = RemoteTokensCfg::fetch_tokens(&networks, remote_tokens)
+            .await
+            .unwrap();
+
+        assert_eq!(tokens.len(), 1);
+        let token = tokens
+            .get("remote-network-Valid-0x0000000000000000000000000000000000000001")
+            .unwrap();
+        assert_eq!(
+            token.bridged_addresses,
+            Some(BTreeMap::from([(
+                234,
+                Address::from_str("0x0000000000000000000000000000000000000002").unwrap()
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_unique_key() {
+        let token = TokenCfg {
+            key: "token".to_string(),
+            ..TokenCfg::default()
+        };
+        let mut tokens = HashMap::from([("token".to_string(), token.clone())]);
+        assert_eq!(RemoteTokensCfg::unique_key(&tokens, &token), "token-1");
+
+        tokens.insert("token-1".to_string(), token.clone());
+        assert_eq!(RemoteTokensCfg::unique_key(&tokens, &token), "token-1-2");
+    }
 }
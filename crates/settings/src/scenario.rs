@@ -1,8 +1,10 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::{yaml::get_hash_value, *};
 use blocks::BlocksCfg;
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     num::ParseIntError,
     sync::{Arc, RwLock},
 };
@@ -15,11 +17,13 @@ use yaml::{
     require_string, FieldErrorKind, YamlError, YamlParsableHash,
 };
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "kebab-case")]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct ScenarioCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(
@@ -27,10 +31,13 @@ pub struct ScenarioCfg {
         serde(serialize_with = "serialize_hashmap_as_object"),
         tsify(type = "Record<string, string>")
     )]
+    #[builder(default)]
     pub bindings: HashMap<String, String>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub runs: Option<u64>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub blocks: Option<BlocksCfg>,
     pub deployer: Arc<DeployerCfg>,
 }
@@ -38,6 +45,38 @@ pub struct ScenarioCfg {
 impl_wasm_traits!(ScenarioCfg);
 
 impl ScenarioCfg {
+    /// Yaml path of a scenario, nested scenario keys are joined with dots
+    pub fn yaml_path_for_key(key: &str) -> Vec<String> {
+        key.split('.')
+            .flat_map(|segment| ["scenarios".to_string(), segment.to_string()])
+            .collect()
+    }
+
+    fn inherited_bindings(&self) -> Result<HashMap<String, String>, YamlError> {
+        let mut bindings = HashMap::new();
+        let document = self.document.read().map_err(|_| YamlError::ReadLockError)?;
+
+        let segments = self.key.split('.').collect::<Vec<_>>();
+        let mut scenarios = optional_hash(&document, "scenarios");
+        for segment in &segments[..segments.len() - 1] {
+            let Some(parent) =
+                scenarios.and_then(|hash| hash.get(&StrictYaml::String(segment.to_string())))
+            else {
+                break;
+            };
+            if let Some(parent_bindings) = optional_hash(parent, "bindings") {
+                for (key, value) in parent_bindings {
+                    if let (Some(key), Some(value)) = (key.as_str(), value.as_str()) {
+                        bindings.insert(key.to_string(), value.to_string());
+                    }
+                }
+            }
+            scenarios = optional_hash(parent, "scenarios");
+        }
+
+        Ok(bindings)
+    }
+
     pub fn validate_runs(value: &str) -> Result<u64, ParseScenarioConfigSourceError> {
         value
             .parse::<u64>()
@@ -402,6 +441,56 @@ impl YamlParsableHash for ScenarioCfg {
     }
 }
 
+impl YamlWritable for ScenarioCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        ScenarioCfg::yaml_path_for_key(&self.key)
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        // bindings equal to the ones of a parent scenario are inherited on
+        // parse, so only the ones defined by this scenario are written
+        let inherited = self.inherited_bindings()?;
+        let bindings = self
+            .bindings
+            .iter()
+            .filter(|(key, value)| inherited.get(*key) != Some(*value))
+            .collect::<BTreeMap<_, _>>();
+
+        let blocks = self.blocks.as_ref().map(|blocks| match blocks {
+            BlocksCfg::SimpleRange(range) => yaml_string(format!("[{}]", range)),
+            BlocksCfg::RangeWithInterval { range, interval } => yaml_hash([
+                ("range", Some(yaml_string(format!("[{}]", range)))),
+                ("interval", Some(yaml_string(interval))),
+            ]),
+        });
+
+        // deployers named after the scenario are picked up without a reference
+        let segment = self.key.rsplit('.').next().unwrap_or_default();
+        let deployer = (self.deployer.key != segment).then(|| yaml_string(&self.deployer.key));
+
+        Ok(yaml_hash([
+            (
+                "bindings",
+                (!bindings.is_empty()).then(|| {
+                    StrictYaml::Hash(
+                        bindings
+                            .into_iter()
+                            .map(|(key, value)| (yaml_string(key), yaml_string(value)))
+                            .collect(),
+                    )
+                }),
+            ),
+            ("runs", self.runs.map(yaml_string)),
+            ("blocks", blocks),
+            ("deployer", deployer),
+        ]))
+    }
+
+    fn preserved_keys(&self) -> &'static [&'static str] {
+        &["scenarios"]
+    }
+}
+
 impl Default for ScenarioCfg {
     fn default() -> Self {
         Self {
@@ -0,0 +1,1213 @@
+use serde_json::{json, Map, Value};
+
+const SCHEMA_DIALECT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Which yaml source a schema describes. Orderbook yaml only holds the
+/// shared settings while a dotrain frontmatter can hold every section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsSchemaKind {
+    Orderbook,
+    Dotrain,
+}
+
+/// Returns the JSON Schema for the given kind of yaml source
+pub fn settings_schema(kind: SettingsSchemaKind) -> Value {
+    match kind {
+        SettingsSchemaKind::Orderbook => orderbook_yaml_schema(),
+        SettingsSchemaKind::Dotrain => dotrain_yaml_schema(),
+    }
+}
+
+/// JSON Schema for orderbook yaml (settings) sources
+pub fn orderbook_yaml_schema() -> Value {
+    document(
+        "Rain Orderbook settings",
+        orderbook_properties(),
+        Map::new(),
+    )
+}
+
+/// JSON Schema for dotrain frontmatter, which accepts the orderbook yaml
+/// sections as well as orders, scenarios, deployments, gui and charts
+pub fn dotrain_yaml_schema() -> Value {
+    let mut properties = orderbook_properties();
+    properties.extend(dotrain_properties());
+
+    let mut definitions = Map::new();
+    definitions.insert("scenario".to_string(), scenario());
+
+    document(
+        "Rain Orderbook dotrain frontmatter",
+        properties,
+        definitions,
+    )
+}
+
+// Every section is optional at the root since sources can be split across
+// multiple documents, requiredness is enforced inside each section instead.
+fn document(title: &str, properties: Map<String, Value>, definitions: Map<String, Value>) -> Value {
+    let mut schema = json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": title,
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+    if !definitions.is_empty() {
+        schema["definitions"] = Value::Object(definitions);
+    }
+    schema
+}
+
+fn orderbook_properties() -> Map<String, Value> {
+    let mut properties = Map::new();
+    properties.insert(
+        "version".to_string(),
+        with_description(integer(), "Spec version of the settings format"),
+    );
+    properties.insert("networks".to_string(), map_of(network()));
+    properties.insert("using-networks-from".to_string(), map_of(remote_networks()));
+    properties.insert("tokens".to_string(), map_of(token()));
+    properties.insert(
+        "using-tokens-from".to_string(),
+        with_description(
+            array_of(json!({
+                "oneOf": [url(), object([("url", url()), ("sha256", sha256())], &["url"])]
+            })),
+            "Token list urls to fetch additional tokens from, optionally pinned to a sha256 of their content",
+        ),
+    );
+    properties.insert("subgraphs".to_string(), map_of(subgraph()));
+    properties.insert("metaboards".to_string(), map_of(url()));
+    properties.insert("orderbooks".to_string(), map_of(orderbook()));
+    properties.insert("deployers".to_string(), map_of(deployer()));
+    properties.insert("accounts".to_string(), map_of(address()));
+    properties.insert(
+        "sentry".to_string(),
+        with_description(boolean(), "Enables error reporting"),
+    );
+    properties
+}
+
+fn dotrain_properties() -> Map<String, Value> {
+    let mut properties = Map::new();
+    properties.insert("orders".to_string(), map_of(order()));
+    properties.insert(
+        "scenarios".to_string(),
+        map_of(json!({ "$ref": "#/definitions/scenario" })),
+    );
+    properties.insert("deployments".to_string(), map_of(deployment()));
+    properties.insert("gui".to_string(), gui());
+    properties.insert("charts".to_string(), map_of(chart()));
+    properties
+}
+
+fn network() -> Value {
+    object(
+        [
+            ("rpcs", array_of(url())),
+            ("chain-id", integer()),
+            ("label", string()),
+            ("network-id", integer()),
+            ("currency", string()),
+        ],
+        &["rpcs", "chain-id"],
+    )
+}
+
+fn remote_networks() -> Value {
+    object(
+        [
+            ("url", url()),
+            ("format", string_enum(&["chainid"])),
+            ("sha256", sha256()),
+        ],
+        &["url", "format"],
+    )
+}
+
+fn token() -> Value {
+    object(
+        [
+            ("network", string()),
+            ("address", address()),
+            ("decimals", integer()),
+            ("label", string()),
+            ("symbol", string()),
+            ("logo-uri", url()),
+            ("tags", array_of(string())),
+        ],
+        &["network", "address"],
+    )
+}
+
+fn subgraph() -> Value {
+    json!({
+        "oneOf": [
+            url(),
+            object(
+                [
+                    ("url", url()),
+                    ("fallback-urls", array_of(url())),
+                    ("headers", map_of(string())),
+                    ("timeout-ms", integer()),
+                    ("max-retries", integer()),
+                ],
+                &["url"],
+            ),
+        ]
+    })
+}
+
+fn orderbook() -> Value {
+    object(
+        [
+            ("address", address()),
+            ("network", string()),
+            ("subgraph", string()),
+            ("label", string()),
+            ("deployment-block", integer()),
+        ],
+        &["address", "deployment-block"],
+    )
+}
+
+fn deployer() -> Value {
+    object(
+        [("address", address()), ("network", string())],
+        &["address"],
+    )
+}
+
+fn order() -> Value {
+    let io = object([("token", string()), ("vault-id", vault_id())], &["token"]);
+    object(
+        [
+            ("inputs", array_of(io.clone())),
+            ("outputs", array_of(io)),
+            ("deployer", string()),
+            ("orderbook", string()),
+        ],
+        &["inputs", "outputs"],
+    )
+}
+
+fn scenario() -> Value {
+    let blocks = json!({
+        "oneOf": [
+            string(),
+            object([("range", string()), ("interval", integer())], &["range", "interval"]),
+        ]
+    });
+    object(
+        [
+            ("bindings", map_of(scalar())),
+            ("runs", integer()),
+            ("blocks", blocks),
+            ("deployer", string()),
+            (
+                "scenarios",
+                map_of(json!({ "$ref": "#/definitions/scenario" })),
+            ),
+        ],
+        &[],
+    )
+}
+
+fn deployment() -> Value {
+    object(
+        [("order", string()), ("scenario", string())],
+        &["order", "scenario"],
+    )
+}
+
+fn gui() -> Value {
+    let deposit_validation = object(
+        [
+            ("minimum", number()),
+            ("exclusive-minimum", number()),
+            ("maximum", number()),
+            ("exclusive-maximum", number()),
+        ],
+        &[],
+    );
+    let deposit = object(
+        [
+            ("token", string()),
+            ("presets", array_of(number())),
+            ("validation", deposit_validation),
+        ],
+        &["token"],
+    );
+
+    let field_validation = object(
+        [
+            (
+                "type",
+                string_enum(&[
+                    "number",
+                    "string",
+                    "boolean",
+                    "address",
+                    "token-amount",
+                    "percentage",
+                    "duration",
+                    "enum",
+                ]),
+            ),
+            ("token", string()),
+            ("minimum", scalar()),
+            ("exclusive-minimum", number()),
+            ("maximum", scalar()),
+            ("exclusive-maximum", number()),
+            ("min-length", integer()),
+            ("max-length", integer()),
+        ],
+        &["type"],
+    );
+    let field_condition = object(
+        [
+            ("field", string()),
+            ("select-token", string()),
+            ("equals", scalar()),
+            ("not-equals", scalar()),
+            ("one-of", array_of(scalar())),
+        ],
+        &[],
+    );
+    let field = object(
+        [
+            ("binding", string()),
+            ("name", string()),
+            ("description", string()),
+            (
+                "presets",
+                array_of(object(
+                    [("name", string()), ("value", scalar())],
+                    &["value"],
+                )),
+            ),
+            ("default", scalar()),
+            ("show-custom-field", boolean()),
+            ("validation", field_validation),
+            ("previous-bindings", array_of(string())),
+            ("show-if", array_of(field_condition.clone())),
+            ("required-if", array_of(field_condition)),
+            ("default-from", string()),
+        ],
+        &["binding", "name"],
+    );
+
+    let select_token = object(
+        [
+            ("key", string()),
+            ("name", string()),
+            ("description", string()),
+        ],
+        &["key"],
+    );
+
+    let deployment = object(
+        [
+            ("name", string()),
+            ("description", string()),
+            ("short-description", string()),
+            ("deposits", array_of(deposit)),
+            ("fields", array_of(field)),
+            ("select-tokens", array_of(select_token)),
+        ],
+        &["name", "description", "deposits", "fields"],
+    );
+
+    object(
+        [
+            ("name", string()),
+            ("description", string()),
+            ("short-description", string()),
+            ("deployments", map_of(deployment)),
+        ],
+        &["name", "description", "deployments"],
+    )
+}
+
+fn chart() -> Value {
+    let metric = object(
+        [
+            ("label", string()),
+            ("description", string()),
+            ("unit-prefix", string()),
+            ("unit-suffix", string()),
+            ("value", string()),
+            ("precision", integer()),
+        ],
+        &["label", "value"],
+    );
+    object(
+        [
+            ("scenario", string()),
+            ("plots", map_of(plot())),
+            ("metrics", array_of(metric)),
+        ],
+        &[],
+    )
+}
+
+fn plot() -> Value {
+    let axis = object(
+        [
+            ("label", string()),
+            ("anchor", string()),
+            ("label-anchor", string()),
+            ("label-arrow", string()),
+        ],
+        &[],
+    );
+    object(
+        [
+            ("title", string()),
+            ("subtitle", string()),
+            ("marks", array_of(mark())),
+            ("x", axis.clone()),
+            ("y", axis),
+            ("margin", integer()),
+            ("margin-left", integer()),
+            ("margin-right", integer()),
+            ("margin-top", integer()),
+            ("margin-bottom", integer()),
+            ("inset", integer()),
+        ],
+        &["marks"],
+    )
+}
+
+fn mark() -> Value {
+    let dot_or_line = object(
+        [
+            ("type", string_enum(&["dot", "line"])),
+            (
+                "options",
+                object(
+                    [
+                        ("x", string()),
+                        ("y", string()),
+                        ("r", integer()),
+                        ("fill", string()),
+                        ("stroke", string()),
+                        ("transform", transform()),
+                    ],
+                    &[],
+                ),
+            ),
+        ],
+        &["type", "options"],
+    );
+    let recty = object(
+        [
+            ("type", string_enum(&["recty"])),
+            (
+                "options",
+                object(
+                    [
+                        ("x0", string()),
+                        ("x1", string()),
+                        ("y0", string()),
+                        ("y1", string()),
+                        ("transform", transform()),
+                    ],
+                    &[],
+                ),
+            ),
+        ],
+        &["type", "options"],
+    );
+    json!({ "oneOf": [dot_or_line, recty] })
+}
+
+fn transform() -> Value {
+    let outputs = object(
+        [
+            ("x", string()),
+            ("y", string()),
+            ("r", string()),
+            ("z", string()),
+            ("stroke", string()),
+            ("fill", string()),
+        ],
+        &[],
+    );
+    let hexbin = object(
+        [
+            ("type", string_enum(&["hexbin"])),
+            (
+                "content",
+                object(
+                    [
+                        ("outputs", outputs.clone()),
+                        (
+                            "options",
+                            object(
+                                [("x", string()), ("y", string()), ("bin-width", integer())],
+                                &[],
+                            ),
+                        ),
+                    ],
+                    &["outputs", "options"],
+                ),
+            ),
+        ],
+        &["type", "content"],
+    );
+    let binx = object(
+        [
+            ("type", string_enum(&["binx"])),
+            (
+                "content",
+                object(
+                    [
+                        ("outputs", outputs),
+                        (
+                            "options",
+                            object([("x", string()), ("thresholds", integer())], &[]),
+                        ),
+                    ],
+                    &["outputs", "options"],
+                ),
+            ),
+        ],
+        &["type", "content"],
+    );
+    json!({ "oneOf": [hexbin, binx] })
+}
+
+// Strict yaml has no scalar types other than strings, so numeric and boolean
+// values are accepted in both their native and their string form for editors
+// that parse yaml with a richer core schema.
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn scalar() -> Value {
+    json!({ "type": ["string", "number", "boolean"] })
+}
+
+fn integer() -> Value {
+    json!({ "type": ["integer", "string"], "pattern": "^[0-9]+$" })
+}
+
+fn number() -> Value {
+    json!({ "type": ["number", "string"], "pattern": "^-?[0-9]+(\\.[0-9]+)?$" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": ["boolean", "string"], "enum": [true, false, "true", "false"] })
+}
+
+fn address() -> Value {
+    json!({ "type": "string", "pattern": "^0x[0-9a-fA-F]{40}$" })
+}
+
+fn vault_id() -> Value {
+    json!({ "type": ["integer", "string"], "pattern": "^(0x[0-9a-fA-F]+|[0-9]+)$" })
+}
+
+fn url() -> Value {
+    json!({ "type": "string", "format": "uri" })
+}
+
+fn sha256() -> Value {
+    json!({ "type": "string", "pattern": "^(0x)?[0-9a-fA-F]{64}$" })
+}
+
+fn string_enum(values: &[&str]) -> Value {
+    json!({ "type": "string", "enum": values })
+}
+
+fn array_of(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn map_of(values: Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
+
+fn object<const N: usize>(properties: [(&str, Value); N], required: &[&str]) -> Value {
+    let properties: Map<String, Value> = properties
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    schema
+}
+
+fn with_description(mut schema: Value, description: &str) -> Value {
+    schema["description"] = json!(description);
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaml::{
+        dotrain::DotrainYaml, load_yaml, orderbook::OrderbookYaml, YamlError, YamlParsable,
+    };
+    use std::collections::BTreeSet;
+    use std::sync::{Arc, RwLock};
+    use strict_yaml_rust::StrictYaml;
+
+    const FULL_YAML: &str = r#"
+version: 3
+networks:
+    mainnet:
+        rpcs:
+            - https://mainnet.infura.io
+        chain-id: 1
+        label: Ethereum Mainnet
+        network-id: 1
+        currency: ETH
+using-networks-from:
+    chainid:
+        url: https://chainid.network/v2/chains.json
+        format: chainid
+        sha256: 0x0000000000000000000000000000000000000000000000000000000000000001
+tokens:
+    token1:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000001
+        decimals: 18
+        label: Wrapped Ether
+        symbol: WETH
+        logo-uri: https://tokens.example.com/weth.png
+        tags:
+            - wrapped
+    token2:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000002
+        decimals: 6
+using-tokens-from:
+    - https://tokens.example.com/list.json
+    - url: file:///etc/rain/tokens.json
+      sha256: 0000000000000000000000000000000000000000000000000000000000000002
+subgraphs:
+    mainnet: https://api.thegraph.com/subgraphs/name/xyz
+    mainnet-hosted:
+        url: https://subgraphs.example.com/orderbook
+        fallback-urls:
+            - https://backup.example.com/orderbook
+        headers:
+            x-api-key: key
+        timeout-ms: 5000
+        max-retries: 3
+metaboards:
+    mainnet: https://meta.example.com/board
+orderbooks:
+    mainnet:
+        address: 0x0000000000000000000000000000000000000003
+        network: mainnet
+        subgraph: mainnet
+        label: Primary Orderbook
+        deployment-block: 12345
+deployers:
+    mainnet:
+        address: 0x0000000000000000000000000000000000000004
+        network: mainnet
+accounts:
+    admin: 0x0000000000000000000000000000000000000005
+sentry: true
+orders:
+    order1:
+        inputs:
+            - token: token1
+              vault-id: 1
+        outputs:
+            - token: token2
+              vault-id: 0x2
+        deployer: mainnet
+        orderbook: mainnet
+scenarios:
+    mainnet:
+        bindings:
+            key1: value1
+        runs: 10
+        blocks:
+            range: "[0..100]"
+            interval: 10
+        deployer: mainnet
+        scenarios:
+            child:
+                bindings:
+                    key2: value2
+                blocks: "[0..]"
+deployments:
+    deployment1:
+        order: order1
+        scenario: mainnet
+gui:
+    name: Test gui
+    description: Test description
+    short-description: Test short description
+    deployments:
+        deployment1:
+            name: Test deployment
+            description: Test description
+            short-description: Test short description
+            deposits:
+                - token: token1
+                  presets:
+                    - 100
+                  validation:
+                    minimum: 1
+                    exclusive-minimum: 0
+                    maximum: 1000
+                    exclusive-maximum: 1001
+            fields:
+                - binding: key1
+                  name: Number field
+                  description: Field description
+                  presets:
+                    - name: Preset
+                      value: 1
+                  default: 1
+                  show-custom-field: true
+                  validation:
+                    type: number
+                    minimum: 0
+                    exclusive-minimum: 0
+                    maximum: 10
+                    exclusive-maximum: 11
+                - binding: key3
+                  name: String field
+                  validation:
+                    type: string
+                    min-length: 1
+                    max-length: 10
+            select-tokens:
+                - key: token3
+                  name: Token
+                  description: Token description
+charts:
+    mainnet:
+        scenario: mainnet.child
+        plots:
+            plot1:
+                title: Title
+                subtitle: Subtitle
+                marks:
+                    - type: dot
+                      options:
+                        x: 0.1
+                        y: 0.2
+                        r: 3
+                        fill: red
+                        stroke: blue
+                        transform:
+                            type: hexbin
+                            content:
+                                outputs:
+                                    x: 1
+                                    y: 2
+                                    r: 3
+                                    z: 4
+                                    stroke: green
+                                    fill: blue
+                                options:
+                                    x: 1
+                                    y: 2
+                                    bin-width: 10
+                    - type: line
+                      options:
+                        transform:
+                            type: binx
+                            content:
+                                outputs:
+                                    x: 1
+                                options:
+                                    x: 1
+                                    thresholds: 10
+                    - type: recty
+                      options:
+                        x0: 1
+                        x1: 2
+                        y0: 3
+                        y1: 4
+                x:
+                    label: X
+                    anchor: start
+                    label-anchor: start
+                    label-arrow: none
+                y:
+                    label: Y
+                    anchor: start
+                    label-anchor: start
+                    label-arrow: none
+                margin: 10
+                margin-left: 20
+                margin-right: 30
+                margin-top: 40
+                margin-bottom: 50
+                inset: 60
+        metrics:
+            - label: Metric
+              description: Metric description
+              unit-prefix: $
+              unit-suffix: USD
+              value: 0.1
+              precision: 2
+"#;
+
+    fn to_json(yaml: &StrictYaml) -> Value {
+        match yaml {
+            StrictYaml::String(s) => Value::String(s.clone()),
+            StrictYaml::Array(items) => Value::Array(items.iter().map(to_json).collect()),
+            StrictYaml::Hash(hash) => Value::Object(
+                hash.iter()
+                    .map(|(k, v)| (k.as_str().unwrap_or_default().to_string(), to_json(v)))
+                    .collect(),
+            ),
+            _ => Value::Null,
+        }
+    }
+
+    fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+        match schema["$ref"].as_str() {
+            Some(reference) => {
+                let name = reference.trim_start_matches("#/definitions/");
+                &root["definitions"][name]
+            }
+            None => schema,
+        }
+    }
+
+    // Minimal validator for the subset of keywords the schema uses. Values
+    // coming from strict yaml are only ever strings, arrays or maps.
+    fn validate(root: &Value, schema: &Value, value: &Value, path: &str) -> Vec<String> {
+        let schema = resolve(root, schema);
+        let mut errors = vec![];
+
+        if let Some(variants) = schema["oneOf"].as_array() {
+            let matches = variants
+                .iter()
+                .filter(|variant| validate(root, variant, value, path).is_empty())
+                .count();
+            if matches != 1 {
+                errors.push(format!("{path}: matched {matches} oneOf variants"));
+            }
+            return errors;
+        }
+
+        let types: Vec<&str> = match &schema["type"] {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(|t| t.as_str()).collect(),
+            _ => vec![],
+        };
+        let actual = match value {
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+            _ => "null",
+        };
+        if !types.contains(&actual) {
+            errors.push(format!("{path}: expected {types:?}, got {actual}"));
+            return errors;
+        }
+
+        if let Some(allowed) = schema["enum"].as_array() {
+            let scalar = value.as_str().unwrap_or_default();
+            if !allowed.iter().any(|v| match v {
+                Value::String(s) => s == scalar,
+                other => other.to_string() == scalar,
+            }) {
+                errors.push(format!("{path}: value not in {allowed:?}"));
+            }
+        }
+
+        match value {
+            Value::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    errors.extend(validate(
+                        root,
+                        &schema["items"],
+                        item,
+                        &format!("{path}[{i}]"),
+                    ));
+                }
+            }
+            Value::Object(map) => {
+                for required in schema["required"].as_array().into_iter().flatten() {
+                    if !map.contains_key(required.as_str().unwrap()) {
+                        errors.push(format!("{path}: missing {required}"));
+                    }
+                }
+                for (key, item) in map {
+                    let child = format!("{path}.{key}");
+                    match schema["properties"].get(key) {
+                        Some(property) => errors.extend(validate(root, property, item, &child)),
+                        None => match &schema["additionalProperties"] {
+                            Value::Bool(false) => errors.push(format!("{child}: unknown property")),
+                            Value::Object(_) => errors.extend(validate(
+                                root,
+                                &schema["additionalProperties"],
+                                item,
+                                &child,
+                            )),
+                            _ => {}
+                        },
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        errors
+    }
+
+    // Collects every named property path of the schema, map keys are
+    // collapsed to `*` and array items to `[]`.
+    fn schema_paths(
+        root: &Value,
+        schema: &Value,
+        path: &str,
+        seen: &mut BTreeSet<String>,
+        paths: &mut BTreeSet<String>,
+    ) {
+        if let Some(reference) = schema["$ref"].as_str() {
+            if !seen.insert(reference.to_string()) {
+                return;
+            }
+        }
+        let schema = resolve(root, schema);
+        for variant in schema["oneOf"].as_array().into_iter().flatten() {
+            schema_paths(root, variant, path, seen, paths);
+        }
+        if let Some(properties) = schema["properties"].as_object() {
+            for (key, property) in properties {
+                let child = format!("{path}.{key}");
+                paths.insert(child.clone());
+                schema_paths(root, property, &child, seen, paths);
+            }
+        }
+        if schema["additionalProperties"].is_object() {
+            let child = format!("{path}.*");
+            schema_paths(root, &schema["additionalProperties"], &child, seen, paths);
+        }
+        if schema["items"].is_object() {
+            let child = format!("{path}[]");
+            schema_paths(root, &schema["items"], &child, seen, paths);
+        }
+    }
+
+    fn value_paths(
+        root: &Value,
+        schema: &Value,
+        value: &Value,
+        path: &str,
+        paths: &mut BTreeSet<String>,
+    ) {
+        let schema = resolve(root, schema);
+        match value {
+            Value::Object(map) => {
+                for (key, item) in map {
+                    let (child, property) = match schema["properties"].get(key) {
+                        Some(property) => (format!("{path}.{key}"), property),
+                        None => (format!("{path}.*"), &schema["additionalProperties"]),
+                    };
+                    paths.insert(child.clone());
+                    match property["oneOf"].as_array() {
+                        Some(variants) => {
+                            for variant in variants {
+                                if validate(root, variant, item, &child).is_empty() {
+                                    value_paths(root, variant, item, &child, paths);
+                                }
+                            }
+                        }
+                        None => value_paths(root, property, item, &child, paths),
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    let child = format!("{path}[]");
+                    match schema["items"]["oneOf"].as_array() {
+                        Some(variants) => {
+                            for variant in variants {
+                                if validate(root, variant, item, &child).is_empty() {
+                                    value_paths(root, variant, item, &child, paths);
+                                }
+                            }
+                        }
+                        None => value_paths(root, &schema["items"], item, &child, paths),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn documents(yaml: StrictYaml) -> Vec<Arc<RwLock<StrictYaml>>> {
+        vec![Arc::new(RwLock::new(yaml))]
+    }
+
+    // Removes a field from the map at a dot separated path, list items are
+    // addressed by their index
+    fn remove_field(yaml: &StrictYaml, path: &str, field: &str) -> StrictYaml {
+        let mut yaml = yaml.clone();
+        let mut item = &mut yaml;
+        for segment in path.split('.') {
+            item = match item {
+                StrictYaml::Hash(hash) => hash
+                    .get_mut(&StrictYaml::String(segment.to_string()))
+                    .unwrap(),
+                StrictYaml::Array(items) => &mut items[segment.parse::<usize>().unwrap()],
+                _ => panic!("{path}: {segment} is not a map or a list"),
+            };
+        }
+        if let StrictYaml::Hash(hash) = item {
+            hash.remove(&StrictYaml::String(field.to_string()));
+        }
+        yaml
+    }
+
+    // Walks the schema along a dot separated path of the value, taking the
+    // oneOf variant the value matches
+    fn schema_at<'a>(root: &'a Value, schema: &'a Value, value: &Value, path: &str) -> &'a Value {
+        let mut schema = resolve(root, schema);
+        if let Some(variants) = schema["oneOf"].as_array() {
+            schema = variants
+                .iter()
+                .find(|variant| validate(root, variant, value, "").is_empty())
+                .map(|variant| resolve(root, variant))
+                .unwrap();
+        }
+        let Some(segment) = path.split('.').next().filter(|s| !s.is_empty()) else {
+            return schema;
+        };
+        let rest = path.split_once('.').map_or("", |(_, rest)| rest);
+        match value {
+            Value::Array(items) => schema_at(
+                root,
+                &schema["items"],
+                &items[segment.parse::<usize>().unwrap()],
+                rest,
+            ),
+            _ => {
+                let child = schema["properties"]
+                    .get(segment)
+                    .unwrap_or(&schema["additionalProperties"]);
+                schema_at(root, child, &value[segment], rest)
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixture_is_accepted_by_parsers() {
+        let docs = documents(load_yaml(FULL_YAML).unwrap());
+        let orderbook_yaml = OrderbookYaml::from_documents(docs.clone());
+        let dotrain_yaml = DotrainYaml::from_documents(docs);
+
+        assert_eq!(orderbook_yaml.get_spec_version().unwrap(), "3");
+        orderbook_yaml.get_networks().unwrap();
+        orderbook_yaml.get_remote_networks().unwrap();
+        orderbook_yaml.get_tokens().unwrap();
+        orderbook_yaml.get_remote_tokens().unwrap().unwrap();
+        orderbook_yaml.get_subgraphs().unwrap();
+        orderbook_yaml.get_metaboards().unwrap();
+        orderbook_yaml.get_orderbooks().unwrap();
+        orderbook_yaml.get_deployers().unwrap();
+        orderbook_yaml.get_accounts().unwrap();
+        assert_eq!(orderbook_yaml.get_sentry().unwrap(), Some(true));
+
+        dotrain_yaml.get_orders().unwrap();
+        dotrain_yaml.get_scenarios().unwrap();
+        dotrain_yaml.get_deployments().unwrap();
+        dotrain_yaml.get_gui(None).unwrap().unwrap();
+        dotrain_yaml.get_charts().unwrap();
+    }
+
+    #[test]
+    fn test_fixture_is_valid_against_schema() {
+        let schema = dotrain_yaml_schema();
+        let value = to_json(&load_yaml(FULL_YAML).unwrap());
+        let errors = validate(&schema, &schema, &value, "");
+        assert!(errors.is_empty(), "{errors:#?}");
+    }
+
+    #[test]
+    fn test_schema_properties_are_covered_by_fixture() {
+        let schema = dotrain_yaml_schema();
+        let value = to_json(&load_yaml(FULL_YAML).unwrap());
+
+        let mut expected = BTreeSet::new();
+        schema_paths(&schema, &schema, "", &mut BTreeSet::new(), &mut expected);
+        let mut actual = BTreeSet::new();
+        value_paths(&schema, &schema, &value, "", &mut actual);
+
+        // nested scenarios are covered through the recursive definition
+        let missing: Vec<_> = expected.difference(&actual).collect();
+        assert!(missing.is_empty(), "{missing:#?}");
+    }
+
+    #[test]
+    fn test_schema_rejects_unknown_fields() {
+        let schema = dotrain_yaml_schema();
+        let value = json!({
+            "networks": { "mainnet": { "rpcs": ["https://rpc.com"], "chain-id": "1", "rpc": "x" } },
+            "unknown": "value"
+        });
+        let errors = validate(&schema, &schema, &value, "");
+        assert_eq!(
+            errors,
+            vec![
+                ".networks.mainnet.rpc: unknown property".to_string(),
+                ".unknown: unknown property".to_string(),
+            ]
+        );
+
+        let value = json!({
+            "charts": { "chart": { "plots": { "plot": { "marks": [
+                { "type": "bar", "options": {} }
+            ] } } } }
+        });
+        let errors = validate(&schema, &schema, &value, "");
+        assert_eq!(
+            errors,
+            vec![".charts.chart.plots.plot.marks[0]: matched 0 oneOf variants".to_string()]
+        );
+
+        let value = json!({
+            "subgraphs": { "mainnet": { "url": "https://sg.com", "retries": "3" } }
+        });
+        let errors = validate(&schema, &schema, &value, "");
+        assert_eq!(
+            errors,
+            vec![".subgraphs.mainnet: matched 0 oneOf variants".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_orderbook_schema_excludes_dotrain_sections() {
+        let schema = settings_schema(SettingsSchemaKind::Orderbook);
+        let properties = schema["properties"].as_object().unwrap();
+        for key in ["orders", "scenarios", "deployments", "gui", "charts"] {
+            assert!(!properties.contains_key(key));
+        }
+        assert!(schema.get("definitions").is_none());
+
+        let dotrain = settings_schema(SettingsSchemaKind::Dotrain);
+        for key in properties.keys() {
+            assert_eq!(dotrain["properties"][key], properties[key]);
+        }
+    }
+
+    #[test]
+    fn test_required_fields_match_parsers() {
+        let yaml = load_yaml(FULL_YAML).unwrap();
+        let value = to_json(&yaml);
+        let schema = dotrain_yaml_schema();
+
+        type Check = fn(Vec<Arc<RwLock<StrictYaml>>>) -> Result<(), YamlError>;
+        let networks: Check = |docs| {
+            OrderbookYaml::from_documents(docs)
+                .get_networks()
+                .map(|_| ())
+        };
+        let remote_networks: Check = |docs| {
+            OrderbookYaml::from_documents(docs)
+                .get_remote_networks()
+                .map(|_| ())
+        };
+        let tokens: Check = |docs| OrderbookYaml::from_documents(docs).get_tokens().map(|_| ());
+        let remote_tokens: Check = |docs| {
+            OrderbookYaml::from_documents(docs)
+                .get_remote_tokens()
+                .map(|_| ())
+        };
+        let subgraphs: Check = |docs| {
+            OrderbookYaml::from_documents(docs)
+                .get_subgraphs()
+                .map(|_| ())
+        };
+        let orderbooks: Check = |docs| {
+            OrderbookYaml::from_documents(docs)
+                .get_orderbooks()
+                .map(|_| ())
+        };
+        let deployers: Check = |docs| {
+            OrderbookYaml::from_documents(docs)
+                .get_deployers()
+                .map(|_| ())
+        };
+        let orders: Check = |docs| DotrainYaml::from_documents(docs).get_orders().map(|_| ());
+        let scenarios: Check = |docs| {
+            DotrainYaml::from_documents(docs)
+                .get_scenarios()
+                .map(|_| ())
+        };
+        let deployments: Check = |docs| {
+            DotrainYaml::from_documents(docs)
+                .get_deployments()
+                .map(|_| ())
+        };
+        let gui: Check = |docs| DotrainYaml::from_documents(docs).get_gui(None).map(|_| ());
+        let charts: Check = |docs| DotrainYaml::from_documents(docs).get_charts().map(|_| ());
+
+        let gui_deployment = "gui.deployments.deployment1";
+        let gui_field = "gui.deployments.deployment1.fields.0";
+        let plot = "charts.mainnet.plots.plot1";
+        let mark = "charts.mainnet.plots.plot1.marks.0";
+        let sections = [
+            ("networks.mainnet".to_string(), networks),
+            ("using-networks-from.chainid".to_string(), remote_networks),
+            ("tokens.token1".to_string(), tokens),
+            ("using-tokens-from.1".to_string(), remote_tokens),
+            ("subgraphs.mainnet-hosted".to_string(), subgraphs),
+            ("orderbooks.mainnet".to_string(), orderbooks),
+            ("deployers.mainnet".to_string(), deployers),
+            ("orders.order1".to_string(), orders),
+            ("orders.order1.inputs.0".to_string(), orders),
+            ("scenarios.mainnet".to_string(), scenarios),
+            ("scenarios.mainnet.blocks".to_string(), scenarios),
+            ("scenarios.mainnet.scenarios.child".to_string(), scenarios),
+            ("deployments.deployment1".to_string(), deployments),
+            ("gui".to_string(), gui),
+            (gui_deployment.to_string(), gui),
+            (format!("{gui_deployment}.deposits.0"), gui),
+            (gui_field.to_string(), gui),
+            (format!("{gui_field}.presets.0"), gui),
+            (format!("{gui_field}.validation"), gui),
+            (format!("{gui_deployment}.select-tokens.0"), gui),
+            ("charts.mainnet".to_string(), charts),
+            (plot.to_string(), charts),
+            (mark.to_string(), charts),
+            (format!("{mark}.options.transform"), charts),
+            (format!("{mark}.options.transform.content"), charts),
+            ("charts.mainnet.metrics.0".to_string(), charts),
+        ];
+
+        for (path, check) in sections {
+            let item = schema_at(&schema, &schema, &value, &path);
+            let properties = item["properties"].as_object().unwrap();
+            let required: Vec<&str> = item["required"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|v| v.as_str().unwrap())
+                .collect();
+
+            for field in properties.keys() {
+                let result = check(documents(remove_field(&yaml, &path, field)));
+                assert_eq!(
+                    result.is_err(),
+                    required.contains(&field.as_str()),
+                    "{path}.{field}"
+                );
+            }
+        }
+    }
+}
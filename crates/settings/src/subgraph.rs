@@ -1,10 +1,12 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
-    context::Context, default_document, require_hash, require_string, FieldErrorKind, YamlError,
-    YamlParsableHash,
+    context::Context, default_document, optional_hash, optional_string, optional_vec, require_hash,
+    require_string, FieldErrorKind, YamlError, YamlParsableHash,
 };
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     sync::{Arc, RwLock},
 };
 use strict_yaml_rust::StrictYaml;
@@ -12,15 +14,30 @@ use url::{ParseError, Url};
 #[cfg(target_family = "wasm")]
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct SubgraphCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
     pub url: Url,
+    /// Urls queried in order when the primary url keeps failing
+    #[cfg_attr(target_family = "wasm", tsify(type = "string[]"))]
+    #[builder(default)]
+    pub fallback_urls: Vec<Url>,
+    /// Headers sent with every query, e.g. an api key
+    #[builder(default)]
+    pub headers: BTreeMap<String, String>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
+    pub timeout_ms: Option<u32>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
+    pub max_retries: Option<u32>,
 }
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(SubgraphCfg);
@@ -30,6 +47,42 @@ impl SubgraphCfg {
         Url::parse(value)
     }
 
+    fn parse_url(value: &str, field: &str, location: &str) -> Result<Url, YamlError> {
+        SubgraphCfg::validate_url(value).map_err(|e| YamlError::Field {
+            kind: FieldErrorKind::InvalidValue {
+                field: field.to_string(),
+                reason: e.to_string(),
+            },
+            location: location.to_string(),
+        })
+    }
+
+    fn parse_optional_u32(
+        value: &StrictYaml,
+        field: &str,
+        location: &str,
+    ) -> Result<Option<u32>, YamlError> {
+        optional_string(value, field)
+            .map(|v| v.parse::<u32>())
+            .transpose()
+            .map_err(|e| YamlError::Field {
+                kind: FieldErrorKind::InvalidValue {
+                    field: field.to_string(),
+                    reason: e.to_string(),
+                },
+                location: location.to_string(),
+            })
+    }
+
+    /// Whether anything besides the url is configured, in which case the
+    /// subgraph is written to yaml as a map instead of a plain url
+    fn has_transport_options(&self) -> bool {
+        !self.fallback_urls.is_empty()
+            || !self.headers.is_empty()
+            || self.timeout_ms.is_some()
+            || self.max_retries.is_some()
+    }
+
     pub fn add_record_to_yaml(
         document: Arc<RwLock<StrictYaml>>,
         key: &str,
@@ -104,22 +157,56 @@ impl YamlParsableHash for SubgraphCfg {
                     let subgraph_key = key_yaml.as_str().unwrap_or_default().to_string();
                     let location = format!("subgraph '{}'", subgraph_key);
 
-                    let url_str = require_string(subgraph_yaml, None, Some(location.clone()))?;
-                    let url =
-                        SubgraphCfg::validate_url(&url_str).map_err(|e| YamlError::Field {
-                            kind: FieldErrorKind::InvalidValue {
-                                field: "url".to_string(),
-                                reason: e.to_string(),
-                            },
-                            location: location.clone(),
-                        })?;
-
-                    let subgraph = SubgraphCfg {
+                    let mut subgraph = SubgraphCfg {
                         document: document.clone(),
                         key: subgraph_key.clone(),
-                        url,
+                        ..SubgraphCfg::default()
                     };
 
+                    if subgraph_yaml.as_hash().is_some() {
+                        let url_str =
+                            require_string(subgraph_yaml, Some("url"), Some(location.clone()))?;
+                        subgraph.url = SubgraphCfg::parse_url(&url_str, "url", &location)?;
+
+                        if let Some(fallback_urls) = optional_vec(subgraph_yaml, "fallback-urls") {
+                            for fallback_url in fallback_urls {
+                                let url_str =
+                                    require_string(fallback_url, None, Some(location.clone()))?;
+                                subgraph.fallback_urls.push(SubgraphCfg::parse_url(
+                                    &url_str,
+                                    "fallback-urls",
+                                    &location,
+                                )?);
+                            }
+                        }
+
+                        if let Some(headers) = optional_hash(subgraph_yaml, "headers") {
+                            for (name, value) in headers {
+                                let name = name.as_str().unwrap_or_default().to_string();
+                                let value = require_string(
+                                    value,
+                                    None,
+                                    Some(format!("header '{}' in {}", name, location)),
+                                )?;
+                                subgraph.headers.insert(name, value);
+                            }
+                        }
+
+                        subgraph.timeout_ms = SubgraphCfg::parse_optional_u32(
+                            subgraph_yaml,
+                            "timeout-ms",
+                            &location,
+                        )?;
+                        subgraph.max_retries = SubgraphCfg::parse_optional_u32(
+                            subgraph_yaml,
+                            "max-retries",
+                            &location,
+                        )?;
+                    } else {
+                        let url_str = require_string(subgraph_yaml, None, Some(location.clone()))?;
+                        subgraph.url = SubgraphCfg::parse_url(&url_str, "url", &location)?;
+                    }
+
                     if subgraphs.contains_key(&subgraph_key) {
                         return Err(YamlError::KeyShadowing(
                             subgraph_key,
@@ -142,19 +229,61 @@ impl YamlParsableHash for SubgraphCfg {
     }
 }
 
+impl YamlWritable for SubgraphCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["subgraphs".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        if !self.has_transport_options() {
+            return Ok(yaml_string(&self.url));
+        }
+
+        let headers = self
+            .headers
+            .iter()
+            .map(|(name, value)| (yaml_string(name), yaml_string(value)))
+            .collect();
+        Ok(yaml_hash([
+            ("url", Some(yaml_string(&self.url))),
+            (
+                "fallback-urls",
+                (!self.fallback_urls.is_empty()).then(|| {
+                    StrictYaml::Array(self.fallback_urls.iter().map(yaml_string).collect())
+                }),
+            ),
+            (
+                "headers",
+                (!self.headers.is_empty()).then_some(StrictYaml::Hash(headers)),
+            ),
+            ("timeout-ms", self.timeout_ms.map(yaml_string)),
+            ("max-retries", self.max_retries.map(yaml_string)),
+        ]))
+    }
+}
+
 impl Default for SubgraphCfg {
     fn default() -> Self {
         Self {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
             key: "".to_string(),
             url: Url::parse("https://subgraph.com").unwrap(),
+            fallback_urls: vec![],
+            headers: BTreeMap::new(),
+            timeout_ms: None,
+            max_retries: None,
         }
     }
 }
 
 impl PartialEq for SubgraphCfg {
     fn eq(&self, other: &Self) -> bool {
-        self.key == other.key && self.url == other.url
+        self.key == other.key
+            && self.url == other.url
+            && self.fallback_urls == other.fallback_urls
+            && self.headers == other.headers
+            && self.timeout_ms == other.timeout_ms
+            && self.max_retries == other.max_retries
     }
 }
 
@@ -186,10 +315,7 @@ subgraphs:
         assert_eq!(
             error,
             YamlError::Field {
-                kind: FieldErrorKind::InvalidType {
-                    field: "value".to_string(),
-                    expected: "a string".to_string(),
-                },
+                kind: FieldErrorKind::Missing("url".to_string()),
                 location: "subgraph 'TestSubgraph'".to_string(),
             }
         );
@@ -274,6 +400,91 @@ subgraphs:
         );
     }
 
+    #[test]
+    fn test_parse_subgraph_transport_options() {
+        let yaml = r#"
+subgraphs:
+    plain: https://subgraph.com
+    mainnet:
+        url: https://primary.com
+        fallback-urls:
+            - https://secondary.com
+            - https://tertiary.com
+        headers:
+            Authorization: Bearer token
+        timeout-ms: 5000
+        max-retries: 4
+"#;
+        let subgraphs = SubgraphCfg::parse_all_from_yaml(vec![get_document(yaml)], None).unwrap();
+
+        let plain = subgraphs.get("plain").unwrap();
+        assert!(plain.fallback_urls.is_empty());
+        assert!(plain.headers.is_empty());
+        assert_eq!(plain.timeout_ms, None);
+        assert_eq!(plain.max_retries, None);
+        assert_eq!(plain.to_yaml_value().unwrap(), yaml_string(&plain.url));
+
+        let mainnet = subgraphs.get("mainnet").unwrap();
+        assert_eq!(mainnet.url, Url::parse("https://primary.com").unwrap());
+        assert_eq!(
+            mainnet.fallback_urls,
+            vec![
+                Url::parse("https://secondary.com").unwrap(),
+                Url::parse("https://tertiary.com").unwrap()
+            ]
+        );
+        assert_eq!(
+            mainnet.headers.get("Authorization"),
+            Some(&"Bearer token".to_string())
+        );
+        assert_eq!(mainnet.timeout_ms, Some(5000));
+        assert_eq!(mainnet.max_retries, Some(4));
+
+        let value = mainnet.to_yaml_value().unwrap();
+        assert_eq!(value["url"].as_str(), Some("https://primary.com/"));
+        assert_eq!(value["fallback-urls"].as_vec().unwrap().len(), 2);
+        assert_eq!(
+            value["headers"]["Authorization"].as_str(),
+            Some("Bearer token")
+        );
+        assert_eq!(value["timeout-ms"].as_str(), Some("5000"));
+        assert_eq!(value["max-retries"].as_str(), Some("4"));
+    }
+
+    #[test]
+    fn test_parse_subgraph_transport_options_invalid() {
+        let yaml = r#"
+subgraphs:
+    mainnet:
+        url: https://primary.com
+        fallback-urls:
+            - not_a_valid_url
+"#;
+        let error = SubgraphCfg::parse_all_from_yaml(vec![get_document(yaml)], None).unwrap_err();
+        assert!(matches!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue { ref field, .. },
+                ..
+            } if field == "fallback-urls"
+        ));
+
+        let yaml = r#"
+subgraphs:
+    mainnet:
+        url: https://primary.com
+        timeout-ms: soon
+"#;
+        let error = SubgraphCfg::parse_all_from_yaml(vec![get_document(yaml)], None).unwrap_err();
+        assert!(matches!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidValue { ref field, .. },
+                ..
+            } if field == "timeout-ms"
+        ));
+    }
+
     #[test]
     fn test_parse_subgraphs_from_yaml_duplicate_key() {
         let yaml_one = r#"
@@ -38,6 +38,7 @@ pub fn mock_orderbook() -> Arc<OrderbookCfg> {
             document: Arc::new(RwLock::new(StrictYaml::String("".to_string()))),
             key: "".to_string(),
             url: "https://subgraph.com".parse().unwrap(),
+            ..SubgraphCfg::default()
         }),
         network: mock_network(),
         deployment_block: 12345,
@@ -54,5 +55,8 @@ pub fn mock_token(name: &str) -> Arc<TokenCfg> {
         symbol: Some("TKN".into()),
         decimals: Some(18),
         network: mock_network(),
+        logo_uri: None,
+        tags: None,
+        bridged_addresses: None,
     })
 }
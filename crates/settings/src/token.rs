@@ -1,13 +1,18 @@
+use crate::yaml::writer::{yaml_hash, yaml_string, YamlWritable};
 use crate::yaml::{
-    default_document, optional_string, require_hash, require_string, FieldErrorKind, YamlError,
-    YamlParsableHash,
+    default_document, optional_string, optional_vec, require_hash, require_string, FieldErrorKind,
+    YamlError, YamlParsableHash,
 };
 use crate::*;
 use alloy::primitives::{hex::FromHexError, Address};
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::RwLock;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 use strict_yaml_rust::strict_yaml::Hash;
 use strict_yaml_rust::StrictYaml;
 use thiserror::Error;
@@ -15,22 +20,41 @@ use thiserror::Error;
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 use yaml::context::Context;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[builder(setter(into), build_fn(error = "YamlError"))]
 pub struct TokenCfg {
     #[serde(skip, default = "default_document")]
+    #[builder(default = "default_document()")]
     pub document: Arc<RwLock<StrictYaml>>,
     pub key: String,
     pub network: Arc<NetworkCfg>,
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
     pub address: Address,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub decimals: Option<u8>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub label: Option<String>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
     pub symbol: Option<String>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
+    pub logo_uri: Option<String>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    #[builder(default, setter(strip_option))]
+    pub tags: Option<Vec<String>>,
+    /// Addresses of the same token on other chains keyed by chain id, only
+    /// populated for tokens coming from a token list with bridge info
+    #[cfg_attr(
+        target_family = "wasm",
+        tsify(optional, type = "Record<number, string>")
+    )]
+    #[builder(default, setter(strip_option))]
+    pub bridged_addresses: Option<BTreeMap<u32, Address>>,
 }
 #[cfg(target_family = "wasm")]
 impl_wasm_traits!(TokenCfg);
@@ -281,6 +305,24 @@ impl YamlParsableHash for TokenCfg {
 
                     let label = optional_string(token_yaml, "label");
                     let symbol = optional_string(token_yaml, "symbol");
+                    let logo_uri = optional_string(token_yaml, "logo-uri");
+                    let tags = optional_vec(token_yaml, "tags")
+                        .map(|tags| {
+                            tags.iter()
+                                .map(|tag| {
+                                    tag.as_str().map(str::to_string).ok_or_else(|| {
+                                        YamlError::Field {
+                                            kind: FieldErrorKind::InvalidType {
+                                                field: "tags".to_string(),
+                                                expected: "a string".to_string(),
+                                            },
+                                            location: location.clone(),
+                                        }
+                                    })
+                                })
+                                .collect::<Result<Vec<_>, _>>()
+                        })
+                        .transpose()?;
 
                     let token = TokenCfg {
                         document: document.clone(),
@@ -290,6 +332,9 @@ impl YamlParsableHash for TokenCfg {
                         decimals,
                         label,
                         symbol,
+                        logo_uri,
+                        tags,
+                        bridged_addresses: None,
                     };
 
                     if tokens.contains_key(&token_key) {
@@ -324,6 +369,29 @@ impl YamlParsableHash for TokenCfg {
     }
 }
 
+impl YamlWritable for TokenCfg {
+    fn yaml_path(&self) -> Vec<String> {
+        vec!["tokens".to_string(), self.key.clone()]
+    }
+
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError> {
+        Ok(yaml_hash([
+            ("network", Some(yaml_string(&self.network.key))),
+            ("address", Some(yaml_string(self.address))),
+            ("decimals", self.decimals.map(yaml_string)),
+            ("label", self.label.as_ref().map(yaml_string)),
+            ("symbol", self.symbol.as_ref().map(yaml_string)),
+            ("logo-uri", self.logo_uri.as_ref().map(yaml_string)),
+            (
+                "tags",
+                self.tags
+                    .as_ref()
+                    .map(|tags| StrictYaml::Array(tags.iter().map(yaml_string).collect())),
+            ),
+        ]))
+    }
+}
+
 impl Default for TokenCfg {
     fn default() -> Self {
         TokenCfg {
@@ -334,6 +402,9 @@ impl Default for TokenCfg {
             decimals: None,
             label: None,
             symbol: None,
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         }
     }
 }
@@ -345,6 +416,9 @@ impl PartialEq for TokenCfg {
             && self.decimals == other.decimals
             && self.label == other.label
             && self.symbol == other.symbol
+            && self.logo_uri == other.logo_uri
+            && self.tags == other.tags
+            && self.bridged_addresses == other.bridged_addresses
     }
 }
 
@@ -634,6 +708,61 @@ tokens:
         assert_eq!(tokens.get("usdt").unwrap().decimals, Some(6));
     }
 
+    #[test]
+    fn test_parse_token_list_metadata() {
+        let yaml = r#"
+networks:
+    mainnet:
+        rpcs:
+            - "https://mainnet.infura.io"
+        chain-id: "1"
+tokens:
+    dai:
+        network: mainnet
+        address: "0x6b175474e89094c44da98b954eedeac495271d0f"
+        logo-uri: https://tokens.example.com/dai.png
+        tags:
+            - stablecoin
+            - defi
+"#;
+        let tokens = TokenCfg::parse_all_from_yaml(vec![get_document(yaml)], None).unwrap();
+        let dai = tokens.get("dai").unwrap();
+        assert_eq!(
+            dai.logo_uri,
+            Some("https://tokens.example.com/dai.png".to_string())
+        );
+        assert_eq!(
+            dai.tags,
+            Some(vec!["stablecoin".to_string(), "defi".to_string()])
+        );
+        assert_eq!(dai.bridged_addresses, None);
+
+        let yaml = r#"
+networks:
+    mainnet:
+        rpcs:
+            - "https://mainnet.infura.io"
+        chain-id: "1"
+tokens:
+    dai:
+        network: mainnet
+        address: "0x6b175474e89094c44da98b954eedeac495271d0f"
+        tags:
+            - test: test
+"#;
+        let error = TokenCfg::parse_all_from_yaml(vec![get_document(yaml)], None).unwrap_err();
+        assert_eq!(
+            error,
+            YamlError::Field {
+                kind: FieldErrorKind::InvalidType {
+                    field: "tags".to_string(),
+                    expected: "a string".to_string(),
+                },
+                location: "token 'dai'".to_string(),
+            }
+        );
+    }
+
     #[test]
     fn test_parse_tokens_from_yaml_duplicate_key() {
         let yaml_one = r#"
@@ -79,6 +79,9 @@ mod tests {
             decimals: Some(decimals),
             label: Some(format!("Test Token {}", key)),
             symbol: Some(key.to_uppercase()),
+            logo_uri: None,
+            tags: None,
+            bridged_addresses: None,
         }
     }
 
@@ -1,4 +1,9 @@
-use super::{cache::Cache, orderbook::OrderbookYaml, ValidationConfig, *};
+use super::{
+    cache::Cache,
+    orderbook::OrderbookYaml,
+    writer::{self, YamlWritable},
+    ValidationConfig, *,
+};
 use crate::{ChartCfg, DeploymentCfg, GuiCfg, OrderCfg, ScenarioCfg};
 use serde::{
     de::{self, SeqAccess, Visitor},
@@ -220,6 +225,15 @@ impl DotrainYaml {
     pub fn get_chart(&self, key: &str) -> Result<ChartCfg, YamlError> {
         ChartCfg::parse_from_yaml(self.documents.clone(), key, None)
     }
+
+    /// Writes the entity back into the yaml documents, see [`writer::write_entity`]
+    pub fn write_entity<T: YamlWritable>(&self, entity: &T) -> Result<(), YamlError> {
+        writer::write_entity(&self.documents, entity)
+    }
+    /// Removes the entity with the given key from a section
+    pub fn remove_entity(&self, section: &str, key: &str) -> Result<bool, YamlError> {
+        writer::remove_entity(&self.documents, &writer::entity_path(section, key))
+    }
 }
 
 impl Serialize for DotrainYaml {
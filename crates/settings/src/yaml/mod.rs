@@ -2,6 +2,7 @@ pub mod cache;
 pub mod context;
 pub mod dotrain;
 pub mod orderbook;
+pub mod writer;
 
 use crate::{
     NetworkCfg, ParseDeployerConfigSourceError, ParseDeploymentConfigSourceError,
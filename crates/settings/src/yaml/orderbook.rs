@@ -1,4 +1,8 @@
-use super::{cache::Cache, ValidationConfig, *};
+use super::{
+    cache::Cache,
+    writer::{self, YamlWritable},
+    ValidationConfig, *,
+};
 use crate::{
     accounts::AccountCfg, metaboard::MetaboardCfg, remote_networks::RemoteNetworksCfg,
     remote_tokens::RemoteTokensCfg, sentry::Sentry, spec_version::SpecVersion,
@@ -343,6 +347,33 @@ impl OrderbookYaml {
     pub fn get_account(&self, key: &str) -> Result<AccountCfg, YamlError> {
         AccountCfg::parse_from_yaml(self.documents.clone(), key, None)
     }
+
+    /// Writes the entity back into the yaml documents, see [`writer::write_entity`]
+    pub fn write_entity<T: YamlWritable>(&self, entity: &T) -> Result<(), YamlError> {
+        writer::write_entity(&self.documents, entity)
+    }
+    /// Removes the entity with the given key from a section
+    pub fn remove_entity(&self, section: &str, key: &str) -> Result<bool, YamlError> {
+        writer::remove_entity(&self.documents, &writer::entity_path(section, key))
+    }
+    /// Sets the spec version of the documents
+    pub fn set_spec_version(&self, version: &str) -> Result<(), YamlError> {
+        writer::write_value(
+            &self.documents,
+            &["version".to_string()],
+            writer::yaml_string(version),
+            &[],
+        )
+    }
+    /// Enables or disables sentry error reporting
+    pub fn set_sentry(&self, enabled: bool) -> Result<(), YamlError> {
+        writer::write_value(
+            &self.documents,
+            &["sentry".to_string()],
+            writer::yaml_string(enabled),
+            &[],
+        )
+    }
 }
 
 impl Serialize for OrderbookYaml {
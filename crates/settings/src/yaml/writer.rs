@@ -0,0 +1,786 @@
+use super::{FieldErrorKind, YamlError};
+use crate::ScenarioCfg;
+use derive_builder::UninitializedFieldError;
+use std::sync::{Arc, RwLock};
+use strict_yaml_rust::{strict_yaml::Hash, StrictYaml};
+
+/// Config entities that can be written back into a yaml document
+pub trait YamlWritable {
+    /// Path of map keys from the document root to the entity
+    fn yaml_path(&self) -> Vec<String>;
+
+    /// Yaml representation of the entity, fields are emitted in spec order
+    fn to_yaml_value(&self) -> Result<StrictYaml, YamlError>;
+
+    /// Keys of an existing entity that are not part of its own value and
+    /// must survive a rewrite, such as nested scenarios. Nested keys are
+    /// given as dot separated paths where `*` matches any key or index.
+    fn preserved_keys(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl From<UninitializedFieldError> for YamlError {
+    fn from(err: UninitializedFieldError) -> Self {
+        YamlError::Field {
+            kind: FieldErrorKind::Missing(err.field_name().to_string()),
+            location: "builder".to_string(),
+        }
+    }
+}
+
+/// Path of a keyed entity, nested scenario keys are joined with dots
+pub fn entity_path(section: &str, key: &str) -> Vec<String> {
+    if section == "scenarios" {
+        ScenarioCfg::yaml_path_for_key(key)
+    } else {
+        vec![section.to_string(), key.to_string()]
+    }
+}
+
+pub fn yaml_string(value: impl ToString) -> StrictYaml {
+    StrictYaml::String(value.to_string())
+}
+
+/// Builds a yaml map from the given fields skipping the ones that are unset
+pub fn yaml_hash<const N: usize>(fields: [(&str, Option<StrictYaml>); N]) -> StrictYaml {
+    let mut hash = Hash::new();
+    for (key, value) in fields {
+        if let Some(value) = value {
+            hash.insert(yaml_string(key), value);
+        }
+    }
+    StrictYaml::Hash(hash)
+}
+
+/// Writes the entity into the document that already defines it or into the
+/// first document when it is new. Existing keys keep their position and new
+/// ones are appended so rewriting a document does not reorder it.
+pub fn write_entity<T: YamlWritable>(
+    documents: &[Arc<RwLock<StrictYaml>>],
+    entity: &T,
+) -> Result<(), YamlError> {
+    write_value(
+        documents,
+        &entity.yaml_path(),
+        entity.to_yaml_value()?,
+        entity.preserved_keys(),
+    )
+}
+
+/// Writes a value at the given path, see [`write_entity`]. Used directly for
+/// root scalars such as `version` and `sentry` that have no config entity.
+pub fn write_value(
+    documents: &[Arc<RwLock<StrictYaml>>],
+    path: &[String],
+    value: StrictYaml,
+    preserved_keys: &[&str],
+) -> Result<(), YamlError> {
+    // prefer the document holding the entity, then the one holding its
+    // closest parent section
+    let mut document = None;
+    for len in (1..=path.len()).rev() {
+        document = find_document(documents, &path[..len])?;
+        if document.is_some() {
+            break;
+        }
+    }
+    let document = document
+        .or_else(|| documents.first().cloned())
+        .ok_or(YamlError::EmptyFile)?;
+    let mut document = document.write().map_err(|_| YamlError::WriteLockError)?;
+
+    let (key, parents) = path.split_last().ok_or(YamlError::InvalidTraitFunction)?;
+    let mut current = &mut *document;
+    for (index, parent) in parents.iter().enumerate() {
+        let location = if index == 0 {
+            "root".to_string()
+        } else {
+            parents[..index].join(".")
+        };
+        current = child_hash(current, parent, &location)?;
+    }
+
+    let location = if parents.is_empty() {
+        "root".to_string()
+    } else {
+        parents.join(".")
+    };
+    let StrictYaml::Hash(hash) = current else {
+        return Err(not_a_map(
+            parents.last().map_or("document", String::as_str),
+            &location,
+        ));
+    };
+    match hash.get_mut(&yaml_string(key)) {
+        Some(existing) => merge_value(existing, value, preserved_keys),
+        None => {
+            hash.insert(yaml_string(key), value);
+        }
+    }
+    Ok(())
+}
+
+/// Removes the value at the given path, returns whether anything was removed
+pub fn remove_entity(
+    documents: &[Arc<RwLock<StrictYaml>>],
+    path: &[String],
+) -> Result<bool, YamlError> {
+    let Some(document) = find_document(documents, path)? else {
+        return Ok(false);
+    };
+    let mut document = document.write().map_err(|_| YamlError::WriteLockError)?;
+
+    let Some((key, parents)) = path.split_last() else {
+        return Ok(false);
+    };
+    let mut current = &mut *document;
+    for parent in parents {
+        let StrictYaml::Hash(hash) = current else {
+            return Ok(false);
+        };
+        let Some(child) = hash.get_mut(&yaml_string(parent)) else {
+            return Ok(false);
+        };
+        current = child;
+    }
+    match current {
+        StrictYaml::Hash(hash) => Ok(hash.remove(&yaml_string(key)).is_some()),
+        _ => Ok(false),
+    }
+}
+
+fn find_document(
+    documents: &[Arc<RwLock<StrictYaml>>],
+    path: &[String],
+) -> Result<Option<Arc<RwLock<StrictYaml>>>, YamlError> {
+    for document in documents {
+        let document_read = document.read().map_err(|_| YamlError::ReadLockError)?;
+        let mut current = &*document_read;
+        let mut found = true;
+        for key in path {
+            match current {
+                StrictYaml::Hash(hash) => match hash.get(&yaml_string(key)) {
+                    Some(child) => current = child,
+                    None => {
+                        found = false;
+                        break;
+                    }
+                },
+                _ => {
+                    found = false;
+                    break;
+                }
+            }
+        }
+        if found {
+            return Ok(Some(document.clone()));
+        }
+    }
+    Ok(None)
+}
+
+fn child_hash<'a>(
+    value: &'a mut StrictYaml,
+    key: &str,
+    location: &str,
+) -> Result<&'a mut StrictYaml, YamlError> {
+    let StrictYaml::Hash(hash) = value else {
+        return Err(not_a_map("document", location));
+    };
+    let key_yaml = yaml_string(key);
+    if !hash.contains_key(&key_yaml) {
+        hash.insert(key_yaml.clone(), StrictYaml::Hash(Hash::new()));
+    }
+    let child = hash.get_mut(&key_yaml).ok_or(YamlError::Field {
+        kind: FieldErrorKind::Missing(key.to_string()),
+        location: location.to_string(),
+    })?;
+    if !matches!(child, StrictYaml::Hash(_)) {
+        return Err(not_a_map(key, location));
+    }
+    Ok(child)
+}
+
+fn not_a_map(field: &str, location: &str) -> YamlError {
+    YamlError::Field {
+        kind: FieldErrorKind::InvalidType {
+            field: field.to_string(),
+            expected: "a map".to_string(),
+        },
+        location: location.to_string(),
+    }
+}
+
+// Maps are merged key by key so untouched keys stay where they are, arrays
+// are merged index by index and scalars are replaced in place.
+fn merge_value(existing: &mut StrictYaml, value: StrictYaml, preserved_keys: &[&str]) {
+    match (existing, value) {
+        (StrictYaml::Hash(existing), StrictYaml::Hash(value)) => {
+            let stale = existing
+                .keys()
+                .filter(|key| {
+                    !value.contains_key(key)
+                        && !preserved_keys.contains(&key.as_str().unwrap_or_default())
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            for key in stale {
+                existing.remove(&key);
+            }
+            for (key, value) in value {
+                let nested =
+                    nested_preserved_keys(preserved_keys, key.as_str().unwrap_or_default());
+                match existing.get_mut(&key) {
+                    Some(current) => merge_value(current, value, &nested),
+                    None => {
+                        existing.insert(key, value);
+                    }
+                }
+            }
+        }
+        (StrictYaml::Array(existing), StrictYaml::Array(value)) => {
+            existing.truncate(value.len());
+            let nested = nested_preserved_keys(preserved_keys, "*");
+            for (index, value) in value.into_iter().enumerate() {
+                match existing.get_mut(index) {
+                    Some(current) => merge_value(current, value, &nested),
+                    None => existing.push(value),
+                }
+            }
+        }
+        (existing, value) => *existing = value,
+    }
+}
+
+// Preserved paths below the given key, array items are matched by `*`
+fn nested_preserved_keys<'a>(preserved_keys: &[&'a str], key: &str) -> Vec<&'a str> {
+    preserved_keys
+        .iter()
+        .filter_map(|path| path.split_once('.'))
+        .filter(|(head, _)| *head == key || *head == "*")
+        .map(|(_, rest)| rest)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaml::{
+        dotrain::DotrainYaml, load_yaml, orderbook::OrderbookYaml, YamlParsable, YamlParsableHash,
+    };
+    use crate::{
+        accounts::AccountCfgBuilder, remote_networks::RemoteNetworksCfg,
+        remote_tokens::RemoteTokensCfg, subgraph::SubgraphCfgBuilder, DeployerCfgBuilder,
+        DeploymentCfgBuilder, NetworkCfgBuilder, OrderCfgBuilder, OrderIOCfg, OrderbookCfgBuilder,
+        ScenarioCfgBuilder, TokenCfgBuilder,
+    };
+    use alloy::primitives::{Address, U256};
+    use std::collections::HashMap;
+    use url::Url;
+
+    const YAML: &str = r#"
+networks:
+    mainnet:
+        rpcs:
+            - https://mainnet.infura.io/v3/key
+        chain-id: 1
+    polygon:
+        rpcs:
+            - https://polygon.infura.io/v3/key
+        chain-id: 137
+subgraphs:
+    mainnet: https://api.thegraph.com/subgraphs/name/xyz
+tokens:
+    token1:
+        network: mainnet
+        address: 0x0000000000000000000000000000000000000001
+        decimals: 18
+        label: Wrapped Ether
+deployers:
+    mainnet:
+        address: 0x0000000000000000000000000000000000000002
+        network: mainnet
+orders:
+    order1:
+        inputs:
+            - token: token1
+              vault-id: 1
+        outputs:
+            - token: token1
+              vault-id: 2
+scenarios:
+    mainnet:
+        bindings:
+            key1: value1
+        scenarios:
+            child:
+                bindings:
+                    key2: value2
+deployments:
+    deployment1:
+        order: order1
+        scenario: mainnet.child
+"#;
+
+    fn get_yaml() -> (OrderbookYaml, DotrainYaml) {
+        let documents = vec![Arc::new(RwLock::new(load_yaml(YAML).unwrap()))];
+        (
+            OrderbookYaml::from_documents(documents.clone()),
+            DotrainYaml::from_documents(documents),
+        )
+    }
+
+    fn keys(yaml: &StrictYaml) -> Vec<String> {
+        yaml.as_hash()
+            .unwrap()
+            .keys()
+            .map(|key| key.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (orderbook_yaml, dotrain_yaml) = get_yaml();
+        let before = OrderbookYaml::get_yaml_string(orderbook_yaml.documents[0].clone()).unwrap();
+
+        for network in orderbook_yaml.get_networks().unwrap().values() {
+            orderbook_yaml.write_entity(network).unwrap();
+        }
+        for token in orderbook_yaml.get_tokens().unwrap().values() {
+            orderbook_yaml.write_entity(token).unwrap();
+        }
+        for deployer in orderbook_yaml.get_deployers().unwrap().values() {
+            orderbook_yaml.write_entity(deployer).unwrap();
+        }
+        for order in dotrain_yaml.get_orders().unwrap().values() {
+            dotrain_yaml.write_entity(order).unwrap();
+        }
+        for deployment in dotrain_yaml.get_deployments().unwrap().values() {
+            dotrain_yaml.write_entity(deployment).unwrap();
+        }
+
+        let after = OrderbookYaml::get_yaml_string(orderbook_yaml.documents[0].clone()).unwrap();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_write_new_entities() {
+        let (orderbook_yaml, dotrain_yaml) = get_yaml();
+
+        let network = NetworkCfgBuilder::default()
+            .key("arbitrum")
+            .rpcs(vec![Url::parse("https://arbitrum.infura.io").unwrap()])
+            .chain_id(42161u32)
+            .label("Arbitrum")
+            .build()
+            .unwrap();
+        orderbook_yaml.write_entity(&network).unwrap();
+
+        let subgraph = SubgraphCfgBuilder::default()
+            .key("arbitrum")
+            .url(Url::parse("https://api.thegraph.com/subgraphs/name/arb").unwrap())
+            .build()
+            .unwrap();
+        orderbook_yaml.write_entity(&subgraph).unwrap();
+
+        let orderbook = OrderbookCfgBuilder::default()
+            .key("arbitrum")
+            .address(Address::repeat_byte(0x11))
+            .network(network.clone())
+            .subgraph(subgraph)
+            .deployment_block(100u64)
+            .build()
+            .unwrap();
+        orderbook_yaml.write_entity(&orderbook).unwrap();
+
+        let token = TokenCfgBuilder::default()
+            .key("usdc")
+            .network(network.clone())
+            .address(Address::repeat_byte(0x22))
+            .decimals(6u8)
+            .symbol("USDC")
+            .build()
+            .unwrap();
+        orderbook_yaml.write_entity(&token).unwrap();
+
+        let deployer = DeployerCfgBuilder::default()
+            .key("arbitrum")
+            .address(Address::repeat_byte(0x33))
+            .network(network.clone())
+            .build()
+            .unwrap();
+        orderbook_yaml.write_entity(&deployer).unwrap();
+
+        let account = AccountCfgBuilder::default()
+            .key("admin")
+            .address(Address::repeat_byte(0x44))
+            .build()
+            .unwrap();
+        orderbook_yaml.write_entity(&account).unwrap();
+
+        let order = OrderCfgBuilder::default()
+            .key("order2")
+            .inputs(vec![OrderIOCfg {
+                token: Some(Arc::new(token.clone())),
+                vault_id: Some(U256::from(1)),
+            }])
+            .outputs(vec![OrderIOCfg {
+                token: Some(Arc::new(token.clone())),
+                vault_id: None,
+            }])
+            .network(network.clone())
+            .deployer(Arc::new(deployer.clone()))
+            .orderbook(Arc::new(orderbook.clone()))
+            .build()
+            .unwrap();
+        dotrain_yaml.write_entity(&order).unwrap();
+
+        let scenario = ScenarioCfgBuilder::default()
+            .key("arbitrum")
+            .bindings(HashMap::from([("key".to_string(), "value".to_string())]))
+            .runs(10u64)
+            .deployer(deployer)
+            .build()
+            .unwrap();
+        dotrain_yaml.write_entity(&scenario).unwrap();
+
+        let deployment = DeploymentCfgBuilder::default()
+            .key("deployment2")
+            .order(order)
+            .scenario(scenario)
+            .build()
+            .unwrap();
+        dotrain_yaml.write_entity(&deployment).unwrap();
+
+        let network = orderbook_yaml.get_network("arbitrum").unwrap();
+        assert_eq!(network.chain_id, 42161);
+        assert_eq!(network.label, Some("Arbitrum".to_string()));
+
+        let orderbook = orderbook_yaml.get_orderbook("arbitrum").unwrap();
+        assert_eq!(orderbook.address, Address::repeat_byte(0x11));
+        assert_eq!(orderbook.subgraph.key, "arbitrum");
+        assert_eq!(orderbook.deployment_block, 100);
+
+        let token = orderbook_yaml.get_token("usdc").unwrap();
+        assert_eq!(token.decimals, Some(6));
+        assert_eq!(token.symbol, Some("USDC".to_string()));
+        assert_eq!(token.label, None);
+
+        let account = orderbook_yaml.get_account("admin").unwrap();
+        assert_eq!(account.address, Address::repeat_byte(0x44));
+
+        let order = dotrain_yaml.get_order("order2").unwrap();
+        assert_eq!(order.inputs[0].vault_id, Some(U256::from(1)));
+        assert_eq!(order.outputs[0].vault_id, None);
+        assert_eq!(order.deployer.unwrap().key, "arbitrum");
+        assert_eq!(order.orderbook.unwrap().key, "arbitrum");
+
+        let deployment = dotrain_yaml.get_deployment("deployment2").unwrap();
+        assert_eq!(deployment.scenario.runs, Some(10));
+        assert_eq!(deployment.scenario.deployer.key, "arbitrum");
+
+        let document = orderbook_yaml.documents[0].read().unwrap();
+        assert_eq!(
+            keys(&document["networks"]),
+            vec!["mainnet", "polygon", "arbitrum"]
+        );
+        assert_eq!(
+            keys(&document),
+            vec![
+                "networks",
+                "subgraphs",
+                "tokens",
+                "deployers",
+                "orders",
+                "scenarios",
+                "deployments",
+                "orderbooks",
+                "accounts"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edit_keeps_key_order() {
+        let (orderbook_yaml, dotrain_yaml) = get_yaml();
+
+        let mut token = orderbook_yaml.get_token("token1").unwrap();
+        token.label = None;
+        token.symbol = Some("WETH".to_string());
+        token.decimals = Some(8);
+        orderbook_yaml.write_entity(&token).unwrap();
+
+        let token = orderbook_yaml.get_token("token1").unwrap();
+        assert_eq!(token.decimals, Some(8));
+        assert_eq!(token.label, None);
+        assert_eq!(token.symbol, Some("WETH".to_string()));
+        {
+            let document = orderbook_yaml.documents[0].read().unwrap();
+            assert_eq!(
+                keys(&document["tokens"]["token1"]),
+                vec!["network", "address", "decimals", "symbol"]
+            );
+        }
+
+        let mut scenario = dotrain_yaml.get_scenario("mainnet").unwrap();
+        scenario.runs = Some(5);
+        dotrain_yaml.write_entity(&scenario).unwrap();
+
+        let mut child = dotrain_yaml.get_scenario("mainnet.child").unwrap();
+        child
+            .bindings
+            .insert("key3".to_string(), "value3".to_string());
+        dotrain_yaml.write_entity(&child).unwrap();
+
+        let child = dotrain_yaml.get_scenario("mainnet.child").unwrap();
+        assert_eq!(child.runs, None);
+        assert_eq!(child.bindings.get("key3"), Some(&"value3".to_string()));
+        assert_eq!(dotrain_yaml.get_scenario("mainnet").unwrap().runs, Some(5));
+
+        let document = orderbook_yaml.documents[0].read().unwrap();
+        assert_eq!(
+            keys(&document["scenarios"]["mainnet"]),
+            vec!["bindings", "scenarios", "runs"]
+        );
+    }
+
+    #[test]
+    fn test_remove_entity() {
+        let (orderbook_yaml, dotrain_yaml) = get_yaml();
+
+        assert!(orderbook_yaml.remove_entity("networks", "polygon").unwrap());
+        assert!(!orderbook_yaml.remove_entity("networks", "polygon").unwrap());
+        assert!(orderbook_yaml.get_network("polygon").is_err());
+
+        assert!(dotrain_yaml
+            .remove_entity("scenarios", "mainnet.child")
+            .unwrap());
+        assert!(dotrain_yaml.get_scenario("mainnet.child").is_err());
+        assert!(dotrain_yaml.get_scenario("mainnet").is_ok());
+    }
+
+    #[test]
+    fn test_write_to_defining_document() {
+        let documents = vec![
+            Arc::new(RwLock::new(load_yaml("version: 3").unwrap())),
+            Arc::new(RwLock::new(load_yaml(YAML).unwrap())),
+        ];
+        let orderbook_yaml = OrderbookYaml::from_documents(documents);
+
+        let mut network = orderbook_yaml.get_network("polygon").unwrap();
+        network.chain_id = 80001;
+        orderbook_yaml.write_entity(&network).unwrap();
+
+        let first = orderbook_yaml.documents[0].read().unwrap();
+        assert!(!first
+            .as_hash()
+            .unwrap()
+            .contains_key(&yaml_string("networks")));
+        drop(first);
+        assert_eq!(
+            orderbook_yaml.get_network("polygon").unwrap().chain_id,
+            80001
+        );
+    }
+
+    #[test]
+    fn test_write_remote_sources_and_root_values() {
+        let (orderbook_yaml, _) = get_yaml();
+
+        let remote_networks = RemoteNetworksCfg::parse_all_from_yaml(
+            vec![Arc::new(RwLock::new(
+                load_yaml(
+                    r#"
+using-networks-from:
+    chainid:
+        url: https://chainid.network/chains.json
+        format: chainid
+"#,
+                )
+                .unwrap(),
+            ))],
+            None,
+        )
+        .unwrap();
+        orderbook_yaml
+            .write_entity(&remote_networks["chainid"])
+            .unwrap();
+
+        let pinned = Url::parse("https://tokens.example.com/pinned.json").unwrap();
+        let remote_tokens = RemoteTokensCfg {
+            urls: vec![
+                Url::parse("https://tokens.example.com/list.json").unwrap(),
+                pinned.clone(),
+            ],
+            sha256: HashMap::from([(pinned.to_string(), "a".repeat(64))]),
+            ..RemoteTokensCfg::default()
+        };
+        orderbook_yaml.write_entity(&remote_tokens).unwrap();
+
+        orderbook_yaml.set_spec_version("3").unwrap();
+        orderbook_yaml.set_sentry(false).unwrap();
+
+        assert_eq!(
+            orderbook_yaml.get_remote_networks().unwrap(),
+            remote_networks
+        );
+        assert_eq!(
+            orderbook_yaml.get_remote_tokens().unwrap(),
+            Some(remote_tokens)
+        );
+        assert_eq!(orderbook_yaml.get_spec_version().unwrap(), "3");
+        assert_eq!(orderbook_yaml.get_sentry().unwrap(), Some(false));
+    }
+
+    const GUI_AND_CHARTS: &str = r#"
+gui:
+    name: Test gui
+    description: Test description
+    short-description: Short description
+    deployments:
+        deployment1:
+            name: Deployment one
+            description: Deployment description
+            short-description: Deployment short description
+            deposits:
+                - token: token1
+                  presets:
+                      - 1
+                      - 2
+                  validation:
+                      minimum: 1
+            fields:
+                - binding: key1
+                  name: Field one
+                  presets:
+                      - name: One
+                        value: 1
+                  default: 1
+                  show-custom-field: true
+                  validation:
+                      type: number
+                      minimum: 0
+                  show-if:
+                      - field: key2
+                        one-of:
+                            - 1
+                            - 2
+                - binding: key2
+                  name: Field two
+charts:
+    chart1:
+        scenario: mainnet
+        plots:
+            my-plot:
+                title: Title
+                marks:
+                    - type: dot
+                      options:
+                          x: 0.1
+                          y: 0.2
+                          transform:
+                              type: hexbin
+                              content:
+                                  outputs:
+                                      x: 0.1
+                                  options:
+                                      bin-width: 10
+                x:
+                    label: x
+                margin: 5
+        metrics:
+            - label: Metric
+              value: 0.1
+              precision: 2
+"#;
+
+    #[test]
+    fn test_write_gui_and_charts() {
+        let documents = vec![Arc::new(RwLock::new(
+            load_yaml(&format!("{YAML}{GUI_AND_CHARTS}")).unwrap(),
+        ))];
+        let dotrain_yaml = DotrainYaml::from_documents(documents);
+        let before = OrderbookYaml::get_yaml_string(dotrain_yaml.documents[0].clone()).unwrap();
+
+        dotrain_yaml
+            .write_entity(&dotrain_yaml.get_gui(None).unwrap().unwrap())
+            .unwrap();
+        dotrain_yaml
+            .write_entity(&dotrain_yaml.get_chart("chart1").unwrap())
+            .unwrap();
+        let after = OrderbookYaml::get_yaml_string(dotrain_yaml.documents[0].clone()).unwrap();
+        assert_eq!(before, after);
+
+        let mut gui = dotrain_yaml.get_gui(None).unwrap().unwrap();
+        gui.name = "New gui".to_string();
+        let deployment = gui.deployments.get_mut("deployment1").unwrap();
+        deployment.fields.pop();
+        deployment.fields[0].show_if = None;
+        dotrain_yaml.write_entity(&gui).unwrap();
+
+        let gui = dotrain_yaml.get_gui(None).unwrap().unwrap();
+        assert_eq!(gui.name, "New gui");
+        assert_eq!(gui.deployments["deployment1"].fields.len(), 1);
+        assert_eq!(gui.deployments["deployment1"].fields[0].show_if, None);
+        {
+            let document = dotrain_yaml.documents[0].read().unwrap();
+            assert_eq!(
+                keys(&document["gui"]),
+                vec!["name", "description", "short-description", "deployments"]
+            );
+            assert_eq!(
+                document["gui"]["deployments"]["deployment1"]["short-description"].as_str(),
+                Some("Deployment short description")
+            );
+        }
+
+        let mut chart = dotrain_yaml.get_chart("chart1").unwrap();
+        let plots = chart.plots.as_mut().unwrap();
+        plots[0].title = Some("New title".to_string());
+        plots.push(plots[0].clone());
+        chart.metrics = None;
+        dotrain_yaml.write_entity(&chart).unwrap();
+
+        let chart = dotrain_yaml.get_chart("chart1").unwrap();
+        assert_eq!(chart.plots.as_ref().unwrap().len(), 2);
+        assert_eq!(
+            chart.plots.as_ref().unwrap()[0].title,
+            Some("New title".to_string())
+        );
+        assert_eq!(chart.metrics, None);
+        let document = dotrain_yaml.documents[0].read().unwrap();
+        assert_eq!(
+            keys(&document["charts"]["chart1"]["plots"]),
+            vec!["my-plot", "plot-1"]
+        );
+    }
+
+    #[test]
+    fn test_remove_gui_and_charts() {
+        let documents = vec![Arc::new(RwLock::new(
+            load_yaml(&format!("{YAML}{GUI_AND_CHARTS}")).unwrap(),
+        ))];
+        let dotrain_yaml = DotrainYaml::from_documents(documents);
+
+        assert!(dotrain_yaml.remove_entity("charts", "chart1").unwrap());
+        assert!(dotrain_yaml.get_charts().unwrap().is_empty());
+        assert!(dotrain_yaml.remove_entity("gui", "deployments").unwrap());
+        assert!(dotrain_yaml.get_gui(None).is_err());
+    }
+
+    #[test]
+    fn test_builder_missing_field() {
+        let err = NetworkCfgBuilder::default()
+            .key("mainnet")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            YamlError::Field {
+                kind: FieldErrorKind::Missing("rpcs".to_string()),
+                location: "builder".to_string(),
+            }
+        );
+    }
+}
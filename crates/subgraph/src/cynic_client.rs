@@ -1,8 +1,9 @@
+use crate::transport::{post_with_retries, SubgraphTransportConfig, DEFAULT_TRANSPORT_CONFIG};
 use cynic::{
     serde::{Deserialize, Serialize},
     GraphQlError, GraphQlResponse, QueryBuilder, QueryFragment,
 };
-use reqwest::Url;
+use reqwest::{StatusCode, Url};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -15,20 +16,50 @@ pub enum CynicClientError {
     Request(#[from] reqwest::Error),
 }
 
+impl CynicClientError {
+    /// Timeouts, connection failures, 5xx and 429 responses are worth retrying
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            CynicClientError::Request(err) => {
+                err.is_timeout()
+                    || is_connection_error(err)
+                    || err.status().is_some_and(|status| {
+                        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn is_connection_error(err: &reqwest::Error) -> bool {
+    err.is_connect()
+}
+#[cfg(target_family = "wasm")]
+fn is_connection_error(err: &reqwest::Error) -> bool {
+    err.is_request()
+}
+
 pub trait CynicClient {
     fn get_base_url(&self) -> &Url;
 
+    fn get_transport_config(&self) -> &SubgraphTransportConfig {
+        &DEFAULT_TRANSPORT_CONFIG
+    }
+
     async fn query<R: QueryFragment + QueryBuilder<V> + for<'a> Deserialize<'a>, V: Serialize>(
         &self,
         variables: V,
     ) -> Result<R, CynicClientError> {
         let request_body = R::build(variables);
 
-        let response = reqwest::Client::new()
-            .post(self.get_base_url().clone())
-            .json(&request_body)
-            .send()
-            .await?;
+        let response = post_with_retries(
+            self.get_base_url(),
+            &request_body,
+            self.get_transport_config(),
+        )
+        .await?;
 
         let response_deserialized: GraphQlResponse<R> =
             response.json::<GraphQlResponse<R>>().await?;
@@ -3,6 +3,7 @@ mod multi_orderbook_client;
 mod orderbook_client;
 mod pagination;
 pub mod performance;
+mod transport;
 pub mod types;
 pub mod utils;
 pub mod validate;
@@ -11,6 +12,12 @@ mod vault_balance_changes_query;
 #[cynic::schema("orderbook")]
 pub mod schema {}
 
-pub use multi_orderbook_client::{MultiOrderbookSubgraphClient, MultiSubgraphArgs};
+pub use multi_orderbook_client::{
+    MultiOrderbookSubgraphClient, MultiSubgraphArgs, MultiSubgraphResult, MultiSubgraphStatus,
+};
 pub use orderbook_client::{OrderbookSubgraphClient, OrderbookSubgraphClientError};
-pub use pagination::{PageQueryClient, SgPaginationArgs};
+pub use pagination::{
+    PageQueryClient, SgPage, SgPaginationArgs, SgPaginationCursor, SgPaginationItem,
+    SgPaginationKey,
+};
+pub use transport::SubgraphTransportConfig;
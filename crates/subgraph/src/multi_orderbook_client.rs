@@ -3,133 +3,342 @@ use crate::{
         SgErc20WithSubgraphName, SgOrderWithSubgraphName, SgOrdersListFilterArgs,
         SgVaultWithSubgraphName, SgVaultsListFilterArgs,
     },
-    OrderbookSubgraphClient, OrderbookSubgraphClientError, SgPaginationArgs,
+    OrderbookSubgraphClient, OrderbookSubgraphClientError, SgPage, SgPaginationArgs,
+    SgPaginationCursor, SubgraphTransportConfig,
 };
 use futures::future::join_all;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::future::Future;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
 use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
+#[cfg(target_family = "wasm")]
+use wasmtimer::std::Instant;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
 pub struct MultiSubgraphArgs {
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
     pub url: Url,
     pub name: String,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub transport: SubgraphTransportConfig,
 }
 impl_wasm_traits!(MultiSubgraphArgs);
 
+/// Outcome of querying a single subgraph as part of a multi-subgraph query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiSubgraphStatus {
+    pub subgraph_name: String,
+    #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
+    pub url: Url,
+    /// Error message if the subgraph query failed
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub error: Option<String>,
+    /// Time spent on the subgraph query, including retries
+    pub latency_ms: u32,
+}
+impl_wasm_traits!(MultiSubgraphStatus);
+
+impl MultiSubgraphStatus {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Merged data from all subgraphs along with the status of each subgraph.
+///
+/// A failed subgraph contributes no data, its error is reported in `statuses`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiSubgraphResult<T> {
+    pub data: T,
+    pub statuses: Vec<MultiSubgraphStatus>,
+}
+
+impl<T> MultiSubgraphResult<T> {
+    pub fn failed_subgraphs(&self) -> Vec<&MultiSubgraphStatus> {
+        self.statuses
+            .iter()
+            .filter(|status| !status.is_ok())
+            .collect()
+    }
+}
+
 pub struct MultiOrderbookSubgraphClient {
     subgraphs: Vec<MultiSubgraphArgs>,
+    block: Option<u64>,
+    subgraph_blocks: HashMap<String, u64>,
 }
 impl MultiOrderbookSubgraphClient {
     pub fn new(subgraphs: Vec<MultiSubgraphArgs>) -> Self {
-        Self { subgraphs }
+        Self {
+            subgraphs,
+            block: None,
+            subgraph_blocks: HashMap::new(),
+        }
     }
 
-    fn get_orderbook_subgraph_client(&self, url: Url) -> OrderbookSubgraphClient {
-        OrderbookSubgraphClient::new(url)
+    /// Pins the queries of every subgraph to the given block. Block numbers
+    /// are chain specific, so the subgraphs should all index the same chain.
+    pub fn at_block(mut self, block: Option<u64>) -> Self {
+        self.block = block;
+        self
     }
 
-    pub async fn orders_list(
+    /// Pins the queries of each subgraph, by name, to its own block. A block
+    /// set with `at_block` takes precedence, subgraphs without a block read
+    /// the latest indexed block.
+    pub fn at_subgraph_blocks(mut self, subgraph_blocks: HashMap<String, u64>) -> Self {
+        self.subgraph_blocks = subgraph_blocks;
+        self
+    }
+
+    fn get_orderbook_subgraph_client(
         &self,
-        filter_args: SgOrdersListFilterArgs,
-        pagination_args: SgPaginationArgs,
-    ) -> Vec<SgOrderWithSubgraphName> {
+        subgraph: &MultiSubgraphArgs,
+    ) -> OrderbookSubgraphClient {
+        OrderbookSubgraphClient::with_transport(subgraph.url.clone(), subgraph.transport.clone())
+            .at_block(
+                self.block
+                    .or_else(|| self.subgraph_blocks.get(&subgraph.name).copied()),
+            )
+    }
+
+    /// Runs the query against every subgraph concurrently, flattening the
+    /// successful results and recording the outcome of each subgraph
+    async fn query_all<T, F, Fut>(&self, query: F) -> MultiSubgraphResult<Vec<T>>
+    where
+        F: Fn(OrderbookSubgraphClient, String) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, OrderbookSubgraphClientError>>,
+    {
         let futures = self.subgraphs.iter().map(|subgraph| {
-            let url = subgraph.url.clone();
-            let filter_args = filter_args.clone();
-            let pagination_args = pagination_args.clone();
+            let future = query(
+                self.get_orderbook_subgraph_client(subgraph),
+                subgraph.name.clone(),
+            );
             async move {
-                let client = self.get_orderbook_subgraph_client(url);
-                let orders = client.orders_list(filter_args, pagination_args).await?;
-                let wrapped_orders: Vec<SgOrderWithSubgraphName> = orders
-                    .into_iter()
-                    .map(|order| SgOrderWithSubgraphName {
-                        order,
-                        subgraph_name: subgraph.name.clone(),
-                    })
-                    .collect();
-                Ok::<_, OrderbookSubgraphClientError>(wrapped_orders)
+                let start = Instant::now();
+                let result = future.await;
+                let latency_ms = start.elapsed().as_millis().min(u32::MAX as u128) as u32;
+                let status = MultiSubgraphStatus {
+                    subgraph_name: subgraph.name.clone(),
+                    url: subgraph.url.clone(),
+                    error: result.as_ref().err().map(ToString::to_string),
+                    latency_ms,
+                };
+                (result.unwrap_or_default(), status)
             }
         });
 
-        let results = join_all(futures).await;
+        let (data, statuses): (Vec<Vec<T>>, Vec<MultiSubgraphStatus>) =
+            join_all(futures).await.into_iter().unzip();
 
-        let mut all_orders: Vec<SgOrderWithSubgraphName> = results
-            .into_iter()
-            .filter_map(Result::ok)
-            .flatten()
-            .collect();
+        MultiSubgraphResult {
+            data: data.into_iter().flatten().collect(),
+            statuses,
+        }
+    }
 
-        all_orders.sort_by(|a, b| {
-            let a_timestamp = a.order.timestamp_added.0.parse::<i64>().unwrap_or(0);
-            let b_timestamp = b.order.timestamp_added.0.parse::<i64>().unwrap_or(0);
-            b_timestamp.cmp(&a_timestamp)
-        });
+    /// Runs the list query against every subgraph, tags each item with the
+    /// subgraph it came from and merges the items of all subgraphs in the
+    /// order given by `compare`
+    async fn query_all_merged<T, W, F, Fut>(
+        &self,
+        query: F,
+        with_subgraph_name: fn(T, String) -> W,
+        compare: impl Fn(&W, &W) -> Ordering,
+    ) -> MultiSubgraphResult<Vec<W>>
+    where
+        F: Fn(OrderbookSubgraphClient) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, OrderbookSubgraphClientError>>,
+    {
+        let mut result = self
+            .query_all(|client, subgraph_name| {
+                let items = query(client);
+                async move {
+                    Ok(items
+                        .await?
+                        .into_iter()
+                        .map(|item| with_subgraph_name(item, subgraph_name.clone()))
+                        .collect())
+                }
+            })
+            .await;
+
+        result.data.sort_by(compare);
 
-        all_orders
+        result
     }
 
-    pub async fn vaults_list(
+    pub async fn orders_list(
         &self,
-        filter_args: SgVaultsListFilterArgs,
+        filter_args: SgOrdersListFilterArgs,
         pagination_args: SgPaginationArgs,
-    ) -> Vec<SgVaultWithSubgraphName> {
-        let futures = self.subgraphs.iter().map(|subgraph| {
-            let url = subgraph.url.clone();
-            let filter_args = filter_args.clone();
-            let pagination_args = pagination_args.clone();
-            async move {
-                let client = self.get_orderbook_subgraph_client(url);
-                let vaults = client.vaults_list(filter_args, pagination_args).await?;
-                let wrapped_vaults: Vec<SgVaultWithSubgraphName> = vaults
-                    .into_iter()
-                    .map(|vault| SgVaultWithSubgraphName {
-                        vault,
-                        subgraph_name: subgraph.name.clone(),
-                    })
-                    .collect();
-                Ok::<_, OrderbookSubgraphClientError>(wrapped_vaults)
-            }
-        });
+    ) -> MultiSubgraphResult<Vec<SgOrderWithSubgraphName>> {
+        self.query_all_merged(
+            |client| {
+                let filter_args = filter_args.clone();
+                let pagination_args = pagination_args.clone();
+                async move { client.orders_list(filter_args, pagination_args).await }
+            },
+            |order, subgraph_name| SgOrderWithSubgraphName {
+                order,
+                subgraph_name,
+            },
+            |a, b| filter_args.compare(&a.order, &b.order),
+        )
+        .await
+    }
 
-        let results = join_all(futures).await;
+    /// Fetch every order matching the filters from all subgraphs
+    pub async fn orders_list_all(
+        &self,
+        filter_args: SgOrdersListFilterArgs,
+    ) -> MultiSubgraphResult<Vec<SgOrderWithSubgraphName>> {
+        self.query_all_merged(
+            |client| {
+                let filter_args = filter_args.clone();
+                async move {
+                    let orders = client.orders_list_matching(&filter_args).await?;
+                    Ok(filter_args.apply_local(orders))
+                }
+            },
+            |order, subgraph_name| SgOrderWithSubgraphName {
+                order,
+                subgraph_name,
+            },
+            |a, b| filter_args.compare(&a.order, &b.order),
+        )
+        .await
+    }
 
-        let all_vaults: Vec<SgVaultWithSubgraphName> = results
-            .into_iter()
-            .filter_map(Result::ok)
-            .flatten()
-            .collect();
+    pub async fn vaults_list(
+        &self,
+        filter_args: SgVaultsListFilterArgs,
+        pagination_args: SgPaginationArgs,
+    ) -> MultiSubgraphResult<Vec<SgVaultWithSubgraphName>> {
+        self.query_all_merged(
+            |client| {
+                let filter_args = filter_args.clone();
+                let pagination_args = pagination_args.clone();
+                async move { client.vaults_list(filter_args, pagination_args).await }
+            },
+            |vault, subgraph_name| SgVaultWithSubgraphName {
+                vault,
+                subgraph_name,
+            },
+            |a, b| filter_args.compare(&a.vault, &b.vault),
+        )
+        .await
+    }
 
-        all_vaults
+    /// Fetch every vault matching the filters from all subgraphs
+    pub async fn vaults_list_all(
+        &self,
+        filter_args: SgVaultsListFilterArgs,
+    ) -> MultiSubgraphResult<Vec<SgVaultWithSubgraphName>> {
+        self.query_all_merged(
+            |client| {
+                let filter_args = filter_args.clone();
+                async move {
+                    let vaults = client.vaults_list_matching(&filter_args).await?;
+                    Ok(filter_args.apply_local(vaults)?)
+                }
+            },
+            |vault, subgraph_name| SgVaultWithSubgraphName {
+                vault,
+                subgraph_name,
+            },
+            |a, b| filter_args.compare(&a.vault, &b.vault),
+        )
+        .await
     }
 
-    pub async fn tokens_list(&self) -> Vec<SgErc20WithSubgraphName> {
-        let futures = self.subgraphs.iter().map(|subgraph| {
-            let url = subgraph.url.clone();
-            async move {
-                let client = self.get_orderbook_subgraph_client(url);
-                let tokens = client.tokens_list_all().await?;
-                let wrapped_tokens: Vec<SgErc20WithSubgraphName> = tokens
-                    .into_iter()
-                    .map(|token| SgErc20WithSubgraphName {
-                        token,
-                        subgraph_name: subgraph.name.clone(),
-                    })
-                    .collect();
-                Ok::<_, OrderbookSubgraphClientError>(wrapped_tokens)
-            }
-        });
+    /// Fetch one page of orders from all subgraphs, merged by the time they were added.
+    ///
+    /// The merged orders are cut to the page size so the returned cursor
+    /// resumes every subgraph right after the last order of this page.
+    pub async fn orders_page(
+        &self,
+        filter_args: SgOrdersListFilterArgs,
+        pagination_args: SgPaginationArgs,
+    ) -> Result<MultiSubgraphResult<SgPage<SgOrderWithSubgraphName>>, OrderbookSubgraphClientError>
+    {
+        Self::validate_cursor(&pagination_args)?;
+        if !filter_args.is_server_side() {
+            return Err(OrderbookSubgraphClientError::CursorUnsupportedForFilters);
+        }
+        let MultiSubgraphResult {
+            data: mut orders,
+            statuses,
+        } = self.orders_list(filter_args, pagination_args.clone()).await;
+        orders.truncate(pagination_args.page_size as usize);
+        let next_cursor = pagination_args.next_cursor(&orders)?;
+        Ok(MultiSubgraphResult {
+            data: SgPage {
+                items: orders,
+                next_cursor,
+            },
+            statuses,
+        })
+    }
 
-        let results = join_all(futures).await;
+    /// Fetch one page of vaults from all subgraphs, merged by id.
+    ///
+    /// The merged vaults are cut to the page size so the returned cursor
+    /// resumes every subgraph right after the last vault of this page.
+    pub async fn vaults_page(
+        &self,
+        filter_args: SgVaultsListFilterArgs,
+        pagination_args: SgPaginationArgs,
+    ) -> Result<MultiSubgraphResult<SgPage<SgVaultWithSubgraphName>>, OrderbookSubgraphClientError>
+    {
+        Self::validate_cursor(&pagination_args)?;
+        if !filter_args.is_server_side() {
+            return Err(OrderbookSubgraphClientError::CursorUnsupportedForFilters);
+        }
+        let MultiSubgraphResult {
+            data: mut vaults,
+            statuses,
+        } = self.vaults_list(filter_args, pagination_args.clone()).await;
+        vaults.truncate(pagination_args.page_size as usize);
+        let next_cursor = pagination_args.next_cursor(&vaults)?;
+        Ok(MultiSubgraphResult {
+            data: SgPage {
+                items: vaults,
+                next_cursor,
+            },
+            statuses,
+        })
+    }
 
-        let all_tokens: Vec<SgErc20WithSubgraphName> = results
-            .into_iter()
-            .filter_map(Result::ok)
-            .flatten()
-            .collect();
+    // Failed subgraphs are skipped when merging, so an invalid cursor has to
+    // be reported before querying them
+    fn validate_cursor(
+        pagination_args: &SgPaginationArgs,
+    ) -> Result<(), OrderbookSubgraphClientError> {
+        if let Some(cursor) = &pagination_args.cursor {
+            SgPaginationCursor::decode(cursor)?;
+        }
+        Ok(())
+    }
 
-        all_tokens
+    pub async fn tokens_list(&self) -> MultiSubgraphResult<Vec<SgErc20WithSubgraphName>> {
+        self.query_all(|client, subgraph_name| async move {
+            let tokens = client.tokens_list_all().await?;
+            Ok(tokens
+                .into_iter()
+                .map(|token| SgErc20WithSubgraphName {
+                    token,
+                    subgraph_name: subgraph_name.clone(),
+                })
+                .collect())
+        })
+        .await
     }
 }
 
@@ -137,7 +346,8 @@ impl MultiOrderbookSubgraphClient {
 mod tests {
     use super::*;
     use crate::types::common::{
-        SgBigInt, SgBytes, SgErc20, SgOrder, SgOrderbook, SgOrdersListFilterArgs, SgVault,
+        SgBigInt, SgBytes, SgErc20, SgOrder, SgOrderbook, SgOrdersListFilterArgs, SgOrdersSortBy,
+        SgSortDirection, SgVault,
     };
     use crate::utils::float::*;
     use httpmock::prelude::*;
@@ -170,6 +380,7 @@ mod tests {
             active: None,
             order_hash: None,
             tokens: vec![],
+            ..Default::default()
         }
     }
 
@@ -177,6 +388,7 @@ mod tests {
         SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         }
     }
 
@@ -185,7 +397,8 @@ mod tests {
         let client = MultiOrderbookSubgraphClient::new(vec![]);
         let result = client
             .orders_list(default_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert!(result.is_empty());
     }
 
@@ -205,11 +418,13 @@ mod tests {
         let client = MultiOrderbookSubgraphClient::new(vec![MultiSubgraphArgs {
             url: sg1_url,
             name: sg1_name.to_string(),
+            transport: SubgraphTransportConfig::default(),
         }]);
 
         let orders = client
             .orders_list(default_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert_eq!(orders.len(), 1);
         assert_eq!(orders[0].order.id, order1_s1.id);
         assert_eq!(orders[0].subgraph_name, sg1_name);
@@ -244,16 +459,19 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
 
         let orders = client
             .orders_list(default_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
 
         assert_eq!(orders.len(), 3);
         assert_eq!(orders[0].order.id, order_b_s2.id);
@@ -264,6 +482,52 @@ mod tests {
         assert_eq!(orders[2].subgraph_name, sg2_name);
     }
 
+    #[tokio::test]
+    async fn test_orders_list_all_merges_subgraphs() {
+        let server1 = MockServer::start_async().await;
+        let server2 = MockServer::start_async().await;
+
+        let order_a_s1 = sample_sg_order("s1_A", "100");
+        let order_b_s2 = sample_sg_order("s2_B", "200");
+        server1.mock(|when, then| {
+            when.method(POST).path("/").body_contains("\"active\":true");
+            then.status(200)
+                .json_body(json!({"data": {"orders": [order_a_s1]}}));
+        });
+        server2.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"orders": [order_b_s2]}}));
+        });
+
+        let client = MultiOrderbookSubgraphClient::new(vec![
+            MultiSubgraphArgs {
+                url: Url::parse(&server1.url("")).unwrap(),
+                name: "sg_one".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+            MultiSubgraphArgs {
+                url: Url::parse(&server2.url("")).unwrap(),
+                name: "sg_two".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+        ]);
+
+        let result = client
+            .orders_list_all(SgOrdersListFilterArgs {
+                active: Some(true),
+                ..Default::default()
+            })
+            .await;
+        assert!(result.failed_subgraphs().is_empty());
+        let orders = result.data;
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].order.id, order_b_s2.id);
+        assert_eq!(orders[0].subgraph_name, "sg_two");
+        assert_eq!(orders[1].order.id, order_a_s1.id);
+        assert_eq!(orders[1].subgraph_name, "sg_one");
+    }
+
     #[tokio::test]
     async fn test_orders_list_multiple_subgraphs_some_empty() {
         let server1 = MockServer::start_async().await;
@@ -289,15 +553,18 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
         let orders = client
             .orders_list(default_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert_eq!(orders.len(), 1);
         assert_eq!(orders[0].order.id, order_a_s1.id);
         assert_eq!(orders[0].subgraph_name, sg1_name);
@@ -328,18 +595,35 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
-                url: sg2_url,
+                url: sg2_url.clone(),
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
-        let orders = client
+        let result = client
             .orders_list(default_filter_args(), default_pagination_args())
             .await;
+        let orders = &result.data;
         assert_eq!(orders.len(), 1);
         assert_eq!(orders[0].order.id, order_a_s1.id);
         assert_eq!(orders[0].subgraph_name, sg1_name);
+
+        assert_eq!(result.statuses.len(), 2);
+        assert_eq!(result.statuses[0].subgraph_name, sg1_name);
+        assert!(result.statuses[0].is_ok());
+        assert_eq!(result.statuses[1].subgraph_name, sg2_name);
+        assert_eq!(result.statuses[1].url, sg2_url);
+        assert!(result.statuses[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("500 Internal Server Error"));
+        let failed = result.failed_subgraphs();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].subgraph_name, sg2_name);
     }
 
     #[tokio::test]
@@ -365,16 +649,19 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
-        let orders = client
+        let result = client
             .orders_list(default_filter_args(), default_pagination_args())
             .await;
-        assert!(orders.is_empty());
+        assert!(result.data.is_empty());
+        assert_eq!(result.failed_subgraphs().len(), 2);
     }
 
     #[tokio::test]
@@ -396,10 +683,12 @@ mod tests {
         let client = MultiOrderbookSubgraphClient::new(vec![MultiSubgraphArgs {
             url: sg1_url,
             name: sg1_name.to_string(),
+            transport: SubgraphTransportConfig::default(),
         }]);
         let orders = client
             .orders_list(default_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert_eq!(orders.len(), 3);
         assert_eq!(orders[0].order.id, order_a.id);
         assert_eq!(orders[1].order.id, order_c.id);
@@ -428,10 +717,12 @@ mod tests {
         let client = MultiOrderbookSubgraphClient::new(vec![MultiSubgraphArgs {
             url: sg1_url,
             name: sg1_name.to_string(),
+            transport: SubgraphTransportConfig::default(),
         }]);
         let orders = client
             .orders_list(default_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert_eq!(orders.len(), 5);
 
         assert_eq!(orders[0].order.id, order_b.id);
@@ -499,6 +790,7 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![],
+            ..Default::default()
         }
     }
 
@@ -507,7 +799,8 @@ mod tests {
         let client = MultiOrderbookSubgraphClient::new(vec![]);
         let result = client
             .vaults_list(default_vault_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert!(result.is_empty());
     }
 
@@ -527,11 +820,13 @@ mod tests {
         let client = MultiOrderbookSubgraphClient::new(vec![MultiSubgraphArgs {
             url: sg1_url,
             name: sg1_name.to_string(),
+            transport: SubgraphTransportConfig::default(),
         }]);
 
         let vaults = client
             .vaults_list(default_vault_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert_eq!(vaults.len(), 1);
         assert_eq!(vaults[0].vault.id, vault1_s1.id);
         assert_eq!(vaults[0].subgraph_name, sg1_name);
@@ -566,16 +861,19 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
 
         let vaults_with_names = client
             .vaults_list(default_vault_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
 
         assert_eq!(vaults_with_names.len(), 3);
 
@@ -617,15 +915,18 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
         let vaults = client
             .vaults_list(default_vault_filter_args(), default_pagination_args())
-            .await;
+            .await
+            .data;
         assert_eq!(vaults.len(), 1);
         assert_eq!(vaults[0].vault.id, vault_a_s1.id);
         assert_eq!(vaults[0].subgraph_name, sg1_name);
@@ -656,18 +957,72 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
-        let vaults = client
+        let result = client
             .vaults_list(default_vault_filter_args(), default_pagination_args())
             .await;
+        let vaults = &result.data;
         assert_eq!(vaults.len(), 1);
         assert_eq!(vaults[0].vault.id, vault_a_s1.id);
         assert_eq!(vaults[0].subgraph_name, sg1_name);
+
+        assert_eq!(result.statuses.len(), 2);
+        assert!(result.statuses[0].is_ok());
+        assert_eq!(result.statuses[1].subgraph_name, sg2_name);
+        assert!(!result.statuses[1].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_vaults_list_all_merges_subgraphs() {
+        let server1 = MockServer::start_async().await;
+        let server2 = MockServer::start_async().await;
+
+        let vault_a_s1 = sample_sg_vault("s1_A");
+        let vault_b_s2 = sample_sg_vault("s2_B");
+        server1.mock(|when, then| {
+            when.method(POST).path("/").body_contains("0xowner");
+            then.status(200)
+                .json_body(json!({"data": {"vaults": [vault_a_s1]}}));
+        });
+        server2.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"vaults": [vault_b_s2]}}));
+        });
+
+        let client = MultiOrderbookSubgraphClient::new(vec![
+            MultiSubgraphArgs {
+                url: Url::parse(&server1.url("")).unwrap(),
+                name: "sg_one".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+            MultiSubgraphArgs {
+                url: Url::parse(&server2.url("")).unwrap(),
+                name: "sg_two".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+        ]);
+
+        let result = client
+            .vaults_list_all(SgVaultsListFilterArgs {
+                owners: vec![SgBytes("0xowner".to_string())],
+                ..default_vault_filter_args()
+            })
+            .await;
+        assert!(result.failed_subgraphs().is_empty());
+        let vaults = result.data;
+        assert_eq!(vaults.len(), 2);
+        assert_eq!(vaults[0].vault.id, vault_b_s2.id);
+        assert_eq!(vaults[0].subgraph_name, "sg_two");
+        assert_eq!(vaults[1].vault.id, vault_a_s1.id);
+        assert_eq!(vaults[1].subgraph_name, "sg_one");
     }
 
     #[tokio::test]
@@ -693,15 +1048,211 @@ mod tests {
             MultiSubgraphArgs {
                 url: sg1_url,
                 name: sg1_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
             MultiSubgraphArgs {
                 url: sg2_url,
                 name: sg2_name.to_string(),
+                transport: SubgraphTransportConfig::default(),
             },
         ]);
-        let vaults = client
+        let result = client
             .vaults_list(default_vault_filter_args(), default_pagination_args())
             .await;
-        assert!(vaults.is_empty());
+        assert!(result.data.is_empty());
+        assert_eq!(result.failed_subgraphs().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_orders_page_truncates_and_returns_cursor() {
+        let server1 = MockServer::start_async().await;
+        let sg1_url = Url::parse(&server1.url("")).unwrap();
+        let server2 = MockServer::start_async().await;
+        let sg2_url = Url::parse(&server2.url("")).unwrap();
+
+        let order_a_s1 = sample_sg_order("s1_A", "300");
+        let order_b_s1 = sample_sg_order("s1_B", "100");
+        let order_c_s2 = sample_sg_order("s2_C", "200");
+        let order_d_s2 = sample_sg_order("s2_D", "50");
+
+        server1.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"orders": [order_a_s1, order_b_s1]}}));
+        });
+        server2.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"orders": [order_c_s2, order_d_s2]}}));
+        });
+
+        let client = MultiOrderbookSubgraphClient::new(vec![
+            MultiSubgraphArgs {
+                url: sg1_url,
+                name: "sg_one".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+            MultiSubgraphArgs {
+                url: sg2_url,
+                name: "sg_two".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+        ]);
+
+        let page = client
+            .orders_page(
+                default_filter_args(),
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: 2,
+                    cursor: None,
+                },
+            )
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].order.id, order_a_s1.id);
+        assert_eq!(page.items[1].order.id, order_c_s2.id);
+        assert_eq!(
+            SgPaginationCursor::decode(&page.next_cursor.unwrap()).unwrap(),
+            SgPaginationCursor::Timestamp {
+                timestamp: "200".to_string(),
+                ids: vec![order_c_s2.id.0],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_orders_page_invalid_cursor() {
+        let client = MultiOrderbookSubgraphClient::new(vec![]);
+        let err = client
+            .orders_page(
+                default_filter_args(),
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: 2,
+                    cursor: Some("invalid".to_string()),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderbookSubgraphClientError::PaginationClientError(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_orders_page_rejects_local_sort() {
+        let client = MultiOrderbookSubgraphClient::new(vec![]);
+        let err = client
+            .orders_page(
+                SgOrdersListFilterArgs {
+                    sort_by: SgOrdersSortBy::LastTrade,
+                    ..default_filter_args()
+                },
+                default_pagination_args(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderbookSubgraphClientError::CursorUnsupportedForFilters
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_merges_in_requested_order() {
+        let server1 = MockServer::start_async().await;
+        let server2 = MockServer::start_async().await;
+
+        let order_a_s1 = sample_sg_order("s1_A", "100");
+        let order_b_s2 = sample_sg_order("s2_B", "200");
+        let order_c_s2 = sample_sg_order("s2_C", "50");
+
+        server1.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"orders": [order_a_s1]}}));
+        });
+        server2.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"orders": [order_b_s2, order_c_s2]}}));
+        });
+
+        let client = MultiOrderbookSubgraphClient::new(vec![
+            MultiSubgraphArgs {
+                url: Url::parse(&server1.url("")).unwrap(),
+                name: "sg_one".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+            MultiSubgraphArgs {
+                url: Url::parse(&server2.url("")).unwrap(),
+                name: "sg_two".to_string(),
+                transport: SubgraphTransportConfig::default(),
+            },
+        ]);
+
+        let orders = client
+            .orders_list(
+                SgOrdersListFilterArgs {
+                    sort_direction: SgSortDirection::Asc,
+                    ..default_filter_args()
+                },
+                default_pagination_args(),
+            )
+            .await
+            .data;
+
+        let ids: Vec<_> = orders.iter().map(|order| order.order.id.clone()).collect();
+        assert_eq!(ids, vec![order_c_s2.id, order_a_s1.id, order_b_s2.id]);
+    }
+
+    #[tokio::test]
+    async fn test_vaults_page_sorts_by_id() {
+        let server1 = MockServer::start_async().await;
+        let sg1_url = Url::parse(&server1.url("")).unwrap();
+
+        let vault_a = sample_sg_vault("a");
+        let vault_b = sample_sg_vault("c");
+        let vault_c = sample_sg_vault("b");
+
+        server1.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"vaults": [vault_a, vault_b, vault_c]}}));
+        });
+
+        let client = MultiOrderbookSubgraphClient::new(vec![MultiSubgraphArgs {
+            url: sg1_url,
+            name: "sg_one".to_string(),
+            transport: SubgraphTransportConfig::default(),
+        }]);
+
+        let page = client
+            .vaults_page(
+                default_vault_filter_args(),
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: 2,
+                    cursor: None,
+                },
+            )
+            .await
+            .unwrap()
+            .data;
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].vault.id, vault_b.id);
+        assert_eq!(page.items[1].vault.id, vault_c.id);
+        assert_eq!(
+            SgPaginationCursor::decode(&page.next_cursor.unwrap()).unwrap(),
+            SgPaginationCursor::Id {
+                id: vault_c.id.0.clone()
+            }
+        );
     }
 }
@@ -0,0 +1,273 @@
+use super::*;
+use crate::types::clear::SgClearsListQuery;
+
+impl OrderbookSubgraphClient {
+    /// Fetch clears paginated, newest first, filtered by the order they
+    /// traded, the clearer or the transaction they were included in
+    pub async fn clears_list(
+        &self,
+        filter_args: SgClearsListFilterArgs,
+        pagination_args: SgPaginationArgs,
+    ) -> Result<Vec<SgClear>, OrderbookSubgraphClientError> {
+        let pagination_variables = Self::parse_pagination_args(pagination_args)?;
+        let (transaction_, id_not_in) = match pagination_variables.cursor {
+            None => (None, vec![]),
+            Some(SgPaginationCursor::Timestamp { timestamp, ids }) => (
+                Some(SgClearTransactionFilter {
+                    timestamp_lte: SgBigInt(timestamp),
+                }),
+                ids.into_iter().map(SgBytes).collect(),
+            ),
+            Some(cursor) => {
+                return Err(PaginationClientError::InvalidCursor(cursor.encode()).into());
+            }
+        };
+
+        let filters = SgClearsListQueryFilters {
+            trades_: filter_args.order_id.map(|order_id| SgClearTradeFilter {
+                order: order_id.0.to_lowercase(),
+            }),
+            sender: filter_args
+                .clearer
+                .map(|clearer| SgBytes(clearer.0.to_lowercase())),
+            transaction: filter_args
+                .transaction_id
+                .map(|transaction_id| transaction_id.0.to_lowercase()),
+            transaction_,
+            id_not_in,
+        };
+        let has_filters = filters.trades_.is_some()
+            || filters.sender.is_some()
+            || filters.transaction.is_some()
+            || filters.transaction_.is_some()
+            || !filters.id_not_in.is_empty();
+
+        let data = self
+            .query::<SgClearsListQuery, SgClearsListQueryVariables>(SgClearsListQueryVariables {
+                first: pagination_variables.first,
+                skip: pagination_variables.skip,
+                filters: has_filters.then_some(filters),
+                block: self.block_height()?,
+            })
+            .await?;
+
+        Ok(data.clears)
+    }
+
+    /// Fetch all pages of clears_list query
+    pub async fn clears_list_all(
+        &self,
+        filter_args: SgClearsListFilterArgs,
+    ) -> Result<Vec<SgClear>, OrderbookSubgraphClientError> {
+        let mut all_pages_merged = vec![];
+        let mut cursor = None;
+
+        loop {
+            let pagination_args = SgPaginationArgs {
+                page: 1,
+                page_size: ALL_PAGES_QUERY_PAGE_SIZE,
+                cursor,
+            };
+            let page_data = self
+                .clears_list(filter_args.clone(), pagination_args.clone())
+                .await?;
+            cursor = pagination_args.next_cursor(&page_data)?;
+            all_pages_merged.extend(page_data);
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(all_pages_merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use reqwest::Url;
+    use serde_json::{json, Value};
+
+    fn setup_client(server: &MockServer) -> OrderbookSubgraphClient {
+        let url = Url::parse(&server.url("")).unwrap();
+        OrderbookSubgraphClient::new(url)
+    }
+
+    fn sample_clear_json(id: &str, timestamp: &str) -> Value {
+        json!({
+            "id": id,
+            "aliceInputAmount": "0x01",
+            "aliceOutputAmount": "0x02",
+            "bobInputAmount": "0x02",
+            "bobOutputAmount": "0x01",
+            "aliceBountyAmount": "0x00",
+            "bobBountyAmount": "0x00",
+            "aliceBountyVaultBalanceChange": null,
+            "bobBountyVaultBalanceChange": null,
+            "orderbook": { "id": "0xorderbook" },
+            "trades": [],
+            "transaction": {
+                "id": "0xtx",
+                "from": "0xclearer",
+                "blockNumber": "100",
+                "timestamp": timestamp
+            },
+            "sender": "0xclearer"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_clears_list_filters() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("\"trades_\":{\"order\":\"0xorder\"}")
+                .body_contains("\"sender\":\"0xclearer\"")
+                .body_contains("\"transaction\":\"0xtx\"");
+            then.status(200).json_body(json!({
+                "data": { "clears": [sample_clear_json("0xclear1", "1700000000")] }
+            }));
+        });
+
+        let clears = client
+            .clears_list(
+                SgClearsListFilterArgs {
+                    order_id: Some(SgBytes("0xORDER".to_string())),
+                    clearer: Some(SgBytes("0xClearer".to_string())),
+                    transaction_id: Some(SgBytes("0xTX".to_string())),
+                },
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: 10,
+                    cursor: None,
+                },
+            )
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(clears.len(), 1);
+        assert_eq!(clears[0].id.0, "0xclear1");
+        assert!(clears[0].alice_bounty_vault_balance_change.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_clears_list_without_filters() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+
+        sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("\"filters\":null");
+            then.status(200)
+                .json_body(json!({ "data": { "clears": [] } }));
+        });
+
+        let clears = client
+            .clears_list(
+                SgClearsListFilterArgs::default(),
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: 10,
+                    cursor: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(clears.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_clears_list_with_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let cursor = SgPaginationCursor::Timestamp {
+            timestamp: "1700000000".to_string(),
+            ids: vec!["0xclear1".to_string()],
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("\"skip\":0")
+                .body_contains("\"transaction_\":{\"timestamp_lte\":\"1700000000\"}")
+                .body_contains("\"id_not_in\":[\"0xclear1\"]");
+            then.status(200).json_body(json!({
+                "data": { "clears": [sample_clear_json("0xclear0", "1700000000")] }
+            }));
+        });
+
+        let clears = client
+            .clears_list(
+                SgClearsListFilterArgs::default(),
+                SgPaginationArgs {
+                    page: 2,
+                    page_size: 10,
+                    cursor: Some(cursor.encode()),
+                },
+            )
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(clears.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_clears_list_rejects_id_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+
+        let result = client
+            .clears_list(
+                SgClearsListFilterArgs::default(),
+                SgPaginationArgs {
+                    page: 1,
+                    page_size: 10,
+                    cursor: Some(
+                        SgPaginationCursor::Id {
+                            id: "0xclear".to_string(),
+                        }
+                        .encode(),
+                    ),
+                },
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(OrderbookSubgraphClientError::PaginationClientError(
+                PaginationClientError::InvalidCursor(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clears_list_all_follows_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let first_page: Vec<Value> = (0..ALL_PAGES_QUERY_PAGE_SIZE)
+            .map(|i| sample_clear_json(&format!("0xclear{i}"), "1700000000"))
+            .collect();
+
+        sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("\"filters\":null");
+            then.status(200)
+                .json_body(json!({ "data": { "clears": first_page } }));
+        });
+        sg_server.mock(|when, then| {
+            when.method(POST).path("/").body_contains("\"id_not_in\"");
+            then.status(200)
+                .json_body(json!({ "data": { "clears": [] } }));
+        });
+
+        let clears = client
+            .clears_list_all(SgClearsListFilterArgs::default())
+            .await
+            .unwrap();
+        assert_eq!(clears.len(), ALL_PAGES_QUERY_PAGE_SIZE as usize);
+    }
+}
@@ -0,0 +1,136 @@
+use super::*;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
+#[cfg(target_family = "wasm")]
+use wasmtimer::std::Instant;
+
+const MAX_CACHED_LISTS: usize = 16;
+
+/// How long a fetched list is reused for the following pages
+pub(crate) const LOCAL_LIST_TTL: Duration = Duration::from_secs(30);
+
+/// Lists whose filters or sort order the subgraph can't apply are fetched in
+/// full and cut into pages locally. The fetched lists are kept here for `ttl`,
+/// so only the first page of a list queries the subgraph and the following
+/// pages are cut from the same list. A list that expired is fetched again.
+pub(crate) struct LocalListCache<T> {
+    lists: Mutex<VecDeque<(String, Instant, Vec<T>)>>,
+    ttl: Duration,
+}
+
+impl<T: Clone> LocalListCache<T> {
+    pub(crate) const fn new(ttl: Duration) -> Self {
+        Self {
+            lists: Mutex::new(VecDeque::new()),
+            ttl,
+        }
+    }
+
+    fn page(&self, key: &str, skip: usize, first: usize) -> Option<Vec<T>> {
+        let lists = self.lists.lock().ok()?;
+        let (_, fetched_at, list) = lists.iter().find(|(list_key, _, _)| list_key == key)?;
+        if fetched_at.elapsed() >= self.ttl {
+            return None;
+        }
+        Some(list.iter().skip(skip).take(first).cloned().collect())
+    }
+
+    fn insert(&self, key: String, list: Vec<T>) {
+        if let Ok(mut lists) = self.lists.lock() {
+            lists.retain(|(list_key, fetched_at, _)| {
+                *list_key != key && fetched_at.elapsed() < self.ttl
+            });
+            if lists.len() == MAX_CACHED_LISTS {
+                lists.pop_front();
+            }
+            lists.push_back((key, Instant::now(), list));
+        }
+    }
+}
+
+impl OrderbookSubgraphClient {
+    /// Returns a page of a locally filtered and sorted list. `fetch` loads the
+    /// whole list, it's called for the first page and whenever the list isn't
+    /// cached anymore.
+    pub(crate) async fn local_list_page<T, F, Fut>(
+        &self,
+        cache: &LocalListCache<T>,
+        filters: &impl Serialize,
+        pagination_args: SgPaginationArgs,
+        fetch: F,
+    ) -> Result<Vec<T>, OrderbookSubgraphClientError>
+    where
+        T: Clone,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<T>, OrderbookSubgraphClientError>>,
+    {
+        if pagination_args.cursor.is_some() {
+            return Err(OrderbookSubgraphClientError::CursorUnsupportedForFilters);
+        }
+        let key = format!(
+            "{}|{:?}|{}",
+            self.url,
+            self.block,
+            serde_json::to_string(filters).unwrap_or_default()
+        );
+        let first_page = pagination_args.page <= 1;
+        let pagination_variables = Self::parse_pagination_args(pagination_args)?;
+        let skip = pagination_variables.skip.unwrap_or(0) as usize;
+        let first = pagination_variables.first.unwrap_or(0) as usize;
+
+        if !first_page {
+            if let Some(page) = cache.page(&key, skip, first) {
+                return Ok(page);
+            }
+        }
+        let list = fetch().await?;
+        let page = list.iter().skip(skip).take(first).cloned().collect();
+        cache.insert(key, list);
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_list_cache_page() {
+        let cache = LocalListCache::new(LOCAL_LIST_TTL);
+        assert_eq!(cache.page("key", 0, 2), None::<Vec<u32>>);
+
+        cache.insert("key".to_string(), vec![1, 2, 3]);
+        assert_eq!(cache.page("key", 0, 2), Some(vec![1, 2]));
+        assert_eq!(cache.page("key", 2, 2), Some(vec![3]));
+        assert_eq!(cache.page("other", 0, 2), None);
+
+        cache.insert("key".to_string(), vec![4]);
+        assert_eq!(cache.page("key", 0, 2), Some(vec![4]));
+    }
+
+    #[test]
+    fn test_local_list_cache_expires() {
+        let cache = LocalListCache::new(Duration::ZERO);
+        cache.insert("key".to_string(), vec![1, 2, 3]);
+        assert_eq!(cache.page("key", 0, 2), None);
+    }
+
+    #[test]
+    fn test_local_list_cache_evicts_oldest() {
+        let cache = LocalListCache::new(LOCAL_LIST_TTL);
+        for i in 0..=MAX_CACHED_LISTS {
+            cache.insert(i.to_string(), vec![i]);
+        }
+        assert_eq!(cache.page("0", 0, 1), None);
+        assert_eq!(cache.page("1", 0, 1), Some(vec![1]));
+        assert_eq!(
+            cache.page(&MAX_CACHED_LISTS.to_string(), 0, 1),
+            Some(vec![MAX_CACHED_LISTS])
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use super::*;
+use crate::transport::sleep;
+use crate::types::meta::{SgMeta, SgMetaQuery};
+use std::time::Duration;
+
+impl OrderbookSubgraphClient {
+    /// Fetch the latest indexed block of the subgraph and whether it hit indexing errors
+    pub async fn meta(&self) -> Result<SgMeta, OrderbookSubgraphClientError> {
+        let data = self.query::<SgMetaQuery, ()>(()).await?;
+        data.meta.ok_or(OrderbookSubgraphClientError::Empty)
+    }
+
+    /// Poll the subgraph until it has indexed at least the given block, e.g. to
+    /// wait for a just mined transaction to show up in query results
+    pub async fn wait_for_indexed_block(
+        &self,
+        block_number: u64,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<SgMeta, OrderbookSubgraphClientError> {
+        // wasm has no monotonic clock, so the timeout is spent in whole poll intervals
+        let max_polls = timeout.as_millis() / poll_interval.as_millis().max(1);
+        let mut polls = 0;
+        loop {
+            let meta = self.meta().await?;
+            if meta.indexed_block() >= block_number {
+                return Ok(meta);
+            }
+            polls += 1;
+            if polls > max_polls {
+                return Err(OrderbookSubgraphClientError::IndexingTimeout {
+                    target: block_number,
+                    indexed: meta.indexed_block(),
+                });
+            }
+            sleep(poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use reqwest::Url;
+    use serde_json::json;
+
+    fn setup_client(server: &MockServer) -> OrderbookSubgraphClient {
+        let url = Url::parse(&server.url("")).unwrap();
+        OrderbookSubgraphClient::new(url)
+    }
+
+    fn meta_json(block: u64, has_indexing_errors: bool) -> serde_json::Value {
+        json!({
+            "data": {
+                "_meta": {
+                    "block": { "number": block, "hash": "0x01", "timestamp": 1700000000 },
+                    "deployment": "Qm123",
+                    "hasIndexingErrors": has_indexing_errors
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_meta() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        sg_server.mock(|when, then| {
+            when.method(POST).path("/").body_contains("_meta");
+            then.status(200).json_body(meta_json(100, true));
+        });
+
+        let meta = client.meta().await.unwrap();
+        assert_eq!(meta.indexed_block(), 100);
+        assert_eq!(meta.deployment, "Qm123");
+        assert!(meta.has_indexing_errors);
+    }
+
+    #[tokio::test]
+    async fn test_meta_empty() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        sg_server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200).json_body(json!({"data": {"_meta": null}}));
+        });
+
+        let result = client.meta().await;
+        assert!(matches!(result, Err(OrderbookSubgraphClientError::Empty)));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_indexed_block_reached() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        sg_server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200).json_body(meta_json(100, false));
+        });
+
+        let meta = client
+            .wait_for_indexed_block(100, Duration::from_millis(1), Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(meta.indexed_block(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_indexed_block_timeout() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200).json_body(meta_json(99, false));
+        });
+
+        let err = client
+            .wait_for_indexed_block(100, Duration::from_millis(1), Duration::from_millis(3))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderbookSubgraphClientError::IndexingTimeout {
+                target: 100,
+                indexed: 99
+            }
+        ));
+        mock.assert_hits(4);
+    }
+}
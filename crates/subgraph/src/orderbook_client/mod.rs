@@ -1,7 +1,10 @@
 use crate::cynic_client::{CynicClient, CynicClientError};
-use crate::pagination::{PaginationClient, PaginationClientError, SgPaginationArgs};
+use crate::pagination::{
+    PaginationClient, PaginationClientError, SgPaginationArgs, SgPaginationCursor, SgPaginationItem,
+};
 // use crate::performance::vol::{get_vaults_vol, VaultVolume};
 // use crate::performance::OrderPerformance;
+use crate::transport::SubgraphTransportConfig;
 use crate::types::add_order::{SgTransactionAddOrdersQuery, TransactionAddOrdersVariables};
 use crate::types::common::*;
 use crate::types::order::{
@@ -17,9 +20,13 @@ use crate::types::vault::{SgVaultDetailQuery, SgVaultsListQuery};
 use crate::vault_balance_changes_query::VaultBalanceChangesListPageQueryClient;
 use cynic::Id;
 use reqwest::Url;
+use std::future::Future;
 use thiserror::Error;
 use wasm_bindgen_utils::prelude::*;
 
+mod clear;
+mod local_list_cache;
+mod meta;
 mod order;
 mod order_trade;
 // mod performance;
@@ -50,6 +57,14 @@ pub enum OrderbookSubgraphClientError {
     SerdeWasmBindgenError(#[from] serde_wasm_bindgen::Error),
     #[error("Failed to extend the order detail")]
     OrderDetailExtendError,
+    #[error("Subgraph did not index block {target} in time, latest indexed block is {indexed}")]
+    IndexingTimeout { target: u64, indexed: u64 },
+    #[error("Block number {0} is out of range for subgraph queries")]
+    BlockNumberOutOfRange(u64),
+    #[error(transparent)]
+    FloatError(#[from] rain_math_float::FloatError),
+    #[error("Cursor pagination only supports the default sort order and subgraph side filters, use page numbers instead")]
+    CursorUnsupportedForFilters,
 }
 
 impl From<OrderbookSubgraphClientError> for JsValue {
@@ -61,21 +76,90 @@ impl From<OrderbookSubgraphClientError> for JsValue {
 #[derive(Debug)]
 pub struct OrderbookSubgraphClient {
     url: Url,
+    transport: SubgraphTransportConfig,
+    block: Option<u64>,
 }
 
 impl CynicClient for OrderbookSubgraphClient {
     fn get_base_url(&self) -> &Url {
         &self.url
     }
+
+    fn get_transport_config(&self) -> &SubgraphTransportConfig {
+        &self.transport
+    }
 }
 impl PaginationClient for OrderbookSubgraphClient {}
 
 impl OrderbookSubgraphClient {
     pub fn new(url: Url) -> Self {
-        Self { url }
+        Self::with_transport(url, SubgraphTransportConfig::default())
+    }
+
+    pub fn with_transport(url: Url, transport: SubgraphTransportConfig) -> Self {
+        Self {
+            url,
+            transport,
+            block: None,
+        }
+    }
+
+    /// Pins every query of this client to the subgraph state as of the given
+    /// block, or to the latest indexed state when `None`
+    pub fn at_block(mut self, block: Option<u64>) -> Self {
+        self.block = block;
+        self
     }
 
     pub fn url(&self) -> &Url {
         &self.url
     }
+
+    pub fn transport(&self) -> &SubgraphTransportConfig {
+        &self.transport
+    }
+
+    pub fn block(&self) -> Option<u64> {
+        self.block
+    }
+
+    fn block_height(&self) -> Result<Option<SgBlockHeight>, OrderbookSubgraphClientError> {
+        self.block
+            .map(|block| {
+                i32::try_from(block)
+                    .map(|number| SgBlockHeight { number })
+                    .map_err(|_| OrderbookSubgraphClientError::BlockNumberOutOfRange(block))
+            })
+            .transpose()
+    }
+
+    /// Fetches every page of a cursor paginated query, `query` is called with
+    /// the pagination args of each page
+    async fn query_all_pages<T, F, Fut>(
+        &self,
+        query: F,
+    ) -> Result<Vec<T>, OrderbookSubgraphClientError>
+    where
+        T: SgPaginationItem,
+        F: Fn(SgPaginationArgs) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, OrderbookSubgraphClientError>>,
+    {
+        let mut all_pages_merged = vec![];
+        let mut cursor = None;
+
+        loop {
+            let pagination_args = SgPaginationArgs {
+                page: 1,
+                page_size: ALL_PAGES_QUERY_PAGE_SIZE,
+                cursor,
+            };
+            let page_data = query(pagination_args.clone()).await?;
+            cursor = pagination_args.next_cursor(&page_data)?;
+            all_pages_merged.extend(page_data);
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(all_pages_merged)
+    }
 }
@@ -1,10 +1,16 @@
+use super::local_list_cache::{LocalListCache, LOCAL_LIST_TTL};
 use super::*;
 
+static LOCAL_ORDERS: LocalListCache<SgOrder> = LocalListCache::new(LOCAL_LIST_TTL);
+
 impl OrderbookSubgraphClient {
     /// Fetch single order
     pub async fn order_detail(&self, id: &Id) -> Result<SgOrder, OrderbookSubgraphClientError> {
         let data = self
-            .query::<SgOrderDetailByIdQuery, SgIdQueryVariables>(SgIdQueryVariables { id })
+            .query::<SgOrderDetailByIdQuery, SgIdQueryVariables>(SgIdQueryVariables {
+                id,
+                block: self.block_height()?,
+            })
             .await?;
         let order = data.order.ok_or(OrderbookSubgraphClientError::Empty)?;
 
@@ -23,6 +29,7 @@ impl OrderbookSubgraphClient {
             .query::<SgBatchOrderDetailQuery, SgBatchOrderDetailQueryVariables>(
                 SgBatchOrderDetailQueryVariables {
                     id_list: SgOrderIdList { id_in: id_list },
+                    block: self.block_height()?,
                 },
             )
             .await?;
@@ -34,16 +41,27 @@ impl OrderbookSubgraphClient {
         tokens: Vec<String>,
         basic_filters: SgOrdersListQueryFilters,
     ) -> SgOrdersListQueryAnyFilters {
-        let vault_token_filter = Some(SgVaultTokenFilter {
-            token_in: tokens.clone(),
-        });
+        // A side that is already limited to some tokens can only match the
+        // tokens in both lists
+        let vault_token_filter = |side_filter: &Option<SgVaultTokenFilter>| {
+            Some(SgVaultTokenFilter {
+                token_in: match side_filter {
+                    Some(side_filter) => tokens
+                        .iter()
+                        .filter(|token| side_filter.token_in.contains(token))
+                        .cloned()
+                        .collect(),
+                    None => tokens.clone(),
+                },
+            })
+        };
 
         let filter_with_inputs = SgOrdersListQueryFilters {
-            inputs_: vault_token_filter.clone(),
+            inputs_: vault_token_filter(&basic_filters.inputs_),
             ..basic_filters.clone()
         };
         let filter_with_outputs = SgOrdersListQueryFilters {
-            outputs_: vault_token_filter.clone(),
+            outputs_: vault_token_filter(&basic_filters.outputs_),
             ..basic_filters.clone()
         };
         SgOrdersListQueryAnyFilters {
@@ -52,16 +70,71 @@ impl OrderbookSubgraphClient {
     }
 
     /// Fetch all orders, paginated
+    ///
+    /// Filters and sort orders the subgraph can't apply are applied to all
+    /// matching orders before the page is cut, which only works with page
+    /// numbers. The matching orders are fetched for the first page and reused
+    /// for the following ones for up to 30 seconds.
     pub async fn orders_list(
         &self,
         filter_args: SgOrdersListFilterArgs,
         pagination_args: SgPaginationArgs,
     ) -> Result<Vec<SgOrder>, OrderbookSubgraphClientError> {
-        let pagination_variables = Self::parse_pagination_args(pagination_args);
+        if filter_args.is_server_side() {
+            return self.orders_list_query(&filter_args, pagination_args).await;
+        }
+        self.local_list_page(&LOCAL_ORDERS, &filter_args, pagination_args, || async {
+            Ok(filter_args.apply_local(self.orders_list_matching(&filter_args).await?))
+        })
+        .await
+    }
+
+    async fn orders_list_query(
+        &self,
+        filter_args: &SgOrdersListFilterArgs,
+        pagination_args: SgPaginationArgs,
+    ) -> Result<Vec<SgOrder>, OrderbookSubgraphClientError> {
+        let pagination_variables = Self::parse_pagination_args(pagination_args)?;
+        let (cursor_timestamp, id_not_in) = match pagination_variables.cursor {
+            None => (None, vec![]),
+            Some(SgPaginationCursor::Timestamp { timestamp, ids }) => {
+                (Some(timestamp), ids.into_iter().map(SgBytes).collect())
+            }
+            Some(cursor) => {
+                return Err(PaginationClientError::InvalidCursor(cursor.encode()).into());
+            }
+        };
+        // A cursor continues after the last timestamp in the direction of the sort
+        let (timestamp_added_gte, timestamp_added_lte) = match filter_args.sort_direction {
+            SgSortDirection::Asc => (
+                Self::narrow_timestamp(cursor_timestamp, filter_args.added_after, u64::max),
+                filter_args
+                    .added_before
+                    .map(|added_before| SgBigInt(added_before.to_string())),
+            ),
+            SgSortDirection::Desc => (
+                filter_args
+                    .added_after
+                    .map(|added_after| SgBigInt(added_after.to_string())),
+                Self::narrow_timestamp(cursor_timestamp, filter_args.added_before, u64::min),
+            ),
+        };
+        let vault_token_filter = |tokens: &Vec<String>| {
+            (!tokens.is_empty()).then(|| SgVaultTokenFilter {
+                token_in: tokens.clone(),
+            })
+        };
 
         let has_basic_filters = !filter_args.owners.is_empty()
             || filter_args.active.is_some()
-            || filter_args.order_hash.is_some();
+            || filter_args.order_hash.is_some()
+            || !filter_args.input_tokens.is_empty()
+            || !filter_args.output_tokens.is_empty()
+            || timestamp_added_gte.is_some()
+            || timestamp_added_lte.is_some()
+            || !filter_args.orderbooks.is_empty()
+            || !filter_args.exclude_owners.is_empty()
+            || filter_args.has_trades == Some(true);
         let has_token_filters = !filter_args.tokens.is_empty();
 
         let filters = if has_basic_filters || has_token_filters {
@@ -69,12 +142,24 @@ impl OrderbookSubgraphClient {
                 owner_in: filter_args.owners.clone(),
                 active: filter_args.active,
                 order_hash: filter_args.order_hash.clone(),
-                inputs_: None,
-                outputs_: None,
+                inputs_: vault_token_filter(&filter_args.input_tokens),
+                outputs_: vault_token_filter(&filter_args.output_tokens),
+                timestamp_added_gte,
+                timestamp_added_lte,
+                id_not_in,
+                orderbook_in: filter_args
+                    .orderbooks
+                    .iter()
+                    .map(|orderbook| orderbook.0.to_lowercase())
+                    .collect(),
+                owner_not_in: filter_args.exclude_owners.clone(),
+                trades_: (filter_args.has_trades == Some(true)).then(|| SgOrderTradesFilter {
+                    timestamp_gte: SgBigInt("0".to_string()),
+                }),
             };
 
             Some(if has_token_filters {
-                Self::build_filter_with_tokens(filter_args.tokens, basic_filters)
+                Self::build_filter_with_tokens(filter_args.tokens.clone(), basic_filters)
             } else {
                 SgOrdersListQueryAnyFilters {
                     or: vec![basic_filters],
@@ -88,6 +173,8 @@ impl OrderbookSubgraphClient {
             first: pagination_variables.first,
             skip: pagination_variables.skip,
             filters,
+            order_direction: filter_args.sort_direction.into(),
+            block: self.block_height()?,
         };
 
         let data = self
@@ -97,33 +184,36 @@ impl OrderbookSubgraphClient {
         Ok(data.orders)
     }
 
+    /// Combines the cursor timestamp with a bound from the filters, keeping the
+    /// narrower one
+    fn narrow_timestamp(
+        cursor_timestamp: Option<String>,
+        bound: Option<u64>,
+        narrower: fn(u64, u64) -> u64,
+    ) -> Option<SgBigInt> {
+        match (cursor_timestamp, bound) {
+            (Some(timestamp), Some(bound)) => Some(SgBigInt(match timestamp.parse::<u64>() {
+                Ok(timestamp) => narrower(timestamp, bound).to_string(),
+                Err(_) => timestamp,
+            })),
+            (Some(timestamp), None) => Some(SgBigInt(timestamp)),
+            (None, bound) => bound.map(|bound| SgBigInt(bound.to_string())),
+        }
+    }
+
+    /// Fetch all pages of orders matching the subgraph side filters
+    pub(crate) async fn orders_list_matching(
+        &self,
+        filter_args: &SgOrdersListFilterArgs,
+    ) -> Result<Vec<SgOrder>, OrderbookSubgraphClientError> {
+        self.query_all_pages(|pagination_args| self.orders_list_query(filter_args, pagination_args))
+            .await
+    }
+
     /// Fetch all pages of orders_list query
     pub async fn orders_list_all(&self) -> Result<Vec<SgOrder>, OrderbookSubgraphClientError> {
-        let mut all_pages_merged = vec![];
-        let mut page = 1;
-
-        loop {
-            let page_data = self
-                .orders_list(
-                    SgOrdersListFilterArgs {
-                        owners: vec![],
-                        active: None,
-                        order_hash: None,
-                        tokens: vec![],
-                    },
-                    SgPaginationArgs {
-                        page,
-                        page_size: ALL_PAGES_QUERY_PAGE_SIZE,
-                    },
-                )
-                .await?;
-            if page_data.is_empty() {
-                break;
-            }
-            all_pages_merged.extend(page_data);
-            page += 1
-        }
-        Ok(all_pages_merged)
+        self.orders_list_matching(&SgOrdersListFilterArgs::default())
+            .await
     }
 
     /// Fetch single order given its hash
@@ -133,7 +223,10 @@ impl OrderbookSubgraphClient {
     ) -> Result<SgOrder, OrderbookSubgraphClientError> {
         let data = self
             .query::<SgOrderDetailByHashQuery, SgOrderDetailByHashQueryVariables>(
-                SgOrderDetailByHashQueryVariables { hash },
+                SgOrderDetailByHashQueryVariables {
+                    hash,
+                    block: self.block_height()?,
+                },
             )
             .await?;
         let order = data
@@ -326,6 +419,39 @@ mod tests {
         assert_sg_order_eq(&order, &expected_order);
     }
 
+    #[tokio::test]
+    async fn test_order_detail_at_block() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server).at_block(Some(123));
+        let expected_order = default_sg_order();
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains(r#""block":{"number":123}"#);
+            then.status(200)
+                .json_body(json!({"data": {"order": expected_order}}));
+        });
+
+        let order = client.order_detail(&Id::new("0x123")).await.unwrap();
+        assert_sg_order_eq(&order, &expected_order);
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_order_detail_block_out_of_range() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server).at_block(Some(u64::MAX));
+
+        let result = client.order_detail(&Id::new("0x123")).await;
+        assert!(matches!(
+            result,
+            Err(OrderbookSubgraphClientError::BlockNumberOutOfRange(
+                u64::MAX
+            ))
+        ));
+    }
+
     #[tokio::test]
     async fn test_order_detail_not_found() {
         let sg_server = MockServer::start_async().await;
@@ -472,10 +598,12 @@ mod tests {
             active: None,
             order_hash: None,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_orders = vec![default_sg_order()];
 
@@ -505,10 +633,12 @@ mod tests {
             active: Some(true),
             order_hash: None,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 5,
+            cursor: None,
         };
         let expected_orders = vec![default_sg_order()];
 
@@ -527,6 +657,77 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_orders_list_with_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgOrdersListFilterArgs {
+            owners: vec![SgBytes("owner1".to_string())],
+            active: None,
+            order_hash: None,
+            tokens: vec!["0xtoken".to_string()],
+            ..Default::default()
+        };
+        let cursor = SgPaginationCursor::Timestamp {
+            timestamp: "1700000000".to_string(),
+            ids: vec!["0xorder1".to_string()],
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 3,
+            page_size: 5,
+            cursor: Some(cursor.encode()),
+        };
+        let expected_orders = vec![default_sg_order()];
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("\"skip\":0")
+                .body_contains("\"timestampAdded_lte\":\"1700000000\"")
+                .body_contains("\"id_not_in\":[\"0xorder1\"]");
+            then.status(200)
+                .json_body(json!({"data": {"orders": expected_orders}}));
+        });
+
+        let orders = client
+            .orders_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(orders.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_with_vault_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgOrdersListFilterArgs {
+            owners: vec![],
+            active: None,
+            order_hash: None,
+            tokens: vec![],
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: Some(
+                SgPaginationCursor::Id {
+                    id: "0xvault".to_string(),
+                }
+                .encode(),
+            ),
+        };
+
+        let result = client.orders_list(filter_args, pagination_args).await;
+        assert!(matches!(
+            result,
+            Err(OrderbookSubgraphClientError::PaginationClientError(
+                PaginationClientError::InvalidCursor(_)
+            ))
+        ));
+    }
+
     #[tokio::test]
     async fn test_orders_list_empty_result() {
         let sg_server = MockServer::start_async().await;
@@ -536,10 +737,12 @@ mod tests {
             active: None,
             order_hash: None,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -561,10 +764,12 @@ mod tests {
             active: None,
             order_hash: None,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -592,7 +797,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains("\"first\":200")
-                .body_contains("\"skip\":0");
+                .body_contains("\"filters\":null");
             then.status(200)
                 .json_body(json!({"data": {"orders": orders_page1}}));
         });
@@ -600,17 +805,10 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains("\"first\":200")
-                .body_contains("\"skip\":200");
+                .body_contains("\"id_not_in\"");
             then.status(200)
                 .json_body(json!({"data": {"orders": orders_page2}}));
         });
-        sg_server.mock(|when, then| {
-            when.method(POST)
-                .path("/")
-                .body_contains("\"first\":200")
-                .body_contains("\"skip\":400");
-            then.status(200).json_body(json!({"data": {"orders": []}}));
-        });
 
         let result = client.orders_list_all().await;
         assert!(result.is_ok());
@@ -645,7 +843,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains("\"first\":200")
-                .body_contains("\"skip\":0");
+                .body_contains("\"filters\":null");
             then.status(200)
                 .json_body(json!({"data": {"orders": orders_page1}}));
         });
@@ -653,7 +851,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains("\"first\":200")
-                .body_contains("\"skip\":200");
+                .body_contains("\"id_not_in\"");
             then.status(500);
         });
 
@@ -729,10 +927,12 @@ mod tests {
             active: None,
             order_hash: None,
             tokens: vec![token_address.clone()],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_orders = vec![default_sg_order()];
 
@@ -763,10 +963,12 @@ mod tests {
             active: None,
             order_hash: None,
             tokens: vec![token1.clone(), token2.clone()],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_orders = vec![default_sg_order(), default_sg_order()];
 
@@ -797,10 +999,12 @@ mod tests {
             active: Some(true),
             order_hash: None,
             tokens: vec![token_address.clone()],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 5,
+            cursor: None,
         };
         let expected_orders = vec![default_sg_order()];
 
@@ -821,4 +1025,235 @@ mod tests {
         let orders = result.unwrap();
         assert_eq!(orders.len(), expected_orders.len());
     }
+
+    #[tokio::test]
+    async fn test_orders_list_with_server_side_filters() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgOrdersListFilterArgs {
+            input_tokens: vec!["0xinput".to_string()],
+            output_tokens: vec!["0xoutput".to_string()],
+            added_after: Some(1700000000),
+            added_before: Some(1800000000),
+            orderbooks: vec![SgBytes("0xOrderbook".to_string())],
+            exclude_owners: vec![SgBytes("0xowner".to_string())],
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: None,
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains(r#""inputs_":{"token_in":["0xinput"]}"#)
+                .body_contains(r#""outputs_":{"token_in":["0xoutput"]}"#)
+                .body_contains(r#""timestampAdded_gte":"1700000000""#)
+                .body_contains(r#""timestampAdded_lte":"1800000000""#)
+                .body_contains(r#""orderbook_in":["0xorderbook"]"#)
+                .body_contains(r#""owner_not_in":["0xowner"]"#);
+            then.status(200)
+                .json_body(json!({"data": {"orders": [default_sg_order()]}}));
+        });
+
+        let orders = client
+            .orders_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(orders.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_added_before_caps_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgOrdersListFilterArgs {
+            added_before: Some(1600000000),
+            ..Default::default()
+        };
+        let cursor = SgPaginationCursor::Timestamp {
+            timestamp: "1700000000".to_string(),
+            ids: vec!["0xorder1".to_string()],
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: Some(cursor.encode()),
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains(r#""timestampAdded_lte":"1600000000""#);
+            then.status(200).json_body(json!({"data": {"orders": []}}));
+        });
+
+        client
+            .orders_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_has_trades_and_direction_server_side() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgOrdersListFilterArgs {
+            has_trades: Some(true),
+            sort_direction: SgSortDirection::Asc,
+            ..Default::default()
+        };
+        let cursor = SgPaginationCursor::Timestamp {
+            timestamp: "1700000000".to_string(),
+            ids: vec!["0xorder1".to_string()],
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: Some(cursor.encode()),
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains(r#""trades_":{"timestamp_gte":"0"}"#)
+                .body_contains(r#""timestampAdded_gte":"1700000000""#)
+                .body_contains(r#""order_direction":"asc""#);
+            then.status(200)
+                .json_body(json!({"data": {"orders": [default_sg_order()]}}));
+        });
+
+        let orders = client
+            .orders_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(orders.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_sorted_by_last_trade_reuses_list() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let order_with_last_trade = |id: &str, timestamp: Option<u64>| {
+            let mut order = default_sg_order();
+            order.id = SgBytes(id.to_string());
+            order.trades = timestamp
+                .map(|timestamp| SgOrderStructPartialTrade {
+                    id: SgBytes(format!("{id}-trade")),
+                    timestamp: SgBigInt(timestamp.to_string()),
+                })
+                .into_iter()
+                .collect();
+            order
+        };
+        let filter_args = SgOrdersListFilterArgs {
+            sort_by: SgOrdersSortBy::LastTrade,
+            ..Default::default()
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST).path("/").body_contains(r#""skip":0"#);
+            then.status(200).json_body(json!({"data": {"orders": [
+                order_with_last_trade("0x1", Some(1)),
+                order_with_last_trade("0x2", None),
+                order_with_last_trade("0x3", Some(3)),
+                order_with_last_trade("0x4", Some(2)),
+            ]}}));
+        });
+
+        let mut ids = vec![];
+        for page in 1..=2 {
+            let orders = client
+                .orders_list(
+                    filter_args.clone(),
+                    SgPaginationArgs {
+                        page,
+                        page_size: 2,
+                        cursor: None,
+                    },
+                )
+                .await
+                .unwrap();
+            ids.extend(orders.into_iter().map(|order| order.id.0));
+        }
+        // the second page is cut from the orders fetched for the first one
+        mock.assert_hits(1);
+        assert_eq!(ids, vec!["0x3", "0x4", "0x1", "0x2"]);
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_sorted_by_trade_count() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let order_with_trades = |id: &str, trades: usize| {
+            let mut order = default_sg_order();
+            order.id = SgBytes(id.to_string());
+            order.trades = (0..trades)
+                .map(|i| SgOrderStructPartialTrade {
+                    id: SgBytes(format!("{id}-trade-{i}")),
+                    timestamp: SgBigInt(i.to_string()),
+                })
+                .collect();
+            order
+        };
+        let filter_args = SgOrdersListFilterArgs {
+            sort_by: SgOrdersSortBy::TradeCount,
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 3,
+            cursor: None,
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST).path("/").body_contains(r#""skip":0"#);
+            then.status(200).json_body(json!({"data": {"orders": [
+                order_with_trades("0x1", 1),
+                order_with_trades("0x2", 0),
+                order_with_trades("0x3", 3),
+                order_with_trades("0x4", 2),
+            ]}}));
+        });
+
+        let orders = client
+            .orders_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        let ids: Vec<_> = orders.iter().map(|order| order.id.0.as_str()).collect();
+        assert_eq!(ids, vec!["0x3", "0x4", "0x1"]);
+    }
+
+    #[tokio::test]
+    async fn test_orders_list_local_sort_rejects_cursor() {
+        let client = OrderbookSubgraphClient::new(Url::parse("http://localhost:1").unwrap());
+        let filter_args = SgOrdersListFilterArgs {
+            sort_by: SgOrdersSortBy::LastTrade,
+            ..Default::default()
+        };
+        let cursor = SgPaginationCursor::Timestamp {
+            timestamp: "1700000000".to_string(),
+            ids: vec![],
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: Some(cursor.encode()),
+        };
+
+        let err = client
+            .orders_list(filter_args, pagination_args)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderbookSubgraphClientError::CursorUnsupportedForFilters
+        ));
+    }
 }
@@ -7,7 +7,10 @@ impl OrderbookSubgraphClient {
         id: Id,
     ) -> Result<SgTrade, OrderbookSubgraphClientError> {
         let data = self
-            .query::<SgOrderTradeDetailQuery, SgIdQueryVariables>(SgIdQueryVariables { id: &id })
+            .query::<SgOrderTradeDetailQuery, SgIdQueryVariables>(SgIdQueryVariables {
+                id: &id,
+                block: self.block_height()?,
+            })
             .await?;
         let order_take = data.trade.ok_or(OrderbookSubgraphClientError::Empty)?;
 
@@ -22,7 +25,19 @@ impl OrderbookSubgraphClient {
         start_timestamp: Option<u64>,
         end_timestamp: Option<u64>,
     ) -> Result<Vec<SgTrade>, OrderbookSubgraphClientError> {
-        let pagination_variables = Self::parse_pagination_args(pagination_args);
+        let pagination_variables = Self::parse_pagination_args(pagination_args)?;
+        let mut timestamp_lte =
+            end_timestamp.map_or(SgBigInt(u64::MAX.to_string()), |v| SgBigInt(v.to_string()));
+        let exclude_ids = match pagination_variables.cursor {
+            None => None,
+            Some(SgPaginationCursor::Timestamp { timestamp, ids }) => {
+                timestamp_lte = SgBigInt(timestamp);
+                Some(ids.into_iter().map(SgBytes).collect())
+            }
+            Some(cursor) => {
+                return Err(PaginationClientError::InvalidCursor(cursor.encode()).into());
+            }
+        };
         let data = self
             .query::<SgOrderTradesListQuery, SgPaginationWithTimestampQueryVariables>(
                 SgPaginationWithTimestampQueryVariables {
@@ -33,10 +48,9 @@ impl OrderbookSubgraphClient {
                         start_timestamp
                             .map_or(SgBigInt("0".to_string()), |v| SgBigInt(v.to_string())),
                     ),
-                    timestamp_lte: Some(
-                        end_timestamp
-                            .map_or(SgBigInt(u64::MAX.to_string()), |v| SgBigInt(v.to_string())),
-                    ),
+                    timestamp_lte: Some(timestamp_lte),
+                    exclude_ids,
+                    block: self.block_height()?,
                 },
             )
             .await?;
@@ -52,25 +66,27 @@ impl OrderbookSubgraphClient {
         end_timestamp: Option<u64>,
     ) -> Result<Vec<SgTrade>, OrderbookSubgraphClientError> {
         let mut all_pages_merged = vec![];
-        let mut page = 1;
+        let mut cursor = None;
 
         loop {
+            let pagination_args = SgPaginationArgs {
+                page: 1,
+                page_size: ALL_PAGES_QUERY_PAGE_SIZE,
+                cursor,
+            };
             let page_data = self
                 .order_trades_list(
                     order_id.clone(),
-                    SgPaginationArgs {
-                        page,
-                        page_size: ALL_PAGES_QUERY_PAGE_SIZE,
-                    },
+                    pagination_args.clone(),
                     start_timestamp,
                     end_timestamp,
                 )
                 .await?;
-            if page_data.is_empty() {
+            cursor = pagination_args.next_cursor(&page_data)?;
+            all_pages_merged.extend(page_data);
+            if cursor.is_none() {
                 break;
             }
-            all_pages_merged.extend(page_data);
-            page += 1
         }
         Ok(all_pages_merged)
     }
@@ -267,6 +283,7 @@ mod tests {
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_trades = vec![default_sg_trade(), default_sg_trade()];
 
@@ -295,6 +312,7 @@ mod tests {
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -315,7 +333,11 @@ mod tests {
         let client = setup_client(&sg_server);
         let order_id = Id::new("0xorder_page2");
         let page_size = 10;
-        let pagination_args = SgPaginationArgs { page: 2, page_size };
+        let pagination_args = SgPaginationArgs {
+            page: 2,
+            page_size,
+            cursor: None,
+        };
         let expected_skip = page_size;
         let expected_trades = vec![default_sg_trade()];
 
@@ -345,6 +367,7 @@ mod tests {
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -376,7 +399,7 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0");
+                .body_contains("\"excludeIds\":null");
             then.status(200)
                 .json_body(json!({"data": {"trades": trades_page1}}));
         });
@@ -385,18 +408,10 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE));
+                .body_contains("\"excludeIds\":[");
             then.status(200)
                 .json_body(json!({"data": {"trades": trades_page2}}));
         });
-        sg_server.mock(|when, then| {
-            when.method(POST)
-                .path("/")
-                .body_contains(order_id.inner())
-                .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE * 2));
-            then.status(200).json_body(json!({"data": {"trades": []}}));
-        });
 
         let result = client.order_trades_list_all(order_id, None, None).await;
         assert!(result.is_ok());
@@ -419,7 +434,7 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0");
+                .body_contains("\"excludeIds\":null");
             then.status(200)
                 .json_body(json!({"data": {"trades": trades_page1}}));
         });
@@ -428,7 +443,7 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE));
+                .body_contains("\"excludeIds\":[");
             then.status(200).json_body(json!({"data": {"trades": []}}));
         });
 
@@ -449,7 +464,7 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0");
+                .body_contains("\"excludeIds\":null");
             then.status(200).json_body(json!({"data": {"trades": []}}));
         });
 
@@ -472,7 +487,7 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0");
+                .body_contains("\"excludeIds\":null");
             then.status(200)
                 .json_body(json!({"data": {"trades": trades_page1}}));
         });
@@ -481,7 +496,7 @@ mod tests {
                 .path("/")
                 .body_contains(order_id.inner())
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE));
+                .body_contains("\"excludeIds\":[");
             then.status(500);
         });
 
@@ -1,10 +1,14 @@
 use super::*;
-use crate::types::common::SgTokensListAllQuery;
+use crate::types::common::{SgBlockQueryVariables, SgTokensListAllQuery};
 
 impl OrderbookSubgraphClient {
     /// Fetch all tokens directly from ERC20 entities
     pub async fn tokens_list_all(&self) -> Result<Vec<SgErc20>, OrderbookSubgraphClientError> {
-        let data = self.query::<SgTokensListAllQuery, ()>(()).await?;
+        let data = self
+            .query::<SgTokensListAllQuery, SgBlockQueryVariables>(SgBlockQueryVariables {
+                block: self.block_height()?,
+            })
+            .await?;
 
         Ok(data.tokens)
     }
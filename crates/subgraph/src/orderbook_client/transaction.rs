@@ -6,7 +6,10 @@ impl OrderbookSubgraphClient {
         id: Id,
     ) -> Result<SgTransaction, OrderbookSubgraphClientError> {
         let data = self
-            .query::<SgTransactionDetailQuery, SgIdQueryVariables>(SgIdQueryVariables { id: &id })
+            .query::<SgTransactionDetailQuery, SgIdQueryVariables>(SgIdQueryVariables {
+                id: &id,
+                block: self.block_height()?,
+            })
             .await?;
         let transaction = data
             .transaction
@@ -23,6 +26,7 @@ impl OrderbookSubgraphClient {
             .query::<SgTransactionAddOrdersQuery, TransactionAddOrdersVariables>(
                 TransactionAddOrdersVariables {
                     id: SgBytes(id.inner().to_string()),
+                    block: self.block_height()?,
                 },
             )
             .await?;
@@ -43,6 +47,7 @@ impl OrderbookSubgraphClient {
             .query::<SgTransactionRemoveOrdersQuery, TransactionRemoveOrdersVariables>(
                 TransactionRemoveOrdersVariables {
                     id: SgBytes(id.inner().to_string()),
+                    block: self.block_height()?,
                 },
             )
             .await?;
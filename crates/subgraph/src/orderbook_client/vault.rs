@@ -1,12 +1,18 @@
 use rain_math_float::Float;
 
+use super::local_list_cache::{LocalListCache, LOCAL_LIST_TTL};
 use super::*;
 
+static LOCAL_VAULTS: LocalListCache<SgVault> = LocalListCache::new(LOCAL_LIST_TTL);
+
 impl OrderbookSubgraphClient {
     /// Fetch single vault
     pub async fn vault_detail(&self, id: Id) -> Result<SgVault, OrderbookSubgraphClientError> {
         let data = self
-            .query::<SgVaultDetailQuery, SgIdQueryVariables>(SgIdQueryVariables { id: &id })
+            .query::<SgVaultDetailQuery, SgIdQueryVariables>(SgIdQueryVariables {
+                id: &id,
+                block: self.block_height()?,
+            })
             .await?;
         let vault = data.vault.ok_or(OrderbookSubgraphClientError::Empty)?;
 
@@ -14,12 +20,43 @@ impl OrderbookSubgraphClient {
     }
 
     /// Fetch all vaults, paginated
+    ///
+    /// Filters and sort orders the subgraph can't apply are applied to all
+    /// matching vaults before the page is cut, which only works with page
+    /// numbers. The matching vaults are fetched for the first page and reused
+    /// for the following ones for up to 30 seconds.
     pub async fn vaults_list(
         &self,
         filter_args: SgVaultsListFilterArgs,
         pagination_args: SgPaginationArgs,
     ) -> Result<Vec<SgVault>, OrderbookSubgraphClientError> {
-        let pagination_variables = Self::parse_pagination_args(pagination_args);
+        if filter_args.is_server_side() {
+            return self.vaults_list_query(&filter_args, pagination_args).await;
+        }
+        self.local_list_page(&LOCAL_VAULTS, &filter_args, pagination_args, || async {
+            Ok(filter_args.apply_local(self.vaults_list_matching(&filter_args).await?)?)
+        })
+        .await
+    }
+
+    async fn vaults_list_query(
+        &self,
+        filter_args: &SgVaultsListFilterArgs,
+        pagination_args: SgPaginationArgs,
+    ) -> Result<Vec<SgVault>, OrderbookSubgraphClientError> {
+        let pagination_variables = Self::parse_pagination_args(pagination_args)?;
+        let cursor_id = match pagination_variables.cursor {
+            None => None,
+            Some(SgPaginationCursor::Id { id }) => Some(SgBytes(id)),
+            Some(cursor) => {
+                return Err(PaginationClientError::InvalidCursor(cursor.encode()).into());
+            }
+        };
+        // A cursor continues after the last id in the direction of the sort
+        let (id_gt, id_lt) = match filter_args.sort_direction {
+            SgSortDirection::Asc => (cursor_id, None),
+            SgSortDirection::Desc => (None, cursor_id),
+        };
 
         let balance_not = if filter_args.hide_zero_balance {
             Some(SgBytes(Float::default().get_inner().to_string()))
@@ -31,6 +68,15 @@ impl OrderbookSubgraphClient {
             owner_in: filter_args.owners.clone(),
             balance_not,
             token_in: filter_args.tokens.clone(),
+            id_lt: id_lt.clone(),
+            id_gt: id_gt.clone(),
+            orderbook_in: filter_args
+                .orderbooks
+                .iter()
+                .map(|orderbook| orderbook.0.to_lowercase())
+                .collect(),
+            owner_not_in: filter_args.exclude_owners.clone(),
+            vault_id_in: filter_args.vault_ids.clone(),
         };
 
         let variables = SgVaultsListQueryVariables {
@@ -39,11 +85,18 @@ impl OrderbookSubgraphClient {
             filters: if !filter_args.owners.is_empty()
                 || filter_args.hide_zero_balance
                 || !filter_args.tokens.is_empty()
+                || id_lt.is_some()
+                || id_gt.is_some()
+                || !filter_args.orderbooks.is_empty()
+                || !filter_args.exclude_owners.is_empty()
+                || !filter_args.vault_ids.is_empty()
             {
                 Some(filters)
             } else {
                 None
             },
+            order_direction: filter_args.sort_direction.into(),
+            block: self.block_height()?,
         };
 
         let data = self
@@ -53,32 +106,22 @@ impl OrderbookSubgraphClient {
         Ok(data.vaults)
     }
 
+    /// Fetch all pages of vaults matching the subgraph side filters
+    pub(crate) async fn vaults_list_matching(
+        &self,
+        filter_args: &SgVaultsListFilterArgs,
+    ) -> Result<Vec<SgVault>, OrderbookSubgraphClientError> {
+        self.query_all_pages(|pagination_args| self.vaults_list_query(filter_args, pagination_args))
+            .await
+    }
+
     /// Fetch all pages of vaults_list query
     pub async fn vaults_list_all(&self) -> Result<Vec<SgVault>, OrderbookSubgraphClientError> {
-        let mut all_pages_merged = vec![];
-        let mut page = 1;
-
-        loop {
-            let page_data = self
-                .vaults_list(
-                    SgVaultsListFilterArgs {
-                        owners: vec![],
-                        hide_zero_balance: true,
-                        tokens: vec![],
-                    },
-                    SgPaginationArgs {
-                        page,
-                        page_size: ALL_PAGES_QUERY_PAGE_SIZE,
-                    },
-                )
-                .await?;
-            if page_data.is_empty() {
-                break;
-            }
-            all_pages_merged.extend(page_data);
-            page += 1
-        }
-        Ok(all_pages_merged)
+        self.vaults_list_matching(&SgVaultsListFilterArgs {
+            hide_zero_balance: true,
+            ..Default::default()
+        })
+        .await
     }
 
     /// Fetch all vault deposits + withdrawals merged paginated, for a single vault
@@ -87,15 +130,29 @@ impl OrderbookSubgraphClient {
         id: cynic::Id,
         pagination_args: SgPaginationArgs,
     ) -> Result<Vec<SgVaultBalanceChangeUnwrapped>, OrderbookSubgraphClientError> {
-        let pagination_vars = Self::parse_pagination_args(pagination_args);
+        let pagination_vars = Self::parse_pagination_args(pagination_args)?;
+        let timestamp_lte = match &pagination_vars.cursor {
+            None => None,
+            Some(SgPaginationCursor::TimestampOffset { timestamp, .. }) => {
+                Some(SgBigInt(timestamp.clone()))
+            }
+            Some(cursor) => {
+                return Err(PaginationClientError::InvalidCursor(cursor.encode()).into());
+            }
+        };
         let res = self
             .query_paginated(
                 pagination_vars,
-                VaultBalanceChangesListPageQueryClient::new(self.url.clone()),
+                VaultBalanceChangesListPageQueryClient::with_transport(
+                    self.url.clone(),
+                    self.transport.clone(),
+                ),
                 SgPaginationWithIdQueryVariables {
                     id: SgBytes(id.inner().to_string()),
                     skip: Some(0),
                     first: Some(200),
+                    timestamp_lte,
+                    block: self.block_height()?,
                 },
                 200,
             )
@@ -110,23 +167,22 @@ impl OrderbookSubgraphClient {
         id: cynic::Id,
     ) -> Result<Vec<SgVaultBalanceChangeUnwrapped>, OrderbookSubgraphClientError> {
         let mut all_pages_merged = vec![];
-        let mut page = 1;
+        let mut cursor = None;
 
         loop {
+            let pagination_args = SgPaginationArgs {
+                page: 1,
+                page_size: ALL_PAGES_QUERY_PAGE_SIZE,
+                cursor,
+            };
             let page_data = self
-                .vault_balance_changes_list(
-                    id.clone(),
-                    SgPaginationArgs {
-                        page,
-                        page_size: ALL_PAGES_QUERY_PAGE_SIZE,
-                    },
-                )
+                .vault_balance_changes_list(id.clone(), pagination_args.clone())
                 .await?;
-            if page_data.is_empty() {
+            cursor = pagination_args.next_cursor(&page_data)?;
+            all_pages_merged.extend(page_data);
+            if cursor.is_none() {
                 break;
             }
-            all_pages_merged.extend(page_data);
-            page += 1
         }
         Ok(all_pages_merged)
     }
@@ -355,10 +411,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault(), default_sg_vault()];
 
@@ -380,6 +438,40 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_vaults_list_at_block() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server).at_block(Some(18_000_000));
+        let filter_args = SgVaultsListFilterArgs {
+            owners: vec![],
+            hide_zero_balance: false,
+            tokens: vec![],
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 10,
+            cursor: None,
+        };
+        let expected_vaults = vec![default_sg_vault()];
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("block: $block")
+                .body_contains("\"block\":{\"number\":18000000}");
+            then.status(200)
+                .json_body(json!({"data": {"vaults": expected_vaults}}));
+        });
+
+        let vaults = client
+            .vaults_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        assert_eq!(vaults.len(), 1);
+        mock.assert();
+    }
+
     #[tokio::test]
     async fn test_vaults_list_with_owner_filter() {
         let sg_server = MockServer::start_async().await;
@@ -389,10 +481,12 @@ mod tests {
             owners: vec![owner_address.clone()],
             hide_zero_balance: false,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 5,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault()];
 
@@ -418,10 +512,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: true,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 5,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault()];
 
@@ -446,10 +542,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 2,
             page_size: 3,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault()];
 
@@ -474,10 +572,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -498,10 +598,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -516,6 +618,44 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_vaults_list_with_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgVaultsListFilterArgs {
+            owners: vec![],
+            hide_zero_balance: false,
+            tokens: vec![],
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 10,
+            cursor: Some(
+                SgPaginationCursor::Id {
+                    id: "0xvault1".to_string(),
+                }
+                .encode(),
+            ),
+        };
+        let expected_vaults = vec![default_sg_vault()];
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains("\"id_lt\":\"0xvault1\"");
+            then.status(200)
+                .json_body(json!({"data": {"vaults": expected_vaults}}));
+        });
+
+        let vaults = client
+            .vaults_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(vaults.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_vaults_list_all_multiple_pages() {
         let sg_server = MockServer::start_async().await;
@@ -529,8 +669,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0")
-                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"");
+                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"}");
             then.status(200)
                 .json_body(json!({"data": {"vaults": vaults_page1}}));
         });
@@ -538,19 +677,10 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"");
+                .body_contains("\"id_lt\"");
             then.status(200)
                 .json_body(json!({"data": {"vaults": vaults_page2}}));
         });
-        sg_server.mock(|when, then| {
-            when.method(POST)
-                .path("/")
-                .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE * 2))
-                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"");
-            then.status(200).json_body(json!({"data": {"vaults": []}}));
-        });
 
         let result = client.vaults_list_all().await;
         let vaults = result.unwrap();
@@ -565,8 +695,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0")
-                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"");
+                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"}");
             then.status(200).json_body(json!({"data": {"vaults": []}}));
         });
         let result = client.vaults_list_all().await;
@@ -585,7 +714,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains("\"skip\":0");
+                .body_contains("\"balance_not\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"}");
             then.status(200)
                 .json_body(json!({"data": {"vaults": vaults_page1}}));
         });
@@ -593,7 +722,7 @@ mod tests {
             when.method(POST)
                 .path("/")
                 .body_contains(format!("\"first\":{}", ALL_PAGES_QUERY_PAGE_SIZE))
-                .body_contains(format!("\"skip\":{}", ALL_PAGES_QUERY_PAGE_SIZE));
+                .body_contains("\"id_lt\"");
             then.status(500);
         });
 
@@ -613,6 +742,7 @@ mod tests {
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_changes = vec![default_sg_vault_balance_change_unwrapped()];
 
@@ -646,6 +776,7 @@ mod tests {
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -669,6 +800,7 @@ mod tests {
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
 
         sg_server.mock(|when, then| {
@@ -761,10 +893,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![token_address.clone()],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault()];
 
@@ -792,10 +926,12 @@ mod tests {
             owners: vec![],
             hide_zero_balance: false,
             tokens: vec![token1.clone(), token2.clone()],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 10,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault(), default_sg_vault()];
 
@@ -823,10 +959,12 @@ mod tests {
             owners: vec![owner_address.clone()],
             hide_zero_balance: true,
             tokens: vec![token_address.clone()],
+            ..Default::default()
         };
         let pagination_args = SgPaginationArgs {
             page: 1,
             page_size: 5,
+            cursor: None,
         };
         let expected_vaults = vec![default_sg_vault()];
 
@@ -844,4 +982,141 @@ mod tests {
         let vaults = result.unwrap();
         assert_eq!(vaults.len(), expected_vaults.len());
     }
+
+    #[tokio::test]
+    async fn test_vaults_list_with_server_side_filters() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgVaultsListFilterArgs {
+            orderbooks: vec![SgBytes("0xOrderbook".to_string())],
+            exclude_owners: vec![SgBytes("0xowner".to_string())],
+            vault_ids: vec![SgBytes("0x01".to_string())],
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: None,
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains(r#""orderbook_in":["0xorderbook"]"#)
+                .body_contains(r#""owner_not_in":["0xowner"]"#)
+                .body_contains(r#""vaultId_in":["0x01"]"#);
+            then.status(200)
+                .json_body(json!({"data": {"vaults": [default_sg_vault()]}}));
+        });
+
+        let vaults = client
+            .vaults_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(vaults.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_vaults_list_balance_bounds_and_sort() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let vault_with_balance = |id: &str, balance: Float| {
+            let mut vault = default_sg_vault();
+            vault.id = SgBytes(id.to_string());
+            vault.balance = SgBytes(balance.as_hex());
+            vault
+        };
+        let filter_args = SgVaultsListFilterArgs {
+            min_balance: Some("1".to_string()),
+            max_balance: Some("5".to_string()),
+            sort_by: SgVaultsSortBy::Balance,
+            sort_direction: SgSortDirection::Asc,
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: None,
+        };
+
+        sg_server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200).json_body(json!({"data": {"vaults": [
+                vault_with_balance("0x4", F3),
+                vault_with_balance("0x3", F0_5),
+                vault_with_balance("0x2", F5),
+                vault_with_balance("0x1", F10),
+                vault_with_balance("0x0", F1),
+            ]}}));
+        });
+
+        let vaults = client
+            .vaults_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        let ids: Vec<_> = vaults.iter().map(|vault| vault.id.0.as_str()).collect();
+        assert_eq!(ids, vec!["0x0", "0x4", "0x2"]);
+    }
+
+    #[tokio::test]
+    async fn test_vaults_list_ascending_cursor() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgVaultsListFilterArgs {
+            sort_direction: SgSortDirection::Asc,
+            ..Default::default()
+        };
+        let cursor = SgPaginationCursor::Id {
+            id: "0x02".to_string(),
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: Some(cursor.encode()),
+        };
+
+        let mock = sg_server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_contains(r#""id_gt":"0x02""#)
+                .body_contains(r#""order_direction":"asc""#);
+            then.status(200)
+                .json_body(json!({"data": {"vaults": [default_sg_vault()]}}));
+        });
+
+        let vaults = client
+            .vaults_list(filter_args, pagination_args)
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(vaults.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_vaults_list_invalid_min_balance() {
+        let sg_server = MockServer::start_async().await;
+        let client = setup_client(&sg_server);
+        let filter_args = SgVaultsListFilterArgs {
+            min_balance: Some("not a number".to_string()),
+            ..Default::default()
+        };
+        let pagination_args = SgPaginationArgs {
+            page: 1,
+            page_size: 5,
+            cursor: None,
+        };
+
+        sg_server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .json_body(json!({"data": {"vaults": [default_sg_vault()]}}));
+        });
+
+        let err = client
+            .vaults_list(filter_args, pagination_args)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OrderbookSubgraphClientError::FloatError(_)));
+    }
 }
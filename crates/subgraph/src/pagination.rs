@@ -9,13 +9,135 @@ use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 pub struct SgPaginationArgs {
     pub page: u16,
     pub page_size: u16,
+    /// Opaque cursor returned with the previous page. When set, `page` is
+    /// ignored and the query continues right after the previous page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub cursor: Option<String>,
 }
 impl_wasm_traits!(SgPaginationArgs);
 
+impl SgPaginationArgs {
+    /// Cursor pointing right after the last item of `page`, or None if
+    /// `page` was the last one
+    pub fn next_cursor<T: SgPaginationItem>(
+        &self,
+        page: &[T],
+    ) -> Result<Option<String>, PaginationClientError> {
+        if page.is_empty() || page.len() < self.page_size as usize {
+            return Ok(None);
+        }
+        let previous = self
+            .cursor
+            .as_deref()
+            .map(SgPaginationCursor::decode)
+            .transpose()?;
+        Ok(SgPaginationCursor::after(previous, page).map(|cursor| cursor.encode()))
+    }
+}
+
+/// Sort key of a list item, used to build the cursor of the next page
+#[derive(Clone, Debug, PartialEq)]
+pub enum SgPaginationKey<'a> {
+    /// Lists ordered by id
+    Id(&'a str),
+    /// Lists ordered by timestamp whose items can be filtered by id
+    Timestamp { timestamp: &'a str, id: &'a str },
+    /// Lists ordered by timestamp, descending, whose items have no id filter
+    TimestampOnly(&'a str),
+}
+
+/// Item of a list query that supports cursor pagination
+pub trait SgPaginationItem {
+    fn pagination_key(&self) -> SgPaginationKey<'_>;
+}
+
+/// Decoded position in a list query.
+///
+/// Keyset pagination avoids the `skip` limit of graph-node. Lists ordered by
+/// timestamp continue from `timestamp_lte` and exclude the items already
+/// returned at that timestamp, so ties are never skipped or repeated.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SgPaginationCursor {
+    Id { id: String },
+    Timestamp { timestamp: String, ids: Vec<String> },
+    TimestampOffset { timestamp: String, offset: u32 },
+}
+
+impl SgPaginationCursor {
+    pub fn encode(&self) -> String {
+        alloy::hex::encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, PaginationClientError> {
+        let bytes = alloy::hex::decode(cursor)
+            .map_err(|_| PaginationClientError::InvalidCursor(cursor.to_string()))?;
+        serde_json::from_slice(&bytes)
+            .map_err(|_| PaginationClientError::InvalidCursor(cursor.to_string()))
+    }
+
+    /// Cursor after the last item of `page`, carrying over the ties already
+    /// returned by `previous`
+    pub fn after<T: SgPaginationItem>(previous: Option<Self>, page: &[T]) -> Option<Self> {
+        let last = page.last()?.pagination_key();
+        let cursor = match last {
+            SgPaginationKey::Id(id) => Self::Id { id: id.to_string() },
+            SgPaginationKey::Timestamp { timestamp, .. } => {
+                let mut ids = match previous {
+                    Some(Self::Timestamp {
+                        timestamp: previous_timestamp,
+                        ids,
+                    }) if previous_timestamp == timestamp => ids,
+                    _ => vec![],
+                };
+                ids.extend(page.iter().filter_map(|item| match item.pagination_key() {
+                    SgPaginationKey::Timestamp { timestamp: t, id } if t == timestamp => {
+                        Some(id.to_string())
+                    }
+                    _ => None,
+                }));
+                Self::Timestamp {
+                    timestamp: timestamp.to_string(),
+                    ids,
+                }
+            }
+            SgPaginationKey::TimestampOnly(timestamp) => {
+                let previous_offset = match previous {
+                    Some(Self::TimestampOffset {
+                        timestamp: previous_timestamp,
+                        offset,
+                    }) if previous_timestamp == timestamp => offset,
+                    _ => 0,
+                };
+                let offset = page
+                    .iter()
+                    .filter(|item| {
+                        item.pagination_key() == SgPaginationKey::TimestampOnly(timestamp)
+                    })
+                    .count() as u32;
+                Self::TimestampOffset {
+                    timestamp: timestamp.to_string(),
+                    offset: previous_offset + offset,
+                }
+            }
+        };
+        Some(cursor)
+    }
+}
+
+/// Results of a cursor paginated list query
+#[derive(Clone, Debug)]
+pub struct SgPage<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct SgQueryPaginationVariables {
     pub skip: Option<i32>,
     pub first: Option<i32>,
+    pub cursor: Option<SgPaginationCursor>,
 }
 
 #[derive(Error, Debug)]
@@ -24,6 +146,8 @@ pub enum PaginationClientError {
     TryFromIntError(#[from] TryFromIntError),
     #[error(transparent)]
     CynicClientError(#[from] CynicClientError),
+    #[error("Invalid pagination cursor: {0}")]
+    InvalidCursor(String),
 }
 
 /// Utiltity for client-side pagination of arbitrary queries
@@ -35,14 +159,31 @@ pub enum PaginationClientError {
 /// After fetching the required pages, it retuns the desired page based
 /// the given 'skip' + 'first'.
 pub trait PaginationClient {
-    fn parse_pagination_args(pagination_args: SgPaginationArgs) -> SgQueryPaginationVariables {
+    fn parse_pagination_args(
+        pagination_args: SgPaginationArgs,
+    ) -> Result<SgQueryPaginationVariables, PaginationClientError> {
         let first: i32 = pagination_args.page_size.into();
+
+        if let Some(cursor) = pagination_args.cursor {
+            let cursor = SgPaginationCursor::decode(&cursor)?;
+            let skip = match cursor {
+                SgPaginationCursor::TimestampOffset { offset, .. } => i32::try_from(offset)?,
+                _ => 0,
+            };
+            return Ok(SgQueryPaginationVariables {
+                first: Some(first),
+                skip: Some(skip),
+                cursor: Some(cursor),
+            });
+        }
+
         let skip: i32 = ((pagination_args.page - 1) * pagination_args.page_size).into();
 
-        SgQueryPaginationVariables {
+        Ok(SgQueryPaginationVariables {
             first: Some(first),
             skip: Some(skip),
-        }
+            cursor: None,
+        })
     }
 
     async fn query_paginated<T: Clone, V: PageQueryVariables + Clone, Q: PageQueryClient<T, V>>(
@@ -182,6 +323,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: None,
                     first: None,
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -206,6 +348,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(100),
                     first: None,
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -231,6 +374,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: None,
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -255,6 +399,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(50),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -279,6 +424,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(2000),
                     first: None,
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -303,6 +449,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: None,
                     first: Some(2000),
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -327,6 +474,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(2000),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client,
                 page_query_variables,
@@ -351,6 +499,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(50),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client.clone(),
                 page_query_variables.clone(),
@@ -366,6 +515,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(50),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client.clone(),
                 page_query_variables.clone(),
@@ -381,6 +531,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(50),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client.clone(),
                 page_query_variables.clone(),
@@ -396,6 +547,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(50),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client.clone(),
                 page_query_variables.clone(),
@@ -411,6 +563,7 @@ mod test {
                 SgQueryPaginationVariables {
                     skip: Some(50),
                     first: Some(500),
+                    cursor: None,
                 },
                 page_query_client.clone(),
                 page_query_variables.clone(),
@@ -426,36 +579,192 @@ mod test {
         let query_pagination_vars = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
             page: 1,
             page_size: 25,
-        });
+            cursor: None,
+        })
+        .unwrap();
         assert_eq!(query_pagination_vars.skip, Some(0));
         assert_eq!(query_pagination_vars.first, Some(25));
 
         let query_pagination_vars = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
             page: 2,
             page_size: 25,
-        });
+            cursor: None,
+        })
+        .unwrap();
         assert_eq!(query_pagination_vars.skip, Some(25));
         assert_eq!(query_pagination_vars.first, Some(25));
 
         let query_pagination_vars = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
             page: 3,
             page_size: 25,
-        });
+            cursor: None,
+        })
+        .unwrap();
         assert_eq!(query_pagination_vars.skip, Some(50));
         assert_eq!(query_pagination_vars.first, Some(25));
 
         let query_pagination_vars = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
             page: 1,
             page_size: 5,
-        });
+            cursor: None,
+        })
+        .unwrap();
         assert_eq!(query_pagination_vars.skip, Some(0));
         assert_eq!(query_pagination_vars.first, Some(5));
 
         let query_pagination_vars = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
             page: 1,
             page_size: 10,
-        });
+            cursor: None,
+        })
+        .unwrap();
         assert_eq!(query_pagination_vars.skip, Some(0));
         assert_eq!(query_pagination_vars.first, Some(10));
     }
+
+    struct MockTimestampItem(&'static str, &'static str);
+    impl SgPaginationItem for MockTimestampItem {
+        fn pagination_key(&self) -> SgPaginationKey<'_> {
+            SgPaginationKey::Timestamp {
+                timestamp: self.0,
+                id: self.1,
+            }
+        }
+    }
+
+    struct MockTimestampOnlyItem(&'static str);
+    impl SgPaginationItem for MockTimestampOnlyItem {
+        fn pagination_key(&self) -> SgPaginationKey<'_> {
+            SgPaginationKey::TimestampOnly(self.0)
+        }
+    }
+
+    #[test]
+    fn parse_pagination_args_with_cursor() {
+        let cursor = SgPaginationCursor::TimestampOffset {
+            timestamp: "100".to_string(),
+            offset: 3,
+        };
+        let query_pagination_vars = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
+            page: 5,
+            page_size: 25,
+            cursor: Some(cursor.encode()),
+        })
+        .unwrap();
+        assert_eq!(query_pagination_vars.skip, Some(3));
+        assert_eq!(query_pagination_vars.first, Some(25));
+        assert_eq!(query_pagination_vars.cursor, Some(cursor));
+
+        let err = MockPaginationClient::parse_pagination_args(SgPaginationArgs {
+            page: 1,
+            page_size: 25,
+            cursor: Some("not-a-cursor".to_string()),
+        })
+        .unwrap_err();
+        assert!(matches!(err, PaginationClientError::InvalidCursor(_)));
+    }
+
+    #[test]
+    fn cursor_encode_decode() {
+        let cursor = SgPaginationCursor::Timestamp {
+            timestamp: "100".to_string(),
+            ids: vec!["0x01".to_string(), "0x02".to_string()],
+        };
+        assert_eq!(
+            SgPaginationCursor::decode(&cursor.encode()).unwrap(),
+            cursor
+        );
+    }
+
+    #[test]
+    fn next_cursor_timestamp() {
+        let args = SgPaginationArgs {
+            page: 1,
+            page_size: 3,
+            cursor: None,
+        };
+        let page = vec![
+            MockTimestampItem("300", "0x03"),
+            MockTimestampItem("200", "0x02"),
+            MockTimestampItem("200", "0x01"),
+        ];
+        let cursor = args.next_cursor(&page).unwrap().unwrap();
+        assert_eq!(
+            SgPaginationCursor::decode(&cursor).unwrap(),
+            SgPaginationCursor::Timestamp {
+                timestamp: "200".to_string(),
+                ids: vec!["0x02".to_string(), "0x01".to_string()],
+            }
+        );
+
+        // ties spanning several pages keep the ids already returned
+        let args = SgPaginationArgs {
+            cursor: Some(cursor),
+            ..args
+        };
+        let page = vec![
+            MockTimestampItem("200", "0x06"),
+            MockTimestampItem("200", "0x05"),
+            MockTimestampItem("200", "0x04"),
+        ];
+        let cursor = args.next_cursor(&page).unwrap().unwrap();
+        assert_eq!(
+            SgPaginationCursor::decode(&cursor).unwrap(),
+            SgPaginationCursor::Timestamp {
+                timestamp: "200".to_string(),
+                ids: vec![
+                    "0x02".to_string(),
+                    "0x01".to_string(),
+                    "0x06".to_string(),
+                    "0x05".to_string(),
+                    "0x04".to_string(),
+                ],
+            }
+        );
+
+        // a short page is the last one
+        assert_eq!(
+            args.next_cursor(&[MockTimestampItem("100", "0x07")])
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn next_cursor_timestamp_offset() {
+        let args = SgPaginationArgs {
+            page: 1,
+            page_size: 2,
+            cursor: Some(
+                SgPaginationCursor::TimestampOffset {
+                    timestamp: "200".to_string(),
+                    offset: 2,
+                }
+                .encode(),
+            ),
+        };
+        let cursor = args
+            .next_cursor(&[MockTimestampOnlyItem("200"), MockTimestampOnlyItem("200")])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            SgPaginationCursor::decode(&cursor).unwrap(),
+            SgPaginationCursor::TimestampOffset {
+                timestamp: "200".to_string(),
+                offset: 4,
+            }
+        );
+
+        let cursor = args
+            .next_cursor(&[MockTimestampOnlyItem("200"), MockTimestampOnlyItem("100")])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            SgPaginationCursor::decode(&cursor).unwrap(),
+            SgPaginationCursor::TimestampOffset {
+                timestamp: "100".to_string(),
+                offset: 1,
+            }
+        );
+    }
 }
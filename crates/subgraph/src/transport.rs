@@ -0,0 +1,229 @@
+use crate::cynic_client::CynicClientError;
+use once_cell::sync::Lazy;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
+
+pub const DEFAULT_TIMEOUT_MS: u32 = 30_000;
+pub const DEFAULT_MAX_RETRIES: u32 = 2;
+pub const DEFAULT_INITIAL_BACKOFF_MS: u32 = 250;
+const MAX_BACKOFF_MS: u32 = 8_000;
+
+/// Shared http client so every subgraph request reuses the same connection pool
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
+pub(crate) static DEFAULT_TRANSPORT_CONFIG: Lazy<SubgraphTransportConfig> =
+    Lazy::new(SubgraphTransportConfig::default);
+
+/// How requests to a subgraph are sent: timeout, retry policy, extra headers
+/// and the ordered list of urls to fall back to when the primary url fails.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SubgraphTransportConfig {
+    /// Per-request timeout in milliseconds
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub timeout_ms: u32,
+    /// Number of retries of a failed request against the same url
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on every subsequent retry
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub initial_backoff_ms: u32,
+    /// Headers sent with every request, e.g. an api key
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub headers: BTreeMap<String, String>,
+    /// Urls tried in order once the primary url is exhausted
+    #[cfg_attr(target_family = "wasm", tsify(optional, type = "string[]"))]
+    pub fallback_urls: Vec<Url>,
+}
+impl_wasm_traits!(SubgraphTransportConfig);
+
+impl Default for SubgraphTransportConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff_ms: DEFAULT_INITIAL_BACKOFF_MS,
+            headers: BTreeMap::new(),
+            fallback_urls: vec![],
+        }
+    }
+}
+
+impl SubgraphTransportConfig {
+    /// Exponential backoff before the given retry (0-based), capped at 8 seconds
+    pub fn backoff_delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .initial_backoff_ms
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(MAX_BACKOFF_MS);
+        Duration::from_millis(delay as u64)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+#[cfg(target_family = "wasm")]
+pub(crate) async fn sleep(duration: Duration) {
+    wasmtimer::tokio::sleep(duration).await;
+}
+
+async fn send<B: Serialize>(
+    url: &Url,
+    body: &B,
+    config: &SubgraphTransportConfig,
+) -> Result<reqwest::Response, CynicClientError> {
+    let mut request = HTTP_CLIENT
+        .post(url.clone())
+        .timeout(Duration::from_millis(config.timeout_ms as u64))
+        .json(body);
+    for (name, value) in &config.headers {
+        request = request.header(name, value);
+    }
+    Ok(request.send().await?.error_for_status()?)
+}
+
+/// Posts the body to the primary url, retrying retryable failures with
+/// backoff and then moving on to the fallback urls in order.
+/// Returns the last error once every url has been exhausted.
+pub(crate) async fn post_with_retries<B: Serialize>(
+    url: &Url,
+    body: &B,
+    config: &SubgraphTransportConfig,
+) -> Result<reqwest::Response, CynicClientError> {
+    let mut last_error = None;
+    for url in std::iter::once(url).chain(config.fallback_urls.iter()) {
+        for attempt in 0..=config.max_retries {
+            if attempt > 0 {
+                sleep(config.backoff_delay(attempt - 1)).await;
+            }
+            match send(url, body, config).await {
+                Ok(response) => return Ok(response),
+                Err(err) => {
+                    let retryable = err.is_retryable();
+                    last_error = Some(err);
+                    if !retryable {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    Err(last_error.unwrap_or(CynicClientError::Empty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay() {
+        let config = SubgraphTransportConfig::default();
+        assert_eq!(config.backoff_delay(0), Duration::from_millis(250));
+        assert_eq!(config.backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(config.backoff_delay(2), Duration::from_millis(1000));
+        assert_eq!(config.backoff_delay(10), Duration::from_millis(8000));
+        assert_eq!(config.backoff_delay(u32::MAX), Duration::from_millis(8000));
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    mod non_wasm {
+        use super::*;
+        use httpmock::{Method::POST, MockServer};
+        use serde_json::json;
+
+        fn fast_config() -> SubgraphTransportConfig {
+            SubgraphTransportConfig {
+                initial_backoff_ms: 1,
+                ..Default::default()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_retries_server_errors() {
+            let server = MockServer::start_async().await;
+            let mock = server.mock(|when, then| {
+                when.method(POST).path("/");
+                then.status(503);
+            });
+
+            let config = fast_config();
+            let err =
+                post_with_retries(&Url::parse(&server.url("/")).unwrap(), &json!({}), &config)
+                    .await
+                    .unwrap_err();
+            assert!(err.is_retryable());
+            mock.assert_hits(3);
+        }
+
+        #[tokio::test]
+        async fn test_does_not_retry_client_errors() {
+            let server = MockServer::start_async().await;
+            let mock = server.mock(|when, then| {
+                when.method(POST).path("/");
+                then.status(401);
+            });
+
+            let config = fast_config();
+            let err =
+                post_with_retries(&Url::parse(&server.url("/")).unwrap(), &json!({}), &config)
+                    .await
+                    .unwrap_err();
+            assert!(!err.is_retryable());
+            mock.assert_hits(1);
+        }
+
+        #[tokio::test]
+        async fn test_falls_back_to_secondary_url_with_headers() {
+            let primary = MockServer::start_async().await;
+            let secondary = MockServer::start_async().await;
+            let primary_mock = primary.mock(|when, then| {
+                when.method(POST).path("/");
+                then.status(429);
+            });
+            let secondary_mock = secondary.mock(|when, then| {
+                when.method(POST).path("/").header("x-api-key", "secret");
+                then.status(200).json_body(json!({"data": {}}));
+            });
+
+            let config = SubgraphTransportConfig {
+                max_retries: 1,
+                headers: BTreeMap::from([("x-api-key".to_string(), "secret".to_string())]),
+                fallback_urls: vec![Url::parse(&secondary.url("/")).unwrap()],
+                ..fast_config()
+            };
+            let response =
+                post_with_retries(&Url::parse(&primary.url("/")).unwrap(), &json!({}), &config)
+                    .await
+                    .unwrap();
+            assert!(response.status().is_success());
+            primary_mock.assert_hits(2);
+            secondary_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_request_timeout() {
+            let server = MockServer::start_async().await;
+            server.mock(|when, then| {
+                when.method(POST).path("/");
+                then.status(200)
+                    .delay(Duration::from_millis(200))
+                    .json_body(json!({"data": {}}));
+            });
+
+            let config = SubgraphTransportConfig {
+                timeout_ms: 20,
+                max_retries: 0,
+                ..fast_config()
+            };
+            let err =
+                post_with_retries(&Url::parse(&server.url("/")).unwrap(), &json!({}), &config)
+                    .await
+                    .unwrap_err();
+            assert!(matches!(&err, CynicClientError::Request(e) if e.is_timeout()));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::common::{SgAddOrderWithOrder, SgBytes};
+use super::common::{SgAddOrderWithOrder, SgBlockHeight, SgBytes};
 use crate::schema;
 use serde::Serialize;
 #[cfg(target_family = "wasm")]
@@ -7,6 +7,7 @@ use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 #[derive(cynic::QueryVariables, Debug)]
 pub struct TransactionAddOrdersVariables {
     pub id: SgBytes,
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryFragment, Debug, Serialize)]
@@ -14,7 +15,7 @@ pub struct TransactionAddOrdersVariables {
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct SgTransactionAddOrdersQuery {
-    #[arguments(where: { transaction_: { id: $id } })]
+    #[arguments(where: { transaction_: { id: $id } }, block: $block)]
     pub add_orders: Vec<SgAddOrderWithOrder>,
 }
 #[cfg(target_family = "wasm")]
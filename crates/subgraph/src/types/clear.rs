@@ -0,0 +1,20 @@
+use super::common::*;
+use crate::schema;
+use serde::Serialize;
+#[cfg(target_family = "wasm")]
+use wasm_bindgen_utils::prelude::*;
+
+#[derive(cynic::QueryFragment, Debug, Clone, Serialize)]
+#[cynic(graphql_type = "Query", variables = "SgClearsListQueryVariables")]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+pub struct SgClearsListQuery {
+    #[arguments(
+        orderBy: "transaction__timestamp",
+        orderDirection: "desc",
+        skip: $skip,
+        first: $first,
+        where: $filters,
+        block: $block
+    )]
+    pub clears: Vec<SgClear>,
+}
@@ -6,9 +6,24 @@ use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 pub struct SgIdQueryVariables<'a> {
     #[cfg_attr(target_family = "wasm", tsify(type = "string"))]
     pub id: &'a cynic::Id,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
+}
+
+/// Pins a query to the state of the subgraph as of a past block
+#[derive(cynic::InputObject, Debug, Clone, PartialEq, Tsify)]
+#[cynic(graphql_type = "Block_height")]
+pub struct SgBlockHeight {
+    pub number: i32,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone, Tsify)]
+pub struct SgBlockQueryVariables {
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Tsify)]
 #[serde(rename_all = "camelCase")]
 pub struct SgOrdersListFilterArgs {
     pub owners: Vec<SgBytes>,
@@ -16,10 +31,73 @@ pub struct SgOrdersListFilterArgs {
     pub active: Option<bool>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub order_hash: Option<SgBytes>,
+    /// Orders that use any of these tokens as either input or output
     pub tokens: Vec<String>,
+    /// Orders that use any of these tokens as input
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub input_tokens: Vec<String>,
+    /// Orders that use any of these tokens as output
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub output_tokens: Vec<String>,
+    /// Orders added at or after this unix timestamp
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub added_after: Option<u64>,
+    /// Orders added at or before this unix timestamp
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub added_before: Option<u64>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub has_trades: Option<bool>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub orderbooks: Vec<SgBytes>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub exclude_owners: Vec<SgBytes>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub sort_by: SgOrdersSortBy,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub sort_direction: SgSortDirection,
 }
 impl_wasm_traits!(SgOrdersListFilterArgs);
 
+/// Sort keys of order lists. The subgraph sorts by the time orders were added,
+/// trade based keys are computed locally from the trades queried for each
+/// order, which are the latest 100, the subgraph limit for nested lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub enum SgOrdersSortBy {
+    #[default]
+    AddedAt,
+    LastTrade,
+    TradeCount,
+}
+impl_wasm_traits!(SgOrdersSortBy);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub enum SgVaultsSortBy {
+    #[default]
+    Id,
+    Balance,
+}
+impl_wasm_traits!(SgVaultsSortBy);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub enum SgSortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+impl_wasm_traits!(SgSortDirection);
+
 #[derive(cynic::QueryVariables, Debug, Clone, Tsify)]
 pub struct SgPaginationQueryVariables {
     #[cfg_attr(target_family = "wasm", tsify(optional))]
@@ -41,6 +119,27 @@ pub struct SgOrdersListQueryFilters {
     pub inputs_: Option<SgVaultTokenFilter>,
     #[cynic(rename = "outputs_", skip_serializing_if = "Option::is_none")]
     pub outputs_: Option<SgVaultTokenFilter>,
+    #[cynic(rename = "timestampAdded_gte", skip_serializing_if = "Option::is_none")]
+    pub timestamp_added_gte: Option<SgBigInt>,
+    #[cynic(rename = "timestampAdded_lte", skip_serializing_if = "Option::is_none")]
+    pub timestamp_added_lte: Option<SgBigInt>,
+    #[cynic(rename = "id_not_in", skip_serializing_if = "Vec::is_empty")]
+    pub id_not_in: Vec<SgBytes>,
+    #[cynic(rename = "orderbook_in", skip_serializing_if = "Vec::is_empty")]
+    pub orderbook_in: Vec<String>,
+    #[cynic(rename = "owner_not_in", skip_serializing_if = "Vec::is_empty")]
+    pub owner_not_in: Vec<SgBytes>,
+    #[cynic(rename = "trades_", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub trades_: Option<SgOrderTradesFilter>,
+}
+
+/// Matches orders with at least one trade
+#[derive(cynic::InputObject, Debug, Clone, Tsify)]
+#[cynic(graphql_type = "Trade_filter")]
+pub struct SgOrderTradesFilter {
+    #[cynic(rename = "timestamp_gte")]
+    pub timestamp_gte: SgBigInt,
 }
 
 #[derive(cynic::InputObject, Debug, Clone, Tsify)]
@@ -66,6 +165,9 @@ pub struct SgOrdersListQueryVariables {
     #[cynic(rename = "filters")]
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub filters: Option<SgOrdersListQueryAnyFilters>,
+    pub order_direction: SgOrderDirection,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryVariables, Debug, Clone, Tsify)]
@@ -75,10 +177,16 @@ pub struct SgPaginationWithIdQueryVariables {
     pub id: SgBytes,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub skip: Option<i32>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub timestamp_lte: Option<SgBigInt>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryVariables, Debug, Clone, Tsify)]
 pub struct SgPaginationWithTimestampQueryVariables {
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub exclude_ids: Option<Vec<SgBytes>>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub first: Option<i32>,
     pub id: SgBytes,
@@ -88,6 +196,8 @@ pub struct SgPaginationWithTimestampQueryVariables {
     pub timestamp_gte: Option<SgBigInt>,
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub timestamp_lte: Option<SgBigInt>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryFragment, Debug, Serialize, Clone, Tsify)]
@@ -115,6 +225,8 @@ pub struct SgOrder {
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub meta: Option<SgRainMetaV1>,
     pub add_events: Vec<SgAddOrder>,
+    // latest trades
+    #[arguments(orderBy: timestamp, orderDirection: desc)]
     pub trades: Vec<SgOrderStructPartialTrade>,
     pub remove_events: Vec<SgRemoveOrder>,
 }
@@ -145,12 +257,35 @@ pub struct SgOrderAsIO {
     pub active: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Tsify)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Tsify)]
 #[serde(rename_all = "camelCase")]
 pub struct SgVaultsListFilterArgs {
     pub owners: Vec<SgBytes>,
     pub hide_zero_balance: bool,
     pub tokens: Vec<String>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub orderbooks: Vec<SgBytes>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub exclude_owners: Vec<SgBytes>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub vault_ids: Vec<SgBytes>,
+    /// Inclusive lower bound of the vault balance, as a decimal string
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub min_balance: Option<String>,
+    /// Inclusive upper bound of the vault balance, as a decimal string
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub max_balance: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub sort_by: SgVaultsSortBy,
+    #[serde(default)]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub sort_direction: SgSortDirection,
 }
 impl_wasm_traits!(SgVaultsListFilterArgs);
 
@@ -164,6 +299,18 @@ pub struct SgVaultsListQueryFilters {
     pub balance_not: Option<SgBytes>,
     #[cynic(rename = "token_in", skip_serializing_if = "Vec::is_empty")]
     pub token_in: Vec<String>,
+    #[cynic(rename = "id_lt", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub id_lt: Option<SgBytes>,
+    #[cynic(rename = "id_gt", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub id_gt: Option<SgBytes>,
+    #[cynic(rename = "orderbook_in", skip_serializing_if = "Vec::is_empty")]
+    pub orderbook_in: Vec<String>,
+    #[cynic(rename = "owner_not_in", skip_serializing_if = "Vec::is_empty")]
+    pub owner_not_in: Vec<SgBytes>,
+    #[cynic(rename = "vaultId_in", skip_serializing_if = "Vec::is_empty")]
+    pub vault_id_in: Vec<SgBytes>,
 }
 
 #[derive(cynic::QueryVariables, Debug, Clone, Tsify)]
@@ -175,6 +322,68 @@ pub struct SgVaultsListQueryVariables {
     #[cynic(rename = "filters")]
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub filters: Option<SgVaultsListQueryFilters>,
+    pub order_direction: SgOrderDirection,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct SgClearsListFilterArgs {
+    /// Clears that traded this order, by order id
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub order_id: Option<SgBytes>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub clearer: Option<SgBytes>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub transaction_id: Option<SgBytes>,
+}
+impl_wasm_traits!(SgClearsListFilterArgs);
+
+#[derive(cynic::InputObject, Debug, Clone, Tsify)]
+#[cynic(graphql_type = "Clear_filter")]
+pub struct SgClearsListQueryFilters {
+    #[cynic(rename = "trades_", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub trades_: Option<SgClearTradeFilter>,
+    #[cynic(rename = "sender", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub sender: Option<SgBytes>,
+    #[cynic(rename = "transaction", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub transaction: Option<String>,
+    #[cynic(rename = "transaction_", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub transaction_: Option<SgClearTransactionFilter>,
+    #[cynic(rename = "id_not_in", skip_serializing_if = "Vec::is_empty")]
+    pub id_not_in: Vec<SgBytes>,
+}
+
+#[derive(cynic::InputObject, Debug, Clone, Tsify)]
+#[cynic(graphql_type = "Trade_filter")]
+pub struct SgClearTradeFilter {
+    #[cynic(rename = "order")]
+    pub order: String,
+}
+
+#[derive(cynic::InputObject, Debug, Clone, Tsify)]
+#[cynic(graphql_type = "Transaction_filter")]
+pub struct SgClearTransactionFilter {
+    #[cynic(rename = "timestamp_lte")]
+    pub timestamp_lte: SgBigInt,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone, Tsify)]
+pub struct SgClearsListQueryVariables {
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub first: Option<i32>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub skip: Option<i32>,
+    #[cynic(rename = "filters")]
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub filters: Option<SgClearsListQueryFilters>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryFragment, Debug, Serialize, Clone, Tsify)]
@@ -326,10 +535,34 @@ pub struct SgTrade {
     pub orderbook: SgOrderbook,
 }
 
+#[derive(cynic::QueryFragment, Debug, Clone, Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[cynic(graphql_type = "Clear")]
+pub struct SgClear {
+    pub id: SgBytes,
+    pub alice_input_amount: SgBytes,
+    pub alice_output_amount: SgBytes,
+    pub bob_input_amount: SgBytes,
+    pub bob_output_amount: SgBytes,
+    pub alice_bounty_amount: SgBytes,
+    pub bob_bounty_amount: SgBytes,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub alice_bounty_vault_balance_change: Option<SgClearBounty>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub bob_bounty_vault_balance_change: Option<SgClearBounty>,
+    pub orderbook: SgOrderbook,
+    /// The trades of both cleared orders
+    pub trades: Vec<SgTrade>,
+    pub transaction: SgTransaction,
+    /// The clearer, which receives the bounties
+    pub sender: SgBytes,
+}
+
 #[derive(cynic::QueryFragment, Debug, Clone, Serialize, Tsify)]
 #[cynic(graphql_type = "Trade")]
 pub struct SgOrderStructPartialTrade {
     pub id: SgBytes,
+    pub timestamp: SgBigInt,
 }
 
 #[cfg_attr(target_family = "wasm", tsify::declare)]
@@ -690,10 +923,11 @@ pub enum SgVaultOrderBy {
 }
 
 #[derive(cynic::QueryFragment, Debug, Clone, Serialize)]
-#[cynic(graphql_type = "Query")]
+#[cynic(graphql_type = "Query", variables = "SgBlockQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgTokensListAllQuery {
     #[cynic(rename = "erc20S")]
+    #[arguments(block: $block)]
     pub tokens: Vec<SgErc20>,
 }
 
@@ -708,6 +942,7 @@ mod impls {
     impl_wasm_traits!(SgTradeVaultBalanceChange);
     impl_wasm_traits!(SgDeposit);
     impl_wasm_traits!(SgClearBounty);
+    impl_wasm_traits!(SgClear);
     impl_wasm_traits!(SgOrderStructPartialTrade);
     impl_wasm_traits!(SgErc20);
     impl_wasm_traits!(SgTransaction);
@@ -1,6 +1,8 @@
 use super::common::*;
+use crate::pagination::{SgPaginationItem, SgPaginationKey};
 use crate::performance::PerformanceError;
-use rain_math_float::Float;
+use rain_math_float::{Float, FloatError};
+use std::cmp::Ordering;
 
 impl SgErc20 {
     pub fn get_decimals(&self) -> Result<u8, PerformanceError> {
@@ -47,6 +49,180 @@ impl SgTrade {
     }
 }
 
+impl SgPaginationItem for SgOrder {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        SgPaginationKey::Timestamp {
+            timestamp: &self.timestamp_added.0,
+            id: &self.id.0,
+        }
+    }
+}
+
+impl SgPaginationItem for SgOrderWithSubgraphName {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        self.order.pagination_key()
+    }
+}
+
+impl SgPaginationItem for SgVault {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        SgPaginationKey::Id(&self.id.0)
+    }
+}
+
+impl SgPaginationItem for SgVaultWithSubgraphName {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        self.vault.pagination_key()
+    }
+}
+
+impl SgPaginationItem for SgTrade {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        SgPaginationKey::Timestamp {
+            timestamp: &self.timestamp.0,
+            id: &self.id.0,
+        }
+    }
+}
+
+impl SgPaginationItem for SgClear {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        SgPaginationKey::Timestamp {
+            timestamp: &self.transaction.timestamp.0,
+            id: &self.id.0,
+        }
+    }
+}
+
+impl SgPaginationItem for SgVaultBalanceChangeUnwrapped {
+    fn pagination_key(&self) -> SgPaginationKey<'_> {
+        SgPaginationKey::TimestampOnly(&self.timestamp.0)
+    }
+}
+
+impl SgSortDirection {
+    /// Applies the direction to an ascending ordering
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            SgSortDirection::Asc => ordering,
+            SgSortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
+
+impl From<SgSortDirection> for SgOrderDirection {
+    fn from(direction: SgSortDirection) -> Self {
+        match direction {
+            SgSortDirection::Asc => SgOrderDirection::Asc,
+            SgSortDirection::Desc => SgOrderDirection::Desc,
+        }
+    }
+}
+
+impl SgOrdersSortBy {
+    /// Sort key of the order, either a timestamp or a number of trades
+    pub fn key(&self, order: &SgOrder) -> u64 {
+        match self {
+            SgOrdersSortBy::AddedAt => order.timestamp_added.0.parse().unwrap_or(0),
+            SgOrdersSortBy::LastTrade => order
+                .trades
+                .iter()
+                .filter_map(|trade| trade.timestamp.0.parse().ok())
+                .max()
+                .unwrap_or(0),
+            SgOrdersSortBy::TradeCount => order.trades.len() as u64,
+        }
+    }
+}
+
+impl SgOrdersListFilterArgs {
+    /// Whether the subgraph can apply every filter and the sort order on its
+    /// own, which is required for cursor pagination. The subgraph can only
+    /// match orders that have trades, not orders without any.
+    pub fn is_server_side(&self) -> bool {
+        self.sort_by == SgOrdersSortBy::AddedAt && self.has_trades != Some(false)
+    }
+
+    /// Applies the filters and sort order the subgraph can't apply
+    pub fn apply_local(&self, orders: Vec<SgOrder>) -> Vec<SgOrder> {
+        let mut orders: Vec<SgOrder> = match self.has_trades {
+            Some(has_trades) => orders
+                .into_iter()
+                .filter(|order| order.trades.is_empty() != has_trades)
+                .collect(),
+            None => orders,
+        };
+        orders.sort_by(|a, b| self.compare(a, b));
+        orders
+    }
+
+    /// Compares two orders by the requested sort order
+    pub fn compare(&self, a: &SgOrder, b: &SgOrder) -> Ordering {
+        self.sort_direction
+            .apply(self.sort_by.key(a).cmp(&self.sort_by.key(b)))
+    }
+}
+
+impl SgVaultsListFilterArgs {
+    /// Whether the subgraph can apply every filter and the sort order on its
+    /// own, which is required for cursor pagination. Balances are stored as
+    /// packed floats, so the subgraph can't compare them.
+    pub fn is_server_side(&self) -> bool {
+        self.sort_by == SgVaultsSortBy::Id
+            && self.min_balance.is_none()
+            && self.max_balance.is_none()
+    }
+
+    /// Applies the balance bounds and sort order the subgraph can't apply
+    pub fn apply_local(&self, vaults: Vec<SgVault>) -> Result<Vec<SgVault>, FloatError> {
+        let min_balance = self.min_balance.clone().map(Float::parse).transpose()?;
+        let max_balance = self.max_balance.clone().map(Float::parse).transpose()?;
+
+        let mut filtered = vec![];
+        for vault in vaults {
+            let balance = Float::from_hex(&vault.balance.0)?;
+            if let Some(min_balance) = min_balance {
+                if balance.lt(min_balance)? {
+                    continue;
+                }
+            }
+            if let Some(max_balance) = max_balance {
+                if balance.gt(max_balance)? {
+                    continue;
+                }
+            }
+            filtered.push(vault);
+        }
+        filtered.sort_by(|a, b| self.compare(a, b));
+        Ok(filtered)
+    }
+
+    /// Compares two vaults by the requested sort order. Unparsable balances
+    /// compare as equal.
+    pub fn compare(&self, a: &SgVault, b: &SgVault) -> Ordering {
+        let ordering = match self.sort_by {
+            SgVaultsSortBy::Id => a.id.0.cmp(&b.id.0),
+            SgVaultsSortBy::Balance => {
+                match (Float::from_hex(&a.balance.0), Float::from_hex(&b.balance.0)) {
+                    (Ok(a), Ok(b)) => compare_floats(a, b),
+                    _ => Ordering::Equal,
+                }
+            }
+        };
+        self.sort_direction.apply(ordering)
+    }
+}
+
+fn compare_floats(a: Float, b: Float) -> Ordering {
+    if a.lt(b).unwrap_or(false) {
+        Ordering::Less
+    } else if a.gt(b).unwrap_or(false) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,48 @@
+use super::common::SgBytes;
+use crate::schema;
+use serde::{Deserialize, Serialize};
+#[cfg(target_family = "wasm")]
+use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
+
+#[derive(cynic::QueryFragment, Debug, Clone, Serialize)]
+#[cynic(graphql_type = "Query")]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+pub struct SgMetaQuery {
+    #[cynic(rename = "_meta")]
+    pub meta: Option<SgMeta>,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(SgMetaQuery);
+
+#[derive(cynic::QueryFragment, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cynic(graphql_type = "_Meta_")]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+pub struct SgMeta {
+    pub block: SgMetaBlock,
+    pub deployment: String,
+    pub has_indexing_errors: bool,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(SgMeta);
+
+#[derive(cynic::QueryFragment, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cynic(graphql_type = "_Block_")]
+#[cfg_attr(target_family = "wasm", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+pub struct SgMetaBlock {
+    pub number: i32,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub hash: Option<SgBytes>,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub timestamp: Option<i32>,
+}
+#[cfg(target_family = "wasm")]
+impl_wasm_traits!(SgMetaBlock);
+
+impl SgMeta {
+    /// Latest block the subgraph has indexed
+    pub fn indexed_block(&self) -> u64 {
+        self.block.number.max(0) as u64
+    }
+}
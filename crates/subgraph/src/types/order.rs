@@ -9,6 +9,8 @@ use wasm_bindgen_utils::prelude::*;
 pub struct SgBatchOrderDetailQueryVariables {
     #[cynic(rename = "id_list")]
     pub id_list: SgOrderIdList,
+    #[cfg_attr(target_family = "wasm", tsify(optional))]
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::InputObject, Debug, Clone)]
@@ -23,7 +25,7 @@ pub struct SgOrderIdList {
 #[cynic(graphql_type = "Query", variables = "SgBatchOrderDetailQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgBatchOrderDetailQuery {
-    #[arguments(where: $id_list)]
+    #[arguments(where: $id_list, block: $block)]
     pub orders: Vec<SgOrder>,
 }
 
@@ -31,13 +33,14 @@ pub struct SgBatchOrderDetailQuery {
 #[cynic(graphql_type = "Query", variables = "SgOrdersListQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgOrdersListQuery {
-    #[arguments(orderBy: "timestampAdded", orderDirection: "desc", skip: $skip, first: $first, where: $filters)]
+    #[arguments(orderBy: "timestampAdded", orderDirection: $order_direction, skip: $skip, first: $first, where: $filters, block: $block)]
     pub orders: Vec<SgOrder>,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
 pub struct SgOrderDetailByHashQueryVariables {
     pub hash: SgBytes,
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryFragment, Debug, Serialize)]
@@ -48,7 +51,7 @@ pub struct SgOrderDetailByHashQueryVariables {
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct SgOrderDetailByHashQuery {
-    #[arguments(where: { orderHash: $hash })]
+    #[arguments(where: { orderHash: $hash }, block: $block)]
     pub orders: Vec<SgOrder>,
 }
 
@@ -56,7 +59,7 @@ pub struct SgOrderDetailByHashQuery {
 #[cynic(graphql_type = "Query", variables = "SgIdQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgOrderDetailByIdQuery {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, block: $block)]
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub order: Option<SgOrder>,
 }
@@ -19,8 +19,10 @@ pub struct SgOrderTradesListQuery {
         where: {
             order_: { id: $id },
             timestamp_gte: $timestamp_gte,
-            timestamp_lte: $timestamp_lte
-        }
+            timestamp_lte: $timestamp_lte,
+            id_not_in: $exclude_ids
+        },
+        block: $block
     )]
     pub trades: Vec<SgTrade>,
 }
@@ -29,7 +31,7 @@ pub struct SgOrderTradesListQuery {
 #[cynic(graphql_type = "Query", variables = "SgIdQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgOrderTradeDetailQuery {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, block: $block)]
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub trade: Option<SgTrade>,
 }
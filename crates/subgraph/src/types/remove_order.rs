@@ -1,4 +1,4 @@
-use super::common::{SgBytes, SgRemoveOrderWithOrder};
+use super::common::{SgBlockHeight, SgBytes, SgRemoveOrderWithOrder};
 use crate::schema;
 use serde::Serialize;
 #[cfg(target_family = "wasm")]
@@ -7,6 +7,7 @@ use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 #[derive(cynic::QueryVariables, Debug)]
 pub struct TransactionRemoveOrdersVariables {
     pub id: SgBytes,
+    pub block: Option<SgBlockHeight>,
 }
 
 #[derive(cynic::QueryFragment, Debug, Serialize)]
@@ -14,7 +15,7 @@ pub struct TransactionRemoveOrdersVariables {
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct SgTransactionRemoveOrdersQuery {
-    #[arguments(where: { transaction_: { id: $id } })]
+    #[arguments(where: { transaction_: { id: $id } }, block: $block)]
     pub remove_orders: Vec<SgRemoveOrderWithOrder>,
 }
 #[cfg(target_family = "wasm")]
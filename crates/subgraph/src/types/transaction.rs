@@ -8,7 +8,7 @@ use wasm_bindgen_utils::{impl_wasm_traits, prelude::*};
 #[cynic(graphql_type = "Query", variables = "SgIdQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgTransactionDetailQuery {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, block: $block)]
     pub transaction: Option<SgTransaction>,
 }
 #[cfg(target_family = "wasm")]
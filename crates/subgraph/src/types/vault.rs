@@ -8,7 +8,7 @@ use wasm_bindgen_utils::prelude::*;
 #[cynic(graphql_type = "Query", variables = "SgVaultsListQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgVaultsListQuery {
-    #[arguments(orderBy: "id", orderDirection: "desc", skip: $skip, first: $first, where: $filters)]
+    #[arguments(orderBy: "id", orderDirection: $order_direction, skip: $skip, first: $first, where: $filters, block: $block)]
     pub vaults: Vec<SgVault>,
 }
 
@@ -16,7 +16,7 @@ pub struct SgVaultsListQuery {
 #[cynic(graphql_type = "Query", variables = "SgIdQueryVariables")]
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 pub struct SgVaultDetailQuery {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, block: $block)]
     #[cfg_attr(target_family = "wasm", tsify(optional))]
     pub vault: Option<SgVault>,
 }
@@ -26,6 +26,6 @@ pub struct SgVaultDetailQuery {
 #[cfg_attr(target_family = "wasm", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct SgVaultBalanceChangesListQuery {
-    #[arguments(orderDirection: "desc", orderBy: "timestamp", where: { vault_: { id: $id } }, skip: $skip, first: $first)]
+    #[arguments(orderDirection: "desc", orderBy: "timestamp", where: { vault_: { id: $id }, timestamp_lte: $timestamp_lte }, skip: $skip, first: $first, block: $block)]
     pub vault_balance_changes: Vec<SgVaultBalanceChangeUnwrapped>,
 }
@@ -1,8 +1,68 @@
 use cynic::{GraphQlResponse, QueryBuilder};
 use cynic_introspection::{CapabilitiesQuery, IntrospectionQuery, SchemaError};
-use std::fs::read_to_string;
+use graphql_parser::{
+    query::{
+        Definition as QueryDefinition, Field, FragmentDefinition, OperationDefinition, Selection,
+        SelectionSet, Type, TypeCondition, Value,
+    },
+    schema::{Definition as SchemaDefinition, InputValue, TypeDefinition},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    fs::read_to_string,
+};
 use thiserror::Error;
 
+/// This crate's schema and the query operations written against it, every
+/// file of `queries/` must be listed, a test checks the list against the
+/// directory
+const MAIN_SCHEMA: &str = include_str!("../schema/orderbook.graphql");
+const QUERIES: &[(&str, &str)] = &[
+    (
+        "clearsList.graphql",
+        include_str!("../queries/clearsList.graphql"),
+    ),
+    ("meta.graphql", include_str!("../queries/meta.graphql")),
+    (
+        "multiOrderDetail.graphql",
+        include_str!("../queries/multiOrderDetail.graphql"),
+    ),
+    (
+        "orderDetail.graphql",
+        include_str!("../queries/orderDetail.graphql"),
+    ),
+    (
+        "orderTakeDetail.graphql",
+        include_str!("../queries/orderTakeDetail.graphql"),
+    ),
+    (
+        "orderTakesList.graphql",
+        include_str!("../queries/orderTakesList.graphql"),
+    ),
+    (
+        "ordersList.graphql",
+        include_str!("../queries/ordersList.graphql"),
+    ),
+    (
+        "tokensListAll.graphql",
+        include_str!("../queries/tokensListAll.graphql"),
+    ),
+    (
+        "vaultBalanceChangesList.graphql",
+        include_str!("../queries/vaultBalanceChangesList.graphql"),
+    ),
+    (
+        "vaultDetail.graphql",
+        include_str!("../queries/vaultDetail.graphql"),
+    ),
+    (
+        "vaultsList.graphql",
+        include_str!("../queries/vaultsList.graphql"),
+    ),
+];
+const BUILTIN_SCALARS: [&str; 5] = ["String", "Int", "Float", "Boolean", "ID"];
+
 #[derive(Debug, Error)]
 pub enum SchemaValidationError {
     #[error("undefined schema")]
@@ -15,6 +75,747 @@ pub enum SchemaValidationError {
     SchemaError(#[from] SchemaError),
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
+    #[error(transparent)]
+    SchemaParseError(#[from] graphql_parser::schema::ParseError),
+    #[error(transparent)]
+    QueryParseError(#[from] graphql_parser::query::ParseError),
+}
+
+/// Kind of a named type of a schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+impl fmt::Display for SchemaTypeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SchemaTypeKind::Scalar => "scalar",
+            SchemaTypeKind::Object => "object",
+            SchemaTypeKind::Interface => "interface",
+            SchemaTypeKind::Union => "union",
+            SchemaTypeKind::Enum => "enum",
+            SchemaTypeKind::InputObject => "input object",
+        })
+    }
+}
+
+/// A difference of a subgraph schema from this crate's schema. Missing
+/// elements are in this crate's schema only, extra elements are in the
+/// subgraph schema only. Fields include the fields of input objects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDifference {
+    MissingType {
+        type_name: String,
+    },
+    ExtraType {
+        type_name: String,
+    },
+    TypeKindChanged {
+        type_name: String,
+        expected: SchemaTypeKind,
+        found: SchemaTypeKind,
+    },
+    MissingField {
+        type_name: String,
+        field_name: String,
+    },
+    ExtraField {
+        type_name: String,
+        field_name: String,
+    },
+    FieldTypeChanged {
+        type_name: String,
+        field_name: String,
+        expected: String,
+        found: String,
+    },
+    MissingArgument {
+        type_name: String,
+        field_name: String,
+        argument: String,
+    },
+    ExtraArgument {
+        type_name: String,
+        field_name: String,
+        argument: String,
+        required: bool,
+    },
+    ArgumentTypeChanged {
+        type_name: String,
+        field_name: String,
+        argument: String,
+        expected: String,
+        found: String,
+    },
+    MissingEnumValue {
+        type_name: String,
+        value: String,
+    },
+    ExtraEnumValue {
+        type_name: String,
+        value: String,
+    },
+}
+
+impl SchemaDifference {
+    /// Schema element an operation has to use to be broken by the difference,
+    /// none for additions that leave every operation working
+    fn breaking_coordinate(&self) -> Option<SchemaCoordinate> {
+        match self {
+            SchemaDifference::MissingType { type_name }
+            | SchemaDifference::TypeKindChanged { type_name, .. } => {
+                Some(SchemaCoordinate::Type(type_name.clone()))
+            }
+            SchemaDifference::MissingField {
+                type_name,
+                field_name,
+            }
+            | SchemaDifference::FieldTypeChanged {
+                type_name,
+                field_name,
+                ..
+            }
+            | SchemaDifference::ExtraArgument {
+                type_name,
+                field_name,
+                required: true,
+                ..
+            } => Some(SchemaCoordinate::Field(
+                type_name.clone(),
+                field_name.clone(),
+            )),
+            SchemaDifference::MissingArgument {
+                type_name,
+                field_name,
+                argument,
+            }
+            | SchemaDifference::ArgumentTypeChanged {
+                type_name,
+                field_name,
+                argument,
+                ..
+            } => Some(SchemaCoordinate::Argument(
+                type_name.clone(),
+                field_name.clone(),
+                argument.clone(),
+            )),
+            SchemaDifference::MissingEnumValue { type_name, value } => Some(
+                SchemaCoordinate::EnumValue(type_name.clone(), value.clone()),
+            ),
+            SchemaDifference::ExtraType { .. }
+            | SchemaDifference::ExtraField { .. }
+            | SchemaDifference::ExtraArgument {
+                required: false, ..
+            }
+            | SchemaDifference::ExtraEnumValue { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for SchemaDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaDifference::MissingType { type_name } => write!(f, "missing type {type_name}"),
+            SchemaDifference::ExtraType { type_name } => write!(f, "extra type {type_name}"),
+            SchemaDifference::TypeKindChanged {
+                type_name,
+                expected,
+                found,
+            } => write!(f, "type {type_name} is {found} instead of {expected}"),
+            SchemaDifference::MissingField {
+                type_name,
+                field_name,
+            } => write!(f, "missing field {type_name}.{field_name}"),
+            SchemaDifference::ExtraField {
+                type_name,
+                field_name,
+            } => write!(f, "extra field {type_name}.{field_name}"),
+            SchemaDifference::FieldTypeChanged {
+                type_name,
+                field_name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "field {type_name}.{field_name} has type {found} instead of {expected}"
+            ),
+            SchemaDifference::MissingArgument {
+                type_name,
+                field_name,
+                argument,
+            } => write!(f, "missing argument {type_name}.{field_name}({argument}:)"),
+            SchemaDifference::ExtraArgument {
+                type_name,
+                field_name,
+                argument,
+                required,
+            } => write!(
+                f,
+                "extra {}argument {type_name}.{field_name}({argument}:)",
+                if *required { "required " } else { "" }
+            ),
+            SchemaDifference::ArgumentTypeChanged {
+                type_name,
+                field_name,
+                argument,
+                expected,
+                found,
+            } => write!(
+                f,
+                "argument {type_name}.{field_name}({argument}:) has type {found} instead of {expected}"
+            ),
+            SchemaDifference::MissingEnumValue { type_name, value } => {
+                write!(f, "missing enum value {type_name}.{value}")
+            }
+            SchemaDifference::ExtraEnumValue { type_name, value } => {
+                write!(f, "extra enum value {type_name}.{value}")
+            }
+        }
+    }
+}
+
+/// A query operation of this crate
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QueryOperation {
+    pub file: String,
+    pub name: String,
+}
+
+impl fmt::Display for QueryOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.file)
+    }
+}
+
+/// A schema difference and the query operations it breaks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDiffEntry {
+    pub difference: SchemaDifference,
+    pub broken_operations: Vec<QueryOperation>,
+}
+
+/// Semantic differences of a subgraph schema from this crate's schema
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub entries: Vec<SchemaDiffEntry>,
+}
+
+impl SchemaDiff {
+    /// Whether the schemas define the same types, fields, arguments and
+    /// enum values
+    pub fn is_identical(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether every query operation of this crate works with the subgraph
+    pub fn is_compatible(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|entry| entry.broken_operations.is_empty())
+    }
+
+    pub fn broken_operations(&self) -> Vec<&QueryOperation> {
+        self.entries
+            .iter()
+            .flat_map(|entry| &entry.broken_operations)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_identical() {
+            return writeln!(f, "no schema differences");
+        }
+        for entry in &self.entries {
+            writeln!(f, "{}", entry.difference)?;
+            for operation in &entry.broken_operations {
+                writeln!(f, "  breaks {operation}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A type, field, argument or enum value of a schema
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum SchemaCoordinate {
+    Type(String),
+    Field(String, String),
+    Argument(String, String, String),
+    EnumValue(String, String),
+}
+
+#[derive(Debug, Clone)]
+struct SchemaArgumentDef {
+    value_type: String,
+    named_type: String,
+    required: bool,
+}
+
+impl SchemaArgumentDef {
+    fn from_input_value(input_value: &InputValue<'_, String>) -> Self {
+        Self {
+            value_type: input_value.value_type.to_string(),
+            named_type: named_type(&input_value.value_type).to_string(),
+            required: matches!(input_value.value_type, Type::NonNullType(_))
+                && input_value.default_value.is_none(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SchemaFieldDef {
+    field_type: String,
+    named_type: String,
+    arguments: BTreeMap<String, SchemaArgumentDef>,
+}
+
+#[derive(Debug, Clone)]
+struct SchemaTypeDef {
+    kind: SchemaTypeKind,
+    fields: BTreeMap<String, SchemaFieldDef>,
+    enum_values: BTreeSet<String>,
+}
+
+impl SchemaTypeDef {
+    fn new(kind: SchemaTypeKind) -> Self {
+        Self {
+            kind,
+            fields: BTreeMap::new(),
+            enum_values: BTreeSet::new(),
+        }
+    }
+
+    fn with_fields(
+        kind: SchemaTypeKind,
+        fields: Vec<graphql_parser::schema::Field<'_, String>>,
+    ) -> Self {
+        Self {
+            fields: fields
+                .into_iter()
+                .map(|field| {
+                    let field_def = SchemaFieldDef {
+                        field_type: field.field_type.to_string(),
+                        named_type: named_type(&field.field_type).to_string(),
+                        arguments: field
+                            .arguments
+                            .iter()
+                            .map(|argument| {
+                                (
+                                    argument.name.clone(),
+                                    SchemaArgumentDef::from_input_value(argument),
+                                )
+                            })
+                            .collect(),
+                    };
+                    (field.name, field_def)
+                })
+                .collect(),
+            ..Self::new(kind)
+        }
+    }
+}
+
+type SchemaTypes = BTreeMap<String, SchemaTypeDef>;
+
+fn named_type<'a>(value_type: &'a Type<'_, String>) -> &'a str {
+    match value_type {
+        Type::NamedType(name) => name.as_str(),
+        Type::ListType(inner) | Type::NonNullType(inner) => named_type(inner),
+    }
+}
+
+/// Reads the named types of a schema, leaving out built-in scalars and
+/// introspection types
+fn parse_schema_types(sdl: &str) -> Result<SchemaTypes, SchemaValidationError> {
+    let document = graphql_parser::parse_schema::<String>(sdl)?;
+    let mut types = SchemaTypes::new();
+    for definition in document.definitions {
+        let SchemaDefinition::TypeDefinition(type_definition) = definition else {
+            continue;
+        };
+        let (name, type_def) = match type_definition {
+            TypeDefinition::Scalar(scalar) => {
+                (scalar.name, SchemaTypeDef::new(SchemaTypeKind::Scalar))
+            }
+            TypeDefinition::Object(object) => (
+                object.name,
+                SchemaTypeDef::with_fields(SchemaTypeKind::Object, object.fields),
+            ),
+            TypeDefinition::Interface(interface) => (
+                interface.name,
+                SchemaTypeDef::with_fields(SchemaTypeKind::Interface, interface.fields),
+            ),
+            TypeDefinition::Union(union) => (union.name, SchemaTypeDef::new(SchemaTypeKind::Union)),
+            TypeDefinition::Enum(enum_type) => (
+                enum_type.name,
+                SchemaTypeDef {
+                    enum_values: enum_type
+                        .values
+                        .into_iter()
+                        .map(|value| value.name)
+                        .collect(),
+                    ..SchemaTypeDef::new(SchemaTypeKind::Enum)
+                },
+            ),
+            TypeDefinition::InputObject(input_object) => (
+                input_object.name,
+                SchemaTypeDef {
+                    fields: input_object
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let argument = SchemaArgumentDef::from_input_value(field);
+                            let field_def = SchemaFieldDef {
+                                field_type: argument.value_type,
+                                named_type: argument.named_type,
+                                arguments: BTreeMap::new(),
+                            };
+                            (field.name.clone(), field_def)
+                        })
+                        .collect(),
+                    ..SchemaTypeDef::new(SchemaTypeKind::InputObject)
+                },
+            ),
+        };
+        if name.starts_with("__") || BUILTIN_SCALARS.contains(&name.as_str()) {
+            continue;
+        }
+        types.insert(name, type_def);
+    }
+    Ok(types)
+}
+
+fn diff_schema_types(expected: &SchemaTypes, found: &SchemaTypes) -> Vec<SchemaDifference> {
+    let mut differences = vec![];
+    for (type_name, expected_type) in expected {
+        let Some(found_type) = found.get(type_name) else {
+            differences.push(SchemaDifference::MissingType {
+                type_name: type_name.clone(),
+            });
+            continue;
+        };
+        if expected_type.kind != found_type.kind {
+            differences.push(SchemaDifference::TypeKindChanged {
+                type_name: type_name.clone(),
+                expected: expected_type.kind,
+                found: found_type.kind,
+            });
+            continue;
+        }
+
+        for (field_name, expected_field) in &expected_type.fields {
+            let Some(found_field) = found_type.fields.get(field_name) else {
+                differences.push(SchemaDifference::MissingField {
+                    type_name: type_name.clone(),
+                    field_name: field_name.clone(),
+                });
+                continue;
+            };
+            if expected_field.field_type != found_field.field_type {
+                differences.push(SchemaDifference::FieldTypeChanged {
+                    type_name: type_name.clone(),
+                    field_name: field_name.clone(),
+                    expected: expected_field.field_type.clone(),
+                    found: found_field.field_type.clone(),
+                });
+            }
+            for (argument, expected_argument) in &expected_field.arguments {
+                match found_field.arguments.get(argument) {
+                    None => differences.push(SchemaDifference::MissingArgument {
+                        type_name: type_name.clone(),
+                        field_name: field_name.clone(),
+                        argument: argument.clone(),
+                    }),
+                    Some(found_argument)
+                        if found_argument.value_type != expected_argument.value_type =>
+                    {
+                        differences.push(SchemaDifference::ArgumentTypeChanged {
+                            type_name: type_name.clone(),
+                            field_name: field_name.clone(),
+                            argument: argument.clone(),
+                            expected: expected_argument.value_type.clone(),
+                            found: found_argument.value_type.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+            for (argument, found_argument) in &found_field.arguments {
+                if !expected_field.arguments.contains_key(argument) {
+                    differences.push(SchemaDifference::ExtraArgument {
+                        type_name: type_name.clone(),
+                        field_name: field_name.clone(),
+                        argument: argument.clone(),
+                        required: found_argument.required,
+                    });
+                }
+            }
+        }
+        for field_name in found_type.fields.keys() {
+            if !expected_type.fields.contains_key(field_name) {
+                differences.push(SchemaDifference::ExtraField {
+                    type_name: type_name.clone(),
+                    field_name: field_name.clone(),
+                });
+            }
+        }
+
+        for value in expected_type
+            .enum_values
+            .difference(&found_type.enum_values)
+        {
+            differences.push(SchemaDifference::MissingEnumValue {
+                type_name: type_name.clone(),
+                value: value.clone(),
+            });
+        }
+        for value in found_type
+            .enum_values
+            .difference(&expected_type.enum_values)
+        {
+            differences.push(SchemaDifference::ExtraEnumValue {
+                type_name: type_name.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+    for type_name in found.keys() {
+        if !expected.contains_key(type_name) {
+            differences.push(SchemaDifference::ExtraType {
+                type_name: type_name.clone(),
+            });
+        }
+    }
+    differences
+}
+
+/// Collects the schema coordinates a query operation uses, following the
+/// types of its fields and arguments through the given schema
+struct CoordinateCollector<'s, 'd> {
+    types: &'s SchemaTypes,
+    fragments: BTreeMap<String, FragmentDefinition<'d, String>>,
+    visited_fragments: BTreeSet<String>,
+    coordinates: BTreeSet<SchemaCoordinate>,
+}
+
+impl<'d> CoordinateCollector<'_, 'd> {
+    fn add_type(&mut self, type_name: &str) {
+        self.coordinates
+            .insert(SchemaCoordinate::Type(type_name.to_string()));
+    }
+
+    fn collect_selection_set(&mut self, parent: &str, selection_set: &SelectionSet<'d, String>) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => self.collect_field(parent, field),
+                Selection::InlineFragment(fragment) => {
+                    let parent = match &fragment.type_condition {
+                        Some(TypeCondition::On(type_name)) => {
+                            self.add_type(type_name);
+                            type_name.as_str()
+                        }
+                        None => parent,
+                    };
+                    self.collect_selection_set(parent, &fragment.selection_set);
+                }
+                Selection::FragmentSpread(spread) => {
+                    if !self.visited_fragments.insert(spread.fragment_name.clone()) {
+                        continue;
+                    }
+                    if let Some(fragment) = self.fragments.get(&spread.fragment_name).cloned() {
+                        let TypeCondition::On(type_name) = &fragment.type_condition;
+                        self.add_type(type_name);
+                        self.collect_selection_set(type_name, &fragment.selection_set);
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_field(&mut self, parent: &str, field: &Field<'d, String>) {
+        // Meta fields like __typename are not part of the schema
+        if field.name.starts_with("__") {
+            return;
+        }
+        self.coordinates.insert(SchemaCoordinate::Field(
+            parent.to_string(),
+            field.name.clone(),
+        ));
+        let types = self.types;
+        let field_def = types
+            .get(parent)
+            .and_then(|parent_type| parent_type.fields.get(&field.name));
+
+        for (argument, value) in &field.arguments {
+            self.coordinates.insert(SchemaCoordinate::Argument(
+                parent.to_string(),
+                field.name.clone(),
+                argument.clone(),
+            ));
+            if let Some(argument_def) = field_def.and_then(|def| def.arguments.get(argument)) {
+                self.add_type(&argument_def.named_type);
+                self.collect_value(&argument_def.named_type, value);
+            }
+        }
+        if let Some(field_def) = field_def {
+            self.add_type(&field_def.named_type);
+            self.collect_selection_set(&field_def.named_type, &field.selection_set);
+        }
+    }
+
+    fn collect_value(&mut self, type_name: &str, value: &Value<'d, String>) {
+        match value {
+            Value::Enum(enum_value) => {
+                self.coordinates.insert(SchemaCoordinate::EnumValue(
+                    type_name.to_string(),
+                    enum_value.clone(),
+                ));
+            }
+            Value::List(values) => {
+                for value in values {
+                    self.collect_value(type_name, value);
+                }
+            }
+            Value::Object(fields) => {
+                let types = self.types;
+                for (field_name, value) in fields {
+                    self.coordinates.insert(SchemaCoordinate::Field(
+                        type_name.to_string(),
+                        field_name.clone(),
+                    ));
+                    if let Some(field_def) = types
+                        .get(type_name)
+                        .and_then(|input_type| input_type.fields.get(field_name))
+                    {
+                        self.add_type(&field_def.named_type);
+                        self.collect_value(&field_def.named_type, value);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses the operations of a query file and the schema coordinates each
+/// of them uses
+fn collect_operations(
+    file: &str,
+    source: &str,
+    types: &SchemaTypes,
+) -> Result<Vec<(QueryOperation, BTreeSet<SchemaCoordinate>)>, SchemaValidationError> {
+    let document = graphql_parser::parse_query::<String>(source)?;
+    let fragments: BTreeMap<_, _> = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            QueryDefinition::Fragment(fragment) => Some((fragment.name.clone(), fragment.clone())),
+            QueryDefinition::Operation(_) => None,
+        })
+        .collect();
+
+    let no_variables = vec![];
+    let mut operations = vec![];
+    for definition in &document.definitions {
+        let QueryDefinition::Operation(operation) = definition else {
+            continue;
+        };
+        let (root, name, variables, selection_set) = match operation {
+            OperationDefinition::SelectionSet(selection_set) => {
+                ("Query", None, &no_variables, selection_set)
+            }
+            OperationDefinition::Query(query) => (
+                "Query",
+                query.name.clone(),
+                &query.variable_definitions,
+                &query.selection_set,
+            ),
+            OperationDefinition::Mutation(mutation) => (
+                "Mutation",
+                mutation.name.clone(),
+                &mutation.variable_definitions,
+                &mutation.selection_set,
+            ),
+            OperationDefinition::Subscription(subscription) => (
+                "Subscription",
+                subscription.name.clone(),
+                &subscription.variable_definitions,
+                &subscription.selection_set,
+            ),
+        };
+
+        let mut collector = CoordinateCollector {
+            types,
+            fragments: fragments.clone(),
+            visited_fragments: BTreeSet::new(),
+            coordinates: BTreeSet::new(),
+        };
+        collector.add_type(root);
+        for variable in variables {
+            collector.add_type(named_type(&variable.var_type));
+        }
+        collector.collect_selection_set(root, selection_set);
+
+        operations.push((
+            QueryOperation {
+                file: file.to_string(),
+                name: name.unwrap_or_else(|| "anonymous".to_string()),
+            },
+            collector.coordinates,
+        ));
+    }
+    Ok(operations)
+}
+
+/// Diffs a schema against the expected one and maps each difference to the
+/// given query operations it breaks
+fn diff_schemas(
+    expected: &str,
+    found: &str,
+    queries: &[(&str, &str)],
+) -> Result<SchemaDiff, SchemaValidationError> {
+    let expected_types = parse_schema_types(expected)?;
+    let found_types = parse_schema_types(found)?;
+
+    let mut operations = vec![];
+    for (file, source) in queries {
+        operations.extend(collect_operations(file, source, &expected_types)?);
+    }
+
+    let entries = diff_schema_types(&expected_types, &found_types)
+        .into_iter()
+        .map(|difference| {
+            let broken_operations = match difference.breaking_coordinate() {
+                Some(coordinate) => operations
+                    .iter()
+                    .filter(|(_, coordinates)| coordinates.contains(&coordinate))
+                    .map(|(operation, _)| operation.clone())
+                    .collect(),
+                None => vec![],
+            };
+            SchemaDiffEntry {
+                difference,
+                broken_operations,
+            }
+        })
+        .collect();
+    Ok(SchemaDiff { entries })
+}
+
+/// Semantic diff of a schema against this crate's schema, listing the
+/// queries of this crate each difference breaks
+pub fn diff_schema(schema: &str) -> Result<SchemaDiff, SchemaValidationError> {
+    diff_schemas(MAIN_SCHEMA, schema, QUERIES)
 }
 
 /// checks if a schema is equal to this crate's schema
@@ -56,6 +857,12 @@ pub async fn validate_subgraph_schema(subgraph_url: &str) -> Result<bool, Schema
     check_schema(get_schema(subgraph_url).await?)
 }
 
+/// Fetches a subgraph's schema from the given url and diffs it
+/// against this crate's schema
+pub async fn diff_subgraph_schema(subgraph_url: &str) -> Result<SchemaDiff, SchemaValidationError> {
+    diff_schema(&get_schema(subgraph_url).await?)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -174,4 +981,190 @@ mod test {
         let result = check_schema(schema).unwrap();
         assert!(!result);
     }
+
+    fn operation(file: &str, name: &str) -> QueryOperation {
+        QueryOperation {
+            file: file.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    const EXPECTED_SCHEMA: &str = "
+        type Query {
+          vaults(first: Int, orderBy: Vault_orderBy, where: Vault_filter): [Vault!]!
+          tokens: [Token!]!
+        }
+        type Vault { id: ID! balance: BigInt! }
+        type Token { id: ID! }
+        enum Vault_orderBy { id balance }
+        input Vault_filter { id: ID balance_gt: BigInt }
+        scalar BigInt
+    ";
+
+    const TEST_QUERIES: [(&str, &str); 2] = [
+        (
+            "vaults.graphql",
+            "query VaultsQuery { vaults(orderBy: balance, where: { id: \"0x1\" }) { id } }
+            query BalancesQuery { vaults(first: 10, where: { balance_gt: \"1\" }) { balance } }",
+        ),
+        ("tokens.graphql", "query TokensQuery { tokens { id } }"),
+    ];
+
+    #[test]
+    fn test_queries_match_query_files() {
+        let mut files = std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/queries"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|file| file.ends_with(".graphql"))
+            .collect::<Vec<_>>();
+        files.sort();
+        let queries = QUERIES.iter().map(|(file, _)| *file).collect::<Vec<_>>();
+        assert_eq!(queries, files);
+    }
+
+    #[test]
+    fn test_diff_schema_identical() {
+        let diff = diff_schema(MAIN_SCHEMA).unwrap();
+        assert!(diff.is_identical());
+        assert!(diff.is_compatible());
+        assert_eq!(diff.to_string(), "no schema differences\n");
+    }
+
+    #[test]
+    fn test_diff_schema_missing_field() {
+        let schema = MAIN_SCHEMA.replacen("  timestampAdded: BigInt!\n", "", 1);
+        let diff = diff_schema(&schema).unwrap();
+        assert_eq!(
+            diff.entries,
+            vec![SchemaDiffEntry {
+                difference: SchemaDifference::MissingField {
+                    type_name: "Order".to_string(),
+                    field_name: "timestampAdded".to_string(),
+                },
+                broken_operations: vec![
+                    operation("orderDetail.graphql", "OrderDetailQuery"),
+                    operation("ordersList.graphql", "OrdersListQuery"),
+                ],
+            }]
+        );
+        assert!(!diff.is_compatible());
+    }
+
+    #[test]
+    fn test_diff_schemas() {
+        let found = "
+            type Query {
+              vaults(first: Int, orderBy: Vault_orderBy, where: Vault_filter, owner: String!): [Vault!]!
+              tokens: [Token!]!
+            }
+            type Vault { id: ID! balance: String! }
+            type Token { id: ID! name: String }
+            enum Vault_orderBy { id }
+            input Vault_filter { id: ID }
+            scalar BigInt
+            type Extra { id: ID! }
+        ";
+        let diff = diff_schemas(EXPECTED_SCHEMA, found, &TEST_QUERIES).unwrap();
+
+        let vaults = operation("vaults.graphql", "VaultsQuery");
+        let balances = operation("vaults.graphql", "BalancesQuery");
+        assert_eq!(
+            diff.entries,
+            vec![
+                SchemaDiffEntry {
+                    difference: SchemaDifference::ExtraArgument {
+                        type_name: "Query".to_string(),
+                        field_name: "vaults".to_string(),
+                        argument: "owner".to_string(),
+                        required: true,
+                    },
+                    broken_operations: vec![vaults.clone(), balances.clone()],
+                },
+                SchemaDiffEntry {
+                    difference: SchemaDifference::ExtraField {
+                        type_name: "Token".to_string(),
+                        field_name: "name".to_string(),
+                    },
+                    broken_operations: vec![],
+                },
+                SchemaDiffEntry {
+                    difference: SchemaDifference::FieldTypeChanged {
+                        type_name: "Vault".to_string(),
+                        field_name: "balance".to_string(),
+                        expected: "BigInt!".to_string(),
+                        found: "String!".to_string(),
+                    },
+                    broken_operations: vec![balances.clone()],
+                },
+                SchemaDiffEntry {
+                    difference: SchemaDifference::MissingField {
+                        type_name: "Vault_filter".to_string(),
+                        field_name: "balance_gt".to_string(),
+                    },
+                    broken_operations: vec![balances.clone()],
+                },
+                SchemaDiffEntry {
+                    difference: SchemaDifference::MissingEnumValue {
+                        type_name: "Vault_orderBy".to_string(),
+                        value: "balance".to_string(),
+                    },
+                    broken_operations: vec![vaults.clone()],
+                },
+                SchemaDiffEntry {
+                    difference: SchemaDifference::ExtraType {
+                        type_name: "Extra".to_string(),
+                    },
+                    broken_operations: vec![],
+                },
+            ]
+        );
+        assert!(!diff.is_compatible());
+        assert_eq!(diff.broken_operations(), vec![&balances, &vaults]);
+    }
+
+    #[test]
+    fn test_diff_schemas_missing_type_and_argument() {
+        let found = "
+            type Query {
+              vaults(first: Int, orderBy: Vault_orderBy, where: Vault_filter): [Vault!]!
+            }
+            type Vault { id: ID! balance: BigInt! }
+            enum Vault_orderBy { id balance }
+            input Vault_filter { id: ID balance_gt: BigInt }
+            enum BigInt { zero }
+        ";
+        let diff = diff_schemas(EXPECTED_SCHEMA, found, &TEST_QUERIES).unwrap();
+
+        assert_eq!(
+            diff.to_string(),
+            "type BigInt is enum instead of scalar
+  breaks BalancesQuery (vaults.graphql)
+missing field Query.tokens
+  breaks TokensQuery (tokens.graphql)
+missing type Token
+  breaks TokensQuery (tokens.graphql)
+"
+        );
+    }
+
+    #[test]
+    fn test_diff_schemas_additions_are_compatible() {
+        let found = format!("{EXPECTED_SCHEMA} type Extra {{ id: ID! }}");
+        let found = found.replace(
+            "vaults(first: Int,",
+            "vaults(skip: Int = 0, owner: String! = \"\", first: Int,",
+        );
+        let diff = diff_schemas(EXPECTED_SCHEMA, &found, &TEST_QUERIES).unwrap();
+        assert!(!diff.is_identical());
+        assert!(diff.is_compatible());
+        assert!(diff.broken_operations().is_empty());
+    }
+
+    #[test]
+    fn test_diff_schema_invalid() {
+        assert!(matches!(
+            diff_schema("type Query {"),
+            Err(SchemaValidationError::SchemaParseError(_))
+        ));
+    }
 }
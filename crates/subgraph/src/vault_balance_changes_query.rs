@@ -1,5 +1,6 @@
 use crate::cynic_client::{CynicClient, CynicClientError};
 use crate::pagination::{PageQueryClient, PageQueryVariables};
+use crate::transport::SubgraphTransportConfig;
 use crate::types::common::*;
 use crate::types::vault::SgVaultBalanceChangesListQuery;
 use chrono::DateTime;
@@ -8,11 +9,16 @@ use std::cmp::Reverse;
 
 pub struct VaultBalanceChangesListPageQueryClient {
     pub url: Url,
+    pub transport: SubgraphTransportConfig,
 }
 
 impl VaultBalanceChangesListPageQueryClient {
     pub fn new(url: Url) -> Self {
-        Self { url }
+        Self::with_transport(url, SubgraphTransportConfig::default())
+    }
+
+    pub fn with_transport(url: Url, transport: SubgraphTransportConfig) -> Self {
+        Self { url, transport }
     }
 }
 
@@ -20,6 +26,10 @@ impl CynicClient for VaultBalanceChangesListPageQueryClient {
     fn get_base_url(&self) -> &Url {
         &self.url
     }
+
+    fn get_transport_config(&self) -> &SubgraphTransportConfig {
+        &self.transport
+    }
 }
 
 impl PageQueryClient<SgVaultBalanceChangeUnwrapped, SgPaginationWithIdQueryVariables>
@@ -60,6 +70,8 @@ impl PageQueryVariables for SgPaginationWithIdQueryVariables {
             skip,
             first,
             id: self.id.clone(),
+            timestamp_lte: self.timestamp_lte.clone(),
+            block: self.block.clone(),
         }
     }
 }
@@ -69,8 +81,8 @@ mod tests {
     use super::*;
     use crate::cynic_client::CynicClientError;
     use crate::types::common::{
-        SgBigInt, SgBytes, SgErc20, SgOrderbook, SgPaginationWithIdQueryVariables, SgTransaction,
-        SgVaultBalanceChangeUnwrapped, SgVaultBalanceChangeVault,
+        SgBigInt, SgBlockHeight, SgBytes, SgErc20, SgOrderbook, SgPaginationWithIdQueryVariables,
+        SgTransaction, SgVaultBalanceChangeUnwrapped, SgVaultBalanceChangeVault,
     };
     use crate::types::vault::SgVaultBalanceChangesListQuery;
     use httpmock::prelude::*;
@@ -226,6 +238,8 @@ mod tests {
             id: default_sg_bytes("some-vault-id"),
             skip: Some(0),
             first: Some(10),
+            timestamp_lte: None,
+            block: None,
         };
 
         let result = client.query_page(variables).await;
@@ -255,6 +269,8 @@ mod tests {
             id: default_sg_bytes("some-vault-id"),
             skip: Some(0),
             first: Some(10),
+            timestamp_lte: None,
+            block: None,
         };
 
         let result = client.query_page(variables).await;
@@ -276,6 +292,8 @@ mod tests {
             id: default_sg_bytes("some-vault-id"),
             skip: Some(0),
             first: Some(10),
+            timestamp_lte: None,
+            block: None,
         };
 
         let result = client.query_page(variables).await;
@@ -301,6 +319,8 @@ mod tests {
             id: default_sg_bytes("some-vault-id"),
             skip: Some(0),
             first: Some(10),
+            timestamp_lte: None,
+            block: None,
         };
 
         let result = client.query_page(variables).await;
@@ -386,11 +406,14 @@ mod tests {
             id: default_sg_bytes("vault-abc"),
             skip: Some(0),
             first: Some(10),
+            timestamp_lte: None,
+            block: Some(SgBlockHeight { number: 100 }),
         };
 
         // Setting both skip and first
         let vars1 = original_vars.with_pagination(Some(10), Some(20));
         assert_eq!(vars1.id.0, "vault-abc");
+        assert_eq!(vars1.block, Some(SgBlockHeight { number: 100 }));
         assert_eq!(vars1.skip, Some(10));
         assert_eq!(vars1.first, Some(20));
 
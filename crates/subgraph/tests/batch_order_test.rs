@@ -11,6 +11,7 @@ fn batch_order_query_gql_output() {
     let id = SgBytes("1234".to_string());
     let request_body = SgBatchOrderDetailQuery::build(SgBatchOrderDetailQueryVariables {
         id_list: SgOrderIdList { id_in: vec![id] },
+        block: None,
     });
 
     assert_snapshot!(request_body.query);
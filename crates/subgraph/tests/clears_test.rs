@@ -0,0 +1,17 @@
+use insta::assert_snapshot;
+use rain_orderbook_subgraph_client::types::clear::SgClearsListQuery;
+use rain_orderbook_subgraph_client::types::common::*;
+
+#[test]
+fn clears_query_gql_output() {
+    use cynic::QueryBuilder;
+
+    let request_body = SgClearsListQuery::build(SgClearsListQueryVariables {
+        first: Some(10),
+        skip: Some(0),
+        filters: None,
+        block: None,
+    });
+
+    assert_snapshot!(request_body.query);
+}
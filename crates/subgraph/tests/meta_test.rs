@@ -0,0 +1,11 @@
+use insta::assert_snapshot;
+use rain_orderbook_subgraph_client::types::meta::SgMetaQuery;
+
+#[test]
+fn meta_query_gql_output() {
+    use cynic::QueryBuilder;
+
+    let request_body = SgMetaQuery::build(());
+
+    assert_snapshot!(request_body.query);
+}
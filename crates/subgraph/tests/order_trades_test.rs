@@ -14,6 +14,8 @@ fn vaults_query_gql_output() {
         first: Some(10),
         timestamp_gte: None,
         timestamp_lte: None,
+        exclude_ids: None,
+        block: None,
     });
 
     assert_snapshot!(request_body.query);
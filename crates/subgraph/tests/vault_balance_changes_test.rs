@@ -12,6 +12,8 @@ fn vault_balance_changes_list_query_gql_output() {
         id: SgBytes(id.inner().to_string()),
         skip: None,
         first: None,
+        timestamp_lte: None,
+        block: None,
     });
 
     assert_snapshot!(request_body.query);
@@ -8,7 +8,10 @@ fn vaults_query_gql_output() {
     use cynic::QueryBuilder;
 
     let id = Id::new("1234");
-    let request_body = SgVaultDetailQuery::build(SgIdQueryVariables { id: &id });
+    let request_body = SgVaultDetailQuery::build(SgIdQueryVariables {
+        id: &id,
+        block: None,
+    });
 
     assert_snapshot!(request_body.query);
 }
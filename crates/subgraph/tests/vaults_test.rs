@@ -10,6 +10,8 @@ fn vaults_query_gql_output() {
         skip: Some(0),
         first: Some(10),
         filters: None,
+        order_direction: SgOrderDirection::Desc,
+        block: None,
     });
 
     assert_snapshot!(request_body.query);
@@ -17,6 +17,8 @@ import {
 	AllGuiConfig,
 	WasmEncodedResult,
 	FieldValue,
+	StateEnvelopeInfo,
+	OrderGuiMeta,
 	Float
 } from '../../dist/cjs';
 import { getLocal } from 'mockttp';
@@ -1039,11 +1041,40 @@ ${dotrain}`;
 			assert.equal(guiDeployment.deployment.order.outputs[0].vaultId, '0x1');
 		});
 
-		it('should throw error if given dotrain is different', async () => {
+		it('should restore a shareable state with its keys', async () => {
+			const shareable = extractWasmEncodedData<string>(
+				gui.serializeShareableState('some-order', undefined)
+			);
+			const info = extractWasmEncodedData<StateEnvelopeInfo>(
+				DotrainOrderGui.getStateEnvelopeInfo(shareable)
+			);
+			assert.equal(info.version, 1);
+			assert.equal(info.orderKey, 'some-order');
+			assert.equal(info.deploymentKey, 'other-deployment');
+			assert.equal(info.signer, undefined);
+
+			const restored = extractWasmEncodedData(
+				await DotrainOrderGui.newFromState(dotrain3, shareable)
+			);
+			const deposits = extractWasmEncodedData<TokenDeposit[]>(restored.getDeposits());
+			assert.equal(deposits.length, 2);
+			assert.equal(deposits[0].amount, '50.6');
+
+			const result = DotrainOrderGui.getStateEnvelopeInfo(serializedState);
+			if (!result.error) expect.fail('Expected error');
+			expect(result.error.msg).toBe('State has no envelope');
+		});
+
+		it('should throw error if given dotrain is different in strict mode', async () => {
 			let testDotrain = `${guiConfig}
 
 ${dotrainWithoutTokens}`;
-			const result = await DotrainOrderGui.newFromState(testDotrain, serializedState);
+			const result = await DotrainOrderGui.newFromState(
+				testDotrain,
+				serializedState,
+				undefined,
+				true
+			);
 			if (!result.error) expect.fail('Expected error');
 			expect(result.error.msg).toBe('Deserialized dotrain mismatch');
 			expect(result.error.readableMsg).toBe(
@@ -1314,6 +1345,40 @@ ${dotrain}`;
 			});
 		});
 
+		it('embeds the gui meta in add order calldata when enabled', async () => {
+			await mockServer
+				.forPost('/rpc-url')
+				.withBodyIncluding('0xf0cfdd37')
+				.thenSendJsonRpcResult(`0x${'0'.repeat(24) + '1'.repeat(40)}`);
+			// iStore() call
+			await mockServer
+				.forPost('/rpc-url')
+				.withBodyIncluding('0xc19423bc')
+				.thenSendJsonRpcResult(`0x${'0'.repeat(24) + '2'.repeat(40)}`);
+			// iParser() call
+			await mockServer
+				.forPost('/rpc-url')
+				.withBodyIncluding('0x24376855')
+				.thenSendJsonRpcResult(`0x${'0'.repeat(24) + '3'.repeat(40)}`);
+			// parse2() call
+			await mockServer
+				.forPost('/rpc-url')
+				.withBodyIncluding('0xa3869e14')
+				// 0x1234 encoded bytes
+				.thenSendJsonRpcResult(
+					'0x000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000021234000000000000000000000000000000000000000000000000000000000000'
+				);
+
+			gui.setFieldValue('test-binding', '10');
+			gui.setEmbedGuiMeta(true);
+
+			const addOrderCalldata = extractWasmEncodedData<string>(await gui.generateAddOrderCalldata());
+			const meta = extractWasmEncodedData<OrderGuiMeta>(gui.getOrderGuiMeta());
+			assert.ok(addOrderCalldata.length > 2186);
+			assert.ok(addOrderCalldata.includes(Buffer.from(meta.dotrain).toString('hex')));
+			assert.ok(addOrderCalldata.includes(Buffer.from(meta.deployment.state).toString('hex')));
+		});
+
 		it('generates add order calldata without entering field value', async () => {
 			await mockServer
 				.forPost('/rpc-url')
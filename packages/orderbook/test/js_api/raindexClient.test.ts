@@ -529,16 +529,37 @@ describe('Rain Orderbook JS API Package Bindgen Tests - Raindex Client', async f
 
 			const raindexClient = extractWasmEncodedData(RaindexClient.new([YAML]));
 
-			let orders = extractWasmEncodedData(await raindexClient.getOrders());
+			let result = extractWasmEncodedData(await raindexClient.getOrders());
+			let orders = result.items;
 			assert.equal(orders.length, 2);
 			assert.equal(orders[0].id, order1.id);
 			assert.equal(orders[1].id, order2.id);
+			assert.equal(result.subgraphStatuses.length, 2);
+			assert.ok(result.subgraphStatuses.every((status) => status.error === undefined));
 
-			orders = extractWasmEncodedData(await raindexClient.getOrders([1]));
+			result = extractWasmEncodedData(await raindexClient.getOrders([1]));
+			orders = result.items;
 			assert.equal(orders.length, 1);
 			assert.equal(orders[0].id, order1.id);
 		});
 
+		it('should report failed subgraphs when getting orders', async function () {
+			await mockServer
+				.forPost('/sg1')
+				.thenReply(200, JSON.stringify({ data: { orders: [order1] } }));
+			await mockServer.forPost('/sg2').thenReply(500, 'Internal Server Error');
+
+			const raindexClient = extractWasmEncodedData(RaindexClient.new([YAML]));
+
+			const result = extractWasmEncodedData(await raindexClient.getOrders());
+			assert.equal(result.items.length, 1);
+			assert.equal(result.items[0].id, order1.id);
+
+			const failed = result.subgraphStatuses.filter((status) => status.error !== undefined);
+			assert.equal(failed.length, 1);
+			assert.equal(failed[0].url, 'http://localhost:8230/sg2');
+		});
+
 		it('should get order by hash', async function () {
 			await mockServer
 				.forPost('/sg1')
@@ -125,7 +125,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[mockOrder]] },
+					data: { pages: [{ items: [mockOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -155,7 +155,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[mockOrder]] },
+					data: { pages: [{ items: [mockOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -231,7 +231,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[orderWithMultipleTokens]] },
+					data: { pages: [{ items: [orderWithMultipleTokens] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -265,7 +265,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[mockOrder]] },
+					data: { pages: [{ items: [mockOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true,
@@ -295,7 +295,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[mockOrder]] },
+					data: { pages: [{ items: [mockOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true,
@@ -332,7 +332,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[inactiveOrder]] },
+					data: { pages: [{ items: [inactiveOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -356,7 +356,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[inactiveOrder]] },
+					data: { pages: [{ items: [inactiveOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -381,7 +381,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[]] },
+					data: { pages: [{ items: [] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -406,7 +406,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[mockOrder]] },
+					data: { pages: [{ items: [mockOrder] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true
@@ -447,7 +447,7 @@ describe('OrdersListTable', () => {
 		mockQuery.createInfiniteQuery = vi.fn((__options, _queryClient) => ({
 			subscribe: (fn: (value: any) => void) => {
 				fn({
-					data: { pages: [[orderWithManyTrades]] },
+					data: { pages: [{ items: [orderWithManyTrades] }] },
 					status: 'success',
 					isFetching: false,
 					isFetched: true